@@ -137,3 +137,64 @@ impl From<DriverAdapterConstraint> for quaint::error::DatabaseConstraint {
         }
     }
 }
+
+/// A single problem found while validating the shape of a JS driver adapter object.
+#[derive(Debug, Clone)]
+pub enum AdapterValidationProblem {
+    /// A required property is absent from the adapter object.
+    MissingProperty { name: &'static str },
+    /// A property is present but isn't usable as-is, e.g. the wrong type or an unrecognized value.
+    InvalidProperty { name: &'static str, reason: String },
+}
+
+impl std::fmt::Display for AdapterValidationProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingProperty { name } => write!(f, "missing required property `{name}`"),
+            Self::InvalidProperty { name, reason } => write!(f, "invalid property `{name}`: {reason}"),
+        }
+    }
+}
+
+/// Every problem found while validating the shape of a JS driver adapter object passed to
+/// `queryable_from_js`, collected so the caller sees them all at once instead of failing on the
+/// first missing or malformed property. A hand-rolled adapter missing several methods is the
+/// common case this is meant to make easier to fix in one pass.
+#[derive(Debug, Clone)]
+pub struct AdapterValidationError {
+    pub problems: Vec<AdapterValidationProblem>,
+}
+
+impl std::fmt::Display for AdapterValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "The driver adapter object passed to the query engine is invalid:")?;
+        for problem in &self.problems {
+            writeln!(f, "  - {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AdapterValidationError {}
+
+#[cfg(test)]
+mod adapter_validation_tests {
+    use super::*;
+
+    #[test]
+    fn display_lists_every_problem() {
+        let err = AdapterValidationError {
+            problems: vec![
+                AdapterValidationProblem::MissingProperty { name: "queryRaw" },
+                AdapterValidationProblem::InvalidProperty {
+                    name: "provider",
+                    reason: "Unsupported adapter flavour: \"oracle\"".to_owned(),
+                },
+            ],
+        };
+
+        let message = err.to_string();
+        assert!(message.contains("missing required property `queryRaw`"));
+        assert!(message.contains("invalid property `provider`: Unsupported adapter flavour: \"oracle\""));
+    }
+}
@@ -123,8 +123,9 @@ impl TransactionCapable for JsQueryableDropGuard {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> quaint::Result<Box<dyn Transaction + 'a>> {
-        self.inner.start_transaction(isolation).await
+        self.inner.start_transaction(isolation, snapshot_id).await
     }
 }
 
@@ -177,6 +178,10 @@ impl QuaintQueryable for JsQueryableDropGuard {
     fn requires_isolation_first(&self) -> bool {
         self.inner.requires_isolation_first()
     }
+
+    async fn set_tx_snapshot(&self, snapshot_id: &str) -> quaint::Result<()> {
+        self.inner.set_tx_snapshot(snapshot_id).await
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -105,6 +105,7 @@ impl From<DriverAdapterError> for QuaintError {
     }
 }
 
+pub use error::AdapterValidationError;
 pub use factory::{adapter_factory_from_js, JsAdapterFactory};
 pub use queryable::{queryable_from_js, JsQueryable};
 pub(crate) use transaction::JsTransaction;
@@ -155,6 +156,25 @@ mod arch {
         }
     }
 
+    /// Fetches a required property, recording a problem instead of short-circuiting when it's
+    /// absent, so a caller validating a whole object can report every missing property at once.
+    pub(crate) fn required_property<T>(
+        object: &super::wasm::JsObjectExtern,
+        name: &'static str,
+        problems: &mut Vec<crate::error::AdapterValidationProblem>,
+    ) -> Option<T>
+    where
+        T: From<wasm_bindgen::JsValue>,
+    {
+        match get_named_property(object, name) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                problems.push(crate::error::AdapterValidationProblem::MissingProperty { name });
+                None
+            }
+        }
+    }
+
     fn has_named_property(object: &super::wasm::JsObjectExtern, name: &str) -> JsResult<bool> {
         js_sys::Reflect::has(object, &JsString::from_str(name).unwrap().into())
     }
@@ -195,6 +215,25 @@ mod arch {
         }
     }
 
+    /// Fetches a required property, recording a problem instead of short-circuiting when it's
+    /// absent, so a caller validating a whole object can report every missing property at once.
+    pub(crate) fn required_property<T>(
+        object: &::napi::JsObject,
+        name: &'static str,
+        problems: &mut Vec<crate::error::AdapterValidationProblem>,
+    ) -> Option<T>
+    where
+        T: ::napi::bindgen_prelude::FromNapiValue + ::napi::bindgen_prelude::ValidateNapiValue,
+    {
+        match get_named_property(object, name) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                problems.push(crate::error::AdapterValidationProblem::MissingProperty { name });
+                None
+            }
+        }
+    }
+
     fn has_named_property(object: &::napi::JsObject, name: &str) -> JsResult<bool> {
         object.has_named_property(name)
     }
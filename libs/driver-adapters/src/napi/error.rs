@@ -1,3 +1,4 @@
+use crate::error::AdapterValidationError;
 use futures::{Future, FutureExt};
 use napi::Error as NapiError;
 use quaint::error::Error as QuaintError;
@@ -12,6 +13,12 @@ pub(crate) fn into_quaint_error(napi_err: NapiError) -> QuaintError {
     QuaintError::raw_connector_error(status, reason)
 }
 
+impl From<AdapterValidationError> for NapiError {
+    fn from(err: AdapterValidationError) -> Self {
+        NapiError::from_reason(err.to_string())
+    }
+}
+
 /// catches a panic thrown during the execution of an asynchronous closure and transforms it into
 /// the Error variant of a napi::Result.
 pub(crate) async fn async_unwinding_panic<F, R>(fut: F) -> napi::Result<R>
@@ -1,10 +1,11 @@
+use crate::error::{AdapterValidationError, AdapterValidationProblem};
+use crate::queryable::JsQueryable;
 use crate::send_future::UnsafeFuture;
 use crate::types::JsConnectionInfo;
-pub use crate::types::{JsResultSet, Query, TransactionOptions};
-use crate::{conversion::MaybeDefined, queryable::JsQueryable};
+pub use crate::types::{JsResultSet, Query, StartTransactionOptions, TransactionOptions};
 use crate::{
-    from_js_value, get_named_property, get_optional_named_property, to_rust_str, AdapterMethod, JsObject, JsResult,
-    JsString, JsTransaction,
+    from_js_value, get_named_property, get_optional_named_property, required_property, to_rust_str, AdapterMethod,
+    JsObject, JsResult, JsString, JsTransaction,
 };
 
 use futures::Future;
@@ -55,8 +56,8 @@ pub(crate) struct DriverProxy {
     /// Retrieve driver-specific info, such as the maximum number of query parameters
     get_connection_info: Option<AdapterMethod<(), JsConnectionInfo>>,
 
-    /// Start a new transaction with a specific isolation level.
-    start_transaction: AdapterMethod<MaybeDefined<String>, JsTransaction>,
+    /// Start a new transaction with a specific isolation level and, optionally, a snapshot to import.
+    start_transaction: AdapterMethod<StartTransactionOptions, JsTransaction>,
 
     /// Dispose of the underlying driver.
     dispose: AdapterMethod<(), ()>,
@@ -78,21 +79,66 @@ pub(crate) struct TransactionProxy {
     closed: AtomicBool,
 }
 
-// TypeScript: Queryable
-impl CommonProxy {
-    pub fn new(object: &JsObject) -> JsResult<Self> {
-        let provider: JsString = get_named_property(object, "provider")?;
-        let provider: AdapterProvider = to_rust_str(provider)?.parse().unwrap();
+/// Reads and parses the `provider` property, recording a problem (missing, not a string, or an
+/// unrecognized provider name) instead of short-circuiting.
+fn required_provider(object: &JsObject, problems: &mut Vec<AdapterValidationProblem>) -> Option<AdapterProvider> {
+    let value: JsString = required_property(object, "provider", problems)?;
+    let name = match to_rust_str(value) {
+        Ok(name) => name,
+        Err(_) => {
+            problems.push(AdapterValidationProblem::InvalidProperty {
+                name: "provider",
+                reason: "not a string".to_owned(),
+            });
+            return None;
+        }
+    };
 
-        let adapter_name: JsString = get_named_property(object, "adapterName")?;
-        let adapter_name: AdapterName = to_rust_str(adapter_name)?.parse().unwrap();
+    match name.parse() {
+        Ok(provider) => Some(provider),
+        Err(reason) => {
+            problems.push(AdapterValidationProblem::InvalidProperty { name: "provider", reason });
+            None
+        }
+    }
+}
 
-        Ok(Self {
-            query_raw: get_named_property(object, "queryRaw")?,
-            execute_raw: get_named_property(object, "executeRaw")?,
-            provider,
-            adapter_name,
-        })
+/// Reads and parses the `adapterName` property, recording a problem instead of short-circuiting
+/// when it's missing or not a string. Unrecognized names fall back to `AdapterName::Unknown`,
+/// same as `AdapterName::from_str` does for any caller.
+fn required_adapter_name(object: &JsObject, problems: &mut Vec<AdapterValidationProblem>) -> Option<AdapterName> {
+    let value: JsString = required_property(object, "adapterName", problems)?;
+    match to_rust_str(value) {
+        Ok(name) => Some(name.parse().unwrap_or(AdapterName::Unknown)),
+        Err(_) => {
+            problems.push(AdapterValidationProblem::InvalidProperty {
+                name: "adapterName",
+                reason: "not a string".to_owned(),
+            });
+            None
+        }
+    }
+}
+
+// TypeScript: Queryable
+impl CommonProxy {
+    pub fn new(object: &JsObject) -> Result<Self, AdapterValidationError> {
+        let mut problems = Vec::new();
+
+        let query_raw = required_property(object, "queryRaw", &mut problems);
+        let execute_raw = required_property(object, "executeRaw", &mut problems);
+        let provider = required_provider(object, &mut problems);
+        let adapter_name = required_adapter_name(object, &mut problems);
+
+        match (query_raw, execute_raw, provider, adapter_name) {
+            (Some(query_raw), Some(execute_raw), Some(provider), Some(adapter_name)) => Ok(Self {
+                query_raw,
+                execute_raw,
+                provider,
+                adapter_name,
+            }),
+            _ => Err(AdapterValidationError { problems }),
+        }
     }
 
     pub async fn query_raw(&self, params: Query) -> quaint::Result<JsResultSet> {
@@ -142,23 +188,49 @@ impl AdapterFactoryProxy {
 
 // TypeScript: DriverAdapter
 impl DriverProxy {
-    pub fn new(object: &JsObject) -> JsResult<Self> {
-        Ok(Self {
-            execute_script: get_named_property(object, "executeScript")?,
-            start_transaction: get_named_property(object, "startTransaction")?,
-            get_connection_info: get_optional_named_property(object, "getConnectionInfo")?,
-            dispose: get_named_property(object, "dispose")?,
-        })
+    pub fn new(object: &JsObject) -> Result<Self, AdapterValidationError> {
+        let mut problems = Vec::new();
+
+        let execute_script = required_property(object, "executeScript", &mut problems);
+        let start_transaction = required_property(object, "startTransaction", &mut problems);
+        let dispose = required_property(object, "dispose", &mut problems);
+        let get_connection_info = match get_optional_named_property(object, "getConnectionInfo") {
+            Ok(value) => value,
+            Err(_) => {
+                problems.push(AdapterValidationProblem::InvalidProperty {
+                    name: "getConnectionInfo",
+                    reason: "wrong type".to_owned(),
+                });
+                None
+            }
+        };
+
+        match (execute_script, start_transaction, dispose) {
+            (Some(execute_script), Some(start_transaction), Some(dispose)) => Ok(Self {
+                execute_script,
+                start_transaction,
+                get_connection_info,
+                dispose,
+            }),
+            _ => Err(AdapterValidationError { problems }),
+        }
     }
 
     pub async fn execute_script(&self, script: String) -> quaint::Result<()> {
         UnsafeFuture(self.execute_script.call_as_async(script)).await
     }
 
-    async fn start_transaction_inner(&self, isolation: Option<IsolationLevel>) -> quaint::Result<Box<JsTransaction>> {
+    async fn start_transaction_inner(
+        &self,
+        isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
+    ) -> quaint::Result<Box<JsTransaction>> {
         let tx = self
             .start_transaction
-            .call_as_async(isolation.map(|lvl| lvl.to_string()).into())
+            .call_as_async(StartTransactionOptions {
+                isolation_level: isolation.map(|lvl| lvl.to_string()),
+                snapshot_id,
+            })
             .await?;
 
         // Decrement for this gauge is done in JsTransaction::commit/JsTransaction::rollback
@@ -172,8 +244,9 @@ impl DriverProxy {
     pub fn start_transaction(
         &self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> impl Future<Output = quaint::Result<Box<JsTransaction>>> + '_ {
-        UnsafeFuture(self.start_transaction_inner(isolation))
+        UnsafeFuture(self.start_transaction_inner(isolation, snapshot_id))
     }
 
     pub async fn get_connection_info(&self) -> quaint::Result<JsConnectionInfo> {
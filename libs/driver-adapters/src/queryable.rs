@@ -1,3 +1,4 @@
+use crate::error::AdapterValidationError;
 use crate::proxy::{CommonProxy, DriverProxy};
 use crate::types::{AdapterProvider, Query};
 use crate::{JsObject, JsResult};
@@ -183,6 +184,29 @@ impl QuaintQueryable for JsBaseQueryable {
             AdapterProvider::SqlServer => true,
         }
     }
+
+    async fn set_tx_snapshot(&self, snapshot_id: &str) -> quaint::Result<()> {
+        match self.provider {
+            #[cfg(feature = "postgresql")]
+            AdapterProvider::Postgres => {
+                let escaped_snapshot_id = snapshot_id.replace('\'', "''");
+                self.raw_cmd(&format!("SET TRANSACTION SNAPSHOT '{escaped_snapshot_id}'"))
+                    .await
+            }
+            #[cfg(feature = "mysql")]
+            AdapterProvider::Mysql => {
+                Err(quaint::error::Error::builder(quaint::error::ErrorKind::transaction_snapshot_unsupported()).build())
+            }
+            #[cfg(feature = "sqlite")]
+            AdapterProvider::Sqlite => {
+                Err(quaint::error::Error::builder(quaint::error::ErrorKind::transaction_snapshot_unsupported()).build())
+            }
+            #[cfg(feature = "mssql")]
+            AdapterProvider::SqlServer => {
+                Err(quaint::error::Error::builder(quaint::error::ErrorKind::transaction_snapshot_unsupported()).build())
+            }
+        }
+    }
 }
 
 impl JsBaseQueryable {
@@ -361,14 +385,19 @@ impl QuaintQueryable for JsQueryable {
     fn requires_isolation_first(&self) -> bool {
         self.inner.requires_isolation_first()
     }
+
+    async fn set_tx_snapshot(&self, snapshot_id: &str) -> quaint::Result<()> {
+        self.inner.set_tx_snapshot(snapshot_id).await
+    }
 }
 
 impl JsQueryable {
     async fn start_transaction_inner<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> quaint::Result<Box<dyn Transaction + 'a>> {
-        let tx = self.driver_proxy.start_transaction(isolation).await?;
+        let tx = self.driver_proxy.start_transaction(isolation, snapshot_id).await?;
         self.server_reset_query(tx.as_ref()).await?;
         Ok(tx)
     }
@@ -383,19 +412,20 @@ impl TransactionCapable for JsQueryable {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> quaint::Result<Box<dyn Transaction + 'a>> {
-        UnsafeFuture(self.start_transaction_inner(isolation)).await
+        UnsafeFuture(self.start_transaction_inner(isolation, snapshot_id)).await
     }
 }
 
-pub fn queryable_from_js(driver: JsObject) -> JsQueryable {
-    let common = CommonProxy::new(&driver).unwrap();
-    let driver_proxy = DriverProxy::new(&driver).unwrap();
+pub fn queryable_from_js(driver: JsObject) -> Result<JsQueryable, AdapterValidationError> {
+    let common = CommonProxy::new(&driver)?;
+    let driver_proxy = DriverProxy::new(&driver)?;
 
-    JsQueryable {
+    Ok(JsQueryable {
         inner: JsBaseQueryable::new(common),
         driver_proxy,
-    }
+    })
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -23,6 +23,12 @@ pub(crate) struct JsConnectionInfo {
     pub schema_name: Option<String>,
     pub max_bind_values: Option<u32>,
     pub supports_relation_joins: bool,
+
+    /// For adapters that can expose more than one schema over a single connection (e.g. D1 or
+    /// Turso with attached databases via `ATTACH DATABASE`), the full list of schema names the
+    /// adapter can resolve table references against. `None`/absent for adapters that don't
+    /// report it.
+    pub attached_schema_names: Option<Vec<String>>,
 }
 
 impl JsConnectionInfo {
@@ -33,6 +39,7 @@ impl JsConnectionInfo {
             self.max_bind_values.map(|v| v as usize),
             self.supports_relation_joins,
         )
+        .with_attached_schema_names(self.attached_schema_names.unwrap_or_default())
     }
 
     fn schema_name(&self, provider: &AdapterProvider) -> Option<&str> {
@@ -261,3 +268,16 @@ pub struct TransactionOptions {
     /// before opening a transaction, committing, or rollbacking.
     pub use_phantom_query: bool,
 }
+
+#[cfg_attr(not(target_arch = "wasm32"), napi_derive::napi(object))]
+#[cfg_attr(target_arch = "wasm32", derive(Serialize))]
+#[cfg_attr(target_arch = "wasm32", serde(rename_all = "camelCase"))]
+#[derive(Debug, Default)]
+pub struct StartTransactionOptions {
+    /// The isolation level to start the transaction with, if any.
+    pub isolation_level: Option<String>,
+
+    /// An exported snapshot to import into the transaction, so its reads observe the database as
+    /// it was when the snapshot was taken. Only supported by Postgres driver adapters.
+    pub snapshot_id: Option<String>,
+}
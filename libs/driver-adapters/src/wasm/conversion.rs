@@ -1,5 +1,5 @@
 use super::to_js::{serde_serialize, ToJsValue};
-use crate::conversion::{JSArg, JSArgType, MaybeDefined};
+use crate::conversion::{JSArg, JSArgType};
 use crate::types::Query;
 use js_sys::{Array, JsString, Object, Reflect, Uint8Array};
 use wasm_bindgen::JsValue;
@@ -55,12 +55,3 @@ impl ToJsValue for JSArgType {
         Ok(JsValue::from(self.to_string()))
     }
 }
-
-impl<V: ToJsValue> ToJsValue for MaybeDefined<V> {
-    fn to_js_value(&self) -> Result<wasm_bindgen::prelude::JsValue, wasm_bindgen::prelude::JsValue> {
-        match &self.0 {
-            Some(value) => value.to_js_value(),
-            None => Ok(JsValue::UNDEFINED),
-        }
-    }
-}
@@ -1,3 +1,4 @@
+use crate::error::AdapterValidationError;
 use js_sys::Reflect;
 use quaint::error::Error as QuaintError;
 use wasm_bindgen::JsValue;
@@ -11,3 +12,9 @@ pub(crate) fn into_quaint_error(wasm_err: JsValue) -> QuaintError {
         .unwrap_or_else(|| "Unknown error".to_string());
     QuaintError::raw_connector_error(status, reason)
 }
+
+impl From<AdapterValidationError> for JsValue {
+    fn from(err: AdapterValidationError) -> Self {
+        js_sys::Error::new(&err.to_string()).into()
+    }
+}
@@ -45,6 +45,9 @@ pub const PRISMA_DATASOURCE_QUERIES_TOTAL: &str = "prisma_datasource_queries_tot
 pub const PRISMA_CLIENT_QUERIES_ACTIVE: &str = "prisma_client_queries_active"; // gauge
 pub const PRISMA_CLIENT_QUERIES_DURATION_HISTOGRAM_MS: &str = "prisma_client_queries_duration_histogram_ms"; // histogram
 pub const PRISMA_DATASOURCE_QUERIES_DURATION_HISTOGRAM_MS: &str = "prisma_datasource_queries_duration_histogram_ms"; // histogram
+pub const PRISMA_DATASOURCE_QUERY_PARAMETER_COUNT: &str = "prisma_datasource_query_parameter_count"; // histogram
+pub const PRISMA_DATASOURCE_CHUNKED_STATEMENTS_TOTAL: &str = "prisma_datasource_chunked_statements_total"; // counter
+pub const PRISMA_DATASOURCE_SCHEMA_DRIFT_TOTAL: &str = "prisma_datasource_schema_drift_total"; // counter
 
 // metrics emitted by the connector pool implementation (mobc) that will be renamed using the `METRIC_RENAMES` map.
 const MOBC_POOL_CONNECTIONS_OPENED_TOTAL: &str = "mobc_pool_connections_opened_total"; // counter
@@ -63,6 +66,9 @@ pub const ACCEPT_LIST: &[&str] = &[
     PRISMA_CLIENT_QUERIES_ACTIVE,
     PRISMA_CLIENT_QUERIES_DURATION_HISTOGRAM_MS,
     PRISMA_DATASOURCE_QUERIES_DURATION_HISTOGRAM_MS,
+    PRISMA_DATASOURCE_QUERY_PARAMETER_COUNT,
+    PRISMA_DATASOURCE_CHUNKED_STATEMENTS_TOTAL,
+    PRISMA_DATASOURCE_SCHEMA_DRIFT_TOTAL,
     // third-party, emitted by mobc
     MOBC_POOL_CONNECTIONS_OPENED_TOTAL,
     MOBC_POOL_CONNECTIONS_CLOSED_TOTAL,
@@ -120,6 +126,18 @@ fn initialize_metrics_descriptions() {
         PRISMA_DATASOURCE_QUERIES_DURATION_HISTOGRAM_MS,
         "The distribution of the time datasource queries took to run"
     );
+    describe_histogram!(
+        PRISMA_DATASOURCE_QUERY_PARAMETER_COUNT,
+        "The distribution of the number of bind parameters used by datasource queries"
+    );
+    describe_counter!(
+        PRISMA_DATASOURCE_CHUNKED_STATEMENTS_TOTAL,
+        "The total number of datasource statements that were split into multiple queries to stay under the parameter limit"
+    );
+    describe_counter!(
+        PRISMA_DATASOURCE_SCHEMA_DRIFT_TOTAL,
+        "The total number of times a query result's columns didn't match the columns expected from the query, indicating the schema changed concurrently"
+    );
 }
 
 /// Initialize all metrics values (first and third-party)
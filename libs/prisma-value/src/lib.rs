@@ -100,6 +100,10 @@ pub enum PrismaValueType {
     Object,
     Bytes,
     Enum(String),
+    /// Wraps a type to signal that the placeholder it's attached to may resolve to `null`, e.g.
+    /// when it targets a nullable column. Executors must accept `null` in addition to the
+    /// wrapped type.
+    Nullable(Box<PrismaValueType>),
 }
 
 impl std::fmt::Display for PrismaValueType {
@@ -118,6 +122,7 @@ impl std::fmt::Display for PrismaValueType {
             PrismaValueType::Object => write!(f, "Object"),
             PrismaValueType::Bytes => write!(f, "Bytes"),
             PrismaValueType::Enum(name) => write!(f, "Enum<{name}>"),
+            PrismaValueType::Nullable(t) => write!(f, "{t}?"),
         }
     }
 }
@@ -9,6 +9,15 @@ pub enum Fragment {
         chunk: String,
     },
     Parameter,
+    /// References a parameter already bound by an earlier [`Fragment::Parameter`] instead of
+    /// binding a fresh, duplicate value. `index` is the position of the reused value in
+    /// [`QueryTemplate::parameters`](crate::QueryTemplate::parameters). Only produced for
+    /// placeholder syntaxes that support referencing the same bound parameter more than once
+    /// (e.g. Postgres' `$1`); never produced for positional-only syntaxes (e.g. MySQL's `?`).
+    #[serde(rename_all = "camelCase")]
+    ParameterRef {
+        index: usize,
+    },
     ParameterTuple,
     #[serde(rename_all = "camelCase")]
     ParameterTupleList {
@@ -28,6 +28,9 @@ impl<P> QueryTemplate<P> {
             match fragment {
                 Fragment::StringChunk { chunk } => sql.push_str(chunk),
                 Fragment::Parameter => self.placeholder_format.write(&mut sql, &mut placeholder_number)?,
+                Fragment::ParameterRef { index } => {
+                    self.placeholder_format.write(&mut sql, &mut (*index as i32 + 1))?
+                }
                 Fragment::ParameterTuple | Fragment::ParameterTupleList { .. } => return Err(fmt::Error), // Unsupported in Query Engine
             };
         }
@@ -46,6 +49,7 @@ impl<P> fmt::Display for QueryTemplate<P> {
             match fragment {
                 Fragment::StringChunk { chunk } => write!(f, "{chunk}")?,
                 Fragment::Parameter => self.placeholder_format.write(f, &mut placeholder_number)?,
+                Fragment::ParameterRef { index } => self.placeholder_format.write(f, &mut (*index as i32 + 1))?,
                 Fragment::ParameterTuple => {
                     f.write_str("[")?;
                     self.placeholder_format.write(f, &mut placeholder_number)?;
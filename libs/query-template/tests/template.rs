@@ -34,6 +34,26 @@ fn query_template_formatting_unnumbered() {
     assert!(qt.to_sql().is_err());
 }
 
+#[test]
+fn query_template_formatting_parameter_ref() {
+    let pf = PlaceholderFormat {
+        prefix: "$",
+        has_numbering: true,
+    };
+
+    let mut qt = new_common_query_template(pf);
+    qt.fragments.push(Fragment::StringChunk {
+        chunk: " AND parent_id = ".to_string(),
+    });
+    qt.fragments.push(Fragment::ParameterRef { index: 0 });
+
+    assert_eq!(qt.to_string(), "SELECT * FROM users WHERE id = $1 AND parent_id = $1");
+    assert_eq!(
+        qt.to_sql().unwrap(),
+        "SELECT * FROM users WHERE id = $1 AND parent_id = $1"
+    );
+}
+
 fn new_query_template(pf: PlaceholderFormat) -> QueryTemplate<Dummy> {
     let mut qt = new_common_query_template(pf);
     qt.fragments.push(Fragment::StringChunk {
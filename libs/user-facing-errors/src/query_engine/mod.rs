@@ -335,3 +335,12 @@ pub struct ExternalError {
 pub struct TooManyConnections {
     pub message: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2038",
+    message = "Query returned more than {limit} rows, which is the maximum allowed by the current configuration"
+)]
+pub struct TooManyRows {
+    pub limit: usize,
+}
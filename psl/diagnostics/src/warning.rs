@@ -70,6 +70,20 @@ impl DatamodelWarning {
         Self::new(message, span)
     }
 
+    pub fn new_query_timeout_exceeds_cap_warning(timeout_ms: u32, cap_ms: u32, span: Span) -> DatamodelWarning {
+        let message = format!(
+            "The query timeout of {timeout_ms}ms exceeds the maximum recommended timeout of {cap_ms}ms. Long-running timeouts can hold connections open and starve the pool."
+        );
+        Self::new(message, span)
+    }
+
+    pub fn new_tenant_field_without_index_warning(field_name: &str, span: Span) -> DatamodelWarning {
+        let message = format!(
+            "The tenant field `{field_name}` is not covered by the primary key or any index. Every query on this model will now filter on `{field_name}`, so we recommend adding an index (or including it in an existing one) to avoid full table scans."
+        );
+        Self::new(message, span)
+    }
+
     pub fn new_named_env_val(span: Span) -> Self {
         let message = "The env function doesn't expect named arguments".to_owned();
         Self::new(message, span)
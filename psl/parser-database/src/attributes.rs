@@ -1,9 +1,13 @@
+mod alias;
+mod computed_sql;
 mod default;
 mod id;
 mod map;
 mod native_types;
+mod query_timeout;
 mod schema;
 mod shard_key;
+mod tenant_field;
 
 use crate::{
     ast::{self, WithName, WithSpan},
@@ -18,7 +22,7 @@ use crate::{
 };
 use diagnostics::Span;
 use itertools::Itertools;
-use std::{borrow::Cow, cell::Cell, fmt::Display};
+use std::{borrow::Cow, cell::Cell, collections::HashMap, fmt::Display};
 
 pub(super) fn resolve_attributes(ctx: &mut Context<'_>) {
     for rfid in ctx.types.iter_relation_field_ids() {
@@ -87,6 +91,7 @@ fn resolve_composite_type_attributes<'db>(
 
 fn resolve_enum_attributes<'db>(enum_id: crate::EnumId, ast_enum: &'db ast::Enum, ctx: &mut Context<'db>) {
     let mut enum_attributes = EnumAttributes::default();
+    let mut seen_aliases: HashMap<StringId, ast::EnumValueId> = HashMap::new();
 
     for (value_id, _) in ast_enum.iter_values() {
         ctx.visit_attributes((enum_id.0, (enum_id.1, value_id)));
@@ -98,6 +103,30 @@ fn resolve_enum_attributes<'db>(enum_id: crate::EnumId, ast_enum: &'db ast::Enum
             }
             ctx.validate_visited_arguments();
         }
+        // @alias
+        if ctx.visit_optional_single_attr("alias") {
+            if let Some(alias) = alias::visit_alias_attribute(ctx) {
+                let collides_with_value = ast_enum
+                    .iter_values()
+                    .any(|(other_id, other_value)| other_id != value_id && ctx.interner.intern(other_value.name()) == alias);
+
+                if collides_with_value {
+                    ctx.push_attribute_validation_error(&format!(
+                        "The alias \"{}\" collides with the name of another value on this enum.",
+                        &ctx[alias]
+                    ));
+                } else if seen_aliases.contains_key(&alias) {
+                    ctx.push_attribute_validation_error(&format!(
+                        "The alias \"{}\" is already used by another value on this enum.",
+                        &ctx[alias]
+                    ));
+                } else {
+                    seen_aliases.insert(alias, value_id);
+                    enum_attributes.aliased_values.insert(value_id, alias);
+                }
+            }
+            ctx.validate_visited_arguments();
+        }
         ctx.validate_visited_attributes();
     }
 
@@ -183,6 +212,18 @@ fn resolve_model_attributes(model_id: crate::ModelId, ctx: &mut Context<'_>) {
         ctx.validate_visited_arguments();
     }
 
+    // @@queryTimeout
+    if ctx.visit_optional_single_attr("queryTimeout") {
+        query_timeout::model(&mut model_attributes, model_id, ctx);
+        ctx.validate_visited_arguments();
+    }
+
+    // @@tenantField
+    if ctx.visit_optional_single_attr("tenantField") {
+        tenant_field::model(&mut model_attributes, model_id, ctx);
+        ctx.validate_visited_arguments();
+    }
+
     // Model-global validations
     id::validate_id_field_arities(model_id, &model_attributes, ctx);
     shard_key::validate_shard_key_field_arities(model_id, &model_attributes, ctx);
@@ -254,6 +295,13 @@ fn visit_scalar_field_attributes(
         ctx.validate_visited_arguments();
     }
 
+    // @computedSql
+    if ctx.visit_optional_single_attr("computedSql") {
+        computed_sql::validate_not_in_write_context(ast_field, ctx);
+        computed_sql::visit_model_field_computed_sql(scalar_field_id, ctx);
+        ctx.validate_visited_arguments();
+    }
+
     if let ScalarFieldType::BuiltInScalar(_scalar_type) = r#type {
         // native type attributes
         if let Some((datasource_name, type_name, attribute_id)) = ctx.visit_datasource_scoped() {
@@ -610,11 +658,22 @@ fn common_index_validations(
         }
     };
 
-    match resolve_field_array_with_args(fields, current_attribute.span, model_id, resolving, ctx) {
+    match resolve_field_array_with_args(fields, model_id, resolving, ctx) {
         Ok(fields) => {
             index_data.fields = fields;
         }
         Err(FieldResolutionError::AlreadyDealtWith) => (),
+        Err(FieldResolutionError::DuplicateField { field_name }) => {
+            ctx.push_error(DatamodelError::new_model_validation_error(
+                &format!(
+                    "The {}index definition refers to the field {field_name} multiple times.",
+                    if index_data.is_unique() { "unique " } else { "" },
+                ),
+                "model",
+                ctx.asts[model_id].name(),
+                current_attribute.span,
+            ));
+        }
         Err(FieldResolutionError::ProblematicFields {
             unknown_fields: unresolvable_fields,
             relation_fields,
@@ -834,6 +893,10 @@ enum FieldResolutionError<'ast> {
         /// Fields that exist on the model but are relation fields.
         relation_fields: Vec<(&'ast ast::Field, ast::FieldId)>,
     },
+    /// A field is referenced more than once in the same field list.
+    DuplicateField {
+        field_name: Cow<'ast, str>,
+    },
 }
 
 /// Takes an attribute argument, validates it as an array of constants, then
@@ -924,7 +987,6 @@ impl FieldResolvingSetup {
 /// contains the fields that are not in the model.
 fn resolve_field_array_with_args<'db>(
     values: &'db ast::Expression,
-    attribute_span: ast::Span,
     model_id: crate::ModelId,
     resolving: FieldResolvingSetup,
     ctx: &mut Context<'db>,
@@ -1033,14 +1095,7 @@ fn resolve_field_array_with_args<'db>(
                 }
             };
 
-            ctx.push_error(DatamodelError::new_model_validation_error(
-                &format!("The unique index definition refers to the field {path_str} multiple times.",),
-                "model",
-                ast_model.name(),
-                attribute_span,
-            ));
-
-            return Err(FieldResolutionError::AlreadyDealtWith);
+            return Err(FieldResolutionError::DuplicateField { field_name: path_str });
         }
 
         field_ids.push(path);
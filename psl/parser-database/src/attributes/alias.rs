@@ -0,0 +1,14 @@
+use crate::{coerce, context::Context, StringId};
+
+pub(super) fn visit_alias_attribute(ctx: &mut Context<'_>) -> Option<StringId> {
+    match ctx
+        .visit_default_arg("name")
+        .map(|value| coerce::string(value, ctx.diagnostics))
+    {
+        Ok(Some(name)) => return Some(ctx.interner.intern(name)),
+        Err(err) => ctx.push_error(err), // not flattened for error handing legacy reasons
+        Ok(None) => (),
+    };
+
+    None
+}
@@ -0,0 +1,46 @@
+use crate::{ast, coerce, context::Context, ScalarFieldId};
+
+/// The placeholder that callers must use in a `@computedSql` expression to refer to the table
+/// alias of the model the field is declared on.
+const SELF_PLACEHOLDER: &str = "{{self}}";
+
+/// `@computedSql("...")` on model scalar fields.
+///
+/// Declares a read-only field whose value is computed by the database from a connector-validated
+/// SQL expression, rendered with `{{self}}` substituted for the current table alias wherever the
+/// field is selected or ordered by.
+pub(super) fn visit_model_field_computed_sql(scalar_field_id: ScalarFieldId, ctx: &mut Context<'_>) {
+    let (_argument_idx, value) = match ctx.visit_default_arg_with_idx("sql") {
+        Ok(value) => value,
+        Err(err) => return ctx.push_error(err),
+    };
+
+    let Some(expr) = coerce::string(value, ctx.diagnostics) else {
+        return;
+    };
+
+    if !expr.contains(SELF_PLACEHOLDER) {
+        ctx.push_attribute_validation_error(
+            "The `@computedSql` expression must reference the current row through the `{{self}}` placeholder.",
+        );
+        return;
+    }
+
+    if expr.contains(';') || expr.contains("--") || expr.contains("/*") {
+        ctx.push_attribute_validation_error(
+            "The `@computedSql` expression cannot contain statement separators (`;`) or comments.",
+        );
+        return;
+    }
+
+    let span = ctx.current_attribute().span;
+    let interned = ctx.interner.intern(expr);
+
+    ctx.types[scalar_field_id].computed_sql = Some((interned, span));
+}
+
+pub(super) fn validate_not_in_write_context(ast_field: &ast::Field, ctx: &mut Context<'_>) {
+    if ast_field.arity.is_list() {
+        ctx.push_attribute_validation_error("Fields marked with `@computedSql` cannot be lists.");
+    }
+}
@@ -195,10 +195,10 @@ fn validate_model_builtin_scalar_type_default(
         | (ScalarType::BigInt, ast::Expression::Function(funcname, funcargs, _))
             if funcname == FN_AUTOINCREMENT =>
         {
-            validate_empty_function_args(funcname, &funcargs.arguments, accept, ctx)
+            validate_autoincrement_args(&funcargs.arguments, accept, ctx)
         }
         (ScalarType::String, ast::Expression::Function(funcname, funcargs, _)) if funcname == FN_ULID => {
-            validate_empty_function_args(funcname, &funcargs.arguments, accept, ctx)
+            validate_ulid_args(&funcargs.arguments, accept, ctx)
         }
         (ScalarType::String, ast::Expression::Function(funcname, funcargs, _)) if funcname == FN_CUID => {
             validate_uid_int_args(funcname, &funcargs.arguments, &CUID_SUPPORTED_VERSIONS, accept, ctx)
@@ -248,7 +248,7 @@ fn validate_composite_builtin_scalar_type_default(
     match (scalar_type, value) {
         // Functions
         (ScalarType::String, ast::Expression::Function(funcname, funcargs, _)) if funcname == FN_ULID => {
-            validate_empty_function_args(funcname, &funcargs.arguments, accept, ctx)
+            validate_ulid_args(&funcargs.arguments, accept, ctx)
         }
         (ScalarType::String, ast::Expression::Function(funcname, funcargs, _)) if funcname == FN_CUID => {
             validate_uid_int_args(funcname, &funcargs.arguments, &CUID_SUPPORTED_VERSIONS, accept, ctx)
@@ -359,6 +359,56 @@ fn validate_empty_function_args(fn_name: &str, args: &[ast::Argument], accept: A
     ));
 }
 
+/// `start`/`increment` let a user pin down the sequence `autoincrement()` is backed by, e.g.
+/// `@id @default(autoincrement(start: 100, increment: 5))`. They only make sense together with
+/// `autoincrement()` itself, so they're validated here rather than as general-purpose arguments.
+fn validate_autoincrement_args(args: &[ast::Argument], accept: AcceptFn<'_>, ctx: &mut Context<'_>) {
+    let bail = |ctx: &mut Context<'_>, message: &str| ctx.push_attribute_validation_error(message);
+
+    for arg in args {
+        match arg.name.as_ref().map(|name| name.name.as_str()) {
+            Some("start") | Some("increment") => {
+                if !matches!(arg.value, ast::Expression::NumericValue(_, _)) {
+                    return bail(
+                        ctx,
+                        "`autoincrement()`'s `start` and `increment` arguments must be integers.",
+                    );
+                }
+            }
+            _ => {
+                return bail(
+                    ctx,
+                    "`autoincrement()` only takes the optional `start` and `increment` integer arguments.",
+                );
+            }
+        }
+    }
+
+    accept(ctx)
+}
+
+/// `monotonic` makes `ulid()` use a process-wide monotonic generator instead of a fresh random
+/// one per call, so ids created in the same millisecond (e.g. within a single `createMany`) still
+/// sort in creation order.
+fn validate_ulid_args(args: &[ast::Argument], accept: AcceptFn<'_>, ctx: &mut Context<'_>) {
+    let bail = |ctx: &mut Context<'_>, message: &str| ctx.push_attribute_validation_error(message);
+
+    for arg in args {
+        match arg.name.as_ref().map(|name| name.name.as_str()) {
+            Some("monotonic") => {
+                if !matches!(&arg.value, ast::Expression::ConstantValue(val, _) if val == "true" || val == "false") {
+                    return bail(ctx, "`ulid()`'s `monotonic` argument must be a Boolean.");
+                }
+            }
+            _ => {
+                return bail(ctx, "`ulid()` only takes the optional Boolean `monotonic` argument.");
+            }
+        }
+    }
+
+    accept(ctx)
+}
+
 fn validate_auto_args(args: &[ast::Argument], accept: AcceptFn<'_>, ctx: &mut Context<'_>) {
     if !args.is_empty() {
         ctx.push_attribute_validation_error("`auto()` takes no arguments");
@@ -431,22 +481,37 @@ fn validate_uid_int_args<const N: usize>(
     }
 }
 
+/// `nanoid()` takes an optional `length` and, behind that, an optional custom `alphabet` to draw
+/// characters from (e.g. `nanoid(21, "0123456789abcdef")`), so the alphabet can only be given
+/// together with an explicit length.
 fn validate_nanoid_args(args: &[ast::Argument], accept: AcceptFn<'_>, ctx: &mut Context<'_>) {
-    let mut bail = || ctx.push_attribute_validation_error("`nanoid()` takes a single Int argument.");
+    let mut bail = || {
+        ctx.push_attribute_validation_error(
+            "`nanoid()` takes either no argument, a single integer argument, or an integer argument followed by a string alphabet argument.",
+        )
+    };
 
-    if args.len() > 1 {
-        bail()
+    if args.len() > 2 {
+        return bail();
     }
 
-    match args.first().map(|arg| &arg.value) {
-        Some(ast::Expression::NumericValue(val, _)) => match val.parse::<u8>().ok() {
-            Some(val) if val >= 2 => accept(ctx),
-            _ => {
-                ctx.push_attribute_validation_error(
-                    "`nanoid()` takes either no argument, or a single integer argument between 2 and 255.",
-                );
-            }
-        },
+    let length_is_valid = match args.first().map(|arg| &arg.value) {
+        Some(ast::Expression::NumericValue(val, _)) => val.parse::<u8>().is_ok_and(|val| val >= 2),
+        None => true,
+        _ => false,
+    };
+
+    if !length_is_valid {
+        return ctx.push_attribute_validation_error(
+            "`nanoid()` takes either no argument, or a single integer argument between 2 and 255.",
+        );
+    }
+
+    match args.get(1).map(|arg| &arg.value) {
+        Some(ast::Expression::StringValue(val, _)) if val.is_empty() => {
+            ctx.push_attribute_validation_error("`nanoid()`'s alphabet argument must not be an empty string.");
+        }
+        Some(ast::Expression::StringValue(_, _)) => accept(ctx),
         None => accept(ctx),
         _ => bail(),
     }
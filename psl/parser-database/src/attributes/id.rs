@@ -1,6 +1,6 @@
 use super::{FieldResolutionError, FieldResolvingSetup};
 use crate::{
-    ast::{self, WithName, WithSpan},
+    ast::{self, WithIdentifier, WithName, WithSpan},
     attributes::{format_fields_in_error_with_leading_word, resolve_field_array_with_args},
     coerce,
     context::Context,
@@ -19,9 +19,17 @@ pub(super) fn model(model_data: &mut ModelAttributes, model_id: crate::ModelId,
 
     let resolving = FieldResolvingSetup::OnlyTopLevel;
 
-    let resolved_fields = match resolve_field_array_with_args(fields, attr.span, model_id, resolving, ctx) {
+    let resolved_fields = match resolve_field_array_with_args(fields, model_id, resolving, ctx) {
         Ok(fields) => fields,
         Err(FieldResolutionError::AlreadyDealtWith) => return,
+        Err(FieldResolutionError::DuplicateField { field_name }) => {
+            return ctx.push_error(DatamodelError::new_model_validation_error(
+                &format!("The multi field id declaration refers to the field {field_name} multiple times."),
+                "model",
+                ctx.asts[model_id].name(),
+                attr.span,
+            ));
+        }
         Err(FieldResolutionError::ProblematicFields {
             unknown_fields: unresolvable_fields,
             relation_fields,
@@ -52,19 +60,17 @@ pub(super) fn model(model_data: &mut ModelAttributes, model_id: crate::ModelId,
                 ));
             }
 
-            if !relation_fields.is_empty() {
-                let field_names = relation_fields.iter().map(|(f, _)| f.name());
-
+            for (field, _) in &relation_fields {
                 let msg = format!(
-                    "The id definition refers to the relation {}. ID definitions must reference only scalar fields.",
-                    format_fields_in_error_with_leading_word(field_names)
+                    "The id definition refers to the relation field {}. ID definitions must reference only scalar fields.",
+                    format_fields_in_error_with_leading_word(std::iter::once(field.name()))
                 );
 
                 ctx.push_error(DatamodelError::new_model_validation_error(
                     &msg,
                     "model",
                     ctx.asts[model_id].name(),
-                    attr.span,
+                    field.identifier().span(),
                 ));
             }
 
@@ -74,6 +80,38 @@ pub(super) fn model(model_data: &mut ModelAttributes, model_id: crate::ModelId,
 
     let ast_model = &ctx.asts[model_id];
 
+    // `@@id` does not resolve `a.b` paths into a composite type like `@@unique`/`@@index` do (see
+    // `FieldResolvingSetup::OnlyTopLevel` above), so a resolved field can only be a composite type
+    // here if it was referenced directly, by the name of the composite-typed field itself. That
+    // can never form a valid id, so it gets its own precise error instead of silently becoming
+    // part of the primary key.
+    let mut has_composite_field_error = false;
+
+    for field in &resolved_fields {
+        if let either::Either::Left(id) = field.path.field_in_index() {
+            let ScalarField { model_id, field_id, r#type, .. } = ctx.types[id];
+
+            if let Some(ctid) = r#type.as_composite_type() {
+                has_composite_field_error = true;
+                let field_name = ctx.asts[model_id][field_id].name();
+                let composite_type_name = ctx.asts[ctid].name();
+
+                ctx.push_error(DatamodelError::new_model_validation_error(
+                    &format!(
+                        "`@@id` cannot reference fields of composite type {composite_type_name}. The field `{field_name}` has that type."
+                    ),
+                    "model",
+                    ast_model.name(),
+                    attr.span,
+                ));
+            }
+        }
+    }
+
+    if has_composite_field_error {
+        return;
+    }
+
     // ID attribute fields must reference only required fields.
     let fields_that_are_not_required: Vec<&str> = resolved_fields
         .iter()
@@ -0,0 +1,65 @@
+use diagnostics::{DatamodelError, DatamodelWarning};
+use schema_ast::ast::{WithName, WithSpan};
+
+use crate::{coerce, types::ModelAttributes, Context};
+
+/// Timeouts longer than this are most likely a mistake: the connection is held open for the
+/// whole duration, which can starve the pool. `@@queryTimeout` still accepts larger values, but
+/// warns.
+const MAX_RECOMMENDED_QUERY_TIMEOUT_MS: u32 = 24 * 60 * 60 * 1000;
+
+/// `@@queryTimeout` on models
+pub(super) fn model(model_data: &mut ModelAttributes, model_id: crate::ModelId, ctx: &mut Context<'_>) {
+    let source_attribute = ctx.current_attribute_id();
+
+    let read_ms = match visit_timeout_arg("read", model_id, ctx) {
+        Some(ms) => ms,
+        None => return,
+    };
+
+    let write_ms = match visit_timeout_arg("write", model_id, ctx) {
+        Some(ms) => ms,
+        None => return,
+    };
+
+    model_data.query_timeout = Some(crate::types::QueryTimeoutAttribute {
+        read_ms,
+        write_ms,
+        source_attribute,
+    });
+}
+
+fn visit_timeout_arg(name: &'static str, model_id: crate::ModelId, ctx: &mut Context<'_>) -> Option<u32> {
+    let arg = match ctx.visit_default_arg(name) {
+        Ok(arg) => arg,
+        Err(err) => {
+            ctx.push_error(err);
+            return None;
+        }
+    };
+    let span = arg.span();
+
+    let ms = coerce::integer(arg, ctx.diagnostics)?;
+
+    if ms <= 0 {
+        ctx.push_error(DatamodelError::new_model_validation_error(
+            &format!("The `{name}` argument of `@@queryTimeout` must be a positive number of milliseconds."),
+            "model",
+            ctx.asts[model_id].name(),
+            span,
+        ));
+        return None;
+    }
+
+    let ms = ms as u32;
+
+    if ms > MAX_RECOMMENDED_QUERY_TIMEOUT_MS {
+        ctx.push_warning(DatamodelWarning::new_query_timeout_exceeds_cap_warning(
+            ms,
+            MAX_RECOMMENDED_QUERY_TIMEOUT_MS,
+            span,
+        ));
+    }
+
+    Some(ms)
+}
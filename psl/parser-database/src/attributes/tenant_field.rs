@@ -0,0 +1,107 @@
+use diagnostics::DatamodelError;
+use schema_ast::ast::{self, WithName, WithSpan};
+
+use crate::{
+    attributes::{format_fields_in_error_with_leading_word, resolve_field_array_without_args, FieldResolutionError},
+    types::{ModelAttributes, ScalarField, TenantFieldAttribute},
+    Context,
+};
+
+/// `@@tenantField` on models
+pub(super) fn model(model_data: &mut ModelAttributes, model_id: crate::ModelId, ctx: &mut Context<'_>) {
+    let attr = ctx.current_attribute();
+    let fields = match ctx.visit_default_arg("fields") {
+        Ok(value) => value,
+        Err(err) => return ctx.push_error(err),
+    };
+
+    let resolved_fields = match resolve_field_array_without_args(fields, attr.span, model_id, ctx) {
+        Ok(fields) => fields,
+        Err(FieldResolutionError::AlreadyDealtWith) => return,
+        Err(FieldResolutionError::ProblematicFields {
+            unknown_fields: unresolvable_fields,
+            relation_fields,
+        }) => {
+            if !unresolvable_fields.is_empty() {
+                let field_names = unresolvable_fields.into_iter().map(|(_, field_name)| field_name);
+
+                let msg = format!(
+                    "The tenant field declaration refers to the unknown {}.",
+                    format_fields_in_error_with_leading_word(field_names),
+                );
+
+                ctx.push_error(DatamodelError::new_model_validation_error(
+                    &msg,
+                    "model",
+                    ctx.asts[model_id].name(),
+                    fields.span(),
+                ));
+            }
+
+            if !relation_fields.is_empty() {
+                let field_names = relation_fields.iter().map(|(f, _)| f.name());
+
+                let msg = format!(
+                    "The tenant field definition refers to the relation {}. `@@tenantField` must reference only scalar fields.",
+                    format_fields_in_error_with_leading_word(field_names),
+                );
+
+                ctx.push_error(DatamodelError::new_model_validation_error(
+                    &msg,
+                    "model",
+                    ctx.asts[model_id].name(),
+                    attr.span,
+                ));
+            }
+
+            return;
+        }
+    };
+
+    let ast_model = &ctx.asts[model_id];
+
+    if resolved_fields.len() > 1 {
+        ctx.push_error(DatamodelError::new_model_validation_error(
+            "`@@tenantField` takes a single field. Composite tenant keys are not supported.",
+            "model",
+            ast_model.name(),
+            attr.span,
+        ));
+        return;
+    }
+
+    let Some(field) = resolved_fields.into_iter().next() else {
+        return;
+    };
+
+    let ScalarField { model_id, field_id, .. } = ctx.types[field];
+    let ast_field = &ctx.asts[model_id][field_id];
+
+    if !ast_field.arity.is_required() {
+        ctx.push_error(DatamodelError::new_model_validation_error(
+            &format!(
+                "The tenant field definition refers to the optional field `{}`. Tenant fields must be required, so the engine can always inject a value.",
+                ast_field.name(),
+            ),
+            "model",
+            ast_model.name(),
+            attr.span,
+        ));
+        return;
+    }
+
+    if model_data.tenant_field.is_some() {
+        ctx.push_error(DatamodelError::new_model_validation_error(
+            "Each model must have at most one `@@tenantField`.",
+            "model",
+            ast_model.name(),
+            attr.span,
+        ));
+        return;
+    }
+
+    model_data.tenant_field = Some(TenantFieldAttribute {
+        field,
+        source_attribute: ctx.current_attribute_id(),
+    });
+}
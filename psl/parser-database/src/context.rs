@@ -2,8 +2,8 @@ mod attributes;
 
 use self::attributes::AttributesValidationState;
 use crate::{
-    ast, interner::StringInterner, names::Names, relations::Relations, types::Types, DatamodelError, Diagnostics,
-    InFile, StringId,
+    ast, interner::StringInterner, names::Names, relations::Relations, types::Types, DatamodelError, DatamodelWarning,
+    Diagnostics, InFile, StringId,
 };
 use schema_ast::ast::{EnumValueId, Expression, WithName};
 use std::collections::{HashMap, HashSet};
@@ -93,6 +93,10 @@ impl<'db> Context<'db> {
         self.push_error(err);
     }
 
+    pub(super) fn push_warning(&mut self, warning: DatamodelWarning) {
+        self.diagnostics.push_warning(warning)
+    }
+
     /// We need special code for scalar field attribute validation, because the
     /// attributes on a scalar field are the attributes on the scalar field
     /// itself, plus the attributes on the type alias it may be using. That type
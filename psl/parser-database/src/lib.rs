@@ -42,7 +42,7 @@ mod types;
 use self::{context::Context, interner::StringId, relations::Relations, types::Types};
 pub use coerce_expression::{coerce, coerce_array, coerce_opt};
 pub use diagnostics::FileId;
-use diagnostics::{DatamodelError, Diagnostics};
+use diagnostics::{DatamodelError, DatamodelWarning, Diagnostics};
 pub use files::Files;
 pub use ids::*;
 pub use names::is_reserved_type_name;
@@ -288,6 +288,9 @@ pub(crate) struct ScalarField {
     ///
     /// For example: `@db.Text` would translate to ("db", "Text", &[], <the span>)
     pub(crate) native_type: Option<(StringId, StringId, Vec<String>, ast::Span)>,
+    /// `@computedSql("...")`: a read-only, database-computed expression using `{{self}}` as a
+    /// placeholder for the current table alias.
+    pub(crate) computed_sql: Option<(StringId, ast::Span)>,
 }
 
 #[derive(Debug)]
@@ -345,6 +348,10 @@ pub(crate) struct ModelAttributes {
     pub(crate) schema: Option<(StringId, ast::Span)>,
     /// @(@)shardKey
     pub(crate) shard_key: Option<ShardKeyAttribute>,
+    /// @@queryTimeout
+    pub(crate) query_timeout: Option<QueryTimeoutAttribute>,
+    /// @@tenantField
+    pub(crate) tenant_field: Option<TenantFieldAttribute>,
 }
 
 /// A type of index as defined by the `type: ...` argument on an index attribute.
@@ -514,6 +521,21 @@ pub(crate) struct ShardKeyAttribute {
     pub(super) source_attribute: crate::AttributeId,
 }
 
+/// `@@tenantField(fields: [tenantId])` on a model.
+#[derive(Debug)]
+pub(crate) struct TenantFieldAttribute {
+    pub(crate) field: ScalarFieldId,
+    pub(super) source_attribute: crate::AttributeId,
+}
+
+/// `@@queryTimeout(read: ..., write: ...)` on a model. Both durations are in milliseconds.
+#[derive(Debug)]
+pub(crate) struct QueryTimeoutAttribute {
+    pub(crate) read_ms: u32,
+    pub(crate) write_ms: u32,
+    pub(super) source_attribute: crate::AttributeId,
+}
+
 /// Defines a path to a field that is not directly in the model.
 ///
 /// ```ignore
@@ -639,6 +661,9 @@ pub(super) struct EnumAttributes {
     pub(super) mapped_name: Option<StringId>,
     /// @map on enum values.
     pub(super) mapped_values: HashMap<EnumValueId, StringId>,
+    /// @alias on enum values: an additional name accepted on input and mapped to the value it is
+    /// declared on, but never produced as output.
+    pub(super) aliased_values: HashMap<EnumValueId, StringId>,
     /// ```ignore
     /// @@schema("public")
     ///          ^^^^^^^^
@@ -663,6 +688,7 @@ fn visit_model<'db>(model_id: crate::ModelId, ast_model: &'db ast::Model, ctx: &
                     default: None,
                     mapped_name: None,
                     native_type: None,
+                    computed_sql: None,
                 });
             }
             Err(supported) => {
@@ -122,4 +122,20 @@ impl<'db> EnumValueWalker<'db> {
             .get(&(self.id.1))
             .map(|id| &self.db[*id])
     }
+
+    /// The alias of the value, accepted as an additional name on input and mapped back to this
+    /// value, but never produced as output:
+    ///
+    /// ```ignore
+    /// enum Status {
+    ///     ACTIVE @map("active") @alias("enabled")
+    ///                                   ^^^^^^^^
+    /// }
+    /// ```
+    pub fn alias(self) -> Option<&'db str> {
+        self.db.types.enum_attributes[&self.id.0]
+            .aliased_values
+            .get(&(self.id.1))
+            .map(|id| &self.db[*id])
+    }
 }
@@ -1,9 +1,13 @@
 mod primary_key;
+mod query_timeout;
 mod shard_key;
+mod tenant_field;
 mod unique_criteria;
 
 pub use primary_key::*;
+pub use query_timeout::*;
 pub use shard_key::*;
+pub use tenant_field::*;
 
 pub(crate) use unique_criteria::*;
 
@@ -130,6 +134,24 @@ impl<'db> ModelWalker<'db> {
         })
     }
 
+    /// The `@@queryTimeout` of the model, if defined.
+    pub fn query_timeout(self) -> Option<QueryTimeoutWalker<'db>> {
+        self.attributes().query_timeout.as_ref().map(|qt| QueryTimeoutWalker {
+            model_id: self.id,
+            attribute: qt,
+            db: self.db,
+        })
+    }
+
+    /// The `@@tenantField` of the model, if defined.
+    pub fn tenant_field(self) -> Option<TenantFieldWalker<'db>> {
+        self.attributes().tenant_field.as_ref().map(|tf| TenantFieldWalker {
+            model_id: self.id,
+            attribute: tf,
+            db: self.db,
+        })
+    }
+
     /// Iterate all the scalar fields in a given model in the order they were defined.
     pub fn scalar_fields(self) -> impl Iterator<Item = ScalarFieldWalker<'db>> + Clone {
         self.db
@@ -0,0 +1,31 @@
+use crate::{ast, types::QueryTimeoutAttribute, walkers::ModelWalker, ParserDatabase};
+
+/// A `@@queryTimeout` attribute in the schema.
+#[derive(Copy, Clone)]
+pub struct QueryTimeoutWalker<'db> {
+    pub(crate) model_id: crate::ModelId,
+    pub(crate) attribute: &'db QueryTimeoutAttribute,
+    pub(crate) db: &'db ParserDatabase,
+}
+
+impl<'db> QueryTimeoutWalker<'db> {
+    /// The `@@queryTimeout` AST node.
+    pub fn ast_attribute(self) -> &'db ast::Attribute {
+        &self.db.asts[(self.model_id.0, self.attribute.source_attribute.1)]
+    }
+
+    /// The model the query timeout is defined on.
+    pub fn model(self) -> ModelWalker<'db> {
+        self.db.walk(self.model_id)
+    }
+
+    /// The timeout applied to read statements (`SELECT`), in milliseconds.
+    pub fn read_ms(self) -> u32 {
+        self.attribute.read_ms
+    }
+
+    /// The timeout applied to write statements (`INSERT`/`UPDATE`/`DELETE`), in milliseconds.
+    pub fn write_ms(self) -> u32 {
+        self.attribute.write_ms
+    }
+}
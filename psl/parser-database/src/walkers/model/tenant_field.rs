@@ -0,0 +1,31 @@
+use crate::{
+    ast,
+    types::TenantFieldAttribute,
+    walkers::{ModelWalker, ScalarFieldWalker},
+    ParserDatabase,
+};
+
+/// A `@@tenantField` attribute in the schema.
+#[derive(Copy, Clone)]
+pub struct TenantFieldWalker<'db> {
+    pub(crate) model_id: crate::ModelId,
+    pub(crate) attribute: &'db TenantFieldAttribute,
+    pub(crate) db: &'db ParserDatabase,
+}
+
+impl<'db> TenantFieldWalker<'db> {
+    /// The `@@tenantField` AST node.
+    pub fn ast_attribute(self) -> &'db ast::Attribute {
+        &self.db.asts[(self.model_id.0, self.attribute.source_attribute.1)]
+    }
+
+    /// The model the tenant field is defined on.
+    pub fn model(self) -> ModelWalker<'db> {
+        self.db.walk(self.model_id)
+    }
+
+    /// The scalar field that holds the tenant identifier.
+    pub fn field(self) -> ScalarFieldWalker<'db> {
+        self.db.walk(self.attribute.field)
+    }
+}
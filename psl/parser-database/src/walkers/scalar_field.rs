@@ -125,6 +125,17 @@ impl<'db> ScalarFieldWalker<'db> {
         self.walk(self.attributes().model_id)
     }
 
+    /// The `@computedSql("...")` expression declared on the field, if any, with `{{self}}` left
+    /// unsubstituted. Computed fields are read-only and database-generated.
+    pub fn computed_sql(self) -> Option<&'db str> {
+        self.attributes().computed_sql.map(|(id, _)| &self.db[id])
+    }
+
+    /// Whether the field is a database-computed, read-only `@computedSql` field.
+    pub fn is_computed(self) -> bool {
+        self.attributes().computed_sql.is_some()
+    }
+
     /// (attribute scope, native type name, arguments, span)
     ///
     /// For example: `@db.Text` would translate to ("db", "Text", &[], <the span>)
@@ -61,7 +61,8 @@ pub const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Conne
     RowIn |
     SupportsFiltersOnRelationsWithoutJoins |
     CorrelatedSubqueries |
-    SupportsDefaultInInsert
+    SupportsDefaultInInsert |
+    SpatialFiltering
 });
 
 const CONSTRAINT_SCOPES: &[ConstraintScope] = &[ConstraintScope::GlobalForeignKey, ConstraintScope::ModelKeyIndex];
@@ -140,6 +141,9 @@ impl Connector for MySqlDatamodelConnector {
             Timestamp(_) => ScalarType::DateTime,
             //Json
             Json => ScalarType::Json,
+            //Spatial, represented as GeoJSON
+            Point => ScalarType::Json,
+            Geometry(_) => ScalarType::Json,
             //Bytes
             LongBlob => ScalarType::Bytes,
             Binary(_) => ScalarType::Bytes,
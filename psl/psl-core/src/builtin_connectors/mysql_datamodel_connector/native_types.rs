@@ -33,6 +33,9 @@ crate::native_type_definition! {
     Timestamp(Option<u32>) -> DateTime,
     Year -> Int,
     Json -> Json,
+    // Represented as GeoJSON, carried around as a Prisma `Json` value.
+    Point -> Json,
+    Geometry(Option<u32>) -> Json,
 }
 
 impl MySqlType {
@@ -72,7 +72,8 @@ pub const CAPABILITIES: ConnectorCapabilities = enumflags2::make_bitflags!(Conne
     DeleteReturning |
     SupportsFiltersOnRelationsWithoutJoins |
     LateralJoin |
-    SupportsDefaultInInsert
+    SupportsDefaultInInsert |
+    LtreeFilters
 });
 
 pub struct PostgresDatamodelConnector;
@@ -310,6 +311,7 @@ impl Connector for PostgresDatamodelConnector {
             Xml => ScalarType::String,
             Inet => ScalarType::String,
             Citext => ScalarType::String,
+            Ltree => ScalarType::String,
             // Boolean
             Boolean => ScalarType::Boolean,
             // Int
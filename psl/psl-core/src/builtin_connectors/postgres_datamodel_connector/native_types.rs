@@ -1,3 +1,7 @@
+// No `LargeObject` entry: this table only governs column typing (and the plain `bytea` codec the
+// query engine already speaks). Storing `Bytes` via Postgres large objects needs a chunked
+// lo_create/lo_put write path, OID-aware reads, and a delete-time unlink hook across the
+// connector and query engine, which is a larger streaming-I/O project than a native type mapping.
 crate::native_type_definition! {
     PostgresType;
     SmallInt -> Int,
@@ -8,6 +12,7 @@ crate::native_type_definition! {
     Inet -> String,
     Oid -> Int,
     Citext -> String,
+    Ltree -> String,
     Real -> Float,
     DoublePrecision -> Float,
     VarChar(Option<u32>) -> String,
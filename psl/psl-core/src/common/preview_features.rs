@@ -60,6 +60,7 @@ features!(
     ImprovedQueryRaw,
     InteractiveTransactions,
     JsonProtocol,
+    JsonUpdateOperations,
     Metrics,
     MicrosoftSqlServer,
     Middlewares,
@@ -80,9 +81,11 @@ features!(
     ReferentialActions,
     ReferentialIntegrity,
     RelationJoins,
+    RelationsToNonUniqueColumns,
     SelectRelationCount,
     ShardKeys,
     StrictUndefinedChecks,
+    TenantIsolation,
     Tracing,
     TransactionApi,
     TypedSql,
@@ -154,14 +157,17 @@ impl<'a> FeatureMapWithProvider<'a> {
         let feature_map: FeatureMap = FeatureMap {
             active: enumflags2::make_bitflags!(PreviewFeature::{
                  DriverAdapters
+                 | JsonUpdateOperations
                  | Metrics
                  | MultiSchema
                  | NativeDistinct
                  | PostgresqlExtensions
                  | QueryCompiler
                  | RelationJoins
+                 | RelationsToNonUniqueColumns
                  | ShardKeys
                  | StrictUndefinedChecks
+                 | TenantIsolation
                  | Views
             }),
             native: HashMap::from([
@@ -110,6 +110,8 @@ capabilities!(
     SupportsFiltersOnRelationsWithoutJoins, // Connector supports rendering filters on relation fields without joins.
     LateralJoin,                            // Connector supports lateral joins to resolve relations.
     CorrelatedSubqueries,                   // Connector supports correlated subqueries to resolve relations.
+    LtreeFilters, // Connector supports `ancestorOf`/`descendantOf`/`matchesLquery` filters on `ltree`-typed columns.
+    SpatialFiltering, // Connector supports the `geoContains` filter on `Point`/`Geometry`-typed columns.
 );
 
 /// Contains all capabilities that the connector is able to serve.
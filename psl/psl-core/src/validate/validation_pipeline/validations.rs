@@ -69,6 +69,8 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
         models::schema_attribute_missing(model, ctx);
         models::shard_key_is_supported(model, ctx);
         models::shard_key_has_fields(model, ctx);
+        models::tenant_field_is_supported(model, ctx);
+        models::tenant_field_recommends_index(model, ctx);
         models::connector_specific(model, ctx);
 
         autoincrement::validate_auto_increment(model, ctx);
@@ -6,7 +6,8 @@ use crate::{
     validate::validation_pipeline::context::Context,
     PreviewFeature,
 };
-use parser_database::walkers::{ModelWalker, PrimaryKeyWalker};
+use diagnostics::DatamodelWarning;
+use parser_database::walkers::{IndexFieldWalker, ModelWalker, PrimaryKeyWalker};
 use std::{borrow::Cow, collections::HashMap};
 
 /// A model must have either a primary key, or a unique criterion
@@ -454,3 +455,52 @@ pub(super) fn shard_key_is_supported(model: ModelWalker<'_>, ctx: &mut Context<'
         ));
     }
 }
+
+pub(super) fn tenant_field_is_supported(model: ModelWalker<'_>, ctx: &mut Context<'_>) {
+    let Some(tenant_field) = model.tenant_field() else { return };
+
+    if !ctx.preview_features.contains(PreviewFeature::TenantIsolation) {
+        ctx.push_error(DatamodelError::new_attribute_validation_error(
+            "Defining a tenant field requires enabling the `tenantIsolation` preview feature",
+            "@@tenantField",
+            tenant_field.ast_attribute().span,
+        ));
+        return;
+    }
+
+    // The query engine doesn't inject the tenant-key equality filter into any read, write, or
+    // relation subquery yet, and has no request-context value source to pull it from - so a
+    // schema using `@@tenantField` today gets none of the row-level isolation the attribute's
+    // name promises. Keep the attribute rejected everywhere until that engine-side enforcement
+    // lands alongside it, so `tenantIsolation` never ships as a declarative-only false promise.
+    ctx.push_error(DatamodelError::new_attribute_validation_error(
+        "`@@tenantField` is not enforced by the query engine yet and cannot be used. The `tenantIsolation` preview feature is withheld until tenant-key filtering is implemented for reads and writes.",
+        "@@tenantField",
+        tenant_field.ast_attribute().span,
+    ));
+}
+
+/// Every read and write the query engine builds for a tenant-scoped model will now filter on
+/// this field, so an unindexed tenant field turns every such query into a full table scan.
+pub(super) fn tenant_field_recommends_index(model: ModelWalker<'_>, ctx: &mut Context<'_>) {
+    let Some(tenant_field) = model.tenant_field() else { return };
+
+    let field = tenant_field.field();
+
+    let covered_by_primary_key = model
+        .primary_key()
+        .is_some_and(|pk| pk.fields().any(|pk_field| pk_field.field_id() == field.field_id()));
+
+    let covered_by_an_index = model.indexes().any(|index| {
+        index
+            .fields()
+            .any(|index_field| matches!(index_field, IndexFieldWalker::Scalar(sf) if sf.field_id() == field.field_id()))
+    });
+
+    if !covered_by_primary_key && !covered_by_an_index {
+        ctx.push_warning(DatamodelWarning::new_tenant_field_without_index_warning(
+            field.name(),
+            tenant_field.ast_attribute().span,
+        ));
+    }
+}
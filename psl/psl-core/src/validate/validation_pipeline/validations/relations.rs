@@ -143,6 +143,27 @@ pub(super) fn references_unique_fields(relation: InlineRelationWalker<'_>, ctx:
         return;
     }
 
+    // `relationsToNonUniqueColumns` is withheld: the query builder still renders a to-one side of
+    // such a relation with an unconditional `.limit(1)` (`build_to_one_select`), and the read/join
+    // code was never taught that the referenced column can have duplicates. Enabling the preview
+    // feature today would let a user declare a relation the engine documents as well-defined
+    // to-many semantics for, while a plain `include`/`select` silently returns one arbitrary
+    // matching row - a correctness bug, not a missing feature. Keep emitting the unique-criterion
+    // error here until the read path is duplicate-aware; connect/disconnect were already guarded
+    // in `query_graph_builder::write::utils`.
+    if ctx.relation_mode.is_prisma()
+        && ctx
+            .preview_features
+            .contains(crate::PreviewFeature::RelationsToNonUniqueColumns)
+    {
+        ctx.push_error(DatamodelError::new_attribute_validation_error(
+            "The `relationsToNonUniqueColumns` preview feature is withheld until the read path renders relations against a non-unique `references` without dropping duplicate rows. The argument `references` must refer to a unique criterion in the related model.",
+            RELATION_ATTRIBUTE_NAME,
+            relation_field.ast_field().span(),
+        ));
+        return;
+    }
+
     let fields: Vec<_> = relation.referenced_fields().map(|f| f.name()).collect();
     let model = relation.referenced_model().name();
 
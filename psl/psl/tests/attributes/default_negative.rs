@@ -373,7 +373,7 @@ fn must_error_on_arguments_in_autoincrement() {
     );
 
     let expected = expect![[r#"
-        [1;91merror[0m: [1mError parsing attribute "@default": The `autoincrement` function does not take any argument. Consider changing this default to `autoincrement()`.[0m
+        [1;91merror[0m: [1mError parsing attribute "@default": `autoincrement()` only takes the optional `start` and `increment` integer arguments.[0m
           [1;94m-->[0m  [4mschema.prisma:2[0m
         [1;94m   | [0m
         [1;94m 1 | [0mmodel Category {
@@ -424,7 +424,7 @@ fn must_error_if_non_string_expression_in_function_default() {
     let error = parse_unwrap_err(dml);
 
     let expectation = expect![[r#"
-        [1;91merror[0m: [1mError parsing attribute "@default": The `autoincrement` function does not take any argument. Consider changing this default to `autoincrement()`.[0m
+        [1;91merror[0m: [1mError parsing attribute "@default": `autoincrement()` only takes the optional `start` and `increment` integer arguments.[0m
           [1;94m-->[0m  [4mschema.prisma:3[0m
         [1;94m   | [0m
         [1;94m 2 | [0m  id      Int @id
@@ -435,6 +435,28 @@ fn must_error_if_non_string_expression_in_function_default() {
     expectation.assert_eq(&error)
 }
 
+#[test]
+fn must_error_on_non_integer_autoincrement_start() {
+    let dml = indoc! {r#"
+        model Model {
+          id Int @id @default(autoincrement(start: "abc"))
+        }
+    "#};
+
+    let error = parse_unwrap_err(dml);
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError parsing attribute "@default": `autoincrement()`'s `start` and `increment` arguments must be integers.[0m
+          [1;94m-->[0m  [4mschema.prisma:2[0m
+        [1;94m   | [0m
+        [1;94m 1 | [0mmodel Model {
+        [1;94m 2 | [0m  id Int @id [1;91m@default(autoincrement(start: "abc"))[0m
+        [1;94m   | [0m
+    "#]];
+
+    expectation.assert_eq(&error)
+}
+
 #[test]
 fn must_error_if_non_string_expression_in_function_default_2() {
     let dml = indoc! {r#"
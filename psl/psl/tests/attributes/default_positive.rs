@@ -1,4 +1,5 @@
 use psl::parser_database::ScalarType;
+use psl::schema_ast::ast;
 
 use crate::common::*;
 
@@ -170,6 +171,31 @@ fn named_default_constraints_should_work_on_sql_server() {
         .assert_mapped_name("meow");
 }
 
+#[test]
+fn autoincrement_accepts_start_and_increment_args() {
+    let dml = indoc! {r#"
+        model Model {
+          id Int @id @default(autoincrement(start: 100, increment: 5))
+        }
+    "#};
+
+    let datamodel = psl::parse_schema(dml).unwrap();
+
+    let default = datamodel
+        .assert_has_model("Model")
+        .assert_has_scalar_field("id")
+        .assert_default_value();
+
+    default.assert_autoincrement();
+
+    let ast::Expression::Function(_, args, _) = default.value() else {
+        panic!("expected a function expression");
+    };
+
+    assert_eq!(args.arguments[0].value.as_numeric_value().unwrap().0, "100");
+    assert_eq!(args.arguments[1].value.as_numeric_value().unwrap().0, "5");
+}
+
 #[test]
 fn string_literals_with_double_quotes_work() {
     let schema = indoc! {r#"
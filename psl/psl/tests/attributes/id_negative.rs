@@ -115,6 +115,29 @@ fn id_must_error_when_multi_field_is_referring_to_undefined_fields() {
     expect_error(dml, &expectation)
 }
 
+#[test]
+fn id_must_error_when_multi_field_refers_to_the_same_field_multiple_times() {
+    let dml = indoc! {r#"
+        model Model {
+          a String
+          b String
+
+          @@id([a, a, b])
+        }
+    "#};
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError validating model "Model": The multi field id declaration refers to the field a multiple times.[0m
+          [1;94m-->[0m  [4mschema.prisma:5[0m
+        [1;94m   | [0m
+        [1;94m 4 | [0m
+        [1;94m 5 | [0m  [1;91m@@id([a, a, b])[0m
+        [1;94m   | [0m
+    "#]];
+
+    expect_error(dml, &expectation)
+}
+
 #[test]
 fn relation_fields_as_part_of_compound_id_must_error() {
     let dml = indoc! {r#"
@@ -132,16 +155,84 @@ fn relation_fields_as_part_of_compound_id_must_error() {
 
     let expectation = expect![[r#"
         [1;91merror[0m: [1mError validating model "User": The id definition refers to the relation field `identification`. ID definitions must reference only scalar fields.[0m
-          [1;94m-->[0m  [4mschema.prisma:5[0m
+          [1;94m-->[0m  [4mschema.prisma:3[0m
         [1;94m   | [0m
-        [1;94m 4 | [0m
-        [1;94m 5 | [0m  [1;91m@@id([name, identification])[0m
+        [1;94m 2 | [0m  name           String
+        [1;94m 3 | [0m  [1;91midentification[0m Identification @relation(references:[id])
         [1;94m   | [0m
     "#]];
 
     expect_error(dml, &expectation)
 }
 
+#[test]
+fn relation_fields_as_part_of_compound_id_each_get_their_own_error_span() {
+    let dml = indoc! {r#"
+        model User {
+          identificationA IdentificationA @relation(references:[id])
+          identificationB IdentificationB @relation(references:[id])
+
+          @@id([identificationA, identificationB])
+        }
+
+        model IdentificationA {
+          id Int @id
+        }
+
+        model IdentificationB {
+          id Int @id
+        }
+    "#};
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError validating model "User": The id definition refers to the relation field `identificationA`. ID definitions must reference only scalar fields.[0m
+          [1;94m-->[0m  [4mschema.prisma:2[0m
+        [1;94m   | [0m
+        [1;94m 1 | [0mmodel User {
+        [1;94m 2 | [0m  [1;91midentificationA[0m IdentificationA @relation(references:[id])
+        [1;94m   | [0m
+        [1;91merror[0m: [1mError validating model "User": The id definition refers to the relation field `identificationB`. ID definitions must reference only scalar fields.[0m
+          [1;94m-->[0m  [4mschema.prisma:3[0m
+        [1;94m   | [0m
+        [1;94m 2 | [0m  identificationA IdentificationA @relation(references:[id])
+        [1;94m 3 | [0m  [1;91midentificationB[0m IdentificationB @relation(references:[id])
+        [1;94m   | [0m
+    "#]];
+
+    expect_error(dml, &expectation)
+}
+
+#[test]
+fn id_must_error_when_referencing_a_composite_field() {
+    let dml = indoc! {r#"
+        datasource db {
+          provider = "mongodb"
+          url      = "mongodb://"
+        }
+
+        type Address {
+          street String
+        }
+
+        model User {
+          address Address
+
+          @@id([address])
+        }
+    "#};
+
+    let expectation = expect![[r#"
+        [1;91merror[0m: [1mError validating model "User": `@@id` cannot reference fields of composite type Address. The field `address` has that type.[0m
+          [1;94m-->[0m  [4mschema.prisma:13[0m
+        [1;94m   | [0m
+        [1;94m12 | [0m
+        [1;94m13 | [0m  [1;91m@@id([address])[0m
+        [1;94m   | [0m
+    "#]];
+
+    expect_error(dml, &expectation)
+}
+
 #[test]
 fn must_error_when_multi_field_is_referring_fields_that_are_not_required() {
     let dml = indoc! {r#"
@@ -414,8 +414,11 @@ fn mysql_allows_compound_id_length_prefix() {
         }
     "#};
 
-    let schema = with_header(dml, Provider::Mysql, &[]);
-    assert_valid(&schema);
+    let schema = psl::parse_schema(with_header(dml, Provider::Mysql, &[])).unwrap();
+
+    let pk = schema.assert_has_model("A").assert_id_on_fields(&["a", "b"]);
+    pk.assert_field("a").assert_length(10);
+    pk.assert_field("b").assert_length(20);
 }
 
 #[test]
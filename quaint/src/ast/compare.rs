@@ -38,6 +38,14 @@ pub enum Compare<'a> {
     Raw(Box<Expression<'a>>, Cow<'a, str>, Box<Expression<'a>>),
     /// All json related comparators
     JsonCompare(JsonCompare<'a>),
+    /// `left` is a spatial type that contains the geometry `right`
+    GeoContains(Box<Expression<'a>>, Box<Expression<'a>>),
+    /// `left` is a spatial type within `distance` (metres) of the point `point`
+    WithinDistance {
+        left: Box<Expression<'a>>,
+        point: Box<Expression<'a>>,
+        distance: Box<Expression<'a>>,
+    },
     /// `left` @@ to_tsquery(`value`)
     Matches(Box<Expression<'a>>, Cow<'a, str>),
     /// (NOT `left` @@ to_tsquery(`value`))
@@ -822,6 +830,41 @@ pub trait Comparable<'a> {
     where
         T: Into<Cow<'a, str>>,
         V: Into<Expression<'a>>;
+
+    /// Tests if a spatial column contains the given geometry.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users")
+    ///     .so_that("location".geo_contains(serde_json::json!({"type": "Point", "coordinates": [0, 0]})));
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE ST_Contains(`location`, ?)", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn geo_contains<T>(self, item: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>;
+
+    /// Tests if a spatial column is within `distance` metres of the given point.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Select::from_table("users")
+    ///     .so_that("location".within_distance(serde_json::json!({"type": "Point", "coordinates": [0, 0]}), 1000));
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("SELECT `users`.* FROM `users` WHERE ST_Distance_Sphere(`location`, ?) <= ?", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn within_distance<T, D>(self, point: T, distance: D) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+        D: Into<Expression<'a>>;
 }
 
 impl<'a, U> Comparable<'a> for U
@@ -985,6 +1028,27 @@ where
         val.json_array_not_contains(item)
     }
 
+    fn geo_contains<T>(self, item: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+
+        val.geo_contains(item)
+    }
+
+    fn within_distance<T, D>(self, point: T, distance: D) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+        D: Into<Expression<'a>>,
+    {
+        let col: Column<'a> = self.into();
+        let val: Expression<'a> = col.into();
+
+        val.within_distance(point, distance)
+    }
+
     fn json_array_begins_with<T>(self, item: T) -> Compare<'a>
     where
         T: Into<Expression<'a>>,
@@ -8,6 +8,8 @@ pub struct Delete<'a> {
     pub(crate) conditions: Option<ConditionTree<'a>>,
     pub(crate) returning: Option<Vec<Column<'a>>>,
     pub(crate) comment: Option<Cow<'a, str>>,
+    pub(crate) ordering: Ordering<'a>,
+    pub(crate) limit: Option<Value<'a>>,
 }
 
 impl<'a> From<Delete<'a>> for Query<'a> {
@@ -38,6 +40,8 @@ impl<'a> Delete<'a> {
             conditions: None,
             returning: None,
             comment: None,
+            ordering: Ordering::default(),
+            limit: None,
         }
     }
 
@@ -99,4 +103,34 @@ impl<'a> Delete<'a> {
         self.returning = Some(columns.into_iter().map(|k| k.into()).collect());
         self
     }
+
+    /// Adds an ordering to the `ORDER BY` section. Only rendered by connectors whose `DELETE`
+    /// syntax supports it directly (MySQL, SQLite); others emulate ordered, limited deletes with
+    /// a correlated subquery in the `WHERE` clause instead.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Delete::from_table("users").order_by("created_at".ascend()).limit(10);
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("DELETE FROM `users` ORDER BY `created_at` ASC LIMIT ?", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_by<T>(mut self, value: T) -> Self
+    where
+        T: IntoOrderDefinition<'a>,
+    {
+        self.ordering = self.ordering.append(value.into_order_definition());
+        self
+    }
+
+    /// Limits the number of rows deleted. Only supported by databases whose `DELETE` syntax
+    /// allows a `LIMIT` clause directly (MySQL, SQLite); other connectors emulate row limiting
+    /// with a correlated subquery in the `WHERE` clause instead of setting this.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(Value::from(limit));
+        self
+    }
 }
@@ -213,6 +213,19 @@ impl<'a> Expression<'a> {
             _ => self,
         }
     }
+
+    /// Turns a parameterized value into a parameterized, dynamic-length list of rows, e.g.
+    /// `(?, ?), (?, ?), ..., (?, ?)`. Used for compound (multi-column) `IN` comparisons where the
+    /// whole right-hand side is bound as a single placeholder instead of one placeholder per row.
+    pub fn into_parameterized_row_list(self) -> Self {
+        match self.kind {
+            ExpressionKind::Parameterized(value) => Expression {
+                kind: ExpressionKind::ParameterizedRowList(value),
+                alias: self.alias,
+            },
+            _ => self,
+        }
+    }
 }
 
 /// An expression we can compare and use in database queries.
@@ -222,6 +235,10 @@ pub enum ExpressionKind<'a> {
     Parameterized(Value<'a>),
     /// List of parameters with an unknown length, e.g. `(?, ?, ..., ?)`
     ParameterizedRow(Value<'a>),
+    /// A dynamic-length list of rows with an unknown length, e.g. `(?, ?), (?, ?), ..., (?, ?)`.
+    /// Used for compound `IN` comparisons parameterized as a single bound value (a list of tuples)
+    /// rather than one placeholder per row.
+    ParameterizedRowList(Value<'a>),
     /// A user-provided value we do not parameterize.
     RawValue(Raw<'a>),
     /// A database column
@@ -480,6 +497,25 @@ impl<'a> Comparable<'a> for Expression<'a> {
         Compare::JsonCompare(JsonCompare::ArrayNotContains(Box::new(self), Box::new(item.into())))
     }
 
+    fn geo_contains<T>(self, item: T) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+    {
+        Compare::GeoContains(Box::new(self), Box::new(item.into()))
+    }
+
+    fn within_distance<T, D>(self, point: T, distance: D) -> Compare<'a>
+    where
+        T: Into<Expression<'a>>,
+        D: Into<Expression<'a>>,
+    {
+        Compare::WithinDistance {
+            left: Box::new(self),
+            point: Box::new(point.into()),
+            distance: Box::new(distance.into()),
+        }
+    }
+
     fn json_array_begins_with<T>(self, item: T) -> Compare<'a>
     where
         T: Into<Expression<'a>>,
@@ -3,19 +3,24 @@ mod average;
 mod coalesce;
 mod concat;
 mod count;
+mod db_function_call;
 mod json_array_agg;
 mod json_build_obj;
 mod json_extract;
 mod json_extract_array;
+mod json_remove;
+mod json_set;
 mod json_unquote;
 mod lower;
 mod maximum;
 mod minimum;
+mod next_val;
 mod row_number;
 mod row_to_json;
 mod search;
 mod sum;
 mod upper;
+mod value_position;
 
 mod uuid;
 
@@ -24,19 +29,24 @@ pub use average::*;
 pub use coalesce::*;
 pub use concat::*;
 pub use count::*;
+pub use db_function_call::*;
 pub use json_array_agg::*;
 pub use json_build_obj::*;
 pub use json_extract::*;
 pub(crate) use json_extract_array::*;
+pub use json_remove::*;
+pub use json_set::*;
 pub use json_unquote::*;
 pub use lower::*;
 pub use maximum::*;
 pub use minimum::*;
+pub use next_val::*;
 pub use row_number::*;
 pub use row_to_json::*;
 pub use search::*;
 pub use sum::*;
 pub use upper::*;
+pub use value_position::*;
 
 pub use self::uuid::*;
 
@@ -58,6 +68,8 @@ impl Function<'_> {
                 | FunctionType::JsonExtract(_)
                 | FunctionType::JsonExtractLastArrayElem(_)
                 | FunctionType::JsonExtractFirstArrayElem(_)
+                | FunctionType::JsonSet(_)
+                | FunctionType::JsonRemove(_)
         )
     }
 }
@@ -82,14 +94,19 @@ pub(crate) enum FunctionType<'a> {
     JsonExtract(JsonExtract<'a>),
     JsonExtractLastArrayElem(JsonExtractLastArrayElem<'a>),
     JsonExtractFirstArrayElem(JsonExtractFirstArrayElem<'a>),
+    JsonSet(JsonSet<'a>),
+    JsonRemove(JsonRemove<'a>),
     JsonUnquote(JsonUnquote<'a>),
     JsonArrayAgg(JsonArrayAgg<'a>),
     JsonBuildObject(JsonBuildObject<'a>),
     TextSearch(TextSearch<'a>),
     TextSearchRelevance(TextSearchRelevance<'a>),
+    ValuePosition(ValuePosition<'a>),
     UuidToBin,
     UuidToBinSwapped,
     Uuid,
+    NextVal(NextVal<'a>),
+    DbFunctionCall(DbFunctionCall<'a>),
 }
 
 impl<'a> Aliasable<'a> for Function<'a> {
@@ -112,12 +129,18 @@ function!(JsonExtractLastArrayElem);
 
 function!(JsonExtractFirstArrayElem);
 
+function!(JsonSet);
+
+function!(JsonRemove);
+
 function!(JsonUnquote);
 
 function!(TextSearch);
 
 function!(TextSearchRelevance);
 
+function!(ValuePosition);
+
 function!(JsonArrayAgg);
 
 function!(JsonBuildObject);
@@ -133,5 +156,7 @@ function!(
     Minimum,
     Maximum,
     Coalesce,
-    Concat
+    Concat,
+    NextVal,
+    DbFunctionCall
 );
@@ -0,0 +1,32 @@
+use super::Function;
+use std::borrow::Cow;
+
+/// A call to a niladic database function, rendered verbatim as `name()`.
+///
+/// Unlike the other functions in this module, this isn't tied to a single well-known SQL
+/// function: it's the generic escape hatch for inlining a server-side default-value generator
+/// (e.g. Postgres' `pg_uuidv7` extension function) into a statement, the same way [`nextval`]
+/// inlines a sequence advance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbFunctionCall<'a> {
+    pub(crate) name: Cow<'a, str>,
+}
+
+/// Calls the named niladic database function, e.g. `uuid_generate_v7`.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Insert::single_into("users").value("id", db_function_call("uuid_generate_v7"));
+/// let (sql, _) = Postgres::build(query)?;
+/// assert_eq!("INSERT INTO \"users\" (\"id\") VALUES (uuid_generate_v7())", sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn db_function_call<'a, T>(name: T) -> Function<'a>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let fun = DbFunctionCall { name: name.into() };
+    fun.into()
+}
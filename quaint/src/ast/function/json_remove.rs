@@ -0,0 +1,48 @@
+use super::{Function, JsonPath};
+use crate::ast::Expression;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonRemove<'a> {
+    pub(crate) column: Box<Expression<'a>>,
+    pub(crate) path: JsonPath<'a>,
+}
+
+/// Removes the value at a path inside a JSON column. See [`super::json_set`] for the path
+/// notation, which is database-specific.
+///
+/// For PostgreSQL:
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let remove: Expression = json_remove(Column::from(("users", "json")), JsonPath::array(["a", "b"])).into();
+/// let query = Update::table("users").set("json", remove);
+/// let (sql, params) = Postgres::build(query)?;
+/// assert_eq!(r#"UPDATE "users" SET "json" = ("json" #- ARRAY[$1, $2]::text[])"#, sql);
+/// assert_eq!(vec![Value::text("a"), Value::text("b")], params);
+/// # Ok(())
+/// # }
+/// ```
+/// For MySQL:
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let remove: Expression = json_remove(Column::from(("users", "json")), JsonPath::string("$.a.b")).into();
+/// let query = Update::table("users").set("json", remove);
+/// let (sql, params) = Mysql::build(query)?;
+/// assert_eq!("UPDATE `users` SET `json` = JSON_REMOVE(`json`, ?)", sql);
+/// assert_eq!(vec![Value::text("$.a.b")], params);
+/// # Ok(())
+/// # }
+/// ```
+pub fn json_remove<'a, C, P>(column: C, path: P) -> Function<'a>
+where
+    C: Into<Expression<'a>>,
+    P: Into<JsonPath<'a>>,
+{
+    let fun = JsonRemove {
+        column: Box::new(column.into()),
+        path: path.into(),
+    };
+
+    fun.into()
+}
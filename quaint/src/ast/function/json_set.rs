@@ -0,0 +1,53 @@
+use super::{Function, JsonPath};
+use crate::ast::Expression;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonSet<'a> {
+    pub(crate) column: Box<Expression<'a>>,
+    pub(crate) path: JsonPath<'a>,
+    pub(crate) value: Box<Expression<'a>>,
+}
+
+/// Sets a value at a path inside a JSON column, creating the path's missing objects along the
+/// way. Like [`super::json_extract`], the path notation is database-specific:
+/// - `String` paths, e.g. `"$.a.b"`. Supported by MySQL, SQLite and MSSQL.
+/// - `Array` paths, e.g. `["a", "b"]`. Supported by PostgreSQL only.
+///
+/// For PostgreSQL:
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let set: Expression = json_set(Column::from(("users", "json")), JsonPath::array(["a", "b"]), Value::text("c")).into();
+/// let query = Update::table("users").set("json", set);
+/// let (sql, params) = Postgres::build(query)?;
+/// assert_eq!(r#"UPDATE "users" SET "json" = JSONB_SET("json", ARRAY[$1, $2]::text[], $3)"#, sql);
+/// assert_eq!(vec![Value::text("a"), Value::text("b"), Value::text("c")], params);
+/// # Ok(())
+/// # }
+/// ```
+/// For MySQL:
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let set: Expression = json_set(Column::from(("users", "json")), JsonPath::string("$.a.b"), Value::text("c")).into();
+/// let query = Update::table("users").set("json", set);
+/// let (sql, params) = Mysql::build(query)?;
+/// assert_eq!("UPDATE `users` SET `json` = JSON_SET(`json`, ?, ?)", sql);
+/// assert_eq!(vec![Value::text("$.a.b"), Value::text("c")], params);
+/// # Ok(())
+/// # }
+/// ```
+pub fn json_set<'a, C, P, V>(column: C, path: P, value: V) -> Function<'a>
+where
+    C: Into<Expression<'a>>,
+    P: Into<JsonPath<'a>>,
+    V: Into<Expression<'a>>,
+{
+    let fun = JsonSet {
+        column: Box::new(column.into()),
+        path: path.into(),
+        value: Box::new(value.into()),
+    };
+
+    fun.into()
+}
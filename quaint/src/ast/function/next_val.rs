@@ -0,0 +1,30 @@
+use super::Function;
+use std::borrow::Cow;
+
+/// A representation of the `nextval` function, advancing and returning the next value of a
+/// database sequence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NextVal<'a> {
+    pub(crate) sequence_name: Cow<'a, str>,
+}
+
+/// Advances the named sequence and returns its next value.
+///
+/// ```rust
+/// # use quaint::{ast::*, visitor::{Visitor, Postgres}};
+/// # fn main() -> Result<(), quaint::error::Error> {
+/// let query = Insert::single_into("users").value("id", nextval("users_id_seq"));
+/// let (sql, _) = Postgres::build(query)?;
+/// assert_eq!("INSERT INTO \"users\" (\"id\") VALUES (nextval('users_id_seq'))", sql);
+/// # Ok(())
+/// # }
+/// ```
+pub fn nextval<'a, T>(sequence_name: T) -> Function<'a>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let fun = NextVal {
+        sequence_name: sequence_name.into(),
+    };
+    fun.into()
+}
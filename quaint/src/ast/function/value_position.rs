@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// The maximum number of values a [`ValuePosition`] may carry when it has to be rendered as a
+/// `CASE` expression (connectors without a native "position in list" function). Larger lists risk
+/// hitting the connector's statement size or parameter count limits.
+pub const VALUE_POSITION_CASE_LIMIT: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds the column and the explicit, ordered list of values to compute the position of the
+/// column's value in.
+pub struct ValuePosition<'a> {
+    pub(crate) column: Column<'a>,
+    pub(crate) values: Vec<Value<'a>>,
+}
+
+/// Computes the 0-based position of `column`'s value within the ordered `values`, so it can be
+/// used in an `ORDER BY` clause to preserve an explicit input order (e.g. for a `WHERE id IN
+/// (...)` lookup). Renders natively where the connector supports it (`array_position` on
+/// PostgreSQL, `FIELD` on MySQL), and falls back to a `CASE` expression elsewhere, bounded by
+/// [`VALUE_POSITION_CASE_LIMIT`].
+pub fn value_position<'a, C>(column: C, values: Vec<Value<'a>>) -> super::Function<'a>
+where
+    C: Into<Column<'a>>,
+{
+    let fun = ValuePosition {
+        column: column.into(),
+        values,
+    };
+
+    fun.into()
+}
@@ -10,6 +10,8 @@ pub struct Update<'a> {
     pub(crate) conditions: Option<ConditionTree<'a>>,
     pub(crate) comment: Option<Cow<'a, str>>,
     pub(crate) returning: Option<Vec<Column<'a>>>,
+    pub(crate) limit: Option<Value<'a>>,
+    pub(crate) ordering: Ordering<'a>,
 }
 
 impl<'a> From<Update<'a>> for Query<'a> {
@@ -31,6 +33,8 @@ impl<'a> Update<'a> {
             conditions: None,
             comment: None,
             returning: None,
+            limit: None,
+            ordering: Ordering::default(),
         }
     }
 
@@ -157,4 +161,45 @@ impl<'a> Update<'a> {
         self.returning = Some(columns.into_iter().map(|k| k.into()).collect());
         self
     }
+
+    /// Limits the number of rows updated. Only supported by databases whose `UPDATE` syntax
+    /// allows a `LIMIT` clause directly (MySQL); other connectors emulate row limiting with a
+    /// correlated subquery in the `WHERE` clause instead of setting this.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Update::table("users").set("foo", 1).limit(10);
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("UPDATE `users` SET `foo` = ? LIMIT ?", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(Value::from(limit));
+        self
+    }
+
+    /// Adds an ordering to the `ORDER BY` section. Only rendered by connectors whose `UPDATE`
+    /// syntax supports it directly (MySQL, SQLite); others emulate ordered, limited updates with
+    /// a correlated subquery in the `WHERE` clause instead.
+    ///
+    /// ```rust
+    /// # use quaint::{ast::*, visitor::{Visitor, Mysql}};
+    /// # fn main() -> Result<(), quaint::error::Error> {
+    /// let query = Update::table("users").set("foo", 1).order_by("created_at".ascend()).limit(10);
+    /// let (sql, _) = Mysql::build(query)?;
+    ///
+    /// assert_eq!("UPDATE `users` SET `foo` = ? ORDER BY `created_at` ASC LIMIT ?", sql);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn order_by<T>(mut self, value: T) -> Self
+    where
+        T: IntoOrderDefinition<'a>,
+    {
+        self.ordering = self.ordering.append(value.into_order_definition());
+        self
+    }
 }
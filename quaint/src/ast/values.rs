@@ -621,6 +621,50 @@ where
     }
 }
 
+/// A decoded composite ("row") type value: the names and values of its fields, in declaration
+/// order.
+///
+/// Carried as an [`Opaque`] value (via [`Value::opaque`]) rather than its own [`ValueType`]
+/// variant, because a composite's shape - its field names, types and count - comes from the
+/// database's type catalog rather than quaint's fixed type system. [`OpaqueType::Unknown`] is used
+/// as its type tag: composites are only ever produced while reading a result row and are never
+/// sent back as a bound parameter, so there's no inferred database type to carry for the write
+/// direction the way there is for placeholders and generator calls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeValue {
+    fields: Vec<(String, Value<'static>)>,
+}
+
+impl CompositeValue {
+    pub fn new(fields: Vec<(String, Value<'static>)>) -> Self {
+        Self { fields }
+    }
+
+    pub fn fields(&self) -> &[(String, Value<'static>)] {
+        &self.fields
+    }
+
+    pub fn into_fields(self) -> Vec<(String, Value<'static>)> {
+        self.fields
+    }
+}
+
+impl fmt::Display for CompositeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ROW(")?;
+
+        for (i, (name, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{name}: {value}")?;
+        }
+
+        write!(f, ")")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum OpaqueType {
     Unknown,
@@ -641,6 +685,10 @@ pub enum OpaqueType {
     DateTime,
     Date,
     Time,
+    /// Wraps a type to signal that the opaque value may also be bound as `NULL`, e.g. a
+    /// placeholder targeting a nullable column. Doesn't change which native type/OID the value
+    /// is bound as - only the wrapped type does.
+    Nullable(Box<OpaqueType>),
 }
 
 impl fmt::Display for OpaqueType {
@@ -668,6 +716,7 @@ impl fmt::Display for OpaqueType {
             OpaqueType::DateTime => write!(f, "DateTime"),
             OpaqueType::Date => write!(f, "Date"),
             OpaqueType::Time => write!(f, "Time"),
+            OpaqueType::Nullable(t) => write!(f, "Nullable<{t}>"),
         }
     }
 }
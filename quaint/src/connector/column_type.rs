@@ -143,49 +143,63 @@ impl From<&ValueType<'_>> for ColumnType {
                 ValueType::Opaque(_) => ColumnType::Unknown,
             },
             ValueType::Array(_) => ColumnType::Unknown,
-            ValueType::Opaque(opaque) => match opaque.typ() {
-                OpaqueType::Unknown => ColumnType::Unknown,
-                OpaqueType::Int32 => ColumnType::Int32,
-                OpaqueType::Int64 => ColumnType::Int64,
-                OpaqueType::Float => ColumnType::Float,
-                OpaqueType::Double => ColumnType::Double,
-                OpaqueType::Text => ColumnType::Text,
-                OpaqueType::Enum => ColumnType::Text,
-                OpaqueType::Bytes => ColumnType::Bytes,
-                OpaqueType::Boolean => ColumnType::Boolean,
-                OpaqueType::Char => ColumnType::Char,
-                OpaqueType::Numeric => ColumnType::Numeric,
-                OpaqueType::Json => ColumnType::Json,
-                OpaqueType::Xml => ColumnType::Xml,
-                OpaqueType::Uuid => ColumnType::Uuid,
-                OpaqueType::DateTime => ColumnType::DateTime,
-                OpaqueType::Date => ColumnType::Date,
-                OpaqueType::Time => ColumnType::Time,
-                OpaqueType::Array(inner) => match &**inner {
-                    OpaqueType::Unknown => ColumnType::Unknown,
-                    OpaqueType::Int32 => ColumnType::Int32Array,
-                    OpaqueType::Int64 => ColumnType::Int64Array,
-                    OpaqueType::Float => ColumnType::FloatArray,
-                    OpaqueType::Double => ColumnType::DoubleArray,
-                    OpaqueType::Text => ColumnType::TextArray,
-                    OpaqueType::Enum => ColumnType::TextArray,
-                    OpaqueType::Bytes => ColumnType::BytesArray,
-                    OpaqueType::Boolean => ColumnType::BooleanArray,
-                    OpaqueType::Char => ColumnType::CharArray,
-                    OpaqueType::Numeric => ColumnType::NumericArray,
-                    OpaqueType::Json => ColumnType::JsonArray,
-                    OpaqueType::Xml => ColumnType::XmlArray,
-                    OpaqueType::Uuid => ColumnType::UuidArray,
-                    OpaqueType::DateTime => ColumnType::DateTimeArray,
-                    OpaqueType::Date => ColumnType::DateArray,
-                    OpaqueType::Time => ColumnType::TimeArray,
-                    OpaqueType::Array(_) => ColumnType::Unknown,
-                },
-            },
+            ValueType::Opaque(opaque) => column_type_for_opaque_type(opaque.typ()),
         }
     }
 }
 
+/// Maps the [`OpaqueType`] tag carried by an opaque [`Value`] to the [`ColumnType`] it
+/// corresponds to. Recurses into [`OpaqueType::Nullable`], since nullability doesn't change the
+/// shape of the underlying column.
+fn column_type_for_opaque_type(typ: &OpaqueType) -> ColumnType {
+    match typ {
+        OpaqueType::Unknown => ColumnType::Unknown,
+        OpaqueType::Int32 => ColumnType::Int32,
+        OpaqueType::Int64 => ColumnType::Int64,
+        OpaqueType::Float => ColumnType::Float,
+        OpaqueType::Double => ColumnType::Double,
+        OpaqueType::Text => ColumnType::Text,
+        OpaqueType::Enum => ColumnType::Text,
+        OpaqueType::Bytes => ColumnType::Bytes,
+        OpaqueType::Boolean => ColumnType::Boolean,
+        OpaqueType::Char => ColumnType::Char,
+        OpaqueType::Numeric => ColumnType::Numeric,
+        OpaqueType::Json => ColumnType::Json,
+        OpaqueType::Xml => ColumnType::Xml,
+        OpaqueType::Uuid => ColumnType::Uuid,
+        OpaqueType::DateTime => ColumnType::DateTime,
+        OpaqueType::Date => ColumnType::Date,
+        OpaqueType::Time => ColumnType::Time,
+        OpaqueType::Nullable(inner) => column_type_for_opaque_type(inner),
+        OpaqueType::Array(inner) => array_column_type_for_opaque_type(inner),
+    }
+}
+
+/// The [`ColumnType`] for an array whose elements have the given (non-array) [`OpaqueType`].
+fn array_column_type_for_opaque_type(typ: &OpaqueType) -> ColumnType {
+    match typ {
+        OpaqueType::Unknown => ColumnType::Unknown,
+        OpaqueType::Int32 => ColumnType::Int32Array,
+        OpaqueType::Int64 => ColumnType::Int64Array,
+        OpaqueType::Float => ColumnType::FloatArray,
+        OpaqueType::Double => ColumnType::DoubleArray,
+        OpaqueType::Text => ColumnType::TextArray,
+        OpaqueType::Enum => ColumnType::TextArray,
+        OpaqueType::Bytes => ColumnType::BytesArray,
+        OpaqueType::Boolean => ColumnType::BooleanArray,
+        OpaqueType::Char => ColumnType::CharArray,
+        OpaqueType::Numeric => ColumnType::NumericArray,
+        OpaqueType::Json => ColumnType::JsonArray,
+        OpaqueType::Xml => ColumnType::XmlArray,
+        OpaqueType::Uuid => ColumnType::UuidArray,
+        OpaqueType::DateTime => ColumnType::DateTimeArray,
+        OpaqueType::Date => ColumnType::DateArray,
+        OpaqueType::Time => ColumnType::TimeArray,
+        OpaqueType::Nullable(inner) => array_column_type_for_opaque_type(inner),
+        OpaqueType::Array(_) => ColumnType::Unknown,
+    }
+}
+
 impl ColumnType {
     #[cfg(any(
         feature = "sqlite-native",
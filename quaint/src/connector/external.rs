@@ -117,6 +117,11 @@ pub struct ExternalConnectionInfo {
     pub schema_name: Option<String>,
     pub max_bind_values: Option<usize>,
     pub supports_relation_joins: bool,
+
+    /// The full set of schema names the adapter's connection can resolve table references
+    /// against, e.g. every database `ATTACH`ed to a SQLite connection. Empty for adapters that
+    /// don't report it (the default), in which case only `schema_name` is known to be valid.
+    pub attached_schema_names: Vec<String>,
 }
 
 impl ExternalConnectionInfo {
@@ -131,8 +136,48 @@ impl ExternalConnectionInfo {
             schema_name,
             max_bind_values,
             supports_relation_joins,
+            attached_schema_names: Vec::new(),
         }
     }
+
+    /// Reports the full set of schema names the adapter's connection can resolve table
+    /// references against (e.g. every database `ATTACH`ed to a SQLite connection), beyond just
+    /// the default `schema_name`. See [`Self::known_schema_names`].
+    pub fn with_attached_schema_names(mut self, attached_schema_names: Vec<String>) -> Self {
+        self.attached_schema_names = attached_schema_names;
+        self
+    }
+
+    /// The schema names this connection is known to be able to resolve table references
+    /// against: `schema_name` plus any reported `attached_schema_names`, without duplicates.
+    pub fn known_schema_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.schema_name.as_deref().into_iter().collect();
+
+        for name in self.attached_schema_names.iter() {
+            if !names.contains(&name.as_str()) {
+                names.push(name.as_str());
+            }
+        }
+
+        names
+    }
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn known_schema_names_includes_the_default_schema_and_dedupes_attached_ones() {
+    let info = ExternalConnectionInfo::new(SqlFamily::Sqlite, Some("main".to_owned()), None, false)
+        .with_attached_schema_names(vec!["main".to_owned(), "analytics".to_owned()]);
+
+    assert_eq!(info.known_schema_names(), vec!["main", "analytics"]);
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn known_schema_names_is_just_the_default_schema_when_nothing_is_attached() {
+    let info = ExternalConnectionInfo::new(SqlFamily::Sqlite, Some("main".to_owned()), None, false);
+
+    assert_eq!(info.known_schema_names(), vec!["main"]);
 }
 
 #[async_trait]
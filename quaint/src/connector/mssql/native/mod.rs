@@ -37,6 +37,7 @@ impl TransactionCapable for Mssql {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> crate::Result<Box<dyn Transaction + 'a>> {
         // Isolation levels in SQL Server are set on the connection and live until they're changed.
         // Always explicitly setting the isolation level each time a tx is started (either to the given value
@@ -46,7 +47,7 @@ impl TransactionCapable for Mssql {
             .or(self.url.query_params.transaction_isolation_level)
             .or(Some(SQL_SERVER_DEFAULT_ISOLATION));
 
-        let opts = TransactionOptions::new(isolation, self.requires_isolation_first());
+        let opts = TransactionOptions::new(isolation, self.requires_isolation_first(), snapshot_id);
 
         Ok(Box::new(
             DefaultTransaction::new(self, self.begin_statement(), opts).await?,
@@ -159,7 +159,9 @@ impl From<PostgresError> for Error {
 
                 builder.build()
             }
-            "40001" => {
+            // 40001: serialization_failure, 40P01: deadlock_detected. Both are transient and safe
+            // to retry.
+            "40001" | "40P01" => {
                 let mut builder: crate::error::ErrorBuilder = Error::builder(ErrorKind::TransactionWriteConflict);
 
                 builder.set_original_code(value.code);
@@ -1,7 +1,7 @@
 mod decimal;
 
 use crate::{
-    ast::{OpaqueType, Value, ValueType},
+    ast::{CompositeValue, OpaqueType, Value, ValueType},
     connector::queryable::{GetRow, ToColumnNames},
     error::{Error, ErrorKind},
     prelude::EnumVariant,
@@ -18,7 +18,7 @@ pub(crate) use decimal::DecimalWrapper;
 use postgres_types::{FromSql, ToSql, WrongType};
 use std::{borrow::Cow, convert::TryFrom, error::Error as StdError};
 use tokio_postgres::{
-    types::{self, IsNull, Kind, Type as PostgresType},
+    types::{self, Field, IsNull, Kind, Type as PostgresType},
     Row as PostgresRow, Statement as PostgresStatement,
 };
 
@@ -100,50 +100,64 @@ pub(crate) fn params_to_types(params: &[Value<'_>]) -> Vec<PostgresType> {
                     }
                 }
 
-                ValueType::Opaque(opaque) => match opaque.typ() {
-                    OpaqueType::Unknown => PostgresType::UNKNOWN,
-                    OpaqueType::Int32 => PostgresType::INT4,
-                    OpaqueType::Int64 => PostgresType::INT8,
-                    OpaqueType::Float => PostgresType::FLOAT4,
-                    OpaqueType::Double => PostgresType::FLOAT8,
-                    OpaqueType::Text => PostgresType::TEXT,
-                    OpaqueType::Enum => PostgresType::UNKNOWN,
-                    OpaqueType::Bytes => PostgresType::BYTEA,
-                    OpaqueType::Boolean => PostgresType::BOOL,
-                    OpaqueType::Char => PostgresType::CHAR,
-                    OpaqueType::Numeric => PostgresType::NUMERIC,
-                    OpaqueType::Json => PostgresType::JSONB,
-                    OpaqueType::Xml => PostgresType::XML,
-                    OpaqueType::Uuid => PostgresType::UUID,
-                    OpaqueType::DateTime => PostgresType::TIMESTAMPTZ,
-                    OpaqueType::Date => PostgresType::TIMESTAMP,
-                    OpaqueType::Time => PostgresType::TIME,
-                    OpaqueType::Array(inner) => match &**inner {
-                        OpaqueType::Unknown => PostgresType::UNKNOWN,
-                        OpaqueType::Int32 => PostgresType::INT4_ARRAY,
-                        OpaqueType::Int64 => PostgresType::INT8_ARRAY,
-                        OpaqueType::Float => PostgresType::FLOAT4_ARRAY,
-                        OpaqueType::Double => PostgresType::FLOAT8_ARRAY,
-                        OpaqueType::Text => PostgresType::TEXT_ARRAY,
-                        OpaqueType::Enum => PostgresType::TEXT_ARRAY,
-                        OpaqueType::Bytes => PostgresType::BYTEA_ARRAY,
-                        OpaqueType::Boolean => PostgresType::BOOL_ARRAY,
-                        OpaqueType::Char => PostgresType::CHAR_ARRAY,
-                        OpaqueType::Numeric => PostgresType::NUMERIC_ARRAY,
-                        OpaqueType::Json => PostgresType::JSONB_ARRAY,
-                        OpaqueType::Xml => PostgresType::XML_ARRAY,
-                        OpaqueType::Uuid => PostgresType::UUID_ARRAY,
-                        OpaqueType::DateTime => PostgresType::TIMESTAMPTZ_ARRAY,
-                        OpaqueType::Date => PostgresType::TIMESTAMP_ARRAY,
-                        OpaqueType::Time => PostgresType::TIME_ARRAY,
-                        OpaqueType::Array(_) => PostgresType::UNKNOWN,
-                    },
-                },
+                ValueType::Opaque(opaque) => postgres_type_for_opaque_type(opaque.typ()),
             }
         })
         .collect()
 }
 
+/// Maps the [`OpaqueType`] tag carried by an opaque [`Value`] to the Postgres type it should be
+/// bound as. Recurses into [`OpaqueType::Nullable`]: nullability doesn't change which OID a value
+/// is bound with, Postgres accepts `NULL` for any type.
+fn postgres_type_for_opaque_type(typ: &OpaqueType) -> PostgresType {
+    match typ {
+        OpaqueType::Unknown => PostgresType::UNKNOWN,
+        OpaqueType::Int32 => PostgresType::INT4,
+        OpaqueType::Int64 => PostgresType::INT8,
+        OpaqueType::Float => PostgresType::FLOAT4,
+        OpaqueType::Double => PostgresType::FLOAT8,
+        OpaqueType::Text => PostgresType::TEXT,
+        OpaqueType::Enum => PostgresType::UNKNOWN,
+        OpaqueType::Bytes => PostgresType::BYTEA,
+        OpaqueType::Boolean => PostgresType::BOOL,
+        OpaqueType::Char => PostgresType::CHAR,
+        OpaqueType::Numeric => PostgresType::NUMERIC,
+        OpaqueType::Json => PostgresType::JSONB,
+        OpaqueType::Xml => PostgresType::XML,
+        OpaqueType::Uuid => PostgresType::UUID,
+        OpaqueType::DateTime => PostgresType::TIMESTAMPTZ,
+        OpaqueType::Date => PostgresType::TIMESTAMP,
+        OpaqueType::Time => PostgresType::TIME,
+        OpaqueType::Nullable(inner) => postgres_type_for_opaque_type(inner),
+        OpaqueType::Array(inner) => postgres_array_type_for_opaque_type(inner),
+    }
+}
+
+/// The Postgres array type for an array whose elements have the given (non-array) [`OpaqueType`].
+fn postgres_array_type_for_opaque_type(typ: &OpaqueType) -> PostgresType {
+    match typ {
+        OpaqueType::Unknown => PostgresType::UNKNOWN,
+        OpaqueType::Int32 => PostgresType::INT4_ARRAY,
+        OpaqueType::Int64 => PostgresType::INT8_ARRAY,
+        OpaqueType::Float => PostgresType::FLOAT4_ARRAY,
+        OpaqueType::Double => PostgresType::FLOAT8_ARRAY,
+        OpaqueType::Text => PostgresType::TEXT_ARRAY,
+        OpaqueType::Enum => PostgresType::TEXT_ARRAY,
+        OpaqueType::Bytes => PostgresType::BYTEA_ARRAY,
+        OpaqueType::Boolean => PostgresType::BOOL_ARRAY,
+        OpaqueType::Char => PostgresType::CHAR_ARRAY,
+        OpaqueType::Numeric => PostgresType::NUMERIC_ARRAY,
+        OpaqueType::Json => PostgresType::JSONB_ARRAY,
+        OpaqueType::Xml => PostgresType::XML_ARRAY,
+        OpaqueType::Uuid => PostgresType::UUID_ARRAY,
+        OpaqueType::DateTime => PostgresType::TIMESTAMPTZ_ARRAY,
+        OpaqueType::Date => PostgresType::TIMESTAMP_ARRAY,
+        OpaqueType::Time => PostgresType::TIME_ARRAY,
+        OpaqueType::Nullable(inner) => postgres_array_type_for_opaque_type(inner),
+        OpaqueType::Array(_) => PostgresType::UNKNOWN,
+    }
+}
+
 struct XmlString(pub String);
 
 impl<'a> FromSql<'a> for XmlString {
@@ -204,10 +218,161 @@ impl<'a> FromSql<'a> for NaiveMoney {
     }
 }
 
+/// Captures a composite ("row") column's raw on-the-wire bytes, to be parsed field by field in
+/// [`decode_composite`] using the field list from the column's Postgres type metadata.
+struct RawBytes<'a>(&'a [u8]);
+
+impl<'a> FromSql<'a> for RawBytes<'a> {
+    fn from_sql(_ty: &PostgresType, raw: &'a [u8]) -> Result<RawBytes<'a>, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(RawBytes(raw))
+    }
+
+    fn accepts(_ty: &PostgresType) -> bool {
+        true
+    }
+}
+
+fn composite_field_error(err: Box<dyn StdError + Sync + Send>) -> Error {
+    let kind = ErrorKind::conversion(format!("Couldn't decode a composite field: {err}"));
+    Error::builder(kind).build()
+}
+
+fn composite_decode_error(msg: impl Into<String>) -> Error {
+    Error::builder(ErrorKind::conversion(msg.into())).build()
+}
+
+fn read_i32(buf: &mut &[u8]) -> crate::Result<i32> {
+    if buf.len() < 4 {
+        return Err(composite_decode_error("truncated composite value"));
+    }
+
+    let (head, rest) = buf.split_at(4);
+    *buf = rest;
+
+    Ok(i32::from_be_bytes(head.try_into().unwrap()))
+}
+
+/// Decodes the fields of a Postgres composite ("row") type from its binary wire representation:
+/// a field count, followed by that many `(oid, length, data)` triples. `fields` is the field
+/// name/type list from the composite's own type metadata, which the wire format doesn't repeat.
+fn decode_composite(fields: &[Field], raw: &[u8]) -> crate::Result<Vec<(String, Value<'static>)>> {
+    let mut buf = raw;
+    let count = read_i32(&mut buf)?;
+
+    if count as usize != fields.len() {
+        return Err(composite_decode_error(format!(
+            "composite value declares {count} fields but its type has {}",
+            fields.len()
+        )));
+    }
+
+    let mut result = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        // Every field is prefixed by its own type OID, which is redundant with `field.type_()`.
+        read_i32(&mut buf)?;
+        let len = read_i32(&mut buf)?;
+
+        let field_raw = if len < 0 {
+            None
+        } else {
+            let len = len as usize;
+
+            if buf.len() < len {
+                return Err(composite_decode_error("truncated composite value"));
+            }
+
+            let (field_raw, rest) = buf.split_at(len);
+            buf = rest;
+
+            Some(field_raw)
+        };
+
+        let value = decode_composite_field(field.type_(), field_raw)?;
+        result.push((field.name().to_owned(), value));
+    }
+
+    Ok(result)
+}
+
+/// Decodes a single composite field's raw bytes according to its Postgres type, recursing into
+/// [`decode_composite`] for nested composites. `raw` is `None` for a SQL `NULL` field.
+fn decode_composite_field(ty: &PostgresType, raw: Option<&[u8]>) -> crate::Result<Value<'static>> {
+    if let Kind::Composite(nested_fields) = ty.kind() {
+        return Ok(match raw {
+            Some(raw) => Value::opaque(CompositeValue::new(decode_composite(nested_fields, raw)?), OpaqueType::Unknown),
+            None => ValueType::Text(None).into_value(),
+        });
+    }
+
+    macro_rules! decode {
+        ($variant:ident, $rust_ty:ty) => {
+            ValueType::$variant(
+                raw.map(|raw| <$rust_ty>::from_sql(ty, raw))
+                    .transpose()
+                    .map_err(composite_field_error)?,
+            )
+        };
+    }
+
+    let value_type = match ty {
+        &PostgresType::BOOL => decode!(Boolean, bool),
+        &PostgresType::INT2 => ValueType::Int32(
+            raw.map(|raw| i16::from_sql(ty, raw).map(i32::from))
+                .transpose()
+                .map_err(composite_field_error)?,
+        ),
+        &PostgresType::INT4 => decode!(Int32, i32),
+        &PostgresType::INT8 => decode!(Int64, i64),
+        &PostgresType::FLOAT4 => decode!(Float, f32),
+        &PostgresType::FLOAT8 => decode!(Double, f64),
+        &PostgresType::TEXT | &PostgresType::VARCHAR | &PostgresType::NAME | &PostgresType::BPCHAR => ValueType::Text(
+            raw.map(|raw| String::from_sql(ty, raw).map(Cow::Owned))
+                .transpose()
+                .map_err(composite_field_error)?,
+        ),
+        &PostgresType::NUMERIC => ValueType::Numeric(
+            raw.map(|raw| DecimalWrapper::from_sql(ty, raw).map(|dw| dw.0))
+                .transpose()
+                .map_err(composite_field_error)?,
+        ),
+        &PostgresType::UUID => decode!(Uuid, Uuid),
+        &PostgresType::JSON | &PostgresType::JSONB => decode!(Json, serde_json::Value),
+        &PostgresType::TIMESTAMP => ValueType::DateTime(
+            raw.map(|raw| {
+                NaiveDateTime::from_sql(ty, raw).map(|ts| DateTime::<Utc>::from_naive_utc_and_offset(ts, Utc))
+            })
+            .transpose()
+            .map_err(composite_field_error)?,
+        ),
+        &PostgresType::TIMESTAMPTZ => decode!(DateTime, DateTime<Utc>),
+        &PostgresType::DATE => decode!(Date, chrono::NaiveDate),
+        &PostgresType::TIME => decode!(Time, chrono::NaiveTime),
+        &PostgresType::BYTEA => ValueType::Bytes(
+            raw.map(|raw| Vec::<u8>::from_sql(ty, raw).map(Cow::Owned))
+                .transpose()
+                .map_err(composite_field_error)?,
+        ),
+        _ => return Err(Error::builder(ErrorKind::UnsupportedColumnType { column_type: ty.to_string() }).build()),
+    };
+
+    Ok(value_type.into_value())
+}
+
 impl GetRow for PostgresRow {
     fn get_result_row(&self) -> crate::Result<Vec<Value<'static>>> {
         fn convert(row: &PostgresRow, i: usize) -> crate::Result<Value<'static>> {
             let pg_ty = row.columns()[i].type_();
+
+            if let Kind::Composite(fields) = pg_ty.kind() {
+                let raw: Option<RawBytes> = row.try_get(i)?;
+
+                return Ok(match raw {
+                    Some(raw) => Value::opaque(CompositeValue::new(decode_composite(fields, raw.0)?), OpaqueType::Unknown),
+                    None => ValueType::Text(None).into_value(),
+                });
+            }
+
             let column_type = PGColumnType::from_pg_type(pg_ty);
 
             // This convoluted nested enum is macro-generated to ensure we have a single source of truth for
@@ -1102,3 +1267,52 @@ impl<'a> TryFrom<&Value<'a>> for Option<BitVec> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_composite_reads_a_two_field_row() {
+        let fields = vec![
+            Field::new("num".to_owned(), PostgresType::INT4),
+            Field::new("name".to_owned(), PostgresType::TEXT),
+        ];
+
+        let name = b"hello";
+        let mut raw = Vec::new();
+        raw.extend_from_slice(&2i32.to_be_bytes()); // field count
+
+        raw.extend_from_slice(&PostgresType::INT4.oid().to_be_bytes());
+        raw.extend_from_slice(&4i32.to_be_bytes());
+        raw.extend_from_slice(&42i32.to_be_bytes());
+
+        raw.extend_from_slice(&PostgresType::TEXT.oid().to_be_bytes());
+        raw.extend_from_slice(&(name.len() as i32).to_be_bytes());
+        raw.extend_from_slice(name);
+
+        let decoded = decode_composite(&fields, &raw).unwrap();
+
+        assert_eq!(
+            decoded,
+            vec![
+                ("num".to_owned(), Value::int32(42)),
+                ("name".to_owned(), Value::text("hello")),
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_composite_reads_a_null_field() {
+        let fields = vec![Field::new("num".to_owned(), PostgresType::INT4)];
+
+        let mut raw = Vec::new();
+        raw.extend_from_slice(&1i32.to_be_bytes());
+        raw.extend_from_slice(&PostgresType::INT4.oid().to_be_bytes());
+        raw.extend_from_slice(&(-1i32).to_be_bytes());
+
+        let decoded = decode_composite(&fields, &raw).unwrap();
+
+        assert_eq!(decoded, vec![("num".to_owned(), Value::null_int32())]);
+    }
+}
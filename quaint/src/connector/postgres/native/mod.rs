@@ -552,8 +552,9 @@ impl<Cache: QueryCache> TransactionCapable for PostgreSql<Cache> {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> crate::Result<Box<dyn Transaction + 'a>> {
-        let opts = TransactionOptions::new(isolation, self.requires_isolation_first());
+        let opts = TransactionOptions::new(isolation, self.requires_isolation_first(), snapshot_id);
 
         Ok(Box::new(
             DefaultTransaction::new(self, self.begin_statement(), opts).await?,
@@ -765,6 +766,14 @@ impl<Cache: QueryCache> Queryable for PostgreSql<Cache> {
     fn requires_isolation_first(&self) -> bool {
         false
     }
+
+    async fn set_tx_snapshot(&self, snapshot_id: &str) -> crate::Result<()> {
+        // `SET TRANSACTION SNAPSHOT` doesn't support bind parameters, same as `SET TRANSACTION
+        // ISOLATION LEVEL` above, so the id is inlined as an escaped string literal instead.
+        let escaped_snapshot_id = snapshot_id.replace('\'', "''");
+        self.raw_cmd(&format!("SET TRANSACTION SNAPSHOT '{escaped_snapshot_id}'"))
+            .await
+    }
 }
 
 /// Sorted list of CockroachDB's reserved keywords.
@@ -1,5 +1,6 @@
 use super::{DescribedQuery, ExternalConnector, IsolationLevel, ResultSet, Transaction};
 use crate::ast::*;
+use crate::error::{Error, ErrorKind};
 use async_trait::async_trait;
 
 pub trait GetRow {
@@ -105,6 +106,14 @@ pub trait Queryable: Send + Sync {
 
     /// Signals if the isolation level SET needs to happen before or after the tx BEGIN.
     fn requires_isolation_first(&self) -> bool;
+
+    /// Imports a previously exported snapshot into the current transaction, so that its reads
+    /// observe the database exactly as it was when the snapshot was taken (`SET TRANSACTION
+    /// SNAPSHOT` on Postgres). Only Postgres overrides this; every other connector keeps this
+    /// default, which always errors.
+    async fn set_tx_snapshot(&self, _snapshot_id: &str) -> crate::Result<()> {
+        Err(Error::builder(ErrorKind::transaction_snapshot_unsupported()).build())
+    }
 }
 
 /// A thing that can start a new transaction.
@@ -114,6 +123,7 @@ pub trait TransactionCapable: Queryable {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> crate::Result<Box<dyn Transaction + 'a>>;
 }
 
@@ -130,8 +140,10 @@ macro_rules! impl_default_TransactionCapable {
             async fn start_transaction<'a>(
                 &'a self,
                 isolation: Option<IsolationLevel>,
+                snapshot_id: Option<String>,
             ) -> crate::Result<Box<dyn crate::connector::Transaction + 'a>> {
-                let opts = crate::connector::TransactionOptions::new(isolation, self.requires_isolation_first());
+                let opts =
+                    crate::connector::TransactionOptions::new(isolation, self.requires_isolation_first(), snapshot_id);
 
                 Ok(Box::new(
                     crate::connector::DefaultTransaction::new(self, self.begin_statement(), opts).await?,
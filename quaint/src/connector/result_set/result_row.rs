@@ -64,6 +64,11 @@ impl ResultRow {
         }
     }
 
+    /// The names of the columns as reported by the database for this row, in positional order.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
     /// Get a value with the given column name from the row. Usage
     /// documentation in [ResultRowRef](struct.ResultRowRef.html).
     pub fn get(&self, name: &str) -> Option<&Value<'static>> {
@@ -6,7 +6,7 @@ mod conversion;
 mod error;
 
 use crate::connector::IsolationLevel;
-use crate::connector::{sqlite::params::SqliteParams, ColumnType, DescribedQuery};
+use crate::connector::{sqlite::params::SqliteParams, ColumnType, DescribedColumn, DescribedParameter, DescribedQuery};
 
 pub use rusqlite::{params_from_iter, version as sqlite_version};
 
@@ -126,8 +126,39 @@ impl Queryable for Sqlite {
         self.query_raw(sql, params).await
     }
 
-    async fn describe_query(&self, _sql: &str) -> crate::Result<DescribedQuery> {
-        unimplemented!("SQLite describe_query is implemented in the schema engine.")
+    async fn describe_query(&self, sql: &str) -> crate::Result<DescribedQuery> {
+        // This only covers declared column types and parameter names/positions, by preparing the
+        // statement and never executing it. It does not infer expression column types or
+        // nullability the way the schema engine's own describe logic does (see
+        // `sql-schema-connector`'s SQLite flavour, which additionally shells out to sqlx to
+        // interpret SQLite's bytecode for that).
+        let client = self.client.lock().await;
+        let stmt = client.prepare_cached(sql)?;
+
+        let parameters = (1..=stmt.parameter_count())
+            .map(|idx| match stmt.parameter_name(idx) {
+                Some(name) => {
+                    // SQLite parameter names are prefixed with a colon; strip it for consistency
+                    // with named parameters on other connectors.
+                    let name = name.strip_prefix(':').unwrap_or(name);
+
+                    DescribedParameter::new_named(name, ColumnType::Unknown)
+                }
+                None => DescribedParameter::new_unnamed(idx, ColumnType::Unknown),
+            })
+            .collect();
+
+        let columns = stmt
+            .columns()
+            .iter()
+            .map(|col| DescribedColumn::new_named(col.name(), ColumnType::from(col)))
+            .collect();
+
+        Ok(DescribedQuery {
+            parameters,
+            columns,
+            enum_names: None,
+        })
     }
 
     async fn execute(&self, q: Query<'_>) -> crate::Result<u64> {
@@ -241,6 +272,32 @@ mod tests {
         assert!(matches!(err.kind(), ErrorKind::TableDoesNotExist { .. }));
     }
 
+    #[tokio::test]
+    async fn describe_query_reports_parameters_and_columns() {
+        let conn = Sqlite::new_in_memory().unwrap();
+
+        conn.raw_cmd("CREATE TABLE test (id INTEGER PRIMARY KEY, txt TEXT NOT NULL);")
+            .await
+            .unwrap();
+
+        let described = conn.describe_query("SELECT id, txt FROM test WHERE id = ?").await.unwrap();
+
+        assert_eq!(described.parameters.len(), 1);
+        assert_eq!(described.columns.len(), 2);
+        assert_eq!(described.columns[0].name, "id");
+        assert_eq!(described.columns[1].name, "txt");
+    }
+
+    #[tokio::test]
+    async fn describe_query_surfaces_prepare_errors() {
+        let conn = Sqlite::new_in_memory().unwrap();
+
+        conn.raw_cmd("CREATE TABLE test (id INTEGER PRIMARY KEY);").await.unwrap();
+
+        let err = conn.describe_query("SELECT missing_column FROM test").await.unwrap_err();
+        assert!(err.to_string().contains("missing_column"));
+    }
+
     #[tokio::test]
     async fn quoting_in_returning_in_sqlite_works() {
         let conn = Sqlite::new_in_memory().unwrap();
@@ -33,6 +33,10 @@ pub(crate) struct TransactionOptions {
 
     /// Whether or not to put the isolation level `SET` before or after the `BEGIN`.
     pub(crate) isolation_first: bool,
+
+    /// An exported snapshot to import into the transaction, so its reads observe the database as
+    /// it was when the snapshot was taken. Only supported by Postgres.
+    pub(crate) snapshot_id: Option<String>,
 }
 
 #[cfg(any(
@@ -42,10 +46,11 @@ pub(crate) struct TransactionOptions {
     feature = "mysql-native"
 ))]
 impl TransactionOptions {
-    pub fn new(isolation_level: Option<IsolationLevel>, isolation_first: bool) -> Self {
+    pub fn new(isolation_level: Option<IsolationLevel>, isolation_first: bool, snapshot_id: Option<String>) -> Self {
         Self {
             isolation_level,
             isolation_first,
+            snapshot_id,
         }
     }
 }
@@ -100,6 +105,10 @@ impl<'a> DefaultTransaction<'a> {
             }
         }
 
+        if let Some(snapshot_id) = tx_opts.snapshot_id {
+            inner.set_tx_snapshot(&snapshot_id).await?;
+        }
+
         inner.server_reset_query(&this).await?;
 
         Ok(this)
@@ -178,6 +187,10 @@ impl Queryable for DefaultTransaction<'_> {
     fn requires_isolation_first(&self) -> bool {
         self.inner.requires_isolation_first()
     }
+
+    async fn set_tx_snapshot(&self, snapshot_id: &str) -> crate::Result<()> {
+        self.inner.set_tx_snapshot(snapshot_id).await
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
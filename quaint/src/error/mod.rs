@@ -249,6 +249,9 @@ pub enum ErrorKind {
 
     #[error("Attempted to execute a query that contains an opaque parameter '{0}'.")]
     RanQueryWithOpaqueParam(String),
+
+    #[error("Importing a transaction snapshot is not supported by this connector")]
+    TransactionSnapshotUnsupported,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -284,6 +287,10 @@ impl ErrorKind {
     pub fn invalid_isolation_level(isolation_level: &IsolationLevel) -> Self {
         Self::InvalidIsolationLevel(isolation_level.to_string())
     }
+
+    pub fn transaction_snapshot_unsupported() -> Self {
+        Self::TransactionSnapshotUnsupported
+    }
 }
 
 impl From<Error> for ErrorKind {
@@ -28,8 +28,9 @@ impl TransactionCapable for PooledConnection {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> crate::Result<Box<dyn Transaction + 'a>> {
-        self.inner.start_transaction(isolation).await
+        self.inner.start_transaction(isolation, snapshot_id).await
     }
 }
 
@@ -90,6 +91,10 @@ impl Queryable for PooledConnection {
     fn requires_isolation_first(&self) -> bool {
         self.inner.requires_isolation_first()
     }
+
+    async fn set_tx_snapshot(&self, snapshot_id: &str) -> crate::Result<()> {
+        self.inner.set_tx_snapshot(snapshot_id).await
+    }
 }
 
 #[doc(hidden)]
@@ -31,8 +31,9 @@ impl TransactionCapable for Quaint {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> crate::Result<Box<dyn connector::Transaction + 'a>> {
-        self.inner.start_transaction(isolation).await
+        self.inner.start_transaction(isolation, snapshot_id).await
     }
 }
 
@@ -65,7 +65,7 @@ async fn select_star_from(api: &mut dyn TestApi) -> crate::Result<()> {
 async fn transactions(api: &mut dyn TestApi) -> crate::Result<()> {
     let table = api.create_temp_table("value int").await?;
 
-    let tx = api.conn().start_transaction(None).await?;
+    let tx = api.conn().start_transaction(None, None).await?;
     let insert = Insert::single_into(&table).value("value", 10);
 
     let rows_affected = tx.execute(insert.into()).await?;
@@ -90,25 +90,25 @@ async fn transactions(api: &mut dyn TestApi) -> crate::Result<()> {
 async fn transactions_with_isolation_works(api: &mut dyn TestApi) -> crate::Result<()> {
     // This test only tests that the SET isolation level statements are accepted.
     api.conn()
-        .start_transaction(Some(IsolationLevel::ReadUncommitted))
+        .start_transaction(Some(IsolationLevel::ReadUncommitted), None)
         .await?
         .commit()
         .await?;
 
     api.conn()
-        .start_transaction(Some(IsolationLevel::ReadCommitted))
+        .start_transaction(Some(IsolationLevel::ReadCommitted), None)
         .await?
         .commit()
         .await?;
 
     api.conn()
-        .start_transaction(Some(IsolationLevel::RepeatableRead))
+        .start_transaction(Some(IsolationLevel::RepeatableRead), None)
         .await?
         .commit()
         .await?;
 
     api.conn()
-        .start_transaction(Some(IsolationLevel::Serializable))
+        .start_transaction(Some(IsolationLevel::Serializable), None)
         .await?
         .commit()
         .await?;
@@ -123,7 +123,7 @@ async fn mssql_transaction_isolation_level(api: &mut dyn TestApi) -> crate::Resu
     let conn_a = api.conn();
     // Start a transaction with the default isolation level, which in tests is
     // set to READ UNCOMMITED via the DB url and insert a row, but do not commit the transaction.
-    let tx_a = conn_a.start_transaction(None).await?;
+    let tx_a = conn_a.start_transaction(None, None).await?;
     let insert = Insert::single_into(&table).value("value", 3).value("id", 4);
     let rows_affected = tx_a.execute(insert.into()).await?;
     assert_eq!(1, rows_affected);
@@ -136,13 +136,13 @@ async fn mssql_transaction_isolation_level(api: &mut dyn TestApi) -> crate::Resu
     ] {
         // Start a transaction that explicitly sets the isolation level to SNAPSHOT and query the table
         // expecting to see the old state.
-        let tx_b = conn_b.start_transaction(Some(IsolationLevel::Snapshot)).await?;
+        let tx_b = conn_b.start_transaction(Some(IsolationLevel::Snapshot), None).await?;
         let res = tx_b.query(Select::from_table(&table).into()).await?;
         assert_eq!(0, res.len());
 
         // Start a transaction without an explicit isolation level, it should be run with the default
         // again, which is set to READ UNCOMMITED here.
-        let tx_c = conn_b.start_transaction(None).await?;
+        let tx_c = conn_b.start_transaction(None, None).await?;
         let res = tx_c.query(Select::from_table(&table).into()).await?;
         assert_eq!(1, res.len());
     }
@@ -154,7 +154,7 @@ async fn mssql_transaction_isolation_level(api: &mut dyn TestApi) -> crate::Resu
 #[test_each_connector(tags("sqlite"))]
 async fn sqlite_serializable_tx(api: &mut dyn TestApi) -> crate::Result<()> {
     api.conn()
-        .start_transaction(Some(IsolationLevel::Serializable))
+        .start_transaction(Some(IsolationLevel::Serializable), None)
         .await?
         .commit()
         .await?;
@@ -166,7 +166,7 @@ async fn sqlite_serializable_tx(api: &mut dyn TestApi) -> crate::Result<()> {
 #[test_each_connector(tags("mssql"))]
 async fn mssql_snapshot_tx(api: &mut dyn TestApi) -> crate::Result<()> {
     api.conn()
-        .start_transaction(Some(IsolationLevel::Snapshot))
+        .start_transaction(Some(IsolationLevel::Snapshot), None)
         .await?
         .commit()
         .await?;
@@ -28,6 +28,7 @@ pub use self::postgres::Postgres;
 pub use self::sqlite::Sqlite;
 
 use crate::ast::*;
+use crate::error::{Error, ErrorKind};
 use query_template::QueryTemplate;
 use std::{borrow::Cow, fmt};
 
@@ -131,6 +132,21 @@ pub trait Visitor<'a> {
     /// What to use to substitute a list of parameters of variable length
     fn visit_parameterized_row(&mut self, value: Value<'a>) -> Result;
 
+    /// What to use to substitute a variable-length list of rows (tuples), e.g.
+    /// `(?, ?), (?, ?), ..., (?, ?)`.
+    fn visit_parameterized_row_list(&mut self, value: Value<'a>) -> Result;
+
+    /// A compound (multi-column) `IN`/`NOT IN` comparison where the right-hand side is a
+    /// variable-length list of rows (tuples) parameterized as a single bound value, e.g.
+    /// `(a, b) IN (?, ?), (?, ?), ...`. Most connectors can render this as a native row-value
+    /// tuple `IN`, but some (MSSQL) need a different strategy entirely since they lack tuple
+    /// support.
+    fn visit_parameterized_row_list_comparison(&mut self, left: Row<'a>, value: Value<'a>, negate: bool) -> Result {
+        self.visit_row(left)?;
+        self.write(if negate { " NOT IN " } else { " IN " })?;
+        self.visit_parameterized_row_list(value)
+    }
+
     /// What to use to aggregate an array of values into a string
     fn visit_aggregate_to_string(&mut self, value: Expression<'a>) -> Result;
 
@@ -145,8 +161,22 @@ pub trait Visitor<'a> {
 
     fn visit_json_extract_first_array_item(&mut self, extract: JsonExtractFirstArrayElem<'a>) -> Result;
 
+    /// Sets a value at a path inside a JSON column, used to render a [`JsonSet`] update
+    /// expression. See [`Self::visit_json_extract`] for the path notation, which is
+    /// database-specific.
+    fn visit_json_set(&mut self, json_set: JsonSet<'a>) -> Result;
+
+    /// Removes the value at a path inside a JSON column. See [`Self::visit_json_set`].
+    fn visit_json_remove(&mut self, json_remove: JsonRemove<'a>) -> Result;
+
     fn visit_json_array_contains(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) -> Result;
 
+    /// Visit a spatial `ST_Contains`-style comparison.
+    fn visit_geo_contains(&mut self, left: Expression<'a>, right: Expression<'a>) -> Result;
+
+    /// Visit a spatial `ST_Distance_Sphere(..) <= ..`-style comparison.
+    fn visit_within_distance(&mut self, left: Expression<'a>, point: Expression<'a>, distance: Expression<'a>) -> Result;
+
     fn visit_json_type_equals(&mut self, left: Expression<'a>, right: JsonType<'a>, not: bool) -> Result;
 
     fn visit_json_unquote(&mut self, json_unquote: JsonUnquote<'a>) -> Result;
@@ -161,6 +191,41 @@ pub trait Visitor<'a> {
 
     fn visit_text_search_relevance(&mut self, text_search_relevance: TextSearchRelevance<'a>) -> Result;
 
+    /// Renders the 0-based position of `value_position.column`'s value within the ordered list of
+    /// values, e.g. to preserve an explicit input order in an `ORDER BY` clause. Connectors with a
+    /// native "position in list" function (PostgreSQL's `array_position`, MySQL's `FIELD`) should
+    /// override this. The default falls back to a `CASE` expression, bounded by
+    /// [`VALUE_POSITION_CASE_LIMIT`] since every value needs its own `WHEN` branch and bound
+    /// parameter.
+    fn visit_value_position(&mut self, value_position: ValuePosition<'a>) -> Result {
+        let ValuePosition { column, values } = value_position;
+
+        if values.len() > VALUE_POSITION_CASE_LIMIT {
+            return Err(Error::builder(ErrorKind::QueryInvalidInput(format!(
+                "Too many values ({}) to preserve input order, the limit is {VALUE_POSITION_CASE_LIMIT}",
+                values.len()
+            )))
+            .build());
+        }
+
+        let len = values.len();
+
+        self.write("(CASE")?;
+
+        for (i, value) in values.into_iter().enumerate() {
+            self.write(" WHEN ")?;
+            self.visit_column(column.clone())?;
+            self.write(" = ")?;
+            self.visit_parameterized(value)?;
+            self.write(" THEN ")?;
+            self.write(i)?;
+        }
+
+        self.write(" ELSE ")?;
+        self.write(len)?;
+        self.write(" END)")
+    }
+
     fn visit_parameterized_enum(&mut self, variant: EnumVariant<'a>, name: Option<EnumName<'a>>) -> Result {
         match name {
             Some(name) => self.add_parameter(Value::enum_variant_with_name(variant, name)),
@@ -619,6 +684,7 @@ pub trait Visitor<'a> {
             ExpressionKind::Compare(compare) => self.visit_compare(compare)?,
             ExpressionKind::Parameterized(val) => self.visit_parameterized(val)?,
             ExpressionKind::ParameterizedRow(val) => self.visit_parameterized_row(val)?,
+            ExpressionKind::ParameterizedRowList(val) => self.visit_parameterized_row_list(val)?,
             ExpressionKind::RawValue(val) => self.visit_raw_value(val.0)?,
             ExpressionKind::Column(column) => self.visit_column(*column)?,
             ExpressionKind::Row(row) => self.visit_row(row)?,
@@ -914,6 +980,19 @@ pub trait Visitor<'a> {
                     },
                 ) => self.visit_multiple_tuple_comparison(row, *values, false),
 
+                // A compound (multi-column) `IN` comparison parameterized as a single bound value,
+                // e.g. `(a, b) IN (?, ?), (?, ?), ...`.
+                (
+                    Expression {
+                        kind: ExpressionKind::Row(row),
+                        ..
+                    },
+                    Expression {
+                        kind: ExpressionKind::ParameterizedRowList(value),
+                        ..
+                    },
+                ) => self.visit_parameterized_row_list_comparison(row, value, false),
+
                 // expr IN (..)
                 (left, right) => {
                     self.visit_expression(left)?;
@@ -1016,6 +1095,19 @@ pub trait Visitor<'a> {
                     },
                 ) => self.visit_multiple_tuple_comparison(row, *values, true),
 
+                // A compound (multi-column) `NOT IN` comparison parameterized as a single bound
+                // value, e.g. `(a, b) NOT IN (?, ?), (?, ?), ...`.
+                (
+                    Expression {
+                        kind: ExpressionKind::Row(row),
+                        ..
+                    },
+                    Expression {
+                        kind: ExpressionKind::ParameterizedRowList(value),
+                        ..
+                    },
+                ) => self.visit_parameterized_row_list_comparison(row, value, true),
+
                 // expr IN (..)
                 (left, right) => {
                     self.visit_expression(left)?;
@@ -1060,6 +1152,10 @@ pub trait Visitor<'a> {
                 JsonCompare::TypeEquals(left, json_type) => self.visit_json_type_equals(*left, json_type, false),
                 JsonCompare::TypeNotEquals(left, json_type) => self.visit_json_type_equals(*left, json_type, true),
             },
+            Compare::GeoContains(left, right) => self.visit_geo_contains(*left, *right),
+            Compare::WithinDistance { left, point, distance } => {
+                self.visit_within_distance(*left, *point, *distance)
+            }
             Compare::Matches(left, right) => self.visit_matches(*left, right, false),
             Compare::NotMatches(left, right) => self.visit_matches(*left, right, true),
             Compare::Any(left) => {
@@ -1191,6 +1287,12 @@ pub trait Visitor<'a> {
             FunctionType::JsonExtractLastArrayElem(extract) => {
                 self.visit_json_extract_last_array_item(extract)?;
             }
+            FunctionType::JsonSet(json_set) => {
+                self.visit_json_set(json_set)?;
+            }
+            FunctionType::JsonRemove(json_remove) => {
+                self.visit_json_remove(json_remove)?;
+            }
             FunctionType::JsonUnquote(unquote) => {
                 self.visit_json_unquote(unquote)?;
             }
@@ -1200,6 +1302,9 @@ pub trait Visitor<'a> {
             FunctionType::TextSearchRelevance(text_search_relevance) => {
                 self.visit_text_search_relevance(text_search_relevance)?;
             }
+            FunctionType::ValuePosition(value_position) => {
+                self.visit_value_position(value_position)?;
+            }
             FunctionType::UuidToBin => {
                 self.write("uuid_to_bin(uuid())")?;
             }
@@ -1216,6 +1321,15 @@ pub trait Visitor<'a> {
             FunctionType::JsonBuildObject(build_obj) => {
                 self.visit_json_build_object(build_obj)?;
             }
+            FunctionType::NextVal(next_val) => {
+                self.write("nextval(")?;
+                self.visit_raw_value(Value::text(next_val.sequence_name))?;
+                self.write(")")?;
+            }
+            FunctionType::DbFunctionCall(call) => {
+                self.write(call.name)?;
+                self.write("()")?;
+            }
         };
 
         if let Some(alias) = fun.alias {
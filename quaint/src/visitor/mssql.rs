@@ -1,5 +1,5 @@
 use super::{NativeColumnType, Visitor};
-use crate::ast::Update;
+use crate::ast::{Delete, Update};
 use crate::prelude::{JsonArrayAgg, JsonBuildObject, JsonExtract, JsonType, JsonUnquote};
 use crate::visitor::query_writer::QueryWriter;
 use crate::{
@@ -34,7 +34,14 @@ impl<'a> Mssql<'a> {
 
     // TODO: figure out that merge shit
     fn visit_returning(&mut self, columns: Vec<Column<'a>>) -> visitor::Result {
-        let cols: Vec<_> = columns.into_iter().map(|c| c.table("Inserted")).collect();
+        self.visit_returning_from(columns, "Inserted")
+    }
+
+    /// Like [`Self::visit_returning`], but reads the `OUTPUT` columns from `source` instead of the
+    /// `Inserted` pseudo-table. `DELETE` has no `Inserted` rows to read back, so its emulation
+    /// reads from `Deleted` instead.
+    fn visit_returning_from(&mut self, columns: Vec<Column<'a>>, source: &'static str) -> visitor::Result {
+        let cols: Vec<_> = columns.into_iter().map(|c| c.table(source)).collect();
 
         self.write(" OUTPUT ")?;
 
@@ -236,6 +243,59 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         Ok(())
     }
 
+    fn visit_parameterized_row_list(&mut self, value: Value<'a>) -> visitor::Result {
+        self.write("(")?;
+        self.query_template.write_parameter_tuple_list("(", ",", ")", ",");
+        self.query_template.parameters.push(value);
+        self.write(")")
+    }
+
+    // MSSQL has no row-value tuple `IN` syntax at all, not even for literal values (see
+    // `visit_multiple_tuple_comparison` below), so a placeholder-driven list of tuples can't be
+    // pre-expanded into `AND`/`OR` at compile time either. Instead we correlate against a
+    // dynamically generated derived table built the same way as `visit_columns`' parameterized
+    // `SELECT <rows>`.
+    fn visit_parameterized_row_list_comparison(&mut self, left: Row<'a>, value: Value<'a>, negate: bool) -> visitor::Result {
+        let columns: Vec<_> = left
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, _)| format!("c{i}"))
+            .collect();
+
+        if negate {
+            self.write("NOT ")?;
+        }
+
+        self.write("EXISTS (SELECT 1 FROM (SELECT ")?;
+        self.query_template.write_parameter_tuple_list("", ",", "", " UNION ALL SELECT ");
+        self.query_template.parameters.push(value);
+        self.write(") AS ")?;
+        self.delimited_identifiers(&["_row_list"])?;
+        self.write(" (")?;
+        for (i, column) in columns.iter().enumerate() {
+            self.delimited_identifiers(&[column.as_str()])?;
+
+            if i < columns.len() - 1 {
+                self.write(",")?;
+            }
+        }
+        self.write(") WHERE ")?;
+
+        let row_len = left.len();
+        for (i, (expr, column)) in left.values.into_iter().zip(columns.iter()).enumerate() {
+            self.visit_expression(expr)?;
+            self.write(" = ")?;
+            self.delimited_identifiers(&["_row_list", column.as_str()])?;
+
+            if i < row_len - 1 {
+                self.write(" AND ")?;
+            }
+        }
+
+        self.write(")")
+    }
+
     fn visit_columns(&mut self, columns: Vec<Expression<'a>>) -> visitor::Result {
         let len = columns.len();
 
@@ -582,6 +642,8 @@ impl<'a> Visitor<'a> for Mssql<'a> {
             expr => self.surround_with("(", ")", |ref mut s| s.visit_expression(expr))?,
         }
 
+        let emulates_returning = insert.returning.is_some();
+
         if let Some(returning) = insert.returning {
             let table = insert.table.unwrap();
             self.write(" ")?;
@@ -589,6 +651,15 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         }
 
         if let Some(comment) = insert.comment {
+            // The `RETURNING` emulation above renders as a `DECLARE` / `INSERT` / `SELECT`
+            // sequence of statements with no semicolons between them (SQL Server's batch parser
+            // tolerates that), so the statement needs an explicit terminator before the comment
+            // to make sure the comment can't be read as trailing the `SELECT` clause instead of
+            // the whole batch.
+            if emulates_returning {
+                self.write(";")?;
+            }
+
             self.write(" ")?;
             self.visit_comment(comment)?;
         }
@@ -631,6 +702,8 @@ impl<'a> Visitor<'a> for Mssql<'a> {
             self.visit_conditions(conditions)?;
         }
 
+        let emulates_returning = update.returning.is_some();
+
         if let Some(returning) = update.returning {
             let table = update.table;
             self.write(" ")?;
@@ -638,6 +711,56 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         }
 
         if let Some(comment) = update.comment {
+            // See the equivalent comment in `visit_insert`.
+            if emulates_returning {
+                self.write(";")?;
+            }
+
+            self.write(" ")?;
+            self.visit_comment(comment)?;
+        }
+
+        Ok(())
+    }
+
+    // Implements `RETURNING` using the `OUTPUT` clause in SQL Server. `OUTPUT` must be written
+    // before `WHERE`, and reads the deleted rows from the `Deleted` pseudo-table rather than
+    // `Inserted`. Emulated the same way as `visit_insert`/`visit_update`: `OUTPUT ... INTO` a
+    // table variable is allowed even when the target table has triggers defined on it (only
+    // `OUTPUT` read directly by the client is restricted in that case), so this needs no separate
+    // trigger-detection fallback.
+    fn visit_delete(&mut self, delete: Delete<'a>) -> visitor::Result {
+        if let Some(returning) = delete.returning.as_ref().cloned() {
+            self.create_generated_keys(returning)?;
+            self.write(" ")?;
+        }
+
+        self.write("DELETE FROM ")?;
+        self.visit_table(delete.table.clone(), true)?;
+
+        if let Some(returning) = delete.returning.as_ref().cloned() {
+            self.visit_returning_from(returning, "Deleted")?;
+        }
+
+        if let Some(conditions) = delete.conditions {
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        }
+
+        let emulates_returning = delete.returning.is_some();
+
+        if let Some(returning) = delete.returning {
+            let table = delete.table;
+            self.write(" ")?;
+            self.select_generated_keys(returning, table)?;
+        }
+
+        if let Some(comment) = delete.comment {
+            // See the equivalent comment in `visit_insert`.
+            if emulates_returning {
+                self.write(";")?;
+            }
+
             self.write(" ")?;
             self.visit_comment(comment)?;
         }
@@ -791,6 +914,38 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         unimplemented!("JSON filtering is not yet supported on MSSQL")
     }
 
+    fn visit_json_set(&mut self, json_set: JsonSet<'a>) -> visitor::Result {
+        self.write("JSON_MODIFY(")?;
+        self.visit_expression(*json_set.column)?;
+        self.write(", ")?;
+
+        match json_set.path {
+            JsonPath::Array(_) => panic!("JSON path array notation is not supported for MSSQL"),
+            JsonPath::String(path) => self.visit_parameterized(Value::text(path))?,
+        }
+
+        self.write(", ")?;
+        self.visit_expression(*json_set.value)?;
+        self.write(")")?;
+
+        Ok(())
+    }
+
+    fn visit_json_remove(&mut self, json_remove: JsonRemove<'a>) -> visitor::Result {
+        self.write("JSON_MODIFY(")?;
+        self.visit_expression(*json_remove.column)?;
+        self.write(", ")?;
+
+        match json_remove.path {
+            JsonPath::Array(_) => panic!("JSON path array notation is not supported for MSSQL"),
+            JsonPath::String(path) => self.visit_parameterized(Value::text(path))?,
+        }
+
+        self.write(", NULL)")?;
+
+        Ok(())
+    }
+
     fn visit_json_array_contains(
         &mut self,
         _left: Expression<'a>,
@@ -800,6 +955,19 @@ impl<'a> Visitor<'a> for Mssql<'a> {
         unimplemented!("JSON filtering is not yet supported on MSSQL")
     }
 
+    fn visit_geo_contains(&mut self, _left: Expression<'a>, _right: Expression<'a>) -> visitor::Result {
+        unimplemented!("Spatial filtering is not yet supported on MSSQL")
+    }
+
+    fn visit_within_distance(
+        &mut self,
+        _left: Expression<'a>,
+        _point: Expression<'a>,
+        _distance: Expression<'a>,
+    ) -> visitor::Result {
+        unimplemented!("Spatial filtering is not yet supported on MSSQL")
+    }
+
     fn visit_json_type_equals(&mut self, _left: Expression<'a>, _json_type: JsonType, _not: bool) -> visitor::Result {
         unimplemented!("JSON_TYPE is not yet supported on MSSQL")
     }
@@ -1456,6 +1624,48 @@ mod tests {
         assert_eq!(vec![Value::from("lol")], params);
     }
 
+    #[test]
+    #[cfg(feature = "mssql")]
+    fn test_returning_insert_with_comment() {
+        let insert = Insert::single_into("foo").value("bar", "lol");
+        let insert = Insert::from(insert).returning(vec!["bar"]).comment("traceparent='foo'");
+        let (sql, _) = Mssql::build(insert).unwrap();
+
+        assert_eq!(
+            "DECLARE @generated_keys table([bar] NVARCHAR(255)) INSERT INTO [foo] ([bar]) OUTPUT [Inserted].[bar] INTO @generated_keys VALUES (@P1) SELECT [t].[bar] FROM @generated_keys AS g INNER JOIN [foo] AS [t] ON [t].[bar] = [g].[bar] WHERE @@ROWCOUNT > 0; /* traceparent='foo' */",
+            sql
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "mssql")]
+    fn test_returning_delete() {
+        let delete = Delete::from_table("foo").so_that("bar".equals("lol")).returning(vec!["bar"]);
+        let (sql, params) = Mssql::build(delete).unwrap();
+
+        assert_eq!(
+            "DECLARE @generated_keys table([bar] NVARCHAR(255)) DELETE FROM [foo] OUTPUT [Deleted].[bar] INTO @generated_keys WHERE [bar] = @P1 SELECT [t].[bar] FROM @generated_keys AS g INNER JOIN [foo] AS [t] ON [t].[bar] = [g].[bar] WHERE @@ROWCOUNT > 0",
+            sql
+        );
+
+        assert_eq!(vec![Value::from("lol")], params);
+    }
+
+    #[test]
+    #[cfg(feature = "mssql")]
+    fn test_returning_delete_with_comment() {
+        let delete = Delete::from_table("foo")
+            .so_that("bar".equals("lol"))
+            .returning(vec!["bar"])
+            .comment("traceparent='foo'");
+        let (sql, _) = Mssql::build(delete).unwrap();
+
+        assert_eq!(
+            "DECLARE @generated_keys table([bar] NVARCHAR(255)) DELETE FROM [foo] OUTPUT [Deleted].[bar] INTO @generated_keys WHERE [bar] = @P1 SELECT [t].[bar] FROM @generated_keys AS g INNER JOIN [foo] AS [t] ON [t].[bar] = [g].[bar] WHERE @@ROWCOUNT > 0; /* traceparent='foo' */",
+            sql
+        );
+    }
+
     #[test]
     fn test_multi_insert() {
         let insert = Insert::multi_into("foo", vec!["bar", "wtf"])
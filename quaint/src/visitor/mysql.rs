@@ -310,6 +310,92 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         unimplemented!("Upsert not supported for the underlying database.")
     }
 
+    /// MySQL supports `ORDER BY`/`LIMIT` directly on `UPDATE`, unlike Postgres and SQLite, so an
+    /// ordered, row-limited update doesn't need to fall back to the
+    /// `WHERE id IN (SELECT id ... ORDER BY ... LIMIT n)` pattern used for those connectors.
+    fn visit_update(&mut self, update: Update<'a>) -> visitor::Result {
+        let ordering = update.ordering.clone();
+        let limit = update.limit.clone();
+
+        self.write("UPDATE ")?;
+        self.visit_table(update.table, true)?;
+
+        {
+            self.write(" SET ")?;
+            let pairs = update.columns.into_iter().zip(update.values);
+            let len = pairs.len();
+
+            for (i, (key, value)) in pairs.enumerate() {
+                self.visit_column(key)?;
+                self.write(" = ")?;
+                self.visit_expression(value)?;
+
+                if i < (len - 1) {
+                    self.write(", ")?;
+                }
+            }
+        }
+
+        if let Some(conditions) = update.conditions {
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        }
+
+        if !ordering.is_empty() {
+            self.write(" ORDER BY ")?;
+            self.visit_ordering(ordering)?;
+        }
+
+        if let Some(limit) = limit {
+            self.write(" LIMIT ")?;
+            self.visit_parameterized(limit)?;
+        }
+
+        if let Some(returning) = update.returning {
+            if !returning.is_empty() {
+                let values = returning.into_iter().map(|r| r.into()).collect();
+                self.write(" RETURNING ")?;
+                self.visit_columns(values)?;
+            }
+        }
+
+        if let Some(comment) = update.comment {
+            self.write(" ")?;
+            self.visit_comment(comment)?;
+        }
+
+        Ok(())
+    }
+
+    /// See the equivalent comment on `visit_update` — MySQL supports `ORDER BY`/`LIMIT` directly
+    /// on `DELETE` as well.
+    fn visit_delete(&mut self, delete: Delete<'a>) -> visitor::Result {
+        self.write("DELETE FROM ")?;
+        self.visit_table(delete.table, true)?;
+
+        if let Some(conditions) = delete.conditions {
+            self.write(" WHERE ")?;
+            self.visit_conditions(conditions)?;
+        }
+
+        if !delete.ordering.is_empty() {
+            self.write(" ORDER BY ")?;
+            self.visit_ordering(delete.ordering)?;
+        }
+
+        if let Some(limit) = delete.limit {
+            self.write(" LIMIT ")?;
+            self.visit_parameterized(limit)?;
+        }
+
+        if let Some(comment) = delete.comment {
+            self.write(" ")?;
+            self.visit_comment(comment)?;
+        }
+
+        Ok(())
+    }
+
     /// MySql will error if a `Update` or `Delete` query has a subselect
     /// that references a table that is being updated or deleted
     /// to get around that, we need to wrap the table in a tmp table name
@@ -352,6 +438,14 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         Ok(())
     }
 
+    fn visit_parameterized_row_list(&mut self, value: Value<'a>) -> visitor::Result {
+        self.write("(")?;
+        self.query_template.write_parameter_tuple_list("(", ",", ")", ",");
+        self.query_template.parameters.push(value);
+        self.write(")")?;
+        Ok(())
+    }
+
     fn visit_limit_and_offset(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> visitor::Result {
         match (limit, offset) {
             (Some(limit), Some(offset)) => {
@@ -471,6 +565,40 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         Ok(())
     }
 
+    #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
+    fn visit_json_set(&mut self, json_set: JsonSet<'a>) -> visitor::Result {
+        self.write("JSON_SET(")?;
+        self.visit_expression(*json_set.column)?;
+        self.write(", ")?;
+
+        match json_set.path {
+            JsonPath::Array(_) => panic!("JSON path array notation is not supported for MySQL"),
+            JsonPath::String(path) => self.visit_parameterized(Value::text(path))?,
+        }
+
+        self.write(", ")?;
+        self.visit_expression(*json_set.value)?;
+        self.write(")")?;
+
+        Ok(())
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
+    fn visit_json_remove(&mut self, json_remove: JsonRemove<'a>) -> visitor::Result {
+        self.write("JSON_REMOVE(")?;
+        self.visit_expression(*json_remove.column)?;
+        self.write(", ")?;
+
+        match json_remove.path {
+            JsonPath::Array(_) => panic!("JSON path array notation is not supported for MySQL"),
+            JsonPath::String(path) => self.visit_parameterized(Value::text(path))?,
+        }
+
+        self.write(")")?;
+
+        Ok(())
+    }
+
     #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
     fn visit_json_array_contains(&mut self, left: Expression<'a>, right: Expression<'a>, not: bool) -> visitor::Result {
         self.write("JSON_CONTAINS(")?;
@@ -486,6 +614,32 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         Ok(())
     }
 
+    fn visit_geo_contains(&mut self, left: Expression<'a>, right: Expression<'a>) -> visitor::Result {
+        self.write("ST_Contains(")?;
+        self.visit_expression(left)?;
+        self.write(", ")?;
+        self.visit_expression(right)?;
+        self.write(")")?;
+
+        Ok(())
+    }
+
+    fn visit_within_distance(
+        &mut self,
+        left: Expression<'a>,
+        point: Expression<'a>,
+        distance: Expression<'a>,
+    ) -> visitor::Result {
+        self.write("ST_Distance_Sphere(")?;
+        self.visit_expression(left)?;
+        self.write(", ")?;
+        self.visit_expression(point)?;
+        self.write(") <= ")?;
+        self.visit_expression(distance)?;
+
+        Ok(())
+    }
+
     #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
     fn visit_json_type_equals(&mut self, left: Expression<'a>, json_type: JsonType<'a>, not: bool) -> visitor::Result {
         self.write("(")?;
@@ -598,6 +752,40 @@ impl<'a> Visitor<'a> for Mysql<'a> {
         Ok(())
     }
 
+    fn visit_value_position(&mut self, value_position: ValuePosition<'a>) -> visitor::Result {
+        let len = value_position.values.len();
+        let column = value_position.column;
+        let values = value_position.values;
+
+        // `FIELD` is 1-indexed and returns `0` when the value isn't found in the list, so we
+        // shift it down to 0-indexed and map the not-found case to `len`, sorting it last (the
+        // same convention as the `CASE`-based fallback). `FIELD` is rendered twice since it
+        // can't be named and reused within the same expression.
+        self.write("(CASE WHEN FIELD(")?;
+        self.visit_column(column.clone())?;
+        self.write(", ")?;
+        for (i, value) in values.clone().into_iter().enumerate() {
+            self.visit_parameterized(value)?;
+
+            if i < (len - 1) {
+                self.write(",")?;
+            }
+        }
+        self.write(") = 0 THEN ")?;
+        self.write(len)?;
+        self.write(" ELSE FIELD(")?;
+        self.visit_column(column)?;
+        self.write(", ")?;
+        for (i, value) in values.into_iter().enumerate() {
+            self.visit_parameterized(value)?;
+
+            if i < (len - 1) {
+                self.write(",")?;
+            }
+        }
+        self.write(") - 1 END)")
+    }
+
     #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
     fn visit_json_extract_last_array_item(&mut self, extract: JsonExtractLastArrayElem<'a>) -> visitor::Result {
         self.write("JSON_EXTRACT(")?;
@@ -1047,6 +1235,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_update_with_limit() {
+        let update = Update::table("users").set("foo", 1).limit(10);
+        let (sql, params) = Mysql::build(update).unwrap();
+
+        assert_eq!("UPDATE `users` SET `foo` = ? LIMIT ?", sql);
+        assert_eq!(vec![Value::from(1), Value::from(10_i64)], params);
+    }
+
+    #[test]
+    fn test_update_with_order_by_and_limit() {
+        let update = Update::table("users")
+            .set("foo", 1)
+            .order_by("created_at".ascend())
+            .limit(10);
+        let (sql, params) = Mysql::build(update).unwrap();
+
+        assert_eq!("UPDATE `users` SET `foo` = ? ORDER BY `created_at` ASC LIMIT ?", sql);
+        assert_eq!(vec![Value::from(1), Value::from(10_i64)], params);
+    }
+
+    #[test]
+    fn test_delete_with_order_by_and_limit() {
+        let delete = Delete::from_table("users")
+            .order_by("created_at".descend())
+            .limit(10);
+        let (sql, params) = Mysql::build(delete).unwrap();
+
+        assert_eq!("DELETE FROM `users` ORDER BY `created_at` DESC LIMIT ?", sql);
+        assert_eq!(vec![Value::from(10_i64)], params);
+    }
+
     #[test]
     fn test_subselect_temp_table_wrapper_for_delete() {
         let table_1 = "table_1";
@@ -90,6 +90,14 @@ impl<'a> Visitor<'a> for Postgres<'a> {
         Ok(())
     }
 
+    fn visit_parameterized_row_list(&mut self, value: Value<'a>) -> visitor::Result {
+        self.write("(")?;
+        self.query_template.write_parameter_tuple_list("(", ",", ")", ",");
+        self.query_template.parameters.push(value);
+        self.write(")")?;
+        Ok(())
+    }
+
     fn visit_parameterized_enum(&mut self, variant: EnumVariant<'a>, name: Option<EnumName<'a>>) -> visitor::Result {
         self.add_parameter(variant.into_text());
 
@@ -481,6 +489,62 @@ impl<'a> Visitor<'a> for Postgres<'a> {
         Ok(())
     }
 
+    #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
+    fn visit_json_set(&mut self, json_set: JsonSet<'a>) -> visitor::Result {
+        match json_set.path {
+            JsonPath::String(_) => panic!("JSON path string notation is not supported for Postgres"),
+            JsonPath::Array(json_path) => {
+                self.write("JSONB_SET(")?;
+                self.visit_expression(*json_set.column)?;
+                self.write(", ")?;
+
+                self.surround_with("ARRAY[", "]::text[]", |s| {
+                    let len = json_path.len();
+                    for (index, path) in json_path.into_iter().enumerate() {
+                        s.visit_parameterized(Value::text(path))?;
+                        if index < len - 1 {
+                            s.write(", ")?;
+                        }
+                    }
+                    Ok(())
+                })?;
+
+                self.write(", ")?;
+                self.visit_expression(*json_set.value)?;
+                self.write("::jsonb)")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
+    fn visit_json_remove(&mut self, json_remove: JsonRemove<'a>) -> visitor::Result {
+        match json_remove.path {
+            JsonPath::String(_) => panic!("JSON path string notation is not supported for Postgres"),
+            JsonPath::Array(json_path) => {
+                self.write("(")?;
+                self.visit_expression(*json_remove.column)?;
+                self.write(" #- ")?;
+
+                self.surround_with("ARRAY[", "]::text[]", |s| {
+                    let len = json_path.len();
+                    for (index, path) in json_path.into_iter().enumerate() {
+                        s.visit_parameterized(Value::text(path))?;
+                        if index < len - 1 {
+                            s.write(", ")?;
+                        }
+                    }
+                    Ok(())
+                })?;
+
+                self.write(")")?;
+            }
+        }
+
+        Ok(())
+    }
+
     #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
     fn visit_json_unquote(&mut self, json_unquote: JsonUnquote<'a>) -> visitor::Result {
         self.write("(")?;
@@ -508,6 +572,19 @@ impl<'a> Visitor<'a> for Postgres<'a> {
         Ok(())
     }
 
+    fn visit_geo_contains(&mut self, _left: Expression<'a>, _right: Expression<'a>) -> visitor::Result {
+        unimplemented!("Spatial filtering is not yet supported on Postgres")
+    }
+
+    fn visit_within_distance(
+        &mut self,
+        _left: Expression<'a>,
+        _point: Expression<'a>,
+        _distance: Expression<'a>,
+    ) -> visitor::Result {
+        unimplemented!("Spatial filtering is not yet supported on Postgres")
+    }
+
     #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
     fn visit_json_extract_last_array_item(&mut self, extract: JsonExtractLastArrayElem<'a>) -> visitor::Result {
         self.write("(")?;
@@ -609,9 +686,20 @@ impl<'a> Visitor<'a> for Postgres<'a> {
     }
 
     fn visit_text_search(&mut self, text_search: crate::prelude::TextSearch<'a>) -> visitor::Result {
-        let len = text_search.exprs.len();
+        let mut exprs = text_search.exprs;
+
+        // A single expression is wrapped bare, with no `concat_ws`, so the emitted
+        // `to_tsvector(col)` is textually identical to the expression a `tsvector_ops` GIN/GiST
+        // index was declared on. `concat_ws` around one argument is a no-op for the result, but
+        // Postgres only considers an index usable when the indexed expression matches exactly.
+        if exprs.len() == 1 {
+            let expr = exprs.remove(0);
+            return self.surround_with("to_tsvector(", ")", |s| s.visit_expression(expr));
+        }
+
+        let len = exprs.len();
         self.surround_with("to_tsvector(concat_ws(' ', ", "))", |s| {
-            for (i, expr) in text_search.exprs.into_iter().enumerate() {
+            for (i, expr) in exprs.into_iter().enumerate() {
                 s.visit_expression(expr)?;
 
                 if i < (len - 1) {
@@ -663,6 +751,26 @@ impl<'a> Visitor<'a> for Postgres<'a> {
         Ok(())
     }
 
+    fn visit_value_position(&mut self, value_position: ValuePosition<'a>) -> visitor::Result {
+        let len = value_position.values.len();
+
+        // `array_position` returns `NULL` when the value isn't found in the list; coalesce it to
+        // `len` so it sorts last, the same convention as the `CASE`-based fallback.
+        self.write("COALESCE(array_position(ARRAY[")?;
+        for (i, value) in value_position.values.into_iter().enumerate() {
+            self.visit_parameterized(value)?;
+
+            if i < (len - 1) {
+                self.write(",")?;
+            }
+        }
+        self.write("], ")?;
+        self.visit_column(value_position.column)?;
+        self.write(") - 1, ")?;
+        self.write(len)?;
+        self.write(")")
+    }
+
     fn visit_like(&mut self, left: Expression<'a>, right: Expression<'a>) -> visitor::Result {
         let need_cast = matches!(&left.kind, ExpressionKind::Column(_));
         self.visit_expression(left)?;
@@ -825,6 +933,24 @@ mod tests {
         assert_eq!(expected.1, params);
     }
 
+    #[test]
+    fn test_single_row_insert_with_nextval() {
+        let query = Insert::single_into("users").value("id", nextval("users_id_seq"));
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!("INSERT INTO \"users\" (\"id\") VALUES (nextval('users_id_seq'))", sql);
+        assert_eq!(default_params(vec![]), params);
+    }
+
+    #[test]
+    fn test_single_row_insert_with_db_function_call() {
+        let query = Insert::single_into("users").value("id", db_function_call("uuid_generate_v7"));
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!("INSERT INTO \"users\" (\"id\") VALUES (uuid_generate_v7())", sql);
+        assert_eq!(default_params(vec![]), params);
+    }
+
     #[test]
     #[cfg(feature = "postgresql")]
     fn test_returning_insert() {
@@ -999,6 +1125,31 @@ mod tests {
         assert_eq!(expected_sql, sql);
     }
 
+    #[test]
+    fn test_delete_with_ordered_limit_subquery_and_compound_pk() {
+        // Postgres has no native `DELETE ... ORDER BY ... LIMIT`, so an ordered, row-limited
+        // delete is emulated with a correlated subquery on the primary key columns, as built by
+        // `sql-query-builder`'s `wrap_with_limit_subquery_if_needed`.
+        let columns = vec![Column::from(("users", "tenant_id")), Column::from(("users", "id"))];
+
+        let select = Select::from_table("users")
+            .columns(columns.clone())
+            .so_that(("users", "deleted_at").is_null())
+            .order_by(("users", "created_at").ascend())
+            .limit(10);
+
+        let query = Delete::from_table("users").so_that(Row::from(columns).in_selection(select));
+
+        let (sql, _) = Postgres::build(query).unwrap();
+
+        assert_eq!(
+            "DELETE FROM \"users\" WHERE (\"users\".\"tenant_id\",\"users\".\"id\") IN \
+             (SELECT \"users\".\"tenant_id\", \"users\".\"id\" FROM \"users\" WHERE \"users\".\"deleted_at\" IS NULL \
+             ORDER BY \"users\".\"created_at\" ASC LIMIT $1)",
+            sql
+        );
+    }
+
     #[test]
     fn equality_with_a_json_value() {
         let expected = expected_values(
@@ -1219,6 +1370,36 @@ mod tests {
         assert!(params.is_empty());
     }
 
+    #[test]
+    fn test_text_search_single_column_skips_concat_ws() {
+        let expected = expected_values(
+            r#"SELECT "recipes".* FROM "recipes" WHERE to_tsvector("name") @@ to_tsquery($1)"#,
+            vec!["chicken"],
+        );
+
+        let search: Expression = text_search(&[Column::from("name")]).into();
+        let query = Select::from_table("recipes").so_that(search.matches("chicken"));
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
+    #[test]
+    fn test_text_search_multiple_columns_uses_concat_ws() {
+        let expected = expected_values(
+            r#"SELECT "recipes".* FROM "recipes" WHERE to_tsvector(concat_ws(' ', "name","ingredients")) @@ to_tsquery($1)"#,
+            vec!["chicken"],
+        );
+
+        let search: Expression = text_search(&[Column::from("name"), Column::from("ingredients")]).into();
+        let query = Select::from_table("recipes").so_that(search.matches("chicken"));
+        let (sql, params) = Postgres::build(query).unwrap();
+
+        assert_eq!(expected.0, sql);
+        assert_eq!(expected.1, params);
+    }
+
     #[test]
     fn test_like_cast_to_string() {
         let expected = expected_values(
@@ -278,6 +278,14 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         Ok(())
     }
 
+    fn visit_parameterized_row_list(&mut self, value: Value<'a>) -> visitor::Result {
+        self.write("(")?;
+        self.query_template.write_parameter_tuple_list("(", ",", ")", ",");
+        self.query_template.parameters.push(value);
+        self.write(")")?;
+        Ok(())
+    }
+
     fn visit_limit_and_offset(&mut self, limit: Option<Value<'a>>, offset: Option<Value<'a>>) -> visitor::Result {
         match (limit, offset) {
             (Some(limit), Some(offset)) => {
@@ -339,6 +347,40 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         Ok(())
     }
 
+    #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
+    fn visit_json_set(&mut self, json_set: JsonSet<'a>) -> visitor::Result {
+        self.write("JSON_SET(")?;
+        self.visit_expression(*json_set.column)?;
+        self.write(", ")?;
+
+        match json_set.path {
+            JsonPath::Array(_) => panic!("JSON path array notation is not supported for SQlite"),
+            JsonPath::String(path) => self.visit_parameterized(Value::text(path))?,
+        }
+
+        self.write(", ")?;
+        self.visit_expression(*json_set.value)?;
+        self.write(")")?;
+
+        Ok(())
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
+    fn visit_json_remove(&mut self, json_remove: JsonRemove<'a>) -> visitor::Result {
+        self.write("JSON_REMOVE(")?;
+        self.visit_expression(*json_remove.column)?;
+        self.write(", ")?;
+
+        match json_remove.path {
+            JsonPath::Array(_) => panic!("JSON path array notation is not supported for SQlite"),
+            JsonPath::String(path) => self.visit_parameterized(Value::text(path))?,
+        }
+
+        self.write(")")?;
+
+        Ok(())
+    }
+
     fn visit_json_array_contains(
         &mut self,
         _left: Expression<'a>,
@@ -348,6 +390,19 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
         unimplemented!("JSON contains is not supported on SQLite")
     }
 
+    fn visit_geo_contains(&mut self, _left: Expression<'a>, _right: Expression<'a>) -> visitor::Result {
+        unimplemented!("Spatial filtering is not supported on SQLite")
+    }
+
+    fn visit_within_distance(
+        &mut self,
+        _left: Expression<'a>,
+        _point: Expression<'a>,
+        _distance: Expression<'a>,
+    ) -> visitor::Result {
+        unimplemented!("Spatial filtering is not supported on SQLite")
+    }
+
     #[cfg(any(feature = "postgresql", feature = "mysql", feature = "sqlite"))]
     fn visit_json_type_equals(&mut self, left: Expression<'a>, json_type: JsonType<'a>, not: bool) -> visitor::Result {
         self.write("(")?;
@@ -522,6 +577,16 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
             self.visit_conditions(conditions)?;
         }
 
+        if !delete.ordering.is_empty() {
+            self.write(" ORDER BY ")?;
+            self.visit_ordering(delete.ordering)?;
+        }
+
+        if let Some(limit) = delete.limit {
+            self.write(" LIMIT ")?;
+            self.visit_parameterized(limit)?;
+        }
+
         self.returning(delete.returning)?;
 
         if let Some(comment) = delete.comment {
@@ -557,6 +622,16 @@ impl<'a> Visitor<'a> for Sqlite<'a> {
             self.visit_conditions(conditions)?;
         }
 
+        if !update.ordering.is_empty() {
+            self.write(" ORDER BY ")?;
+            self.visit_ordering(update.ordering)?;
+        }
+
+        if let Some(limit) = update.limit {
+            self.write(" LIMIT ")?;
+            self.visit_parameterized(limit)?;
+        }
+
         self.returning(update.returning)?;
 
         if let Some(comment) = update.comment {
@@ -1180,4 +1255,27 @@ mod tests {
         assert_eq!(expected.0, sql);
         assert_eq!(expected.1, params);
     }
+
+    #[test]
+    fn test_update_with_order_by_and_limit() {
+        let update = Update::table("users")
+            .set("foo", 1)
+            .order_by("created_at".ascend())
+            .limit(10);
+        let (sql, params) = Sqlite::build(update).unwrap();
+
+        assert_eq!("UPDATE `users` SET `foo` = ? ORDER BY `created_at` ASC LIMIT ?", sql);
+        assert_eq!(vec![Value::from(1), Value::from(10_i64)], params);
+    }
+
+    #[test]
+    fn test_delete_with_order_by_and_limit() {
+        let delete = Delete::from_table("users")
+            .order_by("created_at".descend())
+            .limit(10);
+        let (sql, params) = Sqlite::build(delete).unwrap();
+
+        assert_eq!("DELETE FROM `users` ORDER BY `created_at` DESC LIMIT ?", sql);
+        assert_eq!(vec![Value::from(10_i64)], params);
+    }
 }
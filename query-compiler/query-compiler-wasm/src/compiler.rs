@@ -110,7 +110,7 @@ impl QueryCompiler {
                 return Err(JsCompileError::plain("Unexpected batch request"));
             };
             let dynamic_schema = DynamicSchema::from_str(schema_request);
-            let plan = query_compiler::compile_with_dynamic_schema(&self.schema, op, &self.connection_info, dynamic_schema)?;
+            let plan = query_compiler::compile_with_dynamic_schema(&self.schema, op, &self.connection_info, dynamic_schema, None)?;
             Ok(plan.serialize(&shared_wasm::RESPONSE_SERIALIZER)?)
         })
     }
@@ -128,27 +128,41 @@ impl QueryCompiler {
             let dynamic_schema = DynamicSchema::from_str(schema_request);
             let response = match request.into_doc(&self.schema)? {
                 QueryDocument::Single(op) => {
-                    let plan = query_compiler::compile_with_dynamic_schema(&self.schema, op, &self.connection_info, dynamic_schema.clone())?;
+                    let plan = query_compiler::compile_with_dynamic_schema(&self.schema, op, &self.connection_info, dynamic_schema.clone(), None)?;
                     BatchResponse::Multi { plans: vec![plan] }
                 }
                 QueryDocument::Multi(batch) => match batch.compact(&self.schema) {
                     BatchDocument::Multi(operations, _) => {
                         let plans = operations
                             .into_iter()
-                            .map(|op| query_compiler::compile_with_dynamic_schema(&self.schema, op, &self.connection_info, dynamic_schema.clone()))
+                            .map(|op| query_compiler::compile_with_dynamic_schema(&self.schema, op, &self.connection_info, dynamic_schema.clone(), None))
                             .collect::<Result<Vec<_>, _>>()?;
                         BatchResponse::Multi { plans }
                     }
                     BatchDocument::Compact(compacted) => {
-                        let expect_non_empty = compacted.throw_on_empty();
-                        let plan = query_compiler::compile_with_dynamic_schema(&self.schema, compacted.operation, &self.connection_info, dynamic_schema.clone())?;
-                        BatchResponse::Compacted {
-                            plan,
-                            arguments: compacted.arguments,
-                            nested_selection: compacted.nested_selection,
-                            keys: compacted.keys,
-                            expect_non_empty,
-                        }
+                        let batches = compacted
+                            .into_iter()
+                            .map(|compacted| {
+                                let expect_non_empty = compacted.throw_on_empty();
+                                let plan = query_compiler::compile_with_dynamic_schema(
+                                    &self.schema,
+                                    compacted.operation,
+                                    &self.connection_info,
+                                    dynamic_schema.clone(),
+                                    None,
+                                )?;
+
+                                Ok(CompactedBatch {
+                                    plan,
+                                    arguments: compacted.arguments,
+                                    nested_selection: compacted.nested_selection,
+                                    keys: compacted.keys,
+                                    original_indices: compacted.original_indices,
+                                    expect_non_empty,
+                                })
+                            })
+                            .collect::<Result<Vec<_>, CompileError>>()?;
+                        BatchResponse::Compacted { batches }
                     }
                 },
             };
@@ -164,16 +178,26 @@ pub enum BatchResponse {
     Multi {
         plans: Vec<Expression>,
     },
-    #[serde(rename_all = "camelCase")]
+    /// One [`CompactedBatch`] per distinct model/shape found in the original batch (see
+    /// `query_core::BatchDocument::compact`), in first-seen order.
     Compacted {
-        plan: Expression,
-        arguments: Vec<HashMap<String, ArgumentValue>>,
-        nested_selection: Vec<String>,
-        keys: Vec<String>,
-        expect_non_empty: bool,
+        batches: Vec<CompactedBatch>,
     },
 }
 
+#[derive(Serialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+#[tsify(into_wasm_abi, hashmap_as_object)]
+pub struct CompactedBatch {
+    plan: Expression,
+    arguments: Vec<HashMap<String, ArgumentValue>>,
+    nested_selection: Vec<String>,
+    keys: Vec<String>,
+    /// Index of each entry above in the original, pre-grouping batch request.
+    original_indices: Vec<usize>,
+    expect_non_empty: bool,
+}
+
 #[derive(Serialize, Tsify)]
 #[tsify(into_wasm_abi, hashmap_as_object)]
 pub struct JsCompileError {
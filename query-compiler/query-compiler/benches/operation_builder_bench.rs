@@ -0,0 +1,96 @@
+//! Compares building a query plan from a hand-built `Operation` (via `Selection::with_name`)
+//! against building the same plan from a JSON protocol document, to measure the parsing overhead
+//! a caller that already has a typed `Operation` - e.g. a proxy that already validated the
+//! incoming request - can skip by calling `query_compiler::compile` directly.
+
+use codspeed_criterion_compat::{black_box, criterion_group, criterion_main, Criterion};
+use query_core::{schema::QuerySchema, Operation, QueryGraphBuilder, Selection};
+use query_structure::psl;
+use request_handlers::{JsonProtocolAdapter, JsonSingleQuery};
+use std::sync::Arc;
+
+const FIELD_COUNT: usize = 50;
+
+fn schema_source() -> String {
+    let fields = (0..FIELD_COUNT)
+        .map(|i| format!("  field{i} String"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"
+datasource db {{
+  provider = "postgresql"
+  url      = "postgresql://"
+}}
+
+generator client {{
+  provider = "prisma-client-js"
+}}
+
+model Widget {{
+  id Int @id
+{fields}
+}}
+"#
+    )
+}
+
+fn query_schema() -> QuerySchema {
+    let schema = psl::validate(schema_source().into());
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    query_core::schema::build(Arc::new(schema), true)
+}
+
+fn field_names() -> Vec<String> {
+    (0..FIELD_COUNT)
+        .map(|i| format!("field{i}"))
+        .chain(std::iter::once("id".to_owned()))
+        .collect()
+}
+
+fn json_query() -> JsonSingleQuery {
+    let selection: serde_json::Map<String, serde_json::Value> = field_names()
+        .into_iter()
+        .map(|name| (name, serde_json::Value::Bool(true)))
+        .collect();
+
+    serde_json::from_value(serde_json::json!({
+        "modelName": "Widget",
+        "action": "findMany",
+        "query": { "arguments": {}, "selection": selection },
+    }))
+    .unwrap()
+}
+
+fn via_json_protocol(query_schema: &QuerySchema) -> Operation {
+    JsonProtocolAdapter::new(query_schema).convert_single(json_query()).unwrap()
+}
+
+fn via_builder() -> Operation {
+    let nested = field_names().into_iter().map(Selection::with_name).collect::<Vec<_>>();
+
+    Operation::Read(Selection::new("findManyWidget", None, Vec::new(), nested))
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let query_schema = query_schema();
+
+    c.bench_function("build_plan_via_json_protocol", |b| {
+        b.iter(|| {
+            let operation = black_box(via_json_protocol(&query_schema));
+            QueryGraphBuilder::new(&query_schema).build(operation).unwrap();
+        })
+    });
+
+    c.bench_function("build_plan_via_operation_builder", |b| {
+        b.iter(|| {
+            let operation = black_box(via_builder());
+            QueryGraphBuilder::new(&query_schema).build(operation).unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
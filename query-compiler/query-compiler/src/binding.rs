@@ -35,6 +35,12 @@ pub fn generated(row_idx: usize, field_name: &str) -> Cow<'static, str> {
     format!("{GENERATED}{FIELD_SEPARATOR}row{row_idx}{FIELD_SEPARATOR}{field_name}").into()
 }
 
+/// Like [`generated`], but for a generator call whose value is meant to be shared across every
+/// row of a multi-row insert (currently only `now()`) instead of evaluated once per row.
+pub fn generated_shared(field_name: &str) -> Cow<'static, str> {
+    format!("{GENERATED}{FIELD_SEPARATOR}shared{FIELD_SEPARATOR}{field_name}").into()
+}
+
 pub fn selector(field: &SelectedField) -> Cow<'static, str> {
     format!("{SELECTOR}{FIELD_SEPARATOR}{}", field.prisma_name()).into()
 }
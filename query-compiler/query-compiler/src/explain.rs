@@ -0,0 +1,197 @@
+use quaint::{
+    bigdecimal::BigDecimal,
+    chrono::{TimeZone, Utc},
+    connector::Queryable,
+    prelude::SqlFamily,
+    Value,
+};
+use query_builder::DbQuery;
+use query_structure::{PrismaValue, PrismaValueType};
+use query_template::Fragment;
+use serde::Serialize;
+
+use crate::validate::{collect_statements, to_sql_text};
+use crate::Expression;
+
+/// The outcome of `EXPLAIN`-ing a single statement of a compiled plan, see [`explain_plan`].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplainedQuery {
+    /// Identifies the expression node the statement came from, e.g. `"Seq[1] > Execute"`.
+    pub node: String,
+    /// The original, unprefixed SQL for the statement, with placeholders rendered in the
+    /// connector's own syntax.
+    pub query: String,
+    /// The rows the database's `EXPLAIN` returned, or `None` if `error` is set.
+    pub plan: Option<serde_json::Value>,
+    /// `None` if the statement was explained successfully; otherwise the connector's error, or a
+    /// note that this connector family isn't supported.
+    pub error: Option<String>,
+}
+
+impl ExplainedQuery {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Runs the database's `EXPLAIN` (or `EXPLAIN ANALYZE` when `analyze` is `true`) against every
+/// `DbQuery` in a compiled `plan`, without mutating data.
+///
+/// Parameters are not known at explain time, so any [`PrismaValue::Placeholder`] or
+/// [`PrismaValue::GeneratorCall`] is replaced with a dummy literal of the same
+/// [`PrismaValueType`] before binding.
+///
+/// Plain `EXPLAIN` never executes the statement it describes, so `analyze: false` is always
+/// side-effect free. `EXPLAIN ANALYZE` (Postgres and CockroachDB only) does execute the
+/// statement, including writes, so the whole plan is wrapped in a transaction that is always
+/// rolled back.
+pub async fn explain_plan(
+    plan: &Expression,
+    queryable: &dyn Queryable,
+    family: SqlFamily,
+    analyze: bool,
+) -> Vec<ExplainedQuery> {
+    let mut statements = Vec::new();
+    collect_statements(plan, String::new(), &mut statements);
+
+    if analyze {
+        let _ = queryable.raw_cmd("BEGIN").await;
+    }
+
+    let mut results = Vec::with_capacity(statements.len());
+    for (node, query) in statements {
+        results.push(explain_statement(node, query, queryable, family, analyze).await);
+    }
+
+    if analyze {
+        let _ = queryable.raw_cmd("ROLLBACK").await;
+    }
+
+    results
+}
+
+async fn explain_statement(
+    node: String,
+    query: &DbQuery,
+    queryable: &dyn Queryable,
+    family: SqlFamily,
+    analyze: bool,
+) -> ExplainedQuery {
+    let sql = to_sql_text(query);
+
+    let prefix = match explain_prefix(family, analyze) {
+        Ok(prefix) => prefix,
+        Err(error) => return ExplainedQuery { node, query: sql, plan: None, error: Some(error) },
+    };
+
+    let params: Vec<Value<'static>> = query.params().iter().map(to_dummy_quaint_value).collect();
+    let explained_sql = format!("{prefix}{sql}");
+
+    match queryable.query_raw(&explained_sql, &params).await {
+        Ok(result_set) => ExplainedQuery {
+            node,
+            query: sql,
+            plan: Some(result_set.into()),
+            error: None,
+        },
+        Err(error) => ExplainedQuery {
+            node,
+            query: sql,
+            plan: None,
+            error: Some(error.to_string()),
+        },
+    }
+}
+
+fn explain_prefix(family: SqlFamily, analyze: bool) -> Result<&'static str, String> {
+    match family {
+        #[cfg(feature = "postgresql")]
+        SqlFamily::Postgres if analyze => Ok("EXPLAIN (ANALYZE, FORMAT JSON) "),
+        #[cfg(feature = "postgresql")]
+        SqlFamily::Postgres => Ok("EXPLAIN (FORMAT JSON) "),
+        #[cfg(feature = "mysql")]
+        SqlFamily::Mysql => Ok("EXPLAIN FORMAT=JSON "),
+        #[cfg(feature = "sqlite")]
+        SqlFamily::Sqlite => Ok("EXPLAIN QUERY PLAN "),
+        #[cfg(feature = "mssql")]
+        SqlFamily::Mssql => Err("explain_plan does not support SQL Server yet".to_owned()),
+    }
+}
+
+/// Wraps `query` in the `EXPLAIN` statement `family` understands, without running it. Unlike
+/// [`explain_plan`], this doesn't need a live connection: it just returns the rewritten
+/// [`DbQuery`] for the caller to execute (or inspect) on their own, which is handy for pulling the
+/// exact SQL the engine would run under `EXPLAIN` for ad-hoc performance debugging.
+pub fn build_explain(query: DbQuery, family: SqlFamily, analyze: bool) -> Result<DbQuery, String> {
+    let prefix = explain_prefix(family, analyze)?;
+
+    Ok(match query {
+        DbQuery::RawSql { sql, params } => DbQuery::RawSql {
+            sql: format!("{prefix}{sql}"),
+            params,
+        },
+        DbQuery::TemplateSql {
+            mut fragments,
+            params,
+            placeholder_format,
+        } => {
+            fragments.insert(0, Fragment::StringChunk { chunk: prefix.to_owned() });
+            DbQuery::TemplateSql {
+                fragments,
+                params,
+                placeholder_format,
+            }
+        }
+    })
+}
+
+/// Replaces a parameter with a NULL-safe literal of the same type if it isn't one already, then
+/// converts it to the `quaint::Value` `EXPLAIN`'s `query_raw` call needs.
+fn to_dummy_quaint_value(param: &PrismaValue) -> Value<'static> {
+    match param {
+        PrismaValue::Placeholder(placeholder) => prisma_literal_to_quaint(dummy_prisma_value(&placeholder.r#type)),
+        PrismaValue::GeneratorCall { return_type, .. } => prisma_literal_to_quaint(dummy_prisma_value(return_type)),
+        other => prisma_literal_to_quaint(other.clone()),
+    }
+}
+
+/// A dummy literal standing in for a placeholder or generator call, typed so the statement still
+/// prepares and, under `EXPLAIN ANALYZE`, still runs without a type error.
+fn dummy_prisma_value(value_type: &PrismaValueType) -> PrismaValue {
+    match value_type {
+        PrismaValueType::Any | PrismaValueType::Object | PrismaValueType::Enum(_) => PrismaValue::Null,
+        // `null` is always a valid value for a nullable column, and it's the cheapest dummy we can hand
+        // to EXPLAIN without knowing anything else about the wrapped type.
+        PrismaValueType::Nullable(_) => PrismaValue::Null,
+        PrismaValueType::String => PrismaValue::String(String::new()),
+        PrismaValueType::Int => PrismaValue::Int(0),
+        PrismaValueType::BigInt => PrismaValue::BigInt(0),
+        PrismaValueType::Float | PrismaValueType::Decimal => PrismaValue::Float(BigDecimal::from(0)),
+        PrismaValueType::Boolean => PrismaValue::Boolean(false),
+        PrismaValueType::Date | PrismaValueType::Time => PrismaValue::DateTime(Utc.timestamp_opt(0, 0).unwrap().into()),
+        PrismaValueType::Bytes => PrismaValue::Bytes(Vec::new()),
+        PrismaValueType::Array(_) => PrismaValue::List(Vec::new()),
+    }
+}
+
+fn prisma_literal_to_quaint(value: PrismaValue) -> Value<'static> {
+    match value {
+        PrismaValue::String(s) => Value::text(s),
+        PrismaValue::Boolean(b) => Value::boolean(b),
+        PrismaValue::Enum(e) => Value::text(e),
+        PrismaValue::Int(i) => Value::int64(i),
+        PrismaValue::BigInt(i) => Value::int64(i),
+        PrismaValue::Uuid(u) => Value::text(u.to_string()),
+        PrismaValue::List(l) => Value::array(l.into_iter().map(prisma_literal_to_quaint)),
+        PrismaValue::Json(s) => Value::json(serde_json::from_str(&s).unwrap_or(serde_json::Value::Null)),
+        PrismaValue::Object(_) => Value::json(serde_json::Value::Null),
+        PrismaValue::Null => Value::null_int32(),
+        PrismaValue::DateTime(d) => Value::datetime(d.with_timezone(&Utc)),
+        PrismaValue::Float(f) => Value::numeric(f),
+        PrismaValue::Bytes(b) => Value::bytes(b),
+        PrismaValue::Placeholder(_) | PrismaValue::GeneratorCall { .. } => {
+            unreachable!("placeholders and generator calls are replaced with dummy literals before this point")
+        }
+    }
+}
@@ -177,7 +177,10 @@ impl TryFrom<ScalarWriteOperation> for FieldOperation {
             ScalarWriteOperation::Subtract(val) => Ok(Self::Subtract(val)),
             ScalarWriteOperation::Multiply(val) => Ok(Self::Multiply(val)),
             ScalarWriteOperation::Divide(val) => Ok(Self::Divide(val)),
-            ScalarWriteOperation::Field(_) | ScalarWriteOperation::Unset(_) => Err(UnsupportedScalarWriteOperation(op)),
+            ScalarWriteOperation::Field(_)
+            | ScalarWriteOperation::Unset(_)
+            | ScalarWriteOperation::JsonSet(..)
+            | ScalarWriteOperation::JsonRemove(..) => Err(UnsupportedScalarWriteOperation(op)),
         }
     }
 }
@@ -268,6 +271,7 @@ impl ExpressionType {
             PrismaValueType::Any => ExpressionType::Dynamic,
             PrismaValueType::Array(inner) => ExpressionType::List(Box::new(ExpressionType::from_value_type(*inner))),
             PrismaValueType::Object => ExpressionType::Record,
+            PrismaValueType::Nullable(inner) => ExpressionType::from_value_type(*inner),
             _ => ExpressionType::Scalar,
         }
     }
@@ -369,6 +369,7 @@ where
                                 .append(self.softline())
                                 .append(self.text(count.to_string())),
                             DataRule::Never => self.text("never"),
+                            DataRule::Always => self.text("always"),
                         };
                         self.softline().append(rendered_rule).append(self.line())
                     }),
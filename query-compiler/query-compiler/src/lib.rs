@@ -1,22 +1,38 @@
 mod binding;
 mod data_mapper;
 pub mod expression;
+pub mod explain;
 pub mod result_node;
 mod selection;
 pub mod translate;
+pub mod validate;
 
 pub use expression::Expression;
+pub use explain::{build_explain, explain_plan, ExplainedQuery};
+pub use validate::{validate_plan, StatementValidation, ValidationReport};
 use quaint::{
     prelude::{ConnectionInfo, SqlFamily},
     visitor,
 };
+use query_builder::{DbQuery, QueryBuilder};
+pub use query_builder::Warning as PlanWarning;
 use query_core::{Operation, QueryGraphBuilderError, schema::QuerySchema};
-use sql_query_builder::{Context, DynamicSchema, SqlQueryBuilder};
+use sql_query_builder::{Context, DynamicSchema, JoinStrategyNotSupported, SqlQueryBuilder};
+pub use sql_query_builder::TraceCommentMode;
 use thiserror::Error;
 pub use translate::{TranslateError, translate};
 
 use query_core::QueryGraphBuilder;
 
+/// The result of [`compile_with_diagnostics`]: the compiled expression alongside any warnings
+/// about query plans that had to be silently degraded (e.g. an in-memory distinct, or an upsert
+/// that couldn't use the connector's native `ON CONFLICT`-style statement).
+#[derive(Debug)]
+pub struct CompileResult {
+    pub expression: Expression,
+    pub warnings: Vec<PlanWarning>,
+}
+
 #[derive(Debug, Error)]
 pub enum CompileError {
     #[error("only a single query can be compiled at a time")]
@@ -27,9 +43,26 @@ pub enum CompileError {
 
     #[error("{0}")]
     TranslateError(#[from] TranslateError),
-}
 
+    /// The `ConnectionInfo`'s `SqlFamily` doesn't have a matching query builder compiled into
+    /// this build (e.g. a workspace feature-unification mismatch enabled `Mssql` in `quaint` but
+    /// not the corresponding feature on this crate). Without this, the family match below would
+    /// either fail to compile with a non-exhaustive match error, or silently drop into the
+    /// catch-all added to guard against exactly that skew.
+    #[error("connector {family:?} is not supported by this build of the query compiler: {reason}")]
+    UnsupportedConnector { family: SqlFamily, reason: String },
+
+    #[error("unsupported feature: {0}")]
+    UnsupportedFeature(String),
+}
 
+/// Compiles a single query into an [`Expression`] the interpreter can run.
+///
+/// `query` is usually parsed from a GraphQL or JSON protocol document by the caller, but that's
+/// not required: [`Operation`] and [`query_core::Selection`] can be constructed directly, which
+/// skips protocol parsing entirely for callers that already have a typed query to run. Either way
+/// schema validation happens while building the query graph, so a hand-built `Operation` naming a
+/// field the schema doesn't have fails the same way a parsed one would.
 /**
  * Changed by @vetching-corporation
  * Author: nfl1ryxditimo12@gmail.com
@@ -41,7 +74,7 @@ pub fn compile(
     query: Operation,
     connection_info: &ConnectionInfo,
 ) -> Result<Expression, CompileError> {
-    compile_with_dynamic_schema(query_schema, query, connection_info, DynamicSchema::default())
+    compile_with_dynamic_schema(query_schema, query, connection_info, DynamicSchema::default(), None)
 }
 
 /**
@@ -50,27 +83,102 @@ pub fn compile(
  * Date: 2025-06-16
  * Note: Add `compile_with_dynamic_schema` function to support dynamic schema
  */
+/// `trace_comment_mode` overrides the `TraceCommentMode` the resulting queries are built with
+/// (see `PRISMA_TRACE_COMMENT_MODE`); pass `None` to use the engine-level default.
 pub fn compile_with_dynamic_schema(
     query_schema: &QuerySchema,
     query: Operation,
     connection_info: &ConnectionInfo,
     dynamic_schema: DynamicSchema,
+    trace_comment_mode: Option<TraceCommentMode>,
 ) -> Result<Expression, CompileError> {
-    let ctx = Context::new_with_dynamic_schema(connection_info, dynamic_schema, None);
+    compile_with_diagnostics(query_schema, query, connection_info, dynamic_schema, trace_comment_mode)
+        .map(|result| result.expression)
+}
+
+/// Like `compile_with_dynamic_schema`, but also returns [`PlanWarning`]s about query plans that
+/// had to be silently degraded, e.g. an in-memory distinct or an upsert that couldn't use the
+/// connector's native `ON CONFLICT`-style statement.
+pub fn compile_with_diagnostics(
+    query_schema: &QuerySchema,
+    query: Operation,
+    connection_info: &ConnectionInfo,
+    dynamic_schema: DynamicSchema,
+    trace_comment_mode: Option<TraceCommentMode>,
+) -> Result<CompileResult, CompileError> {
+    let mut ctx = Context::new_with_dynamic_schema(connection_info, dynamic_schema, None);
+    if let Some(mode) = trace_comment_mode {
+        ctx = ctx.with_trace_comment_mode(mode);
+    }
+    let search_path = ctx.dynamic_schema_search_path();
     let (graph, _serializer) = QueryGraphBuilder::new(query_schema)
         .without_eager_default_evaluation()
         .build(query)?;
 
-    let res: Result<Expression, TranslateError> = match connection_info.sql_family() {
+    let mut warnings: Vec<PlanWarning> = graph.diagnostics().to_vec();
+
+    let family = connection_info.sql_family();
+
+    let res: Result<(Expression, Vec<PlanWarning>), TranslateError> = match family {
         #[cfg(feature = "postgresql")]
-        SqlFamily::Postgres => translate(graph, &SqlQueryBuilder::<visitor::Postgres<'_>>::new(ctx)),
+        SqlFamily::Postgres => {
+            let builder = SqlQueryBuilder::<visitor::Postgres<'_>>::new(ctx);
+            translate(graph, &builder).map(|expr| (expr, builder.drain_warnings()))
+        }
         #[cfg(feature = "mysql")]
-        SqlFamily::Mysql => translate(graph, &SqlQueryBuilder::<visitor::Mysql<'_>>::new(ctx)),
+        SqlFamily::Mysql => {
+            let builder = SqlQueryBuilder::<visitor::Mysql<'_>>::new(ctx);
+            translate(graph, &builder).map(|expr| (expr, builder.drain_warnings()))
+        }
         #[cfg(feature = "sqlite")]
-        SqlFamily::Sqlite => translate(graph, &SqlQueryBuilder::<visitor::Sqlite<'_>>::new(ctx)),
+        SqlFamily::Sqlite => {
+            let builder = SqlQueryBuilder::<visitor::Sqlite<'_>>::new(ctx);
+            translate(graph, &builder).map(|expr| (expr, builder.drain_warnings()))
+        }
         #[cfg(feature = "mssql")]
-        SqlFamily::Mssql => translate(graph, &SqlQueryBuilder::<visitor::Mssql<'_>>::new(ctx)),
+        SqlFamily::Mssql => {
+            let builder = SqlQueryBuilder::<visitor::Mssql<'_>>::new(ctx);
+            translate(graph, &builder).map(|expr| (expr, builder.drain_warnings()))
+        }
+        // Guards against a workspace feature-unification mismatch: `family` exists, which means
+        // `quaint` was built with that connector's feature enabled, but this crate wasn't built
+        // with the matching feature, so none of the arms above apply.
+        #[allow(unreachable_patterns)]
+        _ => {
+            return Err(CompileError::UnsupportedConnector {
+                family,
+                reason: "the corresponding query builder feature is not compiled into this build".to_owned(),
+            });
+        }
+    };
+
+    let (expression, builder_warnings) = match res {
+        Ok(ok) => ok,
+        Err(TranslateError::QueryBuildFailure(e)) if e.is::<JoinStrategyNotSupported>() => {
+            return Err(CompileError::UnsupportedFeature("relation_joins".to_owned()));
+        }
+        Err(e) => return Err(CompileError::TranslateError(e)),
+    };
+    warnings.extend(builder_warnings);
+
+    /*
+     * Changed by @vetching-corporation
+     * Author: nfl1ryxditimo12@gmail.com
+     * Date: 2025-06-16
+     * Note: Emit a leading `SET search_path` statement when the dynamic schema is configured to
+     * apply via search_path, so table references can stay schema-less and the generated SQL text
+     * is shared across tenants instead of busting the connector's prepared statement cache.
+     */
+    let expression = match search_path {
+        Some(search_path) => Expression::Seq(vec![
+            Expression::Execute(DbQuery::RawSql {
+                sql: format!("SET search_path TO {search_path}"),
+                params: Vec::new(),
+            }),
+            expression,
+        ]),
+        None => expression,
     };
 
-    res.map_err(CompileError::TranslateError)
+    Ok(CompileResult { expression, warnings })
 }
@@ -1,9 +1,8 @@
 mod read;
 mod write;
 
-use std::mem;
+use std::{collections::HashMap, mem};
 
-use itertools::Itertools;
 use query_builder::QueryBuilder;
 use query_core::Query;
 use query_structure::{PrismaValue, ScalarWriteOperation, WriteOperation};
@@ -22,24 +21,48 @@ pub(crate) fn translate_query(query: Query, builder: &dyn QueryBuilder) -> Trans
         Query::Read(rq) => translate_read_query(rq, builder),
         Query::Write(mut wq) => {
             // Extract any side-effectful generator calls from an underlying INSERT (if any) and
-            // convert them into bindings.
-            let bindings = wq
-                .insert_args_mut()
-                .iter_mut()
-                .enumerate()
-                .flat_map(|(row_idx, args)| args.args.iter_mut().map(move |arg| (row_idx, arg)))
-                .filter_map(|(row_idx, (name, arg))| {
-                    if let WriteOperation::Scalar(ScalarWriteOperation::Set(val @ PrismaValue::GeneratorCall { .. })) =
+            // convert them into bindings, so each is evaluated exactly once instead of wherever
+            // it's referenced from in the query. `now()` is the one generator whose value must
+            // stay the same across every row of a multi-row insert (`WriteArgs::add_datetimes`
+            // and `get_request_now()` already clone the same request-scoped timestamp into every
+            // row), so it gets a single binding shared by field name; every other generator
+            // (`uuid()`, `cuid()`, `ulid()`, `nanoid()`) must produce a fresh value per row and
+            // keeps its own per-row binding.
+            let mut bindings = Vec::new();
+            let mut shared_now_bindings: HashMap<String, std::borrow::Cow<'static, str>> = HashMap::new();
+
+            for (row_idx, args) in wq.insert_args_mut().iter_mut().enumerate() {
+                for (field_name, arg) in args.args.iter_mut() {
+                    let WriteOperation::Scalar(ScalarWriteOperation::Set(val @ PrismaValue::GeneratorCall { .. })) =
                         arg
-                    {
-                        let name = binding::generated(row_idx, name);
-                        let val = mem::replace(val, PrismaValue::placeholder(name.clone(), val.r#type()));
-                        Some(Binding::new(name, Expression::Value(val)))
+                    else {
+                        continue;
+                    };
+
+                    let is_now = matches!(val, PrismaValue::GeneratorCall { name, .. } if name.as_ref() == "now");
+
+                    if is_now {
+                        if let Some(existing) = shared_now_bindings.get(field_name.as_str()) {
+                            *val = PrismaValue::placeholder(existing.clone(), val.r#type());
+                            continue;
+                        }
+                    }
+
+                    let binding_name = if is_now {
+                        binding::generated_shared(field_name)
                     } else {
-                        None
+                        binding::generated(row_idx, field_name)
+                    };
+
+                    if is_now {
+                        shared_now_bindings.insert(field_name.as_str().to_owned(), binding_name.clone());
                     }
-                })
-                .collect_vec();
+
+                    let placeholder = PrismaValue::placeholder(binding_name.clone(), val.r#type());
+                    let val = mem::replace(val, placeholder);
+                    bindings.push(Binding::new(binding_name, Expression::Value(val)));
+                }
+            }
 
             if !bindings.is_empty() {
                 Ok(Expression::Let {
@@ -61,10 +61,12 @@ pub(crate) fn translate_read_query(query: ReadQuery, builder: &dyn QueryBuilder)
                 .args
                 .requires_inmemory_processing()
                 .then(|| extract_pagination(&mut mrq.args));
-            let distinct_by = mrq
-                .args
-                .requires_inmemory_distinct()
-                .then(|| extract_distinct_by(&mut mrq.args));
+            let distinct_by = mrq.args.requires_inmemory_distinct().then(|| {
+                builder.report_warning(query_builder::Warning::InMemoryDistinct {
+                    model: mrq.model.name().to_owned(),
+                });
+                extract_distinct_by(&mut mrq.args)
+            });
 
             // TODO: we ignore chunking for now
             let query = builder
@@ -22,7 +22,7 @@ pub(crate) fn translate_write_query(query: WriteQuery, builder: &dyn QueryBuilde
             let CreateRecord {
                 select_defaults,
                 insert_query,
-                last_insert_id_field,
+                last_insert_id_fields,
                 merge_values,
             } = builder
                 .build_create_record(&cr.model, cr.args, &cr.selected_fields)
@@ -33,7 +33,7 @@ pub(crate) fn translate_write_query(query: WriteQuery, builder: &dyn QueryBuilde
                 .map(|(field, value)| (field.db_name().into(), FieldInitializer::Value(value)))
                 .collect::<BTreeMap<_, _>>();
 
-            if let Some(last_insert_id_field) = last_insert_id_field {
+            for last_insert_id_field in last_insert_id_fields {
                 initializers.insert(last_insert_id_field.db_name().into(), FieldInitializer::LastInsertId);
             }
 
@@ -121,6 +121,8 @@ pub(crate) fn translate_write_query(query: WriteQuery, builder: &dyn QueryBuilde
             args,
             selected_fields,
             limit,
+            order_by,
+            chunk_execution_policy: _,
         }) => {
             let projection = selected_fields.as_ref().map(|f| &f.fields);
 
@@ -129,7 +131,7 @@ pub(crate) fn translate_write_query(query: WriteQuery, builder: &dyn QueryBuilde
                 .unwrap_or_default();
 
             let updates = builder
-                .build_updates(&model, record_filter, args, projection, limit)
+                .build_updates(&model, record_filter, args, projection, order_by, limit)
                 .map_err(TranslateError::QueryBuildFailure)?
                 .into_iter()
                 .map(if projection.is_some() {
@@ -159,6 +161,8 @@ pub(crate) fn translate_write_query(query: WriteQuery, builder: &dyn QueryBuilde
             expr
         }
 
+        // Only constructed upstream for connectors that support `RETURNING` on `UPDATE`; the
+        // builder reads `selected_fields` back from the statement's `RETURNING` clause.
         WriteQuery::UpdateRecord(UpdateRecord::WithSelection(UpdateRecordWithSelection {
             name: _,
             model,
@@ -299,6 +303,8 @@ pub(crate) fn translate_write_query(query: WriteQuery, builder: &dyn QueryBuilde
             model,
             mut record_filter,
             limit,
+            order_by,
+            chunk_execution_policy: _,
         }) => {
             let selector_bindings = limit
                 .map(|limit| extract_selectors_that_require_limit(&mut record_filter, limit))
@@ -306,7 +312,7 @@ pub(crate) fn translate_write_query(query: WriteQuery, builder: &dyn QueryBuilde
 
             let mut expr = Expression::Sum(
                 builder
-                    .build_deletes(&model, record_filter, limit)
+                    .build_deletes(&model, record_filter, order_by, limit)
                     .map_err(TranslateError::QueryBuildFailure)?
                     .into_iter()
                     .map(Expression::Execute)
@@ -327,6 +333,7 @@ pub(crate) fn translate_write_query(query: WriteQuery, builder: &dyn QueryBuilde
             parent_id,
             child_ids,
             relation_field,
+            ..
         }) => {
             let (_, parent) = parent_id
                 .into_iter()
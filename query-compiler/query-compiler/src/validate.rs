@@ -0,0 +1,237 @@
+use quaint::connector::{ColumnType, DescribedParameter, Queryable};
+use query_builder::DbQuery;
+use query_structure::{PrismaValue, PrismaValueType};
+use query_template::Fragment;
+use serde::Serialize;
+
+use crate::expression::{Binding, JoinExpression};
+use crate::Expression;
+
+/// The outcome of re-validating a single statement of a compiled plan against a live connection,
+/// see [`validate_plan`].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementValidation {
+    /// Identifies the expression node the statement came from, e.g. `"Seq[1] > Execute"`.
+    pub node: String,
+    pub sql: String,
+    /// `None` if the statement still matches the database; otherwise the reason it doesn't,
+    /// taken from the connector's prepare error or from a parameter type mismatch.
+    pub error: Option<String>,
+}
+
+impl StatementValidation {
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// A report produced by [`validate_plan`], describing whether every statement of a compiled plan
+/// still matches the shape of a live database.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationReport {
+    pub statements: Vec<StatementValidation>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.statements.iter().all(StatementValidation::is_valid)
+    }
+}
+
+/// Re-validates every statement of a compiled `plan` against a live connection, without executing
+/// any of them, by running the connector's describe/prepare facility
+/// ([`Queryable::describe_query`]).
+///
+/// This is meant for long-lived compiled plans that may run well after compilation, when the
+/// schema they were compiled against could have drifted (e.g. a column was dropped): each
+/// statement is prepared against `queryable` and its described parameter types are compared
+/// against the plan's own parameter metadata. It does not catch every possible drift, only what
+/// the connector's prepare step surfaces plus parameter type mismatches.
+pub async fn validate_plan(plan: &Expression, queryable: &dyn Queryable) -> ValidationReport {
+    let mut statements = Vec::new();
+    collect_statements(plan, String::new(), &mut statements);
+
+    let mut results = Vec::with_capacity(statements.len());
+
+    for (node, query) in statements {
+        results.push(validate_statement(node, query, queryable).await);
+    }
+
+    ValidationReport { statements: results }
+}
+
+pub(crate) fn append(path: &str, segment: impl std::fmt::Display) -> String {
+    if path.is_empty() {
+        segment.to_string()
+    } else {
+        format!("{path} > {segment}")
+    }
+}
+
+pub(crate) fn collect_statements<'a>(expr: &'a Expression, path: String, out: &mut Vec<(String, &'a DbQuery)>) {
+    match expr {
+        Expression::Query(query) => out.push((append(&path, "Query"), query)),
+        Expression::Execute(query) => out.push((append(&path, "Execute"), query)),
+
+        Expression::Seq(exprs) | Expression::Sum(exprs) | Expression::Concat(exprs) => {
+            for (ix, expr) in exprs.iter().enumerate() {
+                collect_statements(expr, append(&path, format_args!("Seq[{ix}]")), out);
+            }
+        }
+
+        Expression::Let { bindings, expr } => {
+            for Binding { name, expr } in bindings {
+                collect_statements(expr, append(&path, format_args!("Let({name})")), out);
+            }
+            collect_statements(expr, append(&path, "Let.body"), out);
+        }
+
+        Expression::Join { parent, children } => {
+            collect_statements(parent, append(&path, "Join.parent"), out);
+            for JoinExpression { child, parent_field, .. } in children {
+                collect_statements(child, append(&path, format_args!("Join.child({parent_field})")), out);
+            }
+        }
+
+        Expression::If { value, then, r#else, .. } => {
+            collect_statements(value, append(&path, "If.value"), out);
+            collect_statements(then, append(&path, "If.then"), out);
+            collect_statements(r#else, append(&path, "If.else"), out);
+        }
+
+        Expression::Diff { from, to } => {
+            collect_statements(from, append(&path, "Diff.from"), out);
+            collect_statements(to, append(&path, "Diff.to"), out);
+        }
+
+        Expression::Reverse(expr)
+        | Expression::Unique(expr)
+        | Expression::Required(expr)
+        | Expression::Transaction(expr)
+        | Expression::MapField { records: expr, .. }
+        | Expression::DataMap { expr, .. }
+        | Expression::Validate { expr, .. }
+        | Expression::DistinctBy { expr, .. }
+        | Expression::Paginate { expr, .. }
+        | Expression::InitializeRecord { expr, .. }
+        | Expression::MapRecord { expr, .. } => {
+            collect_statements(expr, path, out);
+        }
+
+        Expression::Value(_) | Expression::Get { .. } | Expression::GetFirstNonEmpty { .. } | Expression::Unit => {}
+    }
+}
+
+async fn validate_statement(node: String, query: &DbQuery, queryable: &dyn Queryable) -> StatementValidation {
+    let sql = to_sql_text(query);
+
+    let error = match queryable.describe_query(&sql).await {
+        Err(err) => Some(err.to_string()),
+        Ok(described) => check_parameter_types(query.params(), &described.parameters),
+    };
+
+    StatementValidation { node, sql, error }
+}
+
+/// Renders a `DbQuery` to plain SQL text suitable for `Queryable::describe_query`, which only
+/// prepares the statement and never substitutes parameter values.
+pub(crate) fn to_sql_text(query: &DbQuery) -> String {
+    match query {
+        DbQuery::RawSql { sql, .. } => sql.clone(),
+        DbQuery::TemplateSql {
+            fragments,
+            placeholder_format,
+            ..
+        } => {
+            let mut sql = String::new();
+            let mut number = 1;
+
+            for fragment in fragments {
+                match fragment {
+                    Fragment::StringChunk { chunk } => sql.push_str(chunk),
+                    Fragment::Parameter => {
+                        let _ = placeholder_format.write(&mut sql, &mut number);
+                    }
+                    Fragment::ParameterRef { index } => {
+                        let _ = placeholder_format.write(&mut sql, &mut (*index as i32 + 1));
+                    }
+                    Fragment::ParameterTuple => {
+                        sql.push('(');
+                        let _ = placeholder_format.write(&mut sql, &mut number);
+                        sql.push(')');
+                    }
+                    Fragment::ParameterTupleList { .. } => {
+                        sql.push('(');
+                        let _ = placeholder_format.write(&mut sql, &mut number);
+                        sql.push(')');
+                    }
+                }
+            }
+
+            sql
+        }
+    }
+}
+
+fn check_parameter_types(params: &[PrismaValue], described: &[DescribedParameter]) -> Option<String> {
+    if params.len() != described.len() {
+        return Some(format!(
+            "the plan provides {} parameter(s), but the database expects {}",
+            params.len(),
+            described.len()
+        ));
+    }
+
+    for (ix, (param, described)) in params.iter().zip(described).enumerate() {
+        let PrismaValue::Placeholder(placeholder) = param else {
+            continue;
+        };
+
+        if !prisma_type_matches_column_type(&placeholder.r#type, described.typ) {
+            return Some(format!(
+                "parameter {ix} (`{}`) is typed as {}, but the database column is {:?}",
+                placeholder.name, placeholder.r#type, described.typ
+            ));
+        }
+    }
+
+    None
+}
+
+fn prisma_type_matches_column_type(value_type: &PrismaValueType, column_type: ColumnType) -> bool {
+    use ColumnType::*;
+
+    match value_type {
+        PrismaValueType::Nullable(inner) => prisma_type_matches_column_type(inner, column_type),
+        PrismaValueType::Any | PrismaValueType::Object => true,
+        PrismaValueType::String => matches!(column_type, Text | Char | Json | Xml | Uuid | Enum),
+        PrismaValueType::Int => matches!(column_type, Int32 | Int64),
+        PrismaValueType::BigInt => matches!(column_type, Int32 | Int64),
+        PrismaValueType::Float | PrismaValueType::Decimal => matches!(column_type, Float | Double | Numeric),
+        PrismaValueType::Boolean => matches!(column_type, Boolean),
+        PrismaValueType::Date => matches!(column_type, Date | DateTime),
+        PrismaValueType::Time => matches!(column_type, Time | DateTime),
+        PrismaValueType::Bytes => matches!(column_type, Bytes),
+        PrismaValueType::Enum(_) => matches!(column_type, Enum | Text | Char),
+        PrismaValueType::Array(_) => matches!(
+            column_type,
+            Int32Array
+                | Int64Array
+                | FloatArray
+                | DoubleArray
+                | TextArray
+                | CharArray
+                | BytesArray
+                | BooleanArray
+                | NumericArray
+                | JsonArray
+                | XmlArray
+                | UuidArray
+                | DateTimeArray
+                | DateArray
+                | TimeArray
+        ),
+    }
+}
@@ -0,0 +1,70 @@
+use quaint::prelude::{ConnectionInfo, ExternalConnectionInfo, SqlFamily};
+use query_compiler::PlanWarning;
+use query_core::QueryDocument;
+use query_structure::psl;
+use request_handlers::{JsonBody, JsonSingleQuery, RequestBody};
+use sql_query_builder::DynamicSchema;
+use std::{fs, sync::Arc};
+
+fn compile(fixture: &str) -> query_compiler::CompileResult {
+    let schema_string = include_str!("data/schema.prisma");
+    let schema = psl::validate(schema_string.into());
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    let schema = Arc::new(schema);
+    let query_schema = Arc::new(query_core::schema::build(schema, true));
+
+    let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(
+        SqlFamily::Postgres,
+        Some("public".to_owned()),
+        None,
+        true,
+    ));
+
+    let query = fs::read_to_string(format!("tests/data/diagnostics/{fixture}")).unwrap();
+    let query: JsonSingleQuery = serde_json::from_str(&query).unwrap();
+
+    let request = RequestBody::Json(JsonBody::Single(query));
+    let doc = request.into_doc(&query_schema).unwrap();
+
+    let QueryDocument::Single(operation) = doc else {
+        panic!("expected single query");
+    };
+
+    query_compiler::compile_with_diagnostics(
+        &query_schema,
+        operation,
+        &connection_info,
+        DynamicSchema::default(),
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn find_many_with_distinct_reports_in_memory_distinct_warning() {
+    let result = compile("find-many-distinct.json");
+
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| matches!(w, PlanWarning::InMemoryDistinct { model } if model == "User")),
+        "expected an InMemoryDistinct warning, got {:?}",
+        result.warnings
+    );
+}
+
+#[test]
+fn upsert_with_nested_create_reports_emulated_upsert_warning() {
+    let result = compile("upsert-emulated-nested-create.json");
+
+    assert!(
+        result
+            .warnings
+            .iter()
+            .any(|w| matches!(w, PlanWarning::EmulatedUpsert { .. })),
+        "expected an EmulatedUpsert warning, got {:?}",
+        result.warnings
+    );
+}
@@ -0,0 +1,188 @@
+use quaint::prelude::{ConnectionInfo, ExternalConnectionInfo, SqlFamily};
+use query_compiler::Expression;
+use query_core::QueryDocument;
+use query_structure::psl;
+use request_handlers::{JsonBody, JsonSingleQuery, RequestBody};
+use sql_query_builder::DynamicSchema;
+use std::sync::Arc;
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = "postgresql://"
+  schemas  = ["app"]
+}
+
+generator client {
+  provider        = "prisma-client-js"
+  previewFeatures = ["relationJoins", "multiSchema"]
+}
+
+model User {
+  id     Int    @id
+  status Status @default(ACTIVE)
+  posts  Post[]
+
+  @@schema("app")
+}
+
+enum Status {
+  ACTIVE
+  INACTIVE
+
+  @@schema("app")
+}
+
+model Post {
+  id       Int       @id
+  userId   Int
+  user     User      @relation(fields: [userId], references: [id])
+  comments Comment[]
+
+  @@schema("app")
+}
+
+model Comment {
+  id     Int  @id
+  postId Int
+  post   Post @relation(fields: [postId], references: [id])
+
+  @@schema("app")
+}
+"#;
+
+fn compile(dynamic_schema: DynamicSchema) -> Expression {
+    let schema = psl::validate(SCHEMA.into());
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    let schema = Arc::new(schema);
+    let query_schema = Arc::new(query_core::schema::build(schema, true));
+
+    let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(
+        SqlFamily::Postgres,
+        Some("app".to_owned()),
+        None,
+        true,
+    ));
+
+    let request: JsonSingleQuery = serde_json::from_value(serde_json::json!({
+        "modelName": "User",
+        "action": "findMany",
+        "query": {
+            "arguments": { "relationLoadStrategy": "join" },
+            "selection": {
+                "id": true,
+                "posts": {
+                    "arguments": {},
+                    "selection": {
+                        "id": true,
+                        "comments": {
+                            "arguments": {},
+                            "selection": { "id": true },
+                        },
+                    },
+                },
+            },
+        },
+    }))
+    .unwrap();
+
+    let doc = RequestBody::Json(JsonBody::Single(request)).into_doc(&query_schema).unwrap();
+
+    let QueryDocument::Single(operation) = doc else {
+        panic!("expected single query");
+    };
+
+    query_compiler::compile_with_dynamic_schema(&query_schema, operation, &connection_info, dynamic_schema, None).unwrap()
+}
+
+fn compile_enum_filter(dynamic_schema: DynamicSchema) -> Expression {
+    let schema = psl::validate(SCHEMA.into());
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    let schema = Arc::new(schema);
+    let query_schema = Arc::new(query_core::schema::build(schema, true));
+
+    let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(
+        SqlFamily::Postgres,
+        Some("app".to_owned()),
+        None,
+        true,
+    ));
+
+    let request: JsonSingleQuery = serde_json::from_value(serde_json::json!({
+        "modelName": "User",
+        "action": "findMany",
+        "query": {
+            "arguments": { "where": { "status": "ACTIVE" } },
+            "selection": { "id": true },
+        },
+    }))
+    .unwrap();
+
+    let doc = RequestBody::Json(JsonBody::Single(request)).into_doc(&query_schema).unwrap();
+
+    let QueryDocument::Single(operation) = doc else {
+        panic!("expected single query");
+    };
+
+    query_compiler::compile_with_dynamic_schema(&query_schema, operation, &connection_info, dynamic_schema, None).unwrap()
+}
+
+#[test]
+fn enum_filter_casts_to_the_target_schemas_enum_type() {
+    let mut dynamic_schema = DynamicSchema::new();
+    dynamic_schema.insert("app".to_owned(), "tenant_1".to_owned());
+
+    let sql = compile_enum_filter(dynamic_schema).to_string();
+
+    assert!(
+        !sql.contains("\"app\".\"Status\""),
+        "expected the enum cast's origin schema to be fully remapped, got:\n{sql}"
+    );
+    assert!(
+        sql.contains("\"tenant_1\".\"Status\""),
+        "expected the enum cast to use the remapped schema, got:\n{sql}"
+    );
+}
+
+#[test]
+fn relation_joins_select_remaps_every_table_through_the_dynamic_schema() {
+    let mut dynamic_schema = DynamicSchema::new();
+    dynamic_schema.insert("app".to_owned(), "tenant_1".to_owned());
+
+    let sql = compile(dynamic_schema).to_string();
+
+    assert!(
+        !sql.contains("\"app\""),
+        "expected the origin schema name to be fully remapped, got:\n{sql}"
+    );
+    assert!(
+        sql.contains("\"tenant_1\""),
+        "expected every table reference (User, Post, Comment) to use the remapped schema, got:\n{sql}"
+    );
+}
+
+#[test]
+fn search_path_mode_omits_the_schema_prefix_and_emits_a_leading_set_search_path() {
+    let mut dynamic_schema = DynamicSchema::new();
+    dynamic_schema.insert("app".to_owned(), "tenant_1".to_owned());
+    let dynamic_schema = dynamic_schema.with_search_path(true);
+
+    let sql = compile(dynamic_schema).to_string();
+
+    assert!(
+        !sql.contains("\"app\".") && !sql.contains("\"tenant_1\"."),
+        "expected table references to be schema-less, got:\n{sql}"
+    );
+
+    let set_search_path_pos = sql
+        .find("SET search_path TO \"tenant_1\"")
+        .unwrap_or_else(|| panic!("expected a leading SET search_path statement, got:\n{sql}"));
+    let first_select_pos = sql.find("SELECT").unwrap();
+
+    assert!(
+        set_search_path_pos < first_select_pos,
+        "expected SET search_path to come before the first query, got:\n{sql}"
+    );
+}
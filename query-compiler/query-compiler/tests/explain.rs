@@ -0,0 +1,119 @@
+use quaint::connector::Queryable;
+use quaint::prelude::{ConnectionInfo, ExternalConnectionInfo, SqlFamily};
+use query_compiler::Expression;
+use query_core::QueryDocument;
+use query_structure::psl;
+use request_handlers::{JsonBody, JsonSingleQuery, RequestBody};
+use sql_query_builder::DynamicSchema;
+use std::sync::Arc;
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "sqlite"
+  url      = "file:dev.db"
+}
+
+model TestModel {
+  id   Int    @id
+  name String
+}
+"#;
+
+fn compile(action: &str, query: serde_json::Value) -> Expression {
+    let schema = psl::validate(SCHEMA.into());
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    let schema = Arc::new(schema);
+    let query_schema = Arc::new(query_core::schema::build(schema, true));
+
+    let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Sqlite, None, None, false));
+
+    let request: JsonSingleQuery = serde_json::from_value(serde_json::json!({
+        "modelName": "TestModel",
+        "action": action,
+        "query": query,
+    }))
+    .unwrap();
+
+    let doc = RequestBody::Json(JsonBody::Single(request)).into_doc(&query_schema).unwrap();
+
+    let QueryDocument::Single(operation) = doc else {
+        panic!("expected single query");
+    };
+
+    query_compiler::compile_with_dynamic_schema(&query_schema, operation, &connection_info, DynamicSchema::default(), None).unwrap()
+}
+
+async fn sqlite_connection(path: &std::path::Path) -> impl Queryable {
+    let conn = quaint::connector::Sqlite::try_from(format!("file:{}", path.display()).as_str()).unwrap();
+    conn.raw_cmd("CREATE TABLE TestModel (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await
+        .unwrap();
+    conn
+}
+
+#[tokio::test]
+async fn explain_plan_returns_query_plan_rows_for_every_statement() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("test.db");
+    let conn = sqlite_connection(&db_path).await;
+
+    let by_id = compile(
+        "findMany",
+        serde_json::json!({
+            "arguments": { "where": { "id": 1 } },
+            "selection": { "id": true },
+        }),
+    );
+
+    let create = compile(
+        "createOne",
+        serde_json::json!({
+            "arguments": { "data": { "name": "alice" } },
+            "selection": { "id": true },
+        }),
+    );
+
+    let plan = Expression::Seq(vec![by_id, create]);
+
+    let explained = query_compiler::explain_plan(&plan, &conn, SqlFamily::Sqlite, false).await;
+
+    assert!(!explained.is_empty());
+    for query in &explained {
+        assert!(query.is_ok(), "{query:#?}");
+        let plan = query.plan.as_ref().unwrap();
+        assert!(plan.as_array().is_some_and(|rows| !rows.is_empty()), "{query:#?}");
+    }
+
+    // Nothing was executed: `EXPLAIN QUERY PLAN` never runs the statement it describes.
+    let count = conn
+        .query_raw("SELECT COUNT(*) AS c FROM TestModel", &[])
+        .await
+        .unwrap();
+    let row = count.into_single().unwrap();
+    assert_eq!(row.at(0).unwrap().as_i64(), Some(0));
+}
+
+fn raw_query(sql: &str) -> query_builder::DbQuery {
+    query_builder::DbQuery::RawSql {
+        sql: sql.to_owned(),
+        params: Vec::new(),
+    }
+}
+
+#[test]
+fn build_explain_prefixes_per_family() {
+    let postgres = query_compiler::build_explain(raw_query("SELECT 1"), SqlFamily::Postgres, false).unwrap();
+    assert_eq!(postgres.to_string(), "EXPLAIN (FORMAT JSON) SELECT 1");
+
+    let postgres_analyze = query_compiler::build_explain(raw_query("SELECT 1"), SqlFamily::Postgres, true).unwrap();
+    assert_eq!(postgres_analyze.to_string(), "EXPLAIN (ANALYZE, FORMAT JSON) SELECT 1");
+
+    let mysql = query_compiler::build_explain(raw_query("SELECT 1"), SqlFamily::Mysql, false).unwrap();
+    assert_eq!(mysql.to_string(), "EXPLAIN FORMAT=JSON SELECT 1");
+
+    let sqlite = query_compiler::build_explain(raw_query("SELECT 1"), SqlFamily::Sqlite, false).unwrap();
+    assert_eq!(sqlite.to_string(), "EXPLAIN QUERY PLAN SELECT 1");
+
+    assert!(query_compiler::build_explain(raw_query("SELECT 1"), SqlFamily::Mssql, false).is_err());
+}
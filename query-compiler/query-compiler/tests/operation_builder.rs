@@ -0,0 +1,112 @@
+//! Constructing a `query_core::Operation` directly via `Selection::with_name`/`Selection::new`,
+//! instead of parsing one from a GraphQL or JSON protocol document, is a supported way to call
+//! `query_compiler::compile` - see the doc comments on `Operation` and `Selection`. These tests
+//! pin that down: the hand-built path must compile to the exact same plan as the protocol path,
+//! and must still go through the usual schema validation.
+
+use quaint::prelude::{ConnectionInfo, ExternalConnectionInfo, SqlFamily};
+use query_core::{schema::QuerySchema, Operation, QueryDocument, Selection};
+use query_structure::psl;
+use request_handlers::{JsonBody, JsonSingleQuery, RequestBody};
+use std::sync::Arc;
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = "postgresql://"
+}
+
+generator client {
+  provider = "prisma-client-js"
+}
+
+model User {
+  id    Int    @id
+  name  String
+  email String
+}
+"#;
+
+fn query_schema() -> Arc<QuerySchema> {
+    let schema = psl::validate(SCHEMA.into());
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    Arc::new(query_core::schema::build(Arc::new(schema), true))
+}
+
+fn connection_info() -> ConnectionInfo {
+    ConnectionInfo::External(ExternalConnectionInfo::new(
+        SqlFamily::Postgres,
+        Some("public".to_owned()),
+        None,
+        true,
+    ))
+}
+
+fn via_json_protocol(query_schema: &QuerySchema) -> Operation {
+    let request: JsonSingleQuery = serde_json::from_value(serde_json::json!({
+        "modelName": "User",
+        "action": "findMany",
+        "query": {
+            "arguments": {},
+            "selection": { "id": true, "name": true, "email": true },
+        },
+    }))
+    .unwrap();
+
+    let doc = RequestBody::Json(JsonBody::Single(request)).into_doc(query_schema).unwrap();
+
+    let QueryDocument::Single(operation) = doc else {
+        panic!("expected single query");
+    };
+
+    operation
+}
+
+fn via_builder() -> Operation {
+    Operation::Read(Selection::new(
+        "findManyUser",
+        None,
+        Vec::new(),
+        vec![
+            Selection::with_name("id"),
+            Selection::with_name("name"),
+            Selection::with_name("email"),
+        ],
+    ))
+}
+
+#[test]
+fn hand_built_operation_compiles_to_the_same_plan_as_the_json_protocol() {
+    let query_schema = query_schema();
+    let connection_info = connection_info();
+
+    let expr_via_protocol =
+        query_compiler::compile(&query_schema, via_json_protocol(&query_schema), &connection_info).unwrap();
+    let expr_via_builder = query_compiler::compile(&query_schema, via_builder(), &connection_info).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&expr_via_protocol).unwrap(),
+        serde_json::to_string(&expr_via_builder).unwrap(),
+        "expected the hand-built Operation to compile to the exact same plan as the JSON protocol one"
+    );
+}
+
+#[test]
+fn hand_built_operation_with_an_unknown_field_still_fails_schema_validation() {
+    let query_schema = query_schema();
+
+    let operation = Operation::Read(Selection::new(
+        "findManyUser",
+        None,
+        Vec::new(),
+        vec![Selection::with_name("doesNotExist")],
+    ));
+
+    let err = query_compiler::compile(&query_schema, operation, &connection_info()).unwrap_err();
+
+    assert!(
+        matches!(err, query_compiler::CompileError::GraphBuildError(_)),
+        "expected a schema-validation error for an unknown field, got: {err}"
+    );
+}
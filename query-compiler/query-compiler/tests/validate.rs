@@ -0,0 +1,92 @@
+use quaint::connector::Queryable;
+use quaint::prelude::{ConnectionInfo, ExternalConnectionInfo, SqlFamily};
+use query_compiler::Expression;
+use query_core::QueryDocument;
+use query_structure::psl;
+use request_handlers::{JsonBody, JsonSingleQuery, RequestBody};
+use sql_query_builder::DynamicSchema;
+use std::sync::Arc;
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "sqlite"
+  url      = "file:dev.db"
+}
+
+model TestModel {
+  id   Int    @id
+  name String
+}
+"#;
+
+fn compile(action: &str, query: serde_json::Value) -> Expression {
+    let schema = psl::validate(SCHEMA.into());
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    let schema = Arc::new(schema);
+    let query_schema = Arc::new(query_core::schema::build(schema, true));
+
+    let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Sqlite, None, None, false));
+
+    let request: JsonSingleQuery = serde_json::from_value(serde_json::json!({
+        "modelName": "TestModel",
+        "action": action,
+        "query": query,
+    }))
+    .unwrap();
+
+    let doc = RequestBody::Json(JsonBody::Single(request)).into_doc(&query_schema).unwrap();
+
+    let QueryDocument::Single(operation) = doc else {
+        panic!("expected single query");
+    };
+
+    query_compiler::compile_with_dynamic_schema(&query_schema, operation, &connection_info, DynamicSchema::default(), None).unwrap()
+}
+
+async fn sqlite_connection(path: &std::path::Path) -> impl Queryable {
+    let conn = quaint::connector::Sqlite::try_from(format!("file:{}", path.display()).as_str()).unwrap();
+    conn.raw_cmd("CREATE TABLE TestModel (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await
+        .unwrap();
+    conn
+}
+
+#[tokio::test]
+async fn validate_plan_localizes_failure_to_the_statement_using_the_dropped_column() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("test.db");
+    let conn = sqlite_connection(&db_path).await;
+
+    let by_id = compile(
+        "findMany",
+        serde_json::json!({
+            "arguments": { "where": { "id": 1 } },
+            "selection": { "id": true },
+        }),
+    );
+
+    let by_name = compile(
+        "findMany",
+        serde_json::json!({
+            "arguments": { "where": { "name": "alice" } },
+            "selection": { "id": true },
+        }),
+    );
+
+    let plan = Expression::Seq(vec![by_id, by_name]);
+
+    // Before the schema drifts, every statement in the plan is still valid.
+    let report = query_compiler::validate_plan(&plan, &conn).await;
+    assert!(report.is_valid(), "{report:#?}");
+
+    conn.raw_cmd("ALTER TABLE TestModel DROP COLUMN name").await.unwrap();
+
+    // After `name` is dropped, only the statement referencing it should fail.
+    let report = query_compiler::validate_plan(&plan, &conn).await;
+
+    let failing: Vec<_> = report.statements.iter().filter(|s| !s.is_valid()).collect();
+    assert_eq!(failing.len(), 1, "{report:#?}");
+    assert!(failing[0].sql.contains("name"), "{report:#?}");
+    assert_eq!(report.statements.len() - failing.len(), 1, "{report:#?}");
+}
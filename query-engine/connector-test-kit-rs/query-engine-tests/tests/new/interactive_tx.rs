@@ -698,3 +698,86 @@ mod itx_isolation {
         Ok(())
     }
 }
+
+#[test_suite(schema(generic), exclude(Sqlite("cfd1")))]
+mod itx_snapshot {
+    use query_engine_tests::*;
+    use quaint::{prelude::*, single::Quaint};
+
+    // Only Postgres supports importing a transaction snapshot.
+    #[connector_test(exclude(Postgres, CockroachDb))]
+    async fn unsupported_elsewhere(runner: Runner) -> TestResult<()> {
+        let tx_id = runner
+            .start_tx_with_snapshot(5000, 5000, Some("Serializable".to_owned()), "00000000-1".to_owned())
+            .await;
+
+        match tx_id {
+            Ok(_) => panic!("Expected non-Postgres connectors to reject a transaction snapshot, but it succeeded."),
+            Err(err) => assert!(err
+                .to_string()
+                .contains("Importing a transaction snapshot is only supported on PostgreSQL")),
+        };
+
+        Ok(())
+    }
+
+    #[connector_test(only(Postgres))]
+    async fn requires_repeatable_read_or_serializable(runner: Runner) -> TestResult<()> {
+        let tx_id = runner
+            .start_tx_with_snapshot(5000, 5000, Some("ReadCommitted".to_owned()), "00000000-1".to_owned())
+            .await;
+
+        match tx_id {
+            Ok(_) => panic!("Expected ReadCommitted + snapshot to be rejected, but it succeeded."),
+            Err(err) => assert!(err.to_string().contains(
+                "Importing a transaction snapshot requires the isolation level to be set to RepeatableRead or Serializable"
+            )),
+        };
+
+        Ok(())
+    }
+
+    #[connector_test(only(Postgres))]
+    async fn reads_stale_snapshot(mut runner: Runner) -> TestResult<()> {
+        // Open a separate, long-lived connection and export a snapshot of the database as it is
+        // right now. The snapshot stays valid for as long as this connection's transaction is open.
+        let export_conn = Quaint::new(runner.connection_url()).await?;
+        export_conn.raw_cmd("BEGIN ISOLATION LEVEL REPEATABLE READ").await?;
+        let result = export_conn.query_raw("SELECT pg_export_snapshot()", &[]).await?;
+        let snapshot_id = result
+            .into_single()
+            .ok()
+            .and_then(|row| row.into_single().ok())
+            .and_then(|val| val.into_string())
+            .expect("pg_export_snapshot() must return the snapshot id");
+
+        // Mutate the database after the snapshot was taken.
+        run_query!(&runner, r#"mutation { createOneTestModel(data: { id: 1 }) { id }}"#);
+
+        // An interactive transaction importing the snapshot must not see the write above, even
+        // though it happened (and was committed) before the transaction was started.
+        let tx_id = runner
+            .start_tx_with_snapshot(5000, 5000, Some("RepeatableRead".to_owned()), snapshot_id)
+            .await?;
+        runner.set_active_tx(tx_id.clone());
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"query { findManyTestModel { id }}"#),
+          @r###"{"data":{"findManyTestModel":[]}}"###
+        );
+
+        runner.commit_tx(tx_id).await?.expect("commit must succeed");
+        runner.clear_active_tx();
+
+        // Releasing the exported snapshot by ending its owning transaction.
+        export_conn.raw_cmd("COMMIT").await?;
+
+        // Now that the snapshot is gone, a fresh read sees the write.
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"query { findManyTestModel { id }}"#),
+          @r###"{"data":{"findManyTestModel":[{"id":1}]}}"###
+        );
+
+        Ok(())
+    }
+}
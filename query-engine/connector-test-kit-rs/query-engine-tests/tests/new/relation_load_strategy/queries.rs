@@ -476,4 +476,96 @@ mod relation_load_strategy {
 
         Ok(())
     }
+
+    async fn seed_nested_count(runner: &Runner) -> TestResult<()> {
+        run_query!(
+            runner,
+            r#"
+            mutation {
+                createOneUser(
+                    data: {
+                        id: 10,
+                        login: "nested-count-author",
+                        posts: {
+                            create: [
+                                {
+                                    id: 10,
+                                    title: "post with two comments",
+                                    content: "content",
+                                    comments: {
+                                        create: [
+                                            { id: 10, body: "first comment", author: { connect: { id: 10 } } },
+                                            { id: 11, body: "second comment", author: { connect: { id: 10 } } }
+                                        ]
+                                    }
+                                },
+                                {
+                                    id: 11,
+                                    title: "post with no comments",
+                                    content: "content"
+                                }
+                            ]
+                        }
+                    }
+                ) {
+                    id
+                }
+            }
+            "#
+        );
+
+        Ok(())
+    }
+
+    // `_count` is resolved independently at whatever depth it's selected, not just on the first
+    // level of relation nesting. Cover two levels deep (`posts._count`), three levels deep
+    // (`posts.comments.author._count`), a filtered nested count, and that pagination applied to
+    // an intermediate relation (`posts(take: 1)`) doesn't leak into an unrelated `_count`, for
+    // both the join and the query relation load strategies.
+    macro_rules! nested_count_test {
+        ($name:ident, $strategy:ident $(, $attrs:expr)*) => {
+            paste::paste! {
+                #[connector_test(suite = "relation_load_strategy", schema(schema) $(, $attrs)*)]
+                async fn [<test_nested_count_ $name _ $strategy>](runner: Runner) -> TestResult<()> {
+                    seed_nested_count(&runner).await?;
+
+                    let strategy = stringify!($strategy);
+
+                    insta::assert_snapshot!(
+                        run_query!(
+                            runner,
+                            r#"
+                            query {
+                                findUniqueUser(relationLoadStrategy: $STRATEGY, where: { id: 10 }) {
+                                    login
+                                    _count { posts }
+                                    posts(take: 1, orderBy: { id: asc }) {
+                                        title
+                                        _count { comments }
+                                        comments(where: { body: { contains: "first" } }) {
+                                            body
+                                            author {
+                                                login
+                                                _count { comments }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            "#
+                            .replace("$STRATEGY", strategy)
+                        ),
+                        @r###"{"data":{"findUniqueUser":{"login":"nested-count-author","_count":{"posts":2},"posts":[{"title":"post with two comments","_count":{"comments":2},"comments":[{"body":"first comment","author":{"login":"nested-count-author","_count":{"comments":2}}}]}]}}}"###
+                    );
+
+                    Ok(())
+                }
+            }
+        };
+    }
+
+    nested_count_test!(lateral, join, capabilities(LateralJoin));
+    nested_count_test!(subquery, join, capabilities(CorrelatedSubqueries), exclude(Mysql("5.6", "5.7", "mariadb")));
+    nested_count_test!(lateral, query, capabilities(LateralJoin));
+    nested_count_test!(subquery, query, capabilities(CorrelatedSubqueries));
 }
@@ -0,0 +1,117 @@
+use query_engine_tests::*;
+
+#[test_suite(schema(schema), exclude_executors("QueryCompiler"))]
+mod cross_model_compaction {
+    use indoc::indoc;
+    use query_engine_tests::{
+        query_core::{BatchDocument, QueryDocument},
+        run_query, Runner, TestResult,
+    };
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"
+                model User {
+                    #id(id, Int, @id)
+                    name String
+                }
+
+                model Org {
+                    #id(id, Int, @id)
+                    name String
+                }
+
+                model Settings {
+                    #id(id, Int, @id)
+                    theme String
+                }
+            "#
+        };
+
+        schema.to_owned()
+    }
+
+    async fn create_test_data(runner: &Runner) -> TestResult<()> {
+        run_query!(runner, r#"mutation { createOneUser(data: { id: 1, name: "Alice" }) { id } }"#);
+        run_query!(runner, r#"mutation { createOneOrg(data: { id: 1, name: "Acme" }) { id } }"#);
+        run_query!(
+            runner,
+            r#"mutation { createOneSettings(data: { id: 1, theme: "dark" }) { id } }"#
+        );
+
+        Ok(())
+    }
+
+    // A batch mixing `findUnique`s on different models is now compacted into one `findMany`
+    // per model (instead of not compacting at all), and results are routed back to the
+    // original per-operation positions.
+    #[connector_test]
+    async fn mixed_model_batch_compacts_per_model(runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        let queries = vec![
+            r#"query { findUniqueUser(where: { id: 1 }) { name } }"#.to_string(),
+            r#"query { findUniqueOrg(where: { id: 1 }) { name } }"#.to_string(),
+            r#"query { findUniqueSettings(where: { id: 1 }) { theme } }"#.to_string(),
+            r#"query { findUniqueUser(where: { id: 2 }) { name } }"#.to_string(),
+        ];
+
+        let doc = GraphqlBody::Multi(MultiQuery::new(
+            queries.clone().into_iter().map(Into::into).collect(),
+            false,
+            None,
+        ))
+        .into_doc()
+        .unwrap();
+
+        let batch = match doc {
+            QueryDocument::Multi(batch) => batch.compact(runner.query_schema()),
+            _ => unreachable!(),
+        };
+
+        match batch {
+            BatchDocument::Compact(documents) => {
+                // One compacted `findMany` per distinct model: User, Org, Settings.
+                assert_eq!(documents.len(), 3);
+            }
+            BatchDocument::Multi(..) => panic!("expected a mixed-model batch to still compact per model"),
+        }
+
+        let batch_results = runner.batch(queries, false, None).await?;
+        insta::assert_snapshot!(
+            batch_results.to_string(),
+            @r###"{"batchResult":[{"data":{"findUniqueUser":{"name":"Alice"}}},{"data":{"findUniqueOrg":{"name":"Acme"}}},{"data":{"findUniqueSettings":{"theme":"dark"}}},{"data":{"findUniqueUser":null}}]}"###
+        );
+
+        Ok(())
+    }
+
+    // The same mixed-model batch, run inside an interactive transaction, must still produce
+    // correctly routed results even though the per-model groups now run serially over the
+    // transaction's single connection instead of concurrently.
+    #[connector_test]
+    async fn mixed_model_batch_in_itx_is_serial(mut runner: Runner) -> TestResult<()> {
+        create_test_data(&runner).await?;
+
+        let tx_id = runner.start_tx(5000, 5000, None).await?;
+        runner.set_active_tx(tx_id.clone());
+
+        let queries = vec![
+            r#"query { findUniqueUser(where: { id: 1 }) { name } }"#.to_string(),
+            r#"query { findUniqueOrg(where: { id: 1 }) { name } }"#.to_string(),
+            r#"query { findUniqueUser(where: { id: 2 }) { name } }"#.to_string(),
+        ];
+
+        let batch_results = runner.batch(queries, false, None).await?;
+        let res = runner.commit_tx(tx_id).await?;
+        assert!(res.is_ok());
+        runner.clear_active_tx();
+
+        insta::assert_snapshot!(
+            batch_results.to_string(),
+            @r###"{"batchResult":[{"data":{"findUniqueUser":{"name":"Alice"}}},{"data":{"findUniqueOrg":{"name":"Acme"}}},{"data":{"findUniqueUser":null}}]}"###
+        );
+
+        Ok(())
+    }
+}
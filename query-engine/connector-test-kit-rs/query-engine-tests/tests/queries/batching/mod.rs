@@ -1,3 +1,4 @@
+mod cross_model_compaction;
 mod select_different_key_types;
 mod select_one_compound;
 mod select_one_singular;
@@ -269,4 +269,51 @@ mod chunking {
 
         Ok(())
     }
+
+    // Postgres binds a single-column `IN`/`NOT IN` list as one array parameter (`= ANY($1)` /
+    // `<> ALL($1)`), so it never needs to chunk those filters, no matter how far over
+    // QUERY_BATCH_SIZE the list goes. Other connectors still chunk as before.
+    #[test_suite(schema(schema))]
+    mod postgres_array_bind {
+        fn schema() -> String {
+            let schema = indoc! {
+                r#"
+                model A {
+                  #id(id, Int, @id)
+                }
+                "#
+            };
+
+            schema.to_owned()
+        }
+
+        #[connector_test(only(Postgres))]
+        async fn large_in_list_binds_as_single_array_param(mut runner: Runner) -> TestResult<()> {
+            runner
+                .query("mutation { createOneA(data: { id: 1 }) { id } }")
+                .await?
+                .assert_success();
+
+            let id_list = (1..=5000).map(|id| id.to_string()).collect::<Vec<_>>().join(", ");
+
+            runner.clear_logs().await;
+            insta::assert_snapshot!(
+                run_query!(
+                    &runner,
+                    format!("{{ findManyA(where: {{ id: {{ in: [{id_list}] }} }}) {{ id }} }}")
+                ),
+                @r###"{"data":{"findManyA":[{"id":1}]}}"###
+            );
+
+            let logs = runner.get_logs().await;
+            assert_eq!(
+                logs.iter().filter(|log| log.contains("SELECT")).count(),
+                1,
+                "a large single-column `in` list should not be split into multiple queries on postgres"
+            );
+            assert!(logs.iter().any(|log| log.contains("= ANY(")));
+
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use query_engine_tests::*;
+
+#[test_suite(schema(schema), capabilities(Enums))]
+mod enum_alias {
+    use query_engine_tests::Runner;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model TestModel {
+                #id(id, Int, @id)
+                status Status?
+            }
+
+            enum Status {
+                ACTIVE @map("active") @alias("enabled")
+                INACTIVE @map("inactive")
+            }
+            "#
+        };
+
+        schema.to_owned()
+    }
+
+    // Old clients that still send the pre-rename value name should be able to keep writing and
+    // filtering with it, while reads always return the current, canonical value name.
+    #[connector_test]
+    async fn alias_is_accepted_on_write_and_mapped_to_the_canonical_value(runner: Runner) -> TestResult<()> {
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation { createOneTestModel(data: { id: 1, status: enabled }) { status } }"#),
+          @r###"{"data":{"createOneTestModel":{"status":"ACTIVE"}}}"###
+        );
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation { updateOneTestModel(where: { id: 1 }, data: { status: INACTIVE }) { status } }"#),
+          @r###"{"data":{"updateOneTestModel":{"status":"INACTIVE"}}}"###
+        );
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation { updateOneTestModel(where: { id: 1 }, data: { status: enabled }) { status } }"#),
+          @r###"{"data":{"updateOneTestModel":{"status":"ACTIVE"}}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn alias_is_accepted_in_filters(runner: Runner) -> TestResult<()> {
+        run_query!(&runner, r#"mutation { createOneTestModel(data: { id: 1, status: ACTIVE }) { id } }"#);
+        run_query!(&runner, r#"mutation { createOneTestModel(data: { id: 2, status: INACTIVE }) { id } }"#);
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"query { findManyTestModel(where: { status: enabled }) { id status } }"#),
+          @r###"{"data":{"findManyTestModel":[{"id":1,"status":"ACTIVE"}]}}"###
+        );
+
+        Ok(())
+    }
+
+    // The alias is only an accepted input name. It must never be produced as output.
+    #[connector_test]
+    async fn alias_is_never_returned_as_output(runner: Runner) -> TestResult<()> {
+        run_query!(&runner, r#"mutation { createOneTestModel(data: { id: 1, status: enabled }) { id } }"#);
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"query { findUniqueTestModel(where: { id: 1 }) { status } }"#),
+          @r###"{"data":{"findUniqueTestModel":{"status":"ACTIVE"}}}"###
+        );
+
+        Ok(())
+    }
+}
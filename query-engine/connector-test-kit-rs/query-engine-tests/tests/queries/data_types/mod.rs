@@ -3,6 +3,7 @@ mod bool;
 mod bytes;
 mod datetime;
 mod decimal;
+mod enum_alias;
 mod enum_type;
 mod float;
 mod int;
@@ -58,6 +58,74 @@ mod relation_filter {
         Ok(())
     }
 
+    fn one_to_one_typed_schema() -> String {
+        let schema = indoc! {
+            r#"model TestModel {
+              #id(id, Int, @id)
+              childId Int? @unique
+              child Child? @relation(fields:[childId], references: [id])
+            }
+            model Child {
+              #id(id, Int, @id)
+              int1 Int
+              int2 Int
+              dt1 DateTime @default(now())
+              dt2 DateTime @default(now())
+              dec1 Decimal
+              dec2 Decimal
+              test TestModel?
+            }
+            "#
+        };
+
+        schema.to_owned()
+    }
+
+    // Int, DateTime and Decimal are rendered differently from the default (String) comparison, so
+    // make sure gt/lt/equals on each of them still alias correctly once nested inside a relation
+    // filter, the same way `ensure_scalar_filters_can_run` does for String above.
+    #[connector_test(schema(one_to_one_typed_schema), capabilities(DecimalType))]
+    async fn ensure_typed_scalar_filters_can_run(runner: Runner) -> TestResult<()> {
+        run_query!(
+            runner,
+            r#"{ findManyTestModel(where: { child: { int1: { gt: { _ref: "int2", _container: "Child" } } } }) { id } }"#
+        );
+        run_query!(
+            runner,
+            r#"{ findManyTestModel(where: { child: { int1: { lt: { _ref: "int2", _container: "Child" } } } }) { id } }"#
+        );
+        run_query!(
+            runner,
+            r#"{ findManyTestModel(where: { child: { int1: { equals: { _ref: "int2", _container: "Child" } } } }) { id } }"#
+        );
+        run_query!(
+            runner,
+            r#"{ findManyTestModel(where: { child: { dt1: { gt: { _ref: "dt2", _container: "Child" } } } }) { id } }"#
+        );
+        run_query!(
+            runner,
+            r#"{ findManyTestModel(where: { child: { dt1: { lt: { _ref: "dt2", _container: "Child" } } } }) { id } }"#
+        );
+        run_query!(
+            runner,
+            r#"{ findManyTestModel(where: { child: { dt1: { equals: { _ref: "dt2", _container: "Child" } } } }) { id } }"#
+        );
+        run_query!(
+            runner,
+            r#"{ findManyTestModel(where: { child: { dec1: { gt: { _ref: "dec2", _container: "Child" } } } }) { id } }"#
+        );
+        run_query!(
+            runner,
+            r#"{ findManyTestModel(where: { child: { dec1: { lt: { _ref: "dec2", _container: "Child" } } } }) { id } }"#
+        );
+        run_query!(
+            runner,
+            r#"{ findManyTestModel(where: { child: { dec1: { equals: { _ref: "dec2", _container: "Child" } } } }) { id } }"#
+        );
+
+        Ok(())
+    }
+
     fn one_to_one_list_schema() -> String {
         let schema = indoc! {
             r#"model TestModel {
@@ -0,0 +1,114 @@
+use query_engine_tests::*;
+
+#[test_suite(schema(schema), only(Postgres))]
+mod ltree_filter_spec {
+    fn schema() -> String {
+        r#"
+            model Category {
+                #id(id, Int, @id)
+                path String @test.Ltree
+            }
+        "#
+        .to_owned()
+    }
+
+    async fn create_tree(runner: &Runner) -> TestResult<()> {
+        runner
+            .query(r#"mutation { createManyCategory(data: [
+                { id: 1, path: "Top" },
+                { id: 2, path: "Top.Science" },
+                { id: 3, path: "Top.Science.Astronomy" },
+                { id: 4, path: "Top.Science.Astronomy.Cosmology" },
+                { id: 5, path: "Top.Hobbies" },
+                { id: 6, path: "Top.Hobbies.Amateurs_Astronomy" }
+            ]) { count } }"#)
+            .await?
+            .assert_success();
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn ancestor_of_filter(runner: Runner) -> TestResult<()> {
+        create_tree(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"query { findManyCategory(
+              where: { path: { ancestorOf: "Top.Science.Astronomy.Cosmology" } }
+              orderBy: { id: asc }
+          ) { id path } }"#),
+          @r###"{"data":{"findManyCategory":[{"id":1,"path":"Top"},{"id":2,"path":"Top.Science"},{"id":3,"path":"Top.Science.Astronomy"},{"id":4,"path":"Top.Science.Astronomy.Cosmology"}]}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn descendant_of_filter(runner: Runner) -> TestResult<()> {
+        create_tree(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"query { findManyCategory(
+              where: { path: { descendantOf: "Top.Science" } }
+              orderBy: { id: asc }
+          ) { id path } }"#),
+          @r###"{"data":{"findManyCategory":[{"id":2,"path":"Top.Science"},{"id":3,"path":"Top.Science.Astronomy"},{"id":4,"path":"Top.Science.Astronomy.Cosmology"}]}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn matches_lquery_filter(runner: Runner) -> TestResult<()> {
+        create_tree(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"query { findManyCategory(
+              where: { path: { matchesLquery: "Top.*.Astronomy*" } }
+              orderBy: { id: asc }
+          ) { id path } }"#),
+          @r###"{"data":{"findManyCategory":[{"id":3,"path":"Top.Science.Astronomy"},{"id":6,"path":"Top.Hobbies.Amateurs_Astronomy"}]}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn negated_ancestor_of_filter(runner: Runner) -> TestResult<()> {
+        create_tree(&runner).await?;
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"query { findManyCategory(
+              where: { NOT: { path: { ancestorOf: "Top.Science.Astronomy.Cosmology" } } }
+              orderBy: { id: asc }
+          ) { id path } }"#),
+          @r###"{"data":{"findManyCategory":[{"id":5,"path":"Top.Hobbies"},{"id":6,"path":"Top.Hobbies.Amateurs_Astronomy"}]}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn invalid_ltree_path_is_rejected(runner: Runner) -> TestResult<()> {
+        assert_error!(
+            runner,
+            r#"query { findManyCategory(where: { path: { ancestorOf: "Top..Science" } }) { id } }"#,
+            2019,
+            "is not a valid ltree path"
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn invalid_lquery_pattern_is_rejected(runner: Runner) -> TestResult<()> {
+        assert_error!(
+            runner,
+            r#"query { findManyCategory(where: { path: { matchesLquery: "" } }) { id } }"#,
+            2019,
+            "is not a valid lquery pattern"
+        );
+
+        Ok(())
+    }
+}
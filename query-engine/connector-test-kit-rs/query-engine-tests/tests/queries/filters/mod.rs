@@ -13,6 +13,7 @@ pub mod insensitive_filters;
 pub mod json;
 pub mod json_filters;
 pub mod list_filters;
+pub mod ltree_filter;
 pub mod many_relation;
 pub mod one2one_regression;
 pub mod one_relation;
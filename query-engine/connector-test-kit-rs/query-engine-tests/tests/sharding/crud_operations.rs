@@ -833,6 +833,44 @@ mod shard_crud {
         Ok(())
     }
 
+    // Shard-aware identifiers with multiple generated columns
+
+    fn autoincrement_shard_schema() -> String {
+        let schema = indoc! {
+            r#"
+            model Event {
+              id       Int    @id @default(autoincrement())
+              name     String
+              shardRef String @shardKey @default(dbgenerated("(uuid())")) @test.Char(36)
+            }
+            "#
+        };
+
+        schema.to_owned()
+    }
+
+    // The shard-aware primary identifier for `Event` is `[id, shardRef]`: an autoincrement column
+    // plus a separately-generated shard column, neither of which is provided by the caller. Both
+    // need to be reported back on the created record.
+    #[connector_test(schema(autoincrement_shard_schema))]
+    async fn create_record_with_autoincrement_and_generated_shard_column(runner: Runner) -> TestResult<()> {
+        let result = run_query!(
+            &runner,
+            r#"mutation {
+                createOneEvent(data: {
+                    name: "signup"
+                }) {
+                    id
+                    name
+                }
+            }"#
+        );
+
+        insta::assert_snapshot!(result, @r#"{"data":{"createOneEvent":{"id":1,"name":"signup"}}}"#);
+
+        Ok(())
+    }
+
     // Complex Operations with Composite Shard Keys
 
     #[connector_test(schema(crud_schema))]
@@ -7,6 +7,7 @@ mod nested_delete_inside_upsert;
 mod nested_delete_many_inside_update;
 mod nested_disconnect_inside_update;
 mod nested_disconnect_inside_upsert;
+mod nested_disconnect_many_inside_update;
 mod nested_set_inside_update;
 mod nested_update_many_inside_update;
 mod nested_upsert_inside_update;
@@ -9,6 +9,7 @@ mod delete_many_relations;
 mod delete_mutation_relations;
 mod insert_null_in_required_field;
 mod non_embedded_upsert;
+mod return_minimal;
 mod update;
 mod update_many;
 mod update_many_and_return;
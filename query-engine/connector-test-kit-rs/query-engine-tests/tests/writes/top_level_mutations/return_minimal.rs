@@ -0,0 +1,51 @@
+use query_engine_tests::*;
+
+#[test_suite(schema(schema))]
+mod return_minimal {
+    use indoc::indoc;
+    use query_engine_tests::run_query;
+
+    fn schema() -> String {
+        let schema = indoc! {
+            r#"model Test {
+              #id(id, Int, @id)
+              payload String
+            }"#
+        };
+
+        schema.to_owned()
+    }
+
+    // `returnMinimal` forces the write down the same path used for nested creates (minimal
+    // `RETURNING`, client-requested fields fetched with a follow-up read), which must be
+    // transparent to the caller: the response is identical to a create without the option.
+    #[connector_test]
+    async fn create_with_return_minimal_still_returns_requested_fields(runner: Runner) -> TestResult<()> {
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            createOneTest(data: { id: 1, payload: "blob" }, returnMinimal: true) {
+              id payload
+            }
+          }"#),
+          @r###"{"data":{"createOneTest":{"id":1,"payload":"blob"}}}"###
+        );
+
+        Ok(())
+    }
+
+    #[connector_test]
+    async fn update_with_return_minimal_still_returns_requested_fields(runner: Runner) -> TestResult<()> {
+        run_query!(&runner, r#"mutation { createOneTest(data: { id: 1, payload: "blob" }) { id } }"#);
+
+        insta::assert_snapshot!(
+          run_query!(&runner, r#"mutation {
+            updateOneTest(where: { id: 1 }, data: { payload: "updated" }, returnMinimal: true) {
+              id payload
+            }
+          }"#),
+          @r###"{"data":{"updateOneTest":{"id":1,"payload":"updated"}}}"###
+        );
+
+        Ok(())
+    }
+}
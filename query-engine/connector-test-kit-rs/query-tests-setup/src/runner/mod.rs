@@ -440,6 +440,12 @@ impl Runner {
         Ok(())
     }
 
+    /// The connection string of the database under test, for tests that need to open their own
+    /// connection outside of the query engine (e.g. to hold a transaction open concurrently).
+    pub fn connection_url(&self) -> &str {
+        &self.connection_url
+    }
+
     pub async fn batch_json(
         &self,
         queries: Vec<String>,
@@ -534,6 +540,23 @@ impl Runner {
         isolation_level: Option<String>,
     ) -> TestResult<TxId> {
         let tx_opts = TransactionOptions::new(max_acquisition_millis, valid_for_millis, isolation_level);
+        self.start_tx_internal(tx_opts).await
+    }
+
+    /// Like [`Runner::start_tx`], but also imports the given transaction snapshot (Postgres only).
+    pub async fn start_tx_with_snapshot(
+        &self,
+        max_acquisition_millis: u64,
+        valid_for_millis: u64,
+        isolation_level: Option<String>,
+        snapshot_id: String,
+    ) -> TestResult<TxId> {
+        let tx_opts = TransactionOptions::new(max_acquisition_millis, valid_for_millis, isolation_level)
+            .with_snapshot_id(Some(snapshot_id));
+        self.start_tx_internal(tx_opts).await
+    }
+
+    async fn start_tx_internal(&self, tx_opts: TransactionOptions) -> TestResult<TxId> {
         match &self.executor {
             RunnerExecutor::Builtin(executor) => {
                 let id = executor
@@ -224,6 +224,7 @@ impl MongoFilterVisitor {
                     // In this context, `field_ref` refers to an array field, so we actually need an `$in` operator.
                     doc! { "$in": [&field_name, coerce_as_array(self.prefixed_field_ref(&field_ref)?)] }
                 }
+                ConditionListValue::Value(_) => unimplemented!("query compiler not supported with mongodb yet"),
             },
             ScalarCondition::NotIn(vals) => match vals {
                 ConditionListValue::List(vals) => {
@@ -245,6 +246,7 @@ impl MongoFilterVisitor {
                     // In this context, `field_ref` refers to an array field, so we actually need an `$in` operator.
                     doc! { "$not": { "$in": [&field_name, coerce_as_array(self.prefixed_field_ref(&field_ref)?)] } }
                 }
+                ConditionListValue::Value(_) => unimplemented!("query compiler not supported with mongodb yet"),
             },
             ScalarCondition::InTemplate(_) => unimplemented!("query compiler not supported with mongodb yet"),
             ScalarCondition::NotInTemplate(_) => unimplemented!("query compiler not supported with mongodb yet"),
@@ -270,6 +272,18 @@ impl MongoFilterVisitor {
             ScalarCondition::IsSet(is_set) => render_is_set(&field_name, is_set),
             ScalarCondition::Search(_, _) => unimplemented!("Full-text search is not supported yet on MongoDB"),
             ScalarCondition::NotSearch(_, _) => unimplemented!("Full-text search is not supported yet on MongoDB"),
+            ScalarCondition::AncestorOf(_)
+            | ScalarCondition::NotAncestorOf(_)
+            | ScalarCondition::DescendantOf(_)
+            | ScalarCondition::NotDescendantOf(_)
+            | ScalarCondition::MatchesLquery(_)
+            | ScalarCondition::NotMatchesLquery(_) => unimplemented!("ltree filters are not supported on MongoDB"),
+            ScalarCondition::GeoContains(_)
+            | ScalarCondition::NotGeoContains(_)
+            | ScalarCondition::WithinDistance(_)
+            | ScalarCondition::NotWithinDistance(_) => {
+                unimplemented!("spatial filters are not supported on MongoDB")
+            }
         };
 
         let filter_doc = if !is_set_cond {
@@ -381,6 +395,7 @@ impl MongoFilterVisitor {
                     self.regex_match(&Bson::from("$$elem"), field, "^", field, "$", true)?,
                     true,
                 )),
+                ConditionListValue::Value(_) => unimplemented!("query compiler not supported with mongodb yet"),
             },
             ScalarCondition::NotIn(vals) => match vals {
                 ConditionListValue::List(vals) => {
@@ -401,6 +416,7 @@ impl MongoFilterVisitor {
                         .map(|rgx_doc| doc! { "$not": rgx_doc })?,
                     true,
                 )),
+                ConditionListValue::Value(_) => unimplemented!("query compiler not supported with mongodb yet"),
             },
             ScalarCondition::InTemplate(_) => unimplemented!("query compiler not supported with mongodb yet"),
             ScalarCondition::NotInTemplate(_) => unimplemented!("query compiler not supported with mongodb yet"),
@@ -411,6 +427,20 @@ impl MongoFilterVisitor {
             ScalarCondition::Search(_, _) | ScalarCondition::NotSearch(_, _) => Err(MongoError::Unsupported(
                 "Full-text search is not supported yet on MongoDB".to_string(),
             )),
+            ScalarCondition::AncestorOf(_)
+            | ScalarCondition::NotAncestorOf(_)
+            | ScalarCondition::DescendantOf(_)
+            | ScalarCondition::NotDescendantOf(_)
+            | ScalarCondition::MatchesLquery(_)
+            | ScalarCondition::NotMatchesLquery(_) => Err(MongoError::Unsupported(
+                "ltree filters are not supported on MongoDB".to_string(),
+            )),
+            ScalarCondition::GeoContains(_)
+            | ScalarCondition::NotGeoContains(_)
+            | ScalarCondition::WithinDistance(_)
+            | ScalarCondition::NotWithinDistance(_) => Err(MongoError::Unsupported(
+                "spatial filters are not supported on MongoDB".to_string(),
+            )),
         }?;
 
         let filter_doc = if !is_set_cond {
@@ -470,6 +500,9 @@ impl MongoFilterVisitor {
                 doc! { "$in": ["$$elem", coerce_as_array((self.prefix(), &field_ref).into_bson()?)] },
                 true,
             ),
+            ScalarListCondition::ContainsEvery(ConditionListValue::Value(_)) => {
+                unimplemented!("query compiler not supported with mongodb yet")
+            }
 
             ScalarListCondition::ContainsSome(vals) if vals.is_empty() => {
                 // Empty hasSome: Return no records.
@@ -493,6 +526,9 @@ impl MongoFilterVisitor {
                 doc! { "$in": ["$$elem", coerce_as_array((self.prefix(), &field_ref).into_bson()?)] },
                 true,
             ),
+            ScalarListCondition::ContainsSome(ConditionListValue::Value(_)) => {
+                unimplemented!("query compiler not supported with mongodb yet")
+            }
 
             ScalarListCondition::IsEmpty(true) => {
                 doc! { "$eq": [render_size(&field_name, true), 0] }
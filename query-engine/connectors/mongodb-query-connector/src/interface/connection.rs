@@ -26,6 +26,7 @@ impl Connection for MongoDbConnection {
     async fn start_transaction<'a>(
         &'a mut self,
         isolation_level: Option<String>,
+        snapshot_id: Option<String>,
     ) -> connector_interface::Result<Box<dyn connector_interface::Transaction + 'a>> {
         if isolation_level.is_some() {
             return Err(MongoError::Unsupported(
@@ -34,6 +35,13 @@ impl Connection for MongoDbConnection {
             .into_connector_error());
         }
 
+        if snapshot_id.is_some() {
+            return Err(
+                MongoError::Unsupported("Mongo does not support importing transaction snapshots.".to_owned())
+                    .into_connector_error(),
+            );
+        }
+
         let tx = Box::new(MongoDbTransaction::new(self).await?);
 
         Ok(tx as Box<dyn Transaction>)
@@ -94,6 +102,7 @@ impl WriteOperations for MongoDbConnection {
         model: &Model,
         record_filter: query_structure::RecordFilter,
         args: query_structure::WriteArgs,
+        order_by: Vec<query_structure::OrderBy>,
         limit: Option<usize>,
         _traceparent: Option<TraceParent>,
     ) -> connector_interface::Result<usize> {
@@ -104,6 +113,7 @@ impl WriteOperations for MongoDbConnection {
                 model,
                 record_filter,
                 args,
+                order_by,
                 UpdateType::Many { limit },
             )
             .await?;
@@ -119,6 +129,7 @@ impl WriteOperations for MongoDbConnection {
         _record_filter: query_structure::RecordFilter,
         _args: query_structure::WriteArgs,
         _selected_fields: FieldSelection,
+        _order_by: Vec<query_structure::OrderBy>,
         _limit: Option<usize>,
         _traceparent: Option<TraceParent>,
     ) -> connector_interface::Result<ManyRecords> {
@@ -140,6 +151,7 @@ impl WriteOperations for MongoDbConnection {
                 model,
                 record_filter,
                 args,
+                vec![],
                 UpdateType::One,
             )
             .await?;
@@ -162,6 +174,7 @@ impl WriteOperations for MongoDbConnection {
         &mut self,
         model: &Model,
         record_filter: query_structure::RecordFilter,
+        order_by: Vec<query_structure::OrderBy>,
         limit: Option<usize>,
         _traceparent: Option<TraceParent>,
     ) -> connector_interface::Result<usize> {
@@ -170,6 +183,7 @@ impl WriteOperations for MongoDbConnection {
             &mut self.session,
             model,
             record_filter,
+            order_by,
             limit,
         ))
         .await
@@ -198,7 +212,7 @@ impl WriteOperations for MongoDbConnection {
         parent_id: &SelectionResult,
         child_ids: &[SelectionResult],
         _traceparent: Option<TraceParent>,
-    ) -> connector_interface::Result<()> {
+    ) -> connector_interface::Result<usize> {
         catch(write::m2m_connect(
             &self.database,
             &mut self.session,
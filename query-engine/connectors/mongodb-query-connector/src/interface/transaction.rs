@@ -127,6 +127,7 @@ impl WriteOperations for MongoDbTransaction<'_> {
         model: &Model,
         record_filter: query_structure::RecordFilter,
         args: query_structure::WriteArgs,
+        order_by: Vec<query_structure::OrderBy>,
         limit: Option<usize>,
         _traceparent: Option<TraceParent>,
     ) -> connector_interface::Result<usize> {
@@ -137,6 +138,7 @@ impl WriteOperations for MongoDbTransaction<'_> {
                 model,
                 record_filter,
                 args,
+                order_by,
                 UpdateType::Many { limit },
             )
             .await?;
@@ -151,6 +153,7 @@ impl WriteOperations for MongoDbTransaction<'_> {
         _record_filter: query_structure::RecordFilter,
         _args: query_structure::WriteArgs,
         _selected_fields: FieldSelection,
+        _order_by: Vec<query_structure::OrderBy>,
         _limit: Option<usize>,
         _traceparent: Option<TraceParent>,
     ) -> connector_interface::Result<ManyRecords> {
@@ -172,6 +175,7 @@ impl WriteOperations for MongoDbTransaction<'_> {
                 model,
                 record_filter,
                 args,
+                vec![],
                 UpdateType::One,
             )
             .await?;
@@ -193,6 +197,7 @@ impl WriteOperations for MongoDbTransaction<'_> {
         &mut self,
         model: &Model,
         record_filter: query_structure::RecordFilter,
+        order_by: Vec<query_structure::OrderBy>,
         limit: Option<usize>,
         _traceparent: Option<TraceParent>,
     ) -> connector_interface::Result<usize> {
@@ -201,6 +206,7 @@ impl WriteOperations for MongoDbTransaction<'_> {
             &mut self.connection.session,
             model,
             record_filter,
+            order_by,
             limit,
         ))
         .await
@@ -237,7 +243,7 @@ impl WriteOperations for MongoDbTransaction<'_> {
         parent_id: &SelectionResult,
         child_ids: &[SelectionResult],
         _traceparent: Option<TraceParent>,
-    ) -> connector_interface::Result<()> {
+    ) -> connector_interface::Result<usize> {
         catch(write::m2m_connect(
             &self.connection.database,
             &mut self.connection.session,
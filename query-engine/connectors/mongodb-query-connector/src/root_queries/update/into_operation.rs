@@ -47,6 +47,9 @@ impl IntoUpdateOperation for ScalarWriteOperation {
             ScalarWriteOperation::Unset(true) => Some(UpdateOperation::unset(field_path)),
             ScalarWriteOperation::Unset(false) => None,
             ScalarWriteOperation::Field(_) => unimplemented!(),
+            ScalarWriteOperation::JsonSet(..) | ScalarWriteOperation::JsonRemove(..) => {
+                unreachable!("Json update operations are not supported on MongoDB")
+            }
         };
 
         if let Some(doc) = doc {
@@ -3,6 +3,7 @@ use crate::error::MongoError::ConversionError;
 use crate::{
     error::{DecorateErrorWithFieldInformationExtension, MongoError},
     filter::{FilterPrefix, MongoFilter, MongoFilterVisitor},
+    orderby::OrderByBuilder,
     output_meta,
     query_builder::MongoReadQueryBuilder,
     query_strings::{Aggregate, DeleteMany, DeleteOne, Find, InsertMany, InsertOne, RunCommand, UpdateMany, UpdateOne},
@@ -16,7 +17,7 @@ use mongodb::{
     options::InsertManyOptions,
     ClientSession, Collection, Database,
 };
-use query_structure::{Model, PrismaValue, SelectionResult};
+use query_structure::{Model, OrderBy, PrismaValue, SelectionResult};
 use std::future::IntoFuture;
 use std::{collections::HashMap, convert::TryInto};
 use update::IntoUpdateDocumentExtension;
@@ -146,6 +147,7 @@ pub async fn update_records(
     model: &Model,
     record_filter: RecordFilter,
     mut args: WriteArgs,
+    order_by: Vec<OrderBy>,
     update_type: UpdateType,
 ) -> crate::Result<Vec<SelectionResult>> {
     let coll = database.collection::<Document>(model.db_name());
@@ -173,7 +175,11 @@ pub async fn update_records(
             .collect::<crate::Result<Vec<_>>>()?
     } else {
         let filter = MongoFilterVisitor::new(FilterPrefix::default(), false).visit(record_filter.filter)?;
-        find_ids(coll.clone(), session, model, filter, None).await?
+        let limit = match update_type {
+            UpdateType::Many { limit } => limit,
+            UpdateType::One => Some(1),
+        };
+        find_ids(coll.clone(), session, model, filter, order_by, limit).await?
     };
 
     if ids.is_empty() {
@@ -233,6 +239,7 @@ pub async fn delete_records(
     session: &mut ClientSession,
     model: &Model,
     record_filter: RecordFilter,
+    order_by: Vec<OrderBy>,
     limit: Option<usize>,
 ) -> crate::Result<usize> {
     let coll = database.collection::<Document>(model.db_name());
@@ -250,7 +257,7 @@ pub async fn delete_records(
             .collect::<crate::Result<Vec<_>>>()?
     } else {
         let filter = MongoFilterVisitor::new(FilterPrefix::default(), false).visit(record_filter.filter)?;
-        find_ids(coll.clone(), session, model, filter, limit).await?
+        find_ids(coll.clone(), session, model, filter, order_by, limit).await?
     };
 
     if ids.is_empty() {
@@ -310,6 +317,7 @@ async fn find_ids(
     session: &mut ClientSession,
     model: &Model,
     filter: MongoFilter,
+    order_by: Vec<OrderBy>,
     limit: Option<usize>,
 ) -> crate::Result<Vec<Bson>> {
     let id_field = model.primary_identifier();
@@ -326,6 +334,15 @@ async fn find_ids(
 
     let mut builder = builder.with_model_projection(id_field)?;
 
+    // `$limit` truncates whatever the collection scan happens to return first, so without an
+    // explicit sort the chosen rows (and therefore what `update`/`delete` actually act on) are
+    // arbitrary. Apply the requested ordering before the limit is taken, mirroring the relational
+    // connectors' `ORDER BY ... LIMIT` pushdown.
+    let (order, order_aggregate_projections, order_joins) = OrderByBuilder::new(order_by, false).build(false);
+    builder.order = order;
+    builder.order_aggregate_projections = order_aggregate_projections;
+    builder.order_joins = order_joins;
+
     if let Some(limit) = limit {
         builder.limit = match i64::try_from(limit) {
             Ok(limit) => Some(limit),
@@ -353,7 +370,7 @@ pub async fn m2m_connect(
     field: &RelationFieldRef,
     parent_id: &SelectionResult,
     child_ids: &[SelectionResult],
-) -> crate::Result<()> {
+) -> crate::Result<usize> {
     let parent_model = field.model();
     let child_model = field.related_model();
 
@@ -384,7 +401,9 @@ pub async fn m2m_connect(
 
     let query_string_builder = UpdateOne::new(&parent_filter, &parent_update, parent_coll.name());
 
-    observing(&query_string_builder, || {
+    // `$addToSet` only reports whether the parent document was modified at all (0 or 1), not how
+    // many of the given child ids were newly added to the array versus already present.
+    let parent_update_result = observing(&query_string_builder, || {
         parent_coll
             .update_one(parent_filter.clone(), parent_update.clone())
             .session(&mut *session)
@@ -416,7 +435,7 @@ pub async fn m2m_connect(
     })
     .await?;
 
-    Ok(())
+    Ok(parent_update_result.modified_count as usize)
 }
 
 pub async fn m2m_disconnect(
@@ -14,6 +14,11 @@ pub struct ConnectorError {
     pub kind: ErrorKind,
     /// Whether an error is transient and should be retried.
     pub transient: bool,
+    /// Set when this error comes from a chunked `updateMany`/`deleteMany` (one logical write
+    /// split into several statements) that failed under [`query_structure::ChunkExecutionPolicy::FailFast`]
+    /// after one or more earlier statements had already committed: the number of rows those
+    /// earlier statements affected.
+    pub chunked_write_progress: Option<usize>,
 }
 
 impl ConnectorError {
@@ -118,13 +123,22 @@ impl ConnectorError {
                     message: format!("{e}"),
                 },
             )),
+            ErrorKind::TooManyRows { limit } => Some(user_facing_errors::KnownError::new(
+                user_facing_errors::query_engine::TooManyRows { limit: *limit },
+            )),
             _ => None,
         };
 
+        // Serialization failures and deadlocks (Postgres 40001/40P01, MySQL 1213) are the only
+        // kinds the interpreter's retry policy currently knows how to retry safely: nothing has
+        // been committed by the statement that failed with one of them.
+        let transient = matches!(kind, ErrorKind::TransactionWriteConflict);
+
         ConnectorError {
             user_facing_error: user_facing_error.map(Box::new),
             kind,
-            transient: false,
+            transient,
+            chunked_write_progress: None,
         }
     }
 
@@ -135,6 +149,27 @@ impl ConnectorError {
     pub fn is_transient(&self) -> bool {
         self.transient
     }
+
+    /// Records that `affected` rows were already committed by earlier chunks of a chunked
+    /// `updateMany`/`deleteMany` before the statement that produced this error ran.
+    pub fn with_chunked_write_progress(mut self, affected: usize) -> Self {
+        self.chunked_write_progress = Some(affected);
+        self
+    }
+
+    /// Attaches the input path of the query graph node that produced this error (e.g.
+    /// `data.orders.create[2].items.createMany.data`) to the user-facing error's metadata, so
+    /// clients can tell which part of a deeply nested write caused it. No-op if there's no
+    /// user-facing error to annotate.
+    pub fn with_path(mut self, path: &str) -> Self {
+        if let Some(known_error) = &mut self.user_facing_error {
+            if let Some(meta) = known_error.meta.as_object_mut() {
+                meta.insert("path".to_owned(), serde_json::Value::String(path.to_owned()));
+            }
+        }
+
+        self
+    }
 }
 
 #[derive(Debug, Error)]
@@ -263,6 +298,9 @@ pub enum ErrorKind {
     #[error("The query parameter limit supported by your database is exceeded: {0}.")]
     QueryParameterLimitExceeded(String),
 
+    #[error("Query returned more than {limit} rows, which is the maximum allowed")]
+    TooManyRows { limit: usize },
+
     #[error("Cannot find a fulltext index to use for the native search")]
     MissingNativeFullTextSearchIndex,
 
@@ -278,11 +316,23 @@ pub enum ErrorKind {
     #[error("Invalid driver adapter: {0}")]
     InvalidDriverAdapter(String),
 
+    #[error(
+        "The schema `{schema}` referenced by the Prisma schema is not known to the database connection. Schemas available through this connection: {available:?}"
+    )]
+    UnknownSchema { schema: String, available: Vec<String> },
+
     #[error("Too many DB connections opened: {}", _0)]
     TooManyConnections(Box<dyn std::error::Error + Send + Sync>),
 
     #[error("Failed to parse database version: {}. Reason: {}", version, reason)]
     UnexpectedDatabaseVersion { version: String, reason: String },
+
+    #[error(
+        "The columns returned by the database ({:?}) do not match the columns expected by the query ({:?}). The schema may have changed concurrently.",
+        actual,
+        expected
+    )]
+    SchemaDrift { expected: Vec<String>, actual: Vec<String> },
 }
 
 impl From<DomainError> for ConnectorError {
@@ -4,6 +4,7 @@ use prisma_value::PrismaValue;
 use query_structure::*;
 use std::collections::HashMap;
 use telemetry::TraceParent;
+use user_facing_errors::query_engine::DatabaseConstraint;
 
 #[async_trait]
 pub trait Connector {
@@ -26,6 +27,7 @@ pub trait Connection: ConnectionLike {
     async fn start_transaction<'a>(
         &'a mut self,
         isolation_level: Option<String>,
+        snapshot_id: Option<String>,
     ) -> crate::Result<Box<dyn Transaction + 'a>>;
 
     async fn version(&self) -> Option<String>;
@@ -65,6 +67,114 @@ pub enum AggregationResult {
     Max(ScalarFieldRef, PrismaValue),
 }
 
+/// Caps how many [`SkippedRecord`]s [`WriteOperations::create_records_with_skip_report`] keeps in
+/// memory; a batch that skips more than this many rows still reports an exact `skipped_count`,
+/// just with `truncated` set and only the first `MAX_REPORTED_SKIPPED_ROWS` entries kept.
+pub const MAX_REPORTED_SKIPPED_ROWS: usize = 1000;
+
+/// A single row from a `skip_duplicates` batch that wasn't inserted because its primary key
+/// already existed, either in the database or earlier in the same batch.
+///
+/// Only the primary key is reported: a plain `ON CONFLICT DO NOTHING` doesn't tell us which
+/// unique constraint caused a given row to be skipped, only that it was.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SkippedRecord {
+    #[serde(serialize_with = "serialize_unique_key")]
+    pub unique_key: SelectionResult,
+}
+
+/// Result of [`WriteOperations::create_records_with_skip_report`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SkipDuplicatesReport {
+    pub inserted: usize,
+    pub skipped: Vec<SkippedRecord>,
+    pub skipped_count: usize,
+    pub truncated: bool,
+}
+
+/// Serializes a [`SelectionResult`] as a JSON object of db field name to value, since the type
+/// otherwise carries no `Serialize` impl of its own.
+fn serialize_unique_key<S>(unique_key: &SelectionResult, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeMap;
+
+    let mut map = serializer.serialize_map(Some(unique_key.len()))?;
+    for (db_name, (_, value)) in unique_key.db_names().zip(unique_key.pairs.iter()) {
+        map.serialize_entry(&db_name, value)?;
+    }
+    map.end()
+}
+
+/// Caps how many [`CreateManyConflict`]s [`WriteOperations::create_records_collecting_errors`]
+/// keeps in memory; a batch with more unique/null constraint conflicts than this still reports an
+/// exact `conflict_count`, just with `truncated` set and only the first
+/// `MAX_REPORTED_CREATE_MANY_CONFLICTS` entries kept.
+pub const MAX_REPORTED_CREATE_MANY_CONFLICTS: usize = 1000;
+
+/// A single row from a [`WriteOperations::create_records_collecting_errors`] batch that failed to
+/// insert because of a unique or null constraint violation.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CreateManyConflict {
+    /// Position of the failing row in the original `args` slice passed to the operation.
+    pub row_index: usize,
+    pub constraint: DatabaseConstraint,
+    /// The failing row's own values for `constraint`'s fields, in the same order, when
+    /// `constraint` is a [`DatabaseConstraint::Fields`] and every one of them was given a plain
+    /// value (as opposed to a relative write like `increment`, which createMany never produces).
+    /// Empty for every other constraint shape, since there's no field list to read values for.
+    pub conflicting_values: Vec<PrismaValue>,
+}
+
+/// Result of [`WriteOperations::create_records_collecting_errors`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CreateManyErrorReport {
+    pub inserted: usize,
+    pub conflicts: Vec<CreateManyConflict>,
+    pub conflict_count: usize,
+    pub truncated: bool,
+}
+
+/// The outcome of a single statement of a chunked `updateMany`/`deleteMany`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ChunkOutcome {
+    /// Rows this chunk affected. `0` when `error` is set.
+    pub affected: usize,
+    /// Set when this chunk failed. Only populated under [`query_structure::ChunkExecutionPolicy::BestEffort`];
+    /// `FailFast` stops and surfaces the failure as the operation's error instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Per-statement breakdown of a `updateMany`/`deleteMany` that had to be split into more than one
+/// SQL statement, surfaced to clients through the `chunks` response extension.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ChunkBreakdown {
+    pub chunks: Vec<ChunkOutcome>,
+}
+
+impl ChunkBreakdown {
+    pub fn has_failures(&self) -> bool {
+        self.chunks.iter().any(|c| c.error.is_some())
+    }
+}
+
+/// Result of [`WriteOperations::update_records_with_chunks`]/[`WriteOperations::delete_records_with_chunks`].
+#[derive(Debug, Clone)]
+pub struct WriteManyResult {
+    pub count: usize,
+    /// `None` when the write only ever needed a single statement, in which case there's nothing
+    /// interesting to report beyond `count`.
+    pub chunks: Option<ChunkBreakdown>,
+}
+
+impl WriteManyResult {
+    pub fn single_chunk(count: usize) -> Self {
+        Self { count, chunks: None }
+    }
+}
+
 #[async_trait]
 pub trait ReadOperations {
     /// Gets a single record or `None` back from the database.
@@ -160,17 +270,136 @@ pub trait WriteOperations {
         traceparent: Option<TraceParent>,
     ) -> crate::Result<ManyRecords>;
 
+    /// Like [`WriteOperations::create_records_returning`], but only ever returns `model`'s primary
+    /// key column values, for callers that only need the generated ids back and not a whole row
+    /// (e.g. a `createMany` that only wants the ids of what it just inserted).
+    ///
+    /// The default implementation just calls `create_records_returning` scoped to the primary key
+    /// fields; connectors for which a full `RETURNING` is more expensive than necessary to get ids
+    /// alone (e.g. because they have no `RETURNING` on `INSERT` at all and otherwise need a
+    /// companion query) can override this with a cheaper, dedicated path.
+    async fn create_records_returning_ids(
+        &mut self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        traceparent: Option<TraceParent>,
+    ) -> crate::Result<ManyRecords> {
+        self.create_records_returning(model, args, skip_duplicates, model.shard_aware_primary_identifier(), traceparent)
+            .await
+    }
+
+    /// Opt-in variant of [`WriteOperations::create_records`] for `skip_duplicates` batches: in
+    /// addition to the inserted count, reports which of the input rows were skipped because their
+    /// primary key already existed.
+    ///
+    /// Reporting is best-effort. It only covers models whose primary key is made up of fields
+    /// that are given an explicit, literal value in every row of `args` (the common case for
+    /// imports with caller-supplied ids); when that's not the case, or a connector hasn't
+    /// implemented reporting, this falls back to the plain count with no report, the same as
+    /// calling `create_records` directly.
+    async fn create_records_with_skip_report(
+        &mut self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        traceparent: Option<TraceParent>,
+    ) -> crate::Result<(usize, Option<SkipDuplicatesReport>)> {
+        let count = self.create_records(model, args, skip_duplicates, traceparent).await?;
+
+        Ok((count, None))
+    }
+
+    /// Opt-in variant of [`WriteOperations::create_records`] that never aborts the whole batch on
+    /// a unique or null constraint violation: it keeps inserting the rest of `args` and reports
+    /// every conflicting row it found instead, identified the same way
+    /// [`WriteOperations::create_record`] identifies a single-row constraint violation.
+    ///
+    /// Deliberately does not run inside a transaction, including when the caller is itself a
+    /// [`Transaction`]: a transaction aborts on the first failed statement the same way
+    /// `create_records` does, which defeats the point of this mode. Each row that does get
+    /// inserted is committed independently of the others, so callers should only reach for this
+    /// when `createMany`'s usual all-or-nothing semantics aren't what they need.
+    ///
+    /// The default implementation falls back to plain `create_records`: a connector that hasn't
+    /// implemented per-row collection still fails the whole batch on the first conflict, the same
+    /// as calling `create_records` directly, rather than fabricating a misleading partial report.
+    async fn create_records_collecting_errors(
+        &mut self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        traceparent: Option<TraceParent>,
+    ) -> crate::Result<CreateManyErrorReport> {
+        let inserted = self.create_records(model, args, false, traceparent).await?;
+
+        Ok(CreateManyErrorReport {
+            inserted,
+            conflicts: Vec::new(),
+            conflict_count: 0,
+            truncated: false,
+        })
+    }
+
+    /// Allocates a block of `count` ids for `model` without inserting any records, so callers can
+    /// assign ids to rows client-side and avoid a `RETURNING` round trip on the following insert.
+    ///
+    /// Only supported for models whose id is a single autoincrement- or sequence-backed field; the
+    /// default implementation always returns an `UnsupportedFeature` error. The returned block is a
+    /// plain `Vec`: the engine does not reserve anything beyond whatever guarantee the database's
+    /// own id generator gives two concurrent callers of this method.
+    async fn allocate_ids(
+        &mut self,
+        model: &Model,
+        count: usize,
+        traceparent: Option<TraceParent>,
+    ) -> crate::Result<Vec<i64>> {
+        let _ = (model, count, traceparent);
+
+        Err(crate::error::ConnectorError::from_kind(
+            crate::error::ErrorKind::UnsupportedFeature("allocateIds".to_owned()),
+        ))
+    }
+
     /// Update records in the `Model` with the given `WriteArgs` filtered by the
-    /// `Filter`.
+    /// `Filter`. `order_by` is only meaningful together with `limit`, as it determines which
+    /// records are picked when the filter matches more rows than `limit` allows.
     async fn update_records(
         &mut self,
         model: &Model,
         record_filter: RecordFilter,
         args: WriteArgs,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
         traceparent: Option<TraceParent>,
     ) -> crate::Result<usize>;
 
+    /// Opt-in variant of [`WriteOperations::update_records`] that accepts a [`ChunkExecutionPolicy`]
+    /// governing how the connector should behave when the update has to be split into more than
+    /// one statement (e.g. because of a bind-parameter limit), and reports a breakdown of the
+    /// statements it ran.
+    ///
+    /// The default implementation ignores `chunk_execution_policy` and runs `update_records` as a
+    /// single reported chunk, which is correct for connectors that never split a logical
+    /// `updateMany` into more than one statement.
+    async fn update_records_with_chunks(
+        &mut self,
+        model: &Model,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+        order_by: Vec<OrderBy>,
+        limit: Option<usize>,
+        chunk_execution_policy: ChunkExecutionPolicy,
+        traceparent: Option<TraceParent>,
+    ) -> crate::Result<WriteManyResult> {
+        let _ = chunk_execution_policy;
+
+        let count = self
+            .update_records(model, record_filter, args, order_by, limit, traceparent)
+            .await?;
+
+        Ok(WriteManyResult::single_chunk(count))
+    }
+
     /// Updates many records at once into the database and returns their
     /// selected fields.
     /// This method should not be used if the connector does not support
@@ -181,6 +410,7 @@ pub trait WriteOperations {
         record_filter: RecordFilter,
         args: WriteArgs,
         selected_fields: FieldSelection,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
         traceparent: Option<TraceParent>,
     ) -> crate::Result<ManyRecords>;
@@ -204,15 +434,44 @@ pub trait WriteOperations {
         traceparent: Option<TraceParent>,
     ) -> crate::Result<SingleRecord>;
 
-    /// Delete records in the `Model` with the given `Filter`.
+    /// Delete records in the `Model` with the given `Filter`. `order_by` is only meaningful
+    /// together with `limit`, as it determines which records are picked when the filter matches
+    /// more rows than `limit` allows.
     async fn delete_records(
         &mut self,
         model: &Model,
         record_filter: RecordFilter,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
         traceparent: Option<TraceParent>,
     ) -> crate::Result<usize>;
 
+    /// Opt-in variant of [`WriteOperations::delete_records`] that accepts a [`ChunkExecutionPolicy`]
+    /// governing how the connector should behave when the delete has to be split into more than
+    /// one statement (e.g. because of a bind-parameter limit), and reports a breakdown of the
+    /// statements it ran.
+    ///
+    /// The default implementation ignores `chunk_execution_policy` and runs `delete_records` as a
+    /// single reported chunk, which is correct for connectors that never split a logical
+    /// `deleteMany` into more than one statement.
+    async fn delete_records_with_chunks(
+        &mut self,
+        model: &Model,
+        record_filter: RecordFilter,
+        order_by: Vec<OrderBy>,
+        limit: Option<usize>,
+        chunk_execution_policy: ChunkExecutionPolicy,
+        traceparent: Option<TraceParent>,
+    ) -> crate::Result<WriteManyResult> {
+        let _ = chunk_execution_policy;
+
+        let count = self
+            .delete_records(model, record_filter, order_by, limit, traceparent)
+            .await?;
+
+        Ok(WriteManyResult::single_chunk(count))
+    }
+
     /// Delete single record in the `Model` with the given `Filter` and returns
     /// selected fields of the deleted record.
     /// This method should not be used if the connector does not support returning
@@ -227,14 +486,15 @@ pub trait WriteOperations {
 
     // We plan to remove the methods below in the future. We want emulate them with the ones above. Those should suffice.
 
-    /// Connect the children to the parent (m2m relation only).
+    /// Connect the children to the parent (m2m relation only). Returns the number of links
+    /// actually created (may be less than `child_ids.len()` if some links already existed).
     async fn m2m_connect(
         &mut self,
         field: &RelationFieldRef,
         parent_id: &SelectionResult,
         child_ids: &[SelectionResult],
         traceparent: Option<TraceParent>,
-    ) -> crate::Result<()>;
+    ) -> crate::Result<usize>;
 
     /// Disconnect the children from the parent (m2m relation only).
     async fn m2m_disconnect(
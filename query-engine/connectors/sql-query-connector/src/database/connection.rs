@@ -5,16 +5,16 @@ use crate::{database::operations::*, SqlError};
 use async_trait::async_trait;
 use connector::ConnectionLike;
 use connector_interface::{
-    self as connector, AggregationRow, Connection, ReadOperations, Transaction, WriteOperations,
+    self as connector, AggregationRow, Connection, ReadOperations, Transaction, WriteManyResult, WriteOperations,
 };
 use prisma_value::PrismaValue;
 use quaint::{
     connector::{IsolationLevel, TransactionCapable},
-    prelude::{ConnectionInfo, Queryable},
+    prelude::{ConnectionInfo, Queryable, SqlFamily},
 };
 use query_structure::{
-    prelude::*, AggregationSelection, Filter, QueryArguments, RecordFilter, RelationLoadStrategy, SelectionResult,
-    WriteArgs,
+    prelude::*, AggregationSelection, ChunkExecutionPolicy, Filter, QueryArguments, RecordFilter,
+    RelationLoadStrategy, SelectionResult, WriteArgs,
 };
 use sql_query_builder::Context;
 use std::{collections::HashMap, str::FromStr};
@@ -49,6 +49,7 @@ where
     async fn start_transaction<'a>(
         &'a mut self,
         isolation_level: Option<String>,
+        snapshot_id: Option<String>,
     ) -> connector::Result<Box<dyn Transaction + 'a>> {
         let connection_info = &self.connection_info;
         let features = self.features;
@@ -63,7 +64,25 @@ where
             None => None,
         };
 
-        let fut_tx = self.inner.start_transaction(isolation_level);
+        if snapshot_id.is_some() {
+            if connection_info.sql_family() != SqlFamily::Postgres {
+                return Err(SqlError::Unsupported(
+                    "Importing a transaction snapshot is only supported on PostgreSQL".to_owned(),
+                )
+                .into_connector_error(connection_info.as_native()));
+            }
+
+            if !matches!(isolation_level, Some(IsolationLevel::RepeatableRead) | Some(IsolationLevel::Serializable)) {
+                return Err(SqlError::QueryInvalidInput(
+                    "Importing a transaction snapshot requires the isolation level to be set to \
+                     RepeatableRead or Serializable"
+                        .to_owned(),
+                )
+                .into_connector_error(connection_info.as_native()));
+            }
+        }
+
+        let fut_tx = self.inner.start_transaction(isolation_level, snapshot_id);
 
         catch(&self.connection_info, async move {
             let tx = fut_tx.await.map_err(SqlError::from)?;
@@ -224,18 +243,107 @@ where
         .await
     }
 
+    async fn create_records_returning_ids(
+        &mut self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<ManyRecords> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(
+            &self.connection_info,
+            write::create_records_returning_ids(
+                &self.inner,
+                &self.connection_info.sql_family(),
+                model,
+                args,
+                skip_duplicates,
+                &ctx,
+            ),
+        )
+        .await
+    }
+
+    async fn create_records_with_skip_report(
+        &mut self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<(usize, Option<connector::SkipDuplicatesReport>)> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(
+            &self.connection_info,
+            write::create_records_with_skip_report(&self.inner, model, args, skip_duplicates, &ctx),
+        )
+        .await
+    }
+
+    async fn create_records_collecting_errors(
+        &mut self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<connector::CreateManyErrorReport> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(
+            &self.connection_info,
+            write::create_records_collecting_errors(&self.inner, model, args, &ctx),
+        )
+        .await
+    }
+
+    async fn allocate_ids(
+        &mut self,
+        model: &Model,
+        count: usize,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<Vec<i64>> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(&self.connection_info, write::allocate_ids(&self.inner, model, count, &ctx)).await
+    }
+
     async fn update_records(
         &mut self,
         model: &Model,
         record_filter: RecordFilter,
         args: WriteArgs,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
         traceparent: Option<TraceParent>,
     ) -> connector::Result<usize> {
         let ctx = Context::new(&self.connection_info, traceparent);
         catch(
             &self.connection_info,
-            write::update_records(&self.inner, model, record_filter, args, limit, &ctx),
+            write::update_records(&self.inner, model, record_filter, args, order_by, limit, &ctx),
+        )
+        .await
+    }
+
+    async fn update_records_with_chunks(
+        &mut self,
+        model: &Model,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+        order_by: Vec<OrderBy>,
+        limit: Option<usize>,
+        chunk_execution_policy: ChunkExecutionPolicy,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<WriteManyResult> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(
+            &self.connection_info,
+            write::update_records_with_chunks(
+                &self.inner,
+                model,
+                record_filter,
+                args,
+                order_by,
+                limit,
+                chunk_execution_policy,
+                &ctx,
+            ),
         )
         .await
     }
@@ -246,13 +354,23 @@ where
         record_filter: RecordFilter,
         args: WriteArgs,
         selected_fields: FieldSelection,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
         traceparent: Option<TraceParent>,
     ) -> connector::Result<ManyRecords> {
         let ctx = Context::new(&self.connection_info, traceparent);
         catch(
             &self.connection_info,
-            write::update_records_returning(&self.inner, model, record_filter, args, selected_fields, limit, &ctx),
+            write::update_records_returning(
+                &self.inner,
+                model,
+                record_filter,
+                args,
+                selected_fields,
+                order_by,
+                limit,
+                &ctx,
+            ),
         )
         .await
     }
@@ -277,13 +395,31 @@ where
         &mut self,
         model: &Model,
         record_filter: RecordFilter,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
         traceparent: Option<TraceParent>,
     ) -> connector::Result<usize> {
         let ctx = Context::new(&self.connection_info, traceparent);
         catch(
             &self.connection_info,
-            write::delete_records(&self.inner, model, record_filter, limit, &ctx),
+            write::delete_records(&self.inner, model, record_filter, order_by, limit, &ctx),
+        )
+        .await
+    }
+
+    async fn delete_records_with_chunks(
+        &mut self,
+        model: &Model,
+        record_filter: RecordFilter,
+        order_by: Vec<OrderBy>,
+        limit: Option<usize>,
+        chunk_execution_policy: ChunkExecutionPolicy,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<WriteManyResult> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(
+            &self.connection_info,
+            write::delete_records_with_chunks(&self.inner, model, record_filter, order_by, limit, chunk_execution_policy, &ctx),
         )
         .await
     }
@@ -318,7 +454,7 @@ where
         parent_id: &SelectionResult,
         child_ids: &[SelectionResult],
         traceparent: Option<TraceParent>,
-    ) -> connector::Result<()> {
+    ) -> connector::Result<usize> {
         let ctx = Context::new(&self.connection_info, traceparent);
         catch(
             &self.connection_info,
@@ -18,9 +18,17 @@ pub struct Js {
 }
 
 impl Js {
+    /// `required_schemas` is the set of schema names the connected datamodel's `@@schema`
+    /// attributes reference (the `schemas` property of the active datasource). Checked against
+    /// the adapter's reported `attached_schema_names` so a multiSchema datamodel targeting a
+    /// schema the adapter never attached fails clearly at connect time instead of producing
+    /// table-not-found errors the first time that schema's model is queried. Skipped entirely for
+    /// adapters that don't report any attached schemas, so existing adapters that haven't been
+    /// updated to report them keep working unchanged.
     pub async fn new(
         connector: Arc<dyn ExternalConnector>,
         features: psl::PreviewFeatures,
+        required_schemas: &[String],
     ) -> connector_interface::Result<Self> {
         let external_conn_info = connector.get_connection_info().await.map_err(|e| match e.kind() {
             &quaint::error::ErrorKind::ExternalError(id) => ConnectorError::from_kind(ErrorKind::ExternalError(id)),
@@ -29,6 +37,8 @@ impl Js {
             )),
         })?;
 
+        validate_required_schemas(&external_conn_info, required_schemas)?;
+
         Ok(Js {
             connector: DriverAdapter { connector },
             features,
@@ -37,6 +47,79 @@ impl Js {
     }
 }
 
+/// Checked by [`Js::new`]; split out as a free function so the validation logic can be unit
+/// tested without a full `ExternalConnector` mock.
+fn validate_required_schemas(
+    external_conn_info: &quaint::connector::ExternalConnectionInfo,
+    required_schemas: &[String],
+) -> connector_interface::Result<()> {
+    if external_conn_info.attached_schema_names.is_empty() {
+        return Ok(());
+    }
+
+    let known_schema_names = external_conn_info.known_schema_names();
+
+    if let Some(schema) = required_schemas
+        .iter()
+        .find(|schema| !known_schema_names.contains(&schema.as_str()))
+    {
+        return Err(ConnectorError::from_kind(ErrorKind::UnknownSchema {
+            schema: schema.clone(),
+            available: known_schema_names.into_iter().map(ToOwned::to_owned).collect(),
+        }));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[cfg(feature = "sqlite")]
+mod tests {
+    use super::*;
+    use quaint::connector::{ExternalConnectionInfo, SqlFamily};
+
+    /// A mock adapter reporting two attached schemas (e.g. a SQLite connection with a second
+    /// database `ATTACH`ed), with a model split across them.
+    fn two_schema_connection_info() -> ExternalConnectionInfo {
+        ExternalConnectionInfo::new(SqlFamily::Sqlite, Some("main".to_owned()), None, false)
+            .with_attached_schema_names(vec!["main".to_owned(), "analytics".to_owned()])
+    }
+
+    #[test]
+    fn accepts_every_datamodel_schema_the_adapter_attached() {
+        let conn_info = two_schema_connection_info();
+        let required = vec!["main".to_owned(), "analytics".to_owned()];
+
+        assert!(validate_required_schemas(&conn_info, &required).is_ok());
+    }
+
+    #[test]
+    fn rejects_a_datamodel_schema_the_adapter_never_attached() {
+        let conn_info = two_schema_connection_info();
+        let required = vec!["main".to_owned(), "reporting".to_owned()];
+
+        let err = validate_required_schemas(&conn_info, &required).unwrap_err();
+
+        match err.kind {
+            ErrorKind::UnknownSchema { schema, available } => {
+                assert_eq!(schema, "reporting");
+                assert_eq!(available, vec!["main".to_owned(), "analytics".to_owned()]);
+            }
+            other => panic!("expected ErrorKind::UnknownSchema, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn skips_validation_when_the_adapter_reports_no_attached_schemas() {
+        // An adapter that hasn't been updated to report `attached_schema_names` yet must not
+        // start failing datamodels it already served correctly.
+        let conn_info = ExternalConnectionInfo::new(SqlFamily::Sqlite, Some("main".to_owned()), None, false);
+        let required = vec!["anything".to_owned()];
+
+        assert!(validate_required_schemas(&conn_info, &required).is_ok());
+    }
+}
+
 #[async_trait]
 impl Connector for Js {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + Send + Sync + 'static>> {
@@ -139,6 +222,10 @@ impl QuaintQueryable for DriverAdapter {
     fn requires_isolation_first(&self) -> bool {
         self.connector.requires_isolation_first()
     }
+
+    async fn set_tx_snapshot(&self, snapshot_id: &str) -> quaint::Result<()> {
+        self.connector.set_tx_snapshot(snapshot_id).await
+    }
 }
 
 #[async_trait]
@@ -146,7 +233,8 @@ impl TransactionCapable for DriverAdapter {
     async fn start_transaction<'a>(
         &'a self,
         isolation: Option<IsolationLevel>,
+        snapshot_id: Option<String>,
     ) -> quaint::Result<Box<dyn Transaction + 'a>> {
-        self.connector.start_transaction(isolation).await
+        self.connector.start_transaction(isolation, snapshot_id).await
     }
 }
@@ -7,7 +7,7 @@ use crate::{QueryExt, Queryable, SqlError};
 
 use connector_interface::*;
 use futures::stream::{FuturesUnordered, StreamExt};
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 use query_builder::QueryArgumentsExt;
 use query_structure::*;
 use sql_query_builder::{
@@ -16,6 +16,54 @@ use sql_query_builder::{
     AsColumns, AsTable, Context, RelationFieldExt,
 };
 
+/// Caps `query_arguments.take` to `max_result_rows + 1` when it is currently unbounded or would
+/// allow more than that, so the query fetches just enough to tell whether the real result set is
+/// too large without risking an unbounded fetch. Leaves `take` untouched when it already fits
+/// within the limit, so existing take/skip semantics are unaffected in that case.
+fn cap_take_for_max_result_rows(query_arguments: &mut QueryArguments, max_result_rows: Option<usize>) {
+    let Some(limit) = max_result_rows else { return };
+
+    query_arguments.take = capped_take(query_arguments.take, limit);
+}
+
+/// True when `query_arguments` only relies on chunking to keep `IN`/`NOT IN` lists under the
+/// connector's bind limit, and every such list is a single-column one. Postgres can bind the
+/// whole list as a single array parameter (see `sql-query-builder`'s filter visitor) instead of
+/// chunking, so these queries never need to be split there.
+fn skips_batching_via_array_bind(query_arguments: &QueryArguments, ctx: &Context<'_>) -> bool {
+    ctx.sql_family() == SqlFamily::Postgres
+        && query_arguments
+            .filter
+            .as_ref()
+            .is_some_and(|filter| filter.is_single_column_list_only())
+}
+
+fn capped_take(take: Take, limit: usize) -> Take {
+    let exceeds_limit = match take {
+        Take::All => true,
+        Take::One => false,
+        Take::Some(n) => n.unsigned_abs() as usize > limit,
+    };
+
+    if !exceeds_limit {
+        return take;
+    }
+
+    let capped = (limit + 1) as i64;
+
+    Take::Some(if take.is_reversed() { -capped } else { capped })
+}
+
+/// Fails the query with [`SqlError::TooManyRows`] if more than `max_result_rows` rows were
+/// fetched. Paired with [`cap_take_for_max_result_rows`], which makes sure at most
+/// `max_result_rows + 1` rows are ever read off the wire before this check runs.
+fn enforce_max_result_rows(records_len: usize, max_result_rows: Option<usize>) -> crate::Result<()> {
+    match max_result_rows {
+        Some(limit) if records_len > limit => Err(SqlError::TooManyRows { limit }),
+        _ => Ok(()),
+    }
+}
+
 pub(crate) async fn get_single_record(
     conn: &dyn Queryable,
     model: &Model,
@@ -28,7 +76,7 @@ pub(crate) async fn get_single_record(
         #[cfg(feature = "relation_joins")]
         RelationLoadStrategy::Join => get_single_record_joins(conn, model, filter, selected_fields, ctx).await,
         #[cfg(not(feature = "relation_joins"))]
-        RelationLoadStrategy::Join => unreachable!(),
+        RelationLoadStrategy::Join => Err(relation_joins_unsupported()),
         RelationLoadStrategy::Query => get_single_record_wo_joins(conn, model, filter, selected_fields, ctx).await,
     }
 }
@@ -130,18 +178,28 @@ pub(crate) async fn get_many_records(
         #[cfg(feature = "relation_joins")]
         RelationLoadStrategy::Join => get_many_records_joins(conn, model, query_arguments, selected_fields, ctx).await,
         #[cfg(not(feature = "relation_joins"))]
-        RelationLoadStrategy::Join => unreachable!(),
+        RelationLoadStrategy::Join => Err(relation_joins_unsupported()),
         RelationLoadStrategy::Query => {
             get_many_records_wo_joins(conn, model, query_arguments, selected_fields, ctx).await
         }
     }
 }
 
+/// The connector was asked to resolve relations via `RelationLoadStrategy::Join`, but this build
+/// wasn't compiled with the `relation_joins` feature. Reaching here is a query-graph-building bug
+/// (it's supposed to fall back to `RelationLoadStrategy::Query` whenever joins aren't available,
+/// see `get_relation_load_strategy_with_args`/`JoinStrategySupport`), so surface it as a proper
+/// error instead of panicking.
+#[cfg(not(feature = "relation_joins"))]
+fn relation_joins_unsupported() -> SqlError {
+    SqlError::Unsupported("relationLoadStrategy: join (this build was compiled without join support)".to_owned())
+}
+
 #[cfg(feature = "relation_joins")]
 async fn get_many_records_joins(
     conn: &dyn Queryable,
     _model: &Model,
-    query_arguments: QueryArguments,
+    mut query_arguments: QueryArguments,
     selected_fields: &FieldSelection,
     ctx: &Context<'_>,
 ) -> crate::Result<ManyRecords> {
@@ -166,7 +224,9 @@ async fn get_many_records_joins(
     };
 
     match ctx.max_bind_values() {
-        Some(chunk_size) if query_arguments.should_batch(chunk_size) => {
+        Some(chunk_size)
+            if query_arguments.should_batch(chunk_size) && !skips_batching_via_array_bind(&query_arguments, ctx) =>
+        {
             return Err(SqlError::QueryParameterLimitExceeded(
                 "Joined queries cannot be split into multiple queries.".to_string(),
             ));
@@ -174,6 +234,8 @@ async fn get_many_records_joins(
         _ => (),
     };
 
+    cap_take_for_max_result_rows(&mut query_arguments, ctx.max_result_rows());
+
     let query = sql_query_builder::select::SelectBuilder::build(query_arguments.clone(), &selected_fields, ctx);
 
     for item in conn.filter(query.into(), meta.as_slice(), ctx).await?.into_iter() {
@@ -185,6 +247,8 @@ async fn get_many_records_joins(
         records.push(record)
     }
 
+    enforce_max_result_rows(records.records.len(), ctx.max_result_rows())?;
+
     if query_arguments.needs_inmemory_processing_with_joins() {
         records.records = process::InMemoryProcessorForJoins::new(&query_arguments, records.records)
             .process(|record| Some((Cow::Borrowed(record), Cow::Borrowed(&records.field_names))))
@@ -218,7 +282,9 @@ async fn get_many_records_wo_joins(
     // to determine the right queries to fire, and will default to incorrect orderings if no ordering is found.
     // The should_batch has been adjusted to reflect that as a band-aid, but deeper investigation is necessary.
     match ctx.max_bind_values() {
-        Some(chunk_size) if query_arguments.should_batch(chunk_size) => {
+        Some(chunk_size)
+            if query_arguments.should_batch(chunk_size) && !skips_batching_via_array_bind(&query_arguments, ctx) =>
+        {
             if query_arguments.has_unbatchable_ordering() {
                 return Err(SqlError::QueryParameterLimitExceeded(
                     "Your query cannot be split into multiple queries because of the order by aggregation or relevance"
@@ -264,6 +330,8 @@ async fn get_many_records_wo_joins(
             }
         }
         _ => {
+            cap_take_for_max_result_rows(&mut query_arguments, ctx.max_result_rows());
+
             let query = read::get_records(
                 model,
                 ModelProjection::from(&selected_fields)
@@ -277,6 +345,8 @@ async fn get_many_records_wo_joins(
             for item in conn.filter(query.into(), meta.as_slice(), ctx).await?.into_iter() {
                 records.push(Record::from(item))
             }
+
+            enforce_max_result_rows(records.records.len(), ctx.max_result_rows())?;
         }
     }
 
@@ -456,3 +526,38 @@ fn get_selection_indexes<'a>(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn capped_take_leaves_take_within_limit_untouched() {
+        assert_eq!(capped_take(Take::Some(5), 10), Take::Some(5));
+        assert_eq!(capped_take(Take::Some(-5), 10), Take::Some(-5));
+        assert_eq!(capped_take(Take::One, 10), Take::One);
+    }
+
+    #[test]
+    fn capped_take_bounds_unbounded_and_oversized_take() {
+        assert_eq!(capped_take(Take::All, 10), Take::Some(11));
+        assert_eq!(capped_take(Take::Some(20), 10), Take::Some(11));
+        // A negative `take` (i.e. "last N") must stay reversed once capped.
+        assert_eq!(capped_take(Take::Some(-20), 10), Take::Some(-11));
+    }
+
+    #[test]
+    fn enforce_max_result_rows_only_errors_past_the_limit() {
+        assert!(enforce_max_result_rows(10, Some(10)).is_ok());
+        assert!(enforce_max_result_rows(11, Some(10)).is_err());
+        assert!(enforce_max_result_rows(usize::MAX, None).is_ok());
+    }
+
+    // Builds without the `relation_joins` feature must report a proper error instead of
+    // panicking when asked to resolve `RelationLoadStrategy::Join`.
+    #[cfg(not(feature = "relation_joins"))]
+    #[test]
+    fn relation_joins_unsupported_is_a_proper_error_not_a_panic() {
+        assert!(matches!(relation_joins_unsupported(), SqlError::Unsupported(_)));
+    }
+}
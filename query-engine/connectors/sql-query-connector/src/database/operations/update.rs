@@ -20,7 +20,7 @@ pub(crate) async fn update_one_with_selection(
     // If there's nothing to update, just read the record.
     // TODO(perf): Technically, if the selectors are fulfilling the field selection, there's no need to perform an additional read.
     if args.args.is_empty() {
-        let filter = build_update_one_filter(record_filter);
+        let filter = build_update_one_filter(model, record_filter);
         return get_single_record(conn, model, &filter, &selected_fields, RelationLoadStrategy::Query, ctx).await;
     }
 
@@ -71,7 +71,7 @@ pub(crate) async fn update_one_without_selection(
     // Perform the update and return the ids on which we've applied the update.
     // Note: We are _not_ getting back the ids from the update. Either we got some ids passed from the parent operation or we perform a read _before_ doing the update.
     let filter = record_filter.filter.clone();
-    let ids = conn.filter_selectors(model, record_filter, ctx).await?;
+    let ids = resolve_ids_for_update(conn, model, record_filter, ctx).await?;
     let updates = update::update_many_from_ids_and_filter(model, filter, &ids, args, None, ctx);
     for update in updates {
         conn.execute(update).await?;
@@ -89,6 +89,39 @@ pub(crate) async fn update_one_without_selection(
     Ok(record)
 }
 
+/// Resolves the ids an update-without-selection will operate on. If `record_filter` already
+/// carries selectors that cover the model's primary identifier, those are used as-is instead of
+/// issuing a read, since that read would just recompute values the caller already has (e.g. a
+/// nested update whose parent already resolved the ids).
+async fn resolve_ids_for_update(
+    conn: &dyn Queryable,
+    model: &Model,
+    record_filter: RecordFilter,
+    ctx: &Context<'_>,
+) -> crate::Result<Vec<SelectionResult>> {
+    match &record_filter.selectors {
+        Some(selectors) if selectors_cover_primary_identifier(model, selectors) => {
+            let id_selection = model.shard_aware_primary_identifier();
+
+            Ok(record_filter
+                .selectors
+                .unwrap()
+                .into_iter()
+                .map(|selector| selector.project(&id_selection))
+                .collect())
+        }
+        _ => conn.filter_selectors(model, record_filter, ctx).await,
+    }
+}
+
+fn selectors_cover_primary_identifier(model: &Model, selectors: &[SelectionResult]) -> bool {
+    let id_selection = model.shard_aware_primary_identifier();
+
+    selectors
+        .iter()
+        .all(|selector| id_selection.selections().all(|field| selector.get(field).is_some()))
+}
+
 fn process_result_row(
     row: quaint::prelude::ResultRow,
     meta: &[ColumnMetadata<'_>],
@@ -102,15 +135,82 @@ fn process_result_row(
 
 /// Given a record filter, builds a ConditionTree composed of:
 /// 1. The `RecordFilter.filter`
-/// 2. The `RecordFilter.selectors`, if any are present, transformed to an `In()` filter
+/// 2. The `RecordFilter.selectors`, if any are present, projected down to the shard-aware primary
+///    identifier and transformed to an `In()` filter. Selectors are only used for identity here,
+///    so any extra pairs they carry (e.g. inherited from a broader parent selection) are dropped
+///    rather than turned into additional conditions.
 ///
 /// Both filters are 'AND'ed.
 ///
 /// Note: This function should only be called for update_one filters. It is not chunking the filters into multiple queries.
 /// Note: Using this function to render an update_many filter could exceed the maximum query parameters available for a connector.
-fn build_update_one_filter(record_filter: RecordFilter) -> Filter {
+fn build_update_one_filter(model: &Model, record_filter: RecordFilter) -> Filter {
     match record_filter.selectors {
-        Some(selectors) => Filter::and(vec![selectors.filter(), record_filter.filter]),
+        Some(selectors) => {
+            let id_selection = model.shard_aware_primary_identifier();
+            let ids: Vec<SelectionResult> = selectors.into_iter().map(|s| s.project(&id_selection)).collect();
+
+            Filter::and(vec![ids.filter(), record_filter.filter])
+        }
         None => record_filter.filter,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Arc;
+
+    fn test_model() -> Model {
+        let schema_str = r#"
+            datasource db {
+                provider = "postgresql"
+                url      = "postgres://stub"
+            }
+
+            model TestModel {
+                id    Int    @id
+                email String
+            }
+        "#;
+
+        let psl_schema = psl::validate(schema_str.into());
+        assert!(!psl_schema.diagnostics.has_errors(), "{:?}", psl_schema.diagnostics);
+
+        let internal_data_model = InternalDataModel {
+            schema: Arc::new(psl_schema),
+        };
+
+        internal_data_model.find_model("TestModel").unwrap()
+    }
+
+    fn scalar_field(model: &Model, name: &str) -> ScalarFieldRef {
+        model.fields().scalar().find(|f| f.name() == name).unwrap()
+    }
+
+    #[test]
+    fn selectors_cover_primary_identifier_ignores_extra_pairs() {
+        let model = test_model();
+        let selector = SelectionResult::new(vec![
+            (scalar_field(&model, "id"), PrismaValue::Int(1)),
+            (scalar_field(&model, "email"), PrismaValue::String("extra@example.com".into())),
+        ]);
+
+        assert!(selectors_cover_primary_identifier(&model, &[selector]));
+    }
+
+    #[test]
+    fn build_update_one_filter_strips_extra_selector_pairs() {
+        let model = test_model();
+        let selector = SelectionResult::new(vec![
+            (scalar_field(&model, "id"), PrismaValue::Int(1)),
+            (scalar_field(&model, "email"), PrismaValue::String("extra@example.com".into())),
+        ]);
+        let stripped = SelectionResult::new(vec![(scalar_field(&model, "id"), PrismaValue::Int(1))]);
+
+        let filter = build_update_one_filter(&model, RecordFilter::from(vec![selector]));
+        let expected = Filter::and(vec![vec![stripped].filter(), Filter::Empty]);
+
+        assert_eq!(filter, expected);
+    }
+}
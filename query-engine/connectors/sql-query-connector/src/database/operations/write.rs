@@ -2,11 +2,15 @@ use super::update::*;
 use crate::row::ToSqlRow;
 use crate::value::to_prisma_value;
 use crate::{error::SqlError, QueryExt, Queryable};
+use connector_interface::{
+    ChunkBreakdown, ChunkOutcome, CreateManyConflict, CreateManyErrorReport, SkipDuplicatesReport, SkippedRecord,
+    WriteManyResult, MAX_REPORTED_CREATE_MANY_CONFLICTS, MAX_REPORTED_SKIPPED_ROWS,
+};
 use itertools::Itertools;
 use quaint::prelude::ResultSet;
 use quaint::{
     error::ErrorKind,
-    prelude::{Select, SqlFamily},
+    prelude::{Select, SqlFamily, Value},
 };
 use query_structure::*;
 use sql_query_builder::write::defaults_for_mysql_write_args;
@@ -15,6 +19,55 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use user_facing_errors::query_engine::DatabaseConstraint;
 
+/// Adds `next` affected-row count to `total`, returning an error instead of silently wrapping
+/// when a batched write statement pushes the running total past `u64::MAX`.
+fn add_row_count(total: u64, next: u64) -> crate::Result<u64> {
+    total
+        .checked_add(next)
+        .ok_or_else(|| SqlError::ConversionError(anyhow::anyhow!("Affected row count overflowed u64")))
+}
+
+/// Narrows a `u64` affected-row count down to the `usize` the `WriteOperations` trait returns,
+/// instead of truncating it on platforms where `usize` is smaller than 64 bits.
+fn row_count_to_usize(count: u64) -> crate::Result<usize> {
+    usize::try_from(count)
+        .map_err(|_| SqlError::ConversionError(anyhow::anyhow!("Affected row count {count} does not fit in usize")))
+}
+
+/// Applies `chunk_execution_policy` to the result of one statement of a chunked `updateMany`/
+/// `deleteMany`, given the total affected by the statements that ran before it. Shared by
+/// [`update_records_with_chunks`] and [`delete_records_with_chunks`] so the three policies behave
+/// identically regardless of which statement kind is being chunked.
+///
+/// Returns the new running total and the [`ChunkOutcome`] to report for this statement, or the
+/// error to fail the whole operation with under `FailFast`.
+fn apply_chunk_result(
+    chunk_execution_policy: ChunkExecutionPolicy,
+    total: u64,
+    result: Result<u64, quaint::error::Error>,
+) -> crate::Result<(u64, ChunkOutcome)> {
+    match result {
+        Ok(affected) => Ok((
+            add_row_count(total, affected)?,
+            ChunkOutcome {
+                affected: row_count_to_usize(affected)?,
+                error: None,
+            },
+        )),
+        Err(err) if chunk_execution_policy.is_best_effort() => Ok((
+            total,
+            ChunkOutcome {
+                affected: 0,
+                error: Some(err.to_string()),
+            },
+        )),
+        Err(err) => Err(SqlError::ChunkedWriteFailed {
+            source: Box::new(SqlError::from(err)),
+            affected: row_count_to_usize(total)?,
+        }),
+    }
+}
+
 async fn generate_id(
     conn: &dyn Queryable,
     id_field: &FieldSelection,
@@ -31,7 +84,7 @@ async fn generate_id(
         let mut id_select = Select::default();
         id_select.extend(defaults);
 
-        let pk_select = id_select.add_traceparent(ctx.traceparent());
+        let pk_select = id_select.add_traceparent(ctx.traceparent(), ctx.trace_comment_mode());
         let pk_result = conn.query(pk_select.into()).await?;
         let result = try_convert(&(id_field.into()), pk_result)?;
 
@@ -43,6 +96,12 @@ async fn generate_id(
 
 /// Create a single record to the database defined in `conn`, resulting into a
 /// `RecordProjection` as an identifier pointing to the just-created record.
+///
+/// For MySQL, the autoincrement id (when not already known from `args`) is read from the
+/// `LAST_INSERT_ID()` that comes back with the very same `INSERT` result set rather than
+/// through a follow-up query, so there is no window for a retried insert (some driver adapters
+/// transparently retry on transient network errors) or a pooled connection to hand back an id
+/// that doesn't belong to this statement.
 pub(crate) async fn create_record(
     conn: &dyn Queryable,
     sql_family: &SqlFamily,
@@ -141,6 +200,19 @@ pub(crate) async fn create_record(
 
         // We have an auto-incremented id that we got from MySQL or SQLite
         (Some(mut identifier), _, Some(num)) if identifier.misses_autogen_value() => {
+            // A `LAST_INSERT_ID()` of `0` does not happen for a statement that actually performed
+            // an autoincrement insert; it's a sign that the result set we read back belongs to an
+            // unrelated statement on a pooled connection (e.g. a driver adapter that silently
+            // retried the insert on a fresh connection). Surface it instead of fabricating a
+            // record with an id of `0`.
+            if num == 0 {
+                return Err(SqlError::QueryInvalidInput(format!(
+                    "Expected an autoincremented id for model `{}`, but the database returned 0. \
+                     This can happen if the insert was retried on a different connection.",
+                    model.name()
+                )));
+            }
+
             identifier.add_autogen_value(num as i64);
 
             let field_names = identifier.db_names().map(Cow::into_owned).collect();
@@ -161,13 +233,19 @@ pub(crate) async fn create_records_count(
     skip_duplicates: bool,
     ctx: &Context<'_>,
 ) -> crate::Result<usize> {
+    #[cfg(feature = "metrics")]
+    let row_count = args.len();
     let inserts = write::generate_insert_statements(model, args, skip_duplicates, None, ctx);
-    let mut count = 0;
-    for insert in inserts {
-        count += conn.execute(insert.into()).await?;
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_write(crate::metrics::WriteOperation::Insert, inserts.len(), row_count);
+
+    let mut count: u64 = 0;
+    for (_, insert) in inserts {
+        count = add_row_count(count, conn.execute(insert.into()).await?)?;
     }
 
-    Ok(count as usize)
+    row_count_to_usize(count)
 }
 
 /// Inserts records specified as a list of `WriteArgs`. Returns values of fields specified in
@@ -184,9 +262,14 @@ pub(crate) async fn create_records_returning(
     let idents = selected_fields.type_identifiers_with_arities();
     let meta = column_metadata::create(&field_names, &idents);
     let mut records = ManyRecords::new(field_names.clone());
+    #[cfg(feature = "metrics")]
+    let row_count = args.len();
     let inserts = write::generate_insert_statements(model, args, skip_duplicates, Some(&selected_fields.into()), ctx);
 
-    for insert in inserts {
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_write(crate::metrics::WriteOperation::Insert, inserts.len(), row_count);
+
+    for (_, insert) in inserts {
         let result_set = conn.query(insert.into()).await?;
 
         for result_row in result_set {
@@ -200,6 +283,348 @@ pub(crate) async fn create_records_returning(
     Ok(records)
 }
 
+/// Like [`create_records_returning`], but only ever returns the model's primary key column
+/// values - a cheaper fit for callers that only need the generated ids back, not a whole row.
+///
+/// On every connector family but MySQL this is just `create_records_returning` scoped to the id
+/// columns: they support `RETURNING` on `INSERT` directly. MySQL's `INSERT` doesn't support
+/// `RETURNING` at all, so there this instead reads `LAST_INSERT_ID()` back from each insert
+/// statement's own result set and derives the rest of the block arithmetically - a single
+/// multi-row `INSERT` allocates a contiguous block of autoincrement values starting at
+/// `LAST_INSERT_ID()`, one per row it inserts, the same guarantee [`create_record`] already
+/// relies on for a single row.
+///
+/// That arithmetic only applies when the model's id is a single autoincrement field left for the
+/// database to generate on every row in `args`; anything else (a composite id, a caller-supplied
+/// id, a non-autoincrement default) falls back to asking MySQL for each row's id individually,
+/// the same way a single-row create already does. `skip_duplicates` isn't supported together with
+/// MySQL's cheap path - there's no way to tell from a batch's affected-row count alone which of
+/// its rows were the ones skipped - so it returns `SqlError::Unsupported` instead of a block of
+/// ids that might not line up with `args`.
+pub(crate) async fn create_records_returning_ids(
+    conn: &dyn Queryable,
+    sql_family: &SqlFamily,
+    model: &Model,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    ctx: &Context<'_>,
+) -> crate::Result<ManyRecords> {
+    let id_fields = model.shard_aware_primary_identifier();
+
+    if !sql_family.is_mysql() {
+        return create_records_returning(conn, model, args, skip_duplicates, id_fields, ctx).await;
+    }
+
+    if skip_duplicates {
+        return Err(SqlError::Unsupported(
+            "create_records_returning_ids does not support skip_duplicates on MySQL".to_owned(),
+        ));
+    }
+
+    match mysql_autoincrement_id_field(&id_fields, &args) {
+        Some(id_field) => {
+            let mut records = ManyRecords::new(vec![id_field.db_name().to_owned()]);
+            let inserts = write::generate_insert_statements(model, args, skip_duplicates, None, ctx);
+
+            for (row_count, insert) in inserts {
+                let result_set = conn.insert(insert).await?;
+
+                let Some(first_id) = result_set.last_insert_id() else {
+                    // Nothing in this statement triggered an autoincrement, which can only happen
+                    // for a statement covering zero rows - `generate_insert_statements` never
+                    // produces one.
+                    continue;
+                };
+
+                for offset in 0..row_count as u64 {
+                    records.push(Record::new(vec![PrismaValue::Int((first_id + offset) as i64)]));
+                }
+            }
+
+            Ok(records)
+        }
+        None => {
+            let mut records = ManyRecords::new(id_fields.db_names().collect());
+
+            for arg in args {
+                let single = create_record(conn, sql_family, model, arg, id_fields.clone(), ctx).await?;
+                records.push(single.record);
+            }
+
+            Ok(records)
+        }
+    }
+}
+
+/// The model's id field, if it's eligible for [`create_records_returning_ids`]'s cheap MySQL
+/// path: a single scalar field with an `@default(autoincrement())` default that every row in
+/// `args` leaves for the database to generate.
+fn mysql_autoincrement_id_field(id_fields: &FieldSelection, args: &[WriteArgs]) -> Option<ScalarFieldRef> {
+    let id_field = match id_fields.as_scalar_fields().as_deref() {
+        Some([field]) => field.clone(),
+        _ => return None,
+    };
+
+    if !id_field.is_autoincrement() || args.iter().any(|arg| arg.has_arg_for(id_field.db_name())) {
+        return None;
+    }
+
+    Some(id_field)
+}
+
+/// Like [`create_records_count`], but additionally reports which input rows were skipped because
+/// their primary key already existed, by diffing the primary keys actually written (read back via
+/// `RETURNING`) against the primary keys present in `args`.
+///
+/// Returns `Ok(None)` instead of a report when the model's primary identifier isn't made up
+/// entirely of fields that every row in `args` gives an explicit, literal value for (e.g. it
+/// relies on a database default or an autoincrement) — there's no way to know such a row's key
+/// before the insert runs, so there's nothing to diff against. Callers should treat `None` the
+/// same as calling `create_records_count` directly.
+pub(crate) async fn create_records_with_skip_report(
+    conn: &dyn Queryable,
+    model: &Model,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    ctx: &Context<'_>,
+) -> crate::Result<(usize, Option<SkipDuplicatesReport>)> {
+    let id_fields = model.shard_aware_primary_identifier();
+    let id_projection = ModelProjection::from(&id_fields);
+    let id_db_names: Vec<&str> = id_fields.scalars().map(|f| f.db_name()).collect();
+
+    let all_rows_have_explicit_id = args
+        .iter()
+        .all(|arg| id_db_names.iter().all(|name| arg.has_arg_for(name)));
+
+    if !all_rows_have_explicit_id {
+        let count = create_records_count(conn, model, args, skip_duplicates, ctx).await?;
+        return Ok((count, None));
+    }
+
+    // Safe to unwrap: every row was just checked to have an explicit value for every id field.
+    let input_keys: Vec<SelectionResult> = args
+        .iter()
+        .map(|arg| arg.as_selection_result(id_projection.clone()).unwrap())
+        .collect();
+
+    let returned = create_records_returning(conn, model, args, skip_duplicates, id_fields.clone(), ctx).await?;
+    let mut remaining: HashMap<SelectionResult, usize> = HashMap::new();
+
+    for key in returned.extract_selection_results_from_db_name(&id_fields)? {
+        *remaining.entry(key).or_default() += 1;
+    }
+
+    let mut skipped = Vec::new();
+    let mut skipped_count = 0;
+
+    for unique_key in input_keys {
+        match remaining.get_mut(&unique_key) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => {
+                skipped_count += 1;
+
+                if skipped.len() < MAX_REPORTED_SKIPPED_ROWS {
+                    skipped.push(SkippedRecord { unique_key });
+                }
+            }
+        }
+    }
+
+    let report = SkipDuplicatesReport {
+        inserted: returned.records.len(),
+        truncated: skipped_count > skipped.len(),
+        skipped,
+        skipped_count,
+    };
+
+    Ok((report.inserted, Some(report)))
+}
+
+/// Like [`create_records_count`], but never aborts the whole batch on a unique or null constraint
+/// violation. Instead, on a conflict it bisects the failing batch - retrying each half
+/// independently - until every conflicting row has been narrowed down to its own single-row
+/// insert, so a handful of bad rows scattered across a large `createMany` don't take out rows
+/// that would otherwise have inserted cleanly.
+///
+/// Deliberately does not run inside a transaction: a transaction would abort on the first failed
+/// statement the same way [`create_records_count`] does, defeating the point of this mode. Each
+/// row is committed as soon as its own insert succeeds.
+pub(crate) async fn create_records_collecting_errors(
+    conn: &dyn Queryable,
+    model: &Model,
+    args: Vec<WriteArgs>,
+    ctx: &Context<'_>,
+) -> crate::Result<CreateManyErrorReport> {
+    let mut inserted = 0usize;
+    let mut conflicts = Vec::new();
+    let mut conflict_count = 0usize;
+
+    // A stack of not-yet-attempted row batches, each row paired with its original position in
+    // `args`. Processed depth-first so a batch that had to bisect finishes with its left half
+    // before moving on to its right half; the order batches are attempted in has no effect on the
+    // result, only on which rows are reported first.
+    let mut pending: Vec<Vec<(usize, WriteArgs)>> = vec![args.into_iter().enumerate().collect()];
+
+    while let Some(batch) = pending.pop() {
+        if batch.is_empty() {
+            continue;
+        }
+
+        let (indices, batch_args): (Vec<usize>, Vec<WriteArgs>) = batch.into_iter().unzip();
+        // Cloned because a failed attempt needs the original args again to bisect.
+        let mut statements = write::generate_insert_statements(model, batch_args.clone(), false, None, ctx);
+
+        // `ctx`'s own bind-value/row-count limits already split this batch into more than one
+        // statement; bisect instead of running it, so a failure can never straddle a statement
+        // boundary we didn't choose ourselves.
+        if statements.len() != 1 {
+            bisect_batch(indices, batch_args, &mut pending);
+            continue;
+        }
+
+        let statement = statements.pop().unwrap().1.into();
+
+        if indices.len() == 1 {
+            match conn.execute(statement).await {
+                Ok(_) => inserted += 1,
+                Err(err) => match row_conflict_constraint(&err) {
+                    Some(constraint) => {
+                        conflict_count += 1;
+
+                        if conflicts.len() < MAX_REPORTED_CREATE_MANY_CONFLICTS {
+                            let conflicting_values = conflict_field_values(&batch_args[0], &constraint);
+
+                            conflicts.push(CreateManyConflict {
+                                row_index: indices[0],
+                                constraint,
+                                conflicting_values,
+                            });
+                        }
+                    }
+                    None => return Err(SqlError::from(err)),
+                },
+            }
+            continue;
+        }
+
+        match conn.execute(statement).await {
+            Ok(affected) => inserted += row_count_to_usize(affected)?,
+            Err(err) if row_conflict_constraint(&err).is_some() => bisect_batch(indices, batch_args, &mut pending),
+            Err(err) => return Err(SqlError::from(err)),
+        }
+    }
+
+    Ok(CreateManyErrorReport {
+        inserted,
+        truncated: conflict_count > conflicts.len(),
+        conflicts,
+        conflict_count,
+    })
+}
+
+/// Splits a failed batch roughly in half, pushing both halves onto `pending` for another attempt.
+fn bisect_batch(mut indices: Vec<usize>, mut args: Vec<WriteArgs>, pending: &mut Vec<Vec<(usize, WriteArgs)>>) {
+    let mid = indices.len() / 2;
+    let right_indices = indices.split_off(mid);
+    let right_args = args.split_off(mid);
+
+    pending.push(right_indices.into_iter().zip(right_args).collect());
+    pending.push(indices.into_iter().zip(args).collect());
+}
+
+/// Returns the user-facing constraint a failed insert violated, if `err` is a unique or null
+/// constraint violation - the same two `ErrorKind`s [`create_record`] maps for a single-row
+/// insert. Any other kind of error (a dropped connection, a type mismatch, ...) isn't attributable
+/// to this one row, so it's left for the caller to propagate and abort the whole operation.
+fn row_conflict_constraint(err: &quaint::error::Error) -> Option<DatabaseConstraint> {
+    let constraint = match err.kind() {
+        ErrorKind::UniqueConstraintViolation { constraint } => constraint,
+        ErrorKind::NullConstraintViolation { constraint } => constraint,
+        _ => return None,
+    };
+
+    Some(match constraint {
+        quaint::error::DatabaseConstraint::Index(name) => DatabaseConstraint::Index(name.clone()),
+        quaint::error::DatabaseConstraint::Fields(fields) => DatabaseConstraint::Fields(fields.clone()),
+        quaint::error::DatabaseConstraint::ForeignKey => DatabaseConstraint::ForeignKey,
+        quaint::error::DatabaseConstraint::CannotParse => DatabaseConstraint::CannotParse,
+    })
+}
+
+/// Reads `row`'s own values for `constraint`'s fields, in the same order, for a
+/// [`DatabaseConstraint::Fields`] constraint whose fields were all given a plain value. Returns an
+/// empty `Vec` for any other constraint shape, or if a field is missing a plain value - a
+/// `dbgenerated()`/autoincrement default that isn't in `row` at all, for instance - since there's
+/// nothing meaningful to report for it.
+fn conflict_field_values(row: &WriteArgs, constraint: &DatabaseConstraint) -> Vec<PrismaValue> {
+    let DatabaseConstraint::Fields(fields) = constraint else {
+        return Vec::new();
+    };
+
+    fields
+        .iter()
+        .map(|field| match row.get_field_value(field) {
+            Some(WriteOperation::Scalar(ScalarWriteOperation::Set(value))) => Some(value.clone()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default()
+}
+
+/// Allocates a block of `count` ids for `model`'s autoincrement- or sequence-backed id column,
+/// without inserting anything, by drawing `count` values straight from the backing database
+/// sequence.
+///
+/// Only implemented for Postgres-family connectors (including CockroachDB) and models whose id is
+/// a single field with an `@default(autoincrement())` or `@default(sequence())` default; anything
+/// else returns an `UnsupportedFeature` error, same as the trait's default implementation.
+pub(crate) async fn allocate_ids(
+    conn: &dyn Queryable,
+    model: &Model,
+    count: usize,
+    ctx: &Context<'_>,
+) -> crate::Result<Vec<i64>> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    if !ctx.sql_family().is_postgres() {
+        return Err(SqlError::Unsupported(
+            "allocateIds is only supported on Postgres-family connectors".to_owned(),
+        ));
+    }
+
+    let id_fields = model.primary_identifier();
+    let id_field = match id_fields.as_scalar_fields().as_deref() {
+        Some([field]) => field.clone(),
+        _ => {
+            return Err(SqlError::Unsupported(
+                "allocateIds requires a single-field id".to_owned(),
+            ))
+        }
+    };
+
+    let sequence_name = write::sequence_name_for_field(model, &id_field).ok_or_else(|| {
+        SqlError::Unsupported("allocateIds requires an autoincrement- or sequence-backed id".to_owned())
+    })?;
+
+    let result_set = conn
+        .query_raw(
+            "SELECT nextval($1) FROM generate_series(1, $2)",
+            &[Value::text(sequence_name), Value::int64(count as i64)],
+        )
+        .await?;
+
+    result_set
+        .into_iter()
+        .map(|row| {
+            row.at(0)
+                .and_then(|value| value.as_i64())
+                .ok_or_else(|| SqlError::Unsupported("nextval() did not return an integer".to_owned()))
+        })
+        .collect()
+}
+
 /// Update one record in a database defined in `conn` and the records
 /// defined in `args`, resulting the identifiers that were modified in the
 /// operation.
@@ -227,6 +652,7 @@ pub(crate) async fn update_records(
     model: &Model,
     record_filter: RecordFilter,
     args: WriteArgs,
+    order_by: Vec<OrderBy>,
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> crate::Result<usize> {
@@ -234,11 +660,77 @@ pub(crate) async fn update_records(
         return Ok(0);
     }
 
-    let mut count = 0;
-    for update in write::generate_update_statements(model, record_filter, args, None, limit, ctx) {
-        count += conn.execute(update).await?;
+    #[cfg(feature = "metrics")]
+    let selector_count = record_filter.selectors.as_ref().map_or(0, |s| s.len());
+    let updates = write::generate_update_statements(model, record_filter, args, None, &order_by, limit, ctx);
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_write(crate::metrics::WriteOperation::Update, updates.len(), selector_count);
+
+    let mut count: u64 = 0;
+    for update in updates {
+        count = add_row_count(count, conn.execute(update).await?)?;
+    }
+    row_count_to_usize(count)
+}
+
+/// Like [`update_records`], but executes the generated statements according to a
+/// [`ChunkExecutionPolicy`] and reports a breakdown of them when more than one was needed.
+///
+/// `Atomic` is only honored when the write fits in a single statement, where it's free: a lone
+/// statement is already atomic. A multi-statement write requested as `Atomic` is rejected up
+/// front with [`SqlError::Unsupported`] instead of silently running non-atomically, since nothing
+/// at this layer (a plain `&dyn Queryable`) can roll back statements that already committed.
+pub(crate) async fn update_records_with_chunks(
+    conn: &dyn Queryable,
+    model: &Model,
+    record_filter: RecordFilter,
+    args: WriteArgs,
+    order_by: Vec<OrderBy>,
+    limit: Option<usize>,
+    chunk_execution_policy: ChunkExecutionPolicy,
+    ctx: &Context<'_>,
+) -> crate::Result<WriteManyResult> {
+    if args.args.is_empty() {
+        return Ok(WriteManyResult::single_chunk(0));
+    }
+
+    #[cfg(feature = "metrics")]
+    let selector_count = record_filter.selectors.as_ref().map_or(0, |s| s.len());
+    let updates = write::generate_update_statements(model, record_filter, args, None, &order_by, limit, ctx);
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_write(crate::metrics::WriteOperation::Update, updates.len(), selector_count);
+
+    if chunk_execution_policy.is_atomic() && updates.len() > 1 {
+        return Err(SqlError::Unsupported(
+            "chunkExecutionPolicy: atomic requires a single statement, but this updateMany needed \
+             more than one; atomicity across chunks is not yet supported at this layer"
+                .to_owned(),
+        ));
+    }
+
+    if updates.len() <= 1 {
+        let mut count: u64 = 0;
+        for update in updates {
+            count = add_row_count(count, conn.execute(update).await?)?;
+        }
+        return Ok(WriteManyResult::single_chunk(row_count_to_usize(count)?));
     }
-    Ok(count as usize)
+
+    let mut total: u64 = 0;
+    let mut outcomes = Vec::with_capacity(updates.len());
+
+    for update in updates {
+        let (new_total, outcome) = apply_chunk_result(chunk_execution_policy, total, conn.execute(update).await)?;
+        total = new_total;
+        outcomes.push(outcome);
+    }
+
+    Ok(WriteManyResult {
+        count: row_count_to_usize(total)?,
+        chunks: Some(ChunkBreakdown { chunks: outcomes }),
+    })
 }
 
 /// Update records according to `WriteArgs`. Returns values of fields specified in
@@ -249,6 +741,7 @@ pub(crate) async fn update_records_returning(
     record_filter: RecordFilter,
     args: WriteArgs,
     selected_fields: FieldSelection,
+    order_by: Vec<OrderBy>,
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> crate::Result<ManyRecords> {
@@ -257,9 +750,22 @@ pub(crate) async fn update_records_returning(
     let meta = column_metadata::create(&field_names, &idents);
     let mut records = ManyRecords::new(field_names.clone());
 
-    for update in
-        write::generate_update_statements(model, record_filter, args, Some(&selected_fields.into()), limit, ctx)
-    {
+    #[cfg(feature = "metrics")]
+    let selector_count = record_filter.selectors.as_ref().map_or(0, |s| s.len());
+    let updates = write::generate_update_statements(
+        model,
+        record_filter,
+        args,
+        Some(&selected_fields.into()),
+        &order_by,
+        limit,
+        ctx,
+    );
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_write(crate::metrics::WriteOperation::Update, updates.len(), selector_count);
+
+    for update in updates {
         let result_set = conn.query(update).await?;
 
         for result_row in result_set {
@@ -273,22 +779,94 @@ pub(crate) async fn update_records_returning(
     Ok(records)
 }
 
+/// Like [`delete_records`], but executes the generated statements according to a
+/// [`ChunkExecutionPolicy`] and reports a breakdown of them when more than one was needed.
+///
+/// See [`update_records_with_chunks`] for how `Atomic` is handled.
+pub(crate) async fn delete_records_with_chunks(
+    conn: &dyn Queryable,
+    model: &Model,
+    record_filter: RecordFilter,
+    order_by: Vec<OrderBy>,
+    limit: Option<usize>,
+    chunk_execution_policy: ChunkExecutionPolicy,
+    ctx: &Context<'_>,
+) -> crate::Result<WriteManyResult> {
+    #[cfg(feature = "metrics")]
+    let selector_count = record_filter.selectors.as_ref().map_or(0, |s| s.len());
+    let deletes = write::generate_delete_statements(model, record_filter, &order_by, limit, ctx);
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_write(crate::metrics::WriteOperation::Delete, deletes.len(), selector_count);
+
+    if chunk_execution_policy.is_atomic() && deletes.len() > 1 {
+        return Err(SqlError::Unsupported(
+            "chunkExecutionPolicy: atomic requires a single statement, but this deleteMany needed \
+             more than one; atomicity across chunks is not yet supported at this layer"
+                .to_owned(),
+        ));
+    }
+
+    if deletes.len() <= 1 {
+        let mut row_count: u64 = 0;
+        for delete in deletes {
+            row_count = add_row_count(row_count, conn.execute(delete).await?)?;
+        }
+        return Ok(WriteManyResult::single_chunk(row_count_to_usize(row_count)?));
+    }
+
+    let mut total: u64 = 0;
+    let mut remaining_limit = limit;
+    let mut outcomes = Vec::with_capacity(deletes.len());
+
+    for delete in deletes {
+        let (new_total, outcome) = apply_chunk_result(chunk_execution_policy, total, conn.execute(delete).await)?;
+        total = new_total;
+
+        // A failed chunk affects 0 rows, so it never consumes any of the remaining limit.
+        if let Some(old_remaining_limit) = remaining_limit {
+            let new_remaining_limit = old_remaining_limit - outcome.affected;
+            outcomes.push(outcome);
+
+            if new_remaining_limit == 0 {
+                break;
+            }
+            remaining_limit = Some(new_remaining_limit);
+        } else {
+            outcomes.push(outcome);
+        }
+    }
+
+    Ok(WriteManyResult {
+        count: row_count_to_usize(total)?,
+        chunks: Some(ChunkBreakdown { chunks: outcomes }),
+    })
+}
+
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
 pub(crate) async fn delete_records(
     conn: &dyn Queryable,
     model: &Model,
     record_filter: RecordFilter,
+    order_by: Vec<OrderBy>,
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> crate::Result<usize> {
-    let mut row_count = 0;
+    #[cfg(feature = "metrics")]
+    let selector_count = record_filter.selectors.as_ref().map_or(0, |s| s.len());
+    let deletes = write::generate_delete_statements(model, record_filter, &order_by, limit, ctx);
+
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_write(crate::metrics::WriteOperation::Delete, deletes.len(), selector_count);
+
+    let mut row_count: u64 = 0;
     let mut remaining_limit = limit;
 
-    for delete in write::generate_delete_statements(model, record_filter, limit, ctx) {
-        row_count += conn.execute(delete).await?;
+    for delete in deletes {
+        row_count = add_row_count(row_count, conn.execute(delete).await?)?;
         if let Some(old_remaining_limit) = remaining_limit {
-            // u64 to usize cast here cannot 'overflow' as the number of rows was limited to MAX usize in the first place.
-            let new_remaining_limit = old_remaining_limit - row_count as usize;
+            // Cannot overflow as the number of rows was limited to MAX usize in the first place.
+            let new_remaining_limit = old_remaining_limit - row_count_to_usize(row_count)?;
             if new_remaining_limit == 0 {
                 break;
             }
@@ -296,7 +874,7 @@ pub(crate) async fn delete_records(
         }
     }
 
-    Ok(row_count as usize)
+    row_count_to_usize(row_count)
 }
 
 pub(crate) async fn delete_record(
@@ -340,18 +918,20 @@ pub(crate) async fn delete_record(
 }
 
 /// Connect relations defined in `child_ids` to a parent defined in `parent_id`.
-/// The relation information is in the `RelationFieldRef`.
+/// The relation information is in the `RelationFieldRef`. Returns the number of links actually
+/// created, which can be lower than `child_ids.len()` when some of the links already existed
+/// (the insert uses `ON CONFLICT DO NOTHING`/`INSERT IGNORE` semantics).
 pub(crate) async fn m2m_connect(
     conn: &dyn Queryable,
     field: &RelationFieldRef,
     parent_id: &SelectionResult,
     child_ids: &[SelectionResult],
     ctx: &Context<'_>,
-) -> crate::Result<()> {
+) -> crate::Result<usize> {
     let query = write::create_relation_table_records(field, parent_id, child_ids, ctx);
-    conn.query(query).await?;
+    let count = conn.execute(query).await?;
 
-    Ok(())
+    row_count_to_usize(count)
 }
 
 /// Disconnect relations defined in `child_ids` to a parent defined in `parent_id`.
@@ -417,3 +997,139 @@ fn try_convert(model_projection: &ModelProjection, result_set: ResultSet) -> cra
         )))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Arc;
+
+    fn test_model(id_fields: &str) -> Model {
+        let schema_str = format!(
+            r#"
+            datasource db {{
+                provider = "mysql"
+                url      = "mysql://stub"
+            }}
+
+            model TestModel {{
+                {id_fields}
+                email String
+            }}
+        "#
+        );
+
+        let psl_schema = psl::validate(schema_str.into());
+        assert!(!psl_schema.diagnostics.has_errors(), "{:?}", psl_schema.diagnostics);
+
+        let internal_data_model = InternalDataModel {
+            schema: Arc::new(psl_schema),
+        };
+
+        internal_data_model.find_model("TestModel").unwrap()
+    }
+
+    #[test]
+    fn mysql_autoincrement_id_field_accepts_a_bare_autoincrement_id() {
+        let model = test_model("id Int @id @default(autoincrement())");
+        let id_fields = model.shard_aware_primary_identifier();
+        let args = vec![WriteArgs::new_empty(PrismaValue::Null)];
+
+        assert!(mysql_autoincrement_id_field(&id_fields, &args).is_some());
+    }
+
+    #[test]
+    fn mysql_autoincrement_id_field_rejects_a_composite_id() {
+        let model = test_model("a Int\n                b Int\n                @@id([a, b])");
+        let id_fields = model.shard_aware_primary_identifier();
+        let args = vec![WriteArgs::new_empty(PrismaValue::Null)];
+
+        assert!(mysql_autoincrement_id_field(&id_fields, &args).is_none());
+    }
+
+    #[test]
+    fn mysql_autoincrement_id_field_rejects_a_non_autoincrement_default() {
+        let model = test_model("id Int @id @default(1)");
+        let id_fields = model.shard_aware_primary_identifier();
+        let args = vec![WriteArgs::new_empty(PrismaValue::Null)];
+
+        assert!(mysql_autoincrement_id_field(&id_fields, &args).is_none());
+    }
+
+    #[test]
+    fn mysql_autoincrement_id_field_rejects_a_row_with_an_explicit_id() {
+        let model = test_model("id Int @id @default(autoincrement())");
+        let id_fields = model.shard_aware_primary_identifier();
+        let mut explicit = WriteArgs::new_empty(PrismaValue::Null);
+        explicit.insert(DatasourceFieldName("id".to_owned()), WriteOperation::scalar_set(PrismaValue::Int(5)));
+        let args = vec![WriteArgs::new_empty(PrismaValue::Null), explicit];
+
+        assert!(mysql_autoincrement_id_field(&id_fields, &args).is_none());
+    }
+
+    #[test]
+    fn add_row_count_sums_normally() {
+        assert_eq!(add_row_count(1, 2).unwrap(), 3);
+        assert_eq!(add_row_count(0, u64::MAX).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn add_row_count_errors_on_overflow() {
+        assert!(add_row_count(u64::MAX, 1).is_err());
+    }
+
+    #[test]
+    fn row_count_to_usize_passes_through_values_near_i32_and_i64_boundaries() {
+        let near_i32_max = i32::MAX as u64 + 1; // ~3 billion, past the i32::MAX boundary
+        assert_eq!(row_count_to_usize(near_i32_max).unwrap(), near_i32_max as usize);
+
+        let near_i64_max = i64::MAX as u64 + 1;
+        assert_eq!(row_count_to_usize(near_i64_max).unwrap(), near_i64_max as usize);
+
+        assert_eq!(row_count_to_usize(u64::MAX).unwrap(), u64::MAX as usize);
+    }
+
+    fn query_error(message: &str) -> quaint::error::Error {
+        quaint::error::Error::builder(quaint::error::ErrorKind::QueryInvalidInput(message.to_owned())).build()
+    }
+
+    #[test]
+    fn apply_chunk_result_accumulates_successes() {
+        let (total, outcome) = apply_chunk_result(ChunkExecutionPolicy::FailFast, 5, Ok(3)).unwrap();
+        assert_eq!(total, 8);
+        assert_eq!(outcome.affected, 3);
+        assert!(outcome.error.is_none());
+    }
+
+    #[test]
+    fn apply_chunk_result_fail_fast_stops_on_first_failure_and_reports_progress() {
+        let err = apply_chunk_result(ChunkExecutionPolicy::FailFast, 5, Err(query_error("chunk 3 of 7 failed")))
+            .unwrap_err();
+
+        match err {
+            SqlError::ChunkedWriteFailed { affected, .. } => assert_eq!(affected, 5),
+            other => panic!("expected ChunkedWriteFailed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn apply_chunk_result_best_effort_continues_past_a_failure() {
+        let (total, outcome) =
+            apply_chunk_result(ChunkExecutionPolicy::BestEffort, 5, Err(query_error("chunk 3 of 7 failed"))).unwrap();
+
+        // The running total is unaffected by the failed chunk, and execution is free to continue.
+        assert_eq!(total, 5);
+        assert_eq!(outcome.affected, 0);
+        assert!(outcome.error.unwrap().contains("chunk 3 of 7 failed"));
+    }
+
+    #[test]
+    fn apply_chunk_result_atomic_behaves_like_fail_fast_for_a_failing_statement() {
+        // `Atomic` only ever reaches `apply_chunk_result` for a single-statement write (multi-statement
+        // `Atomic` writes are rejected before any statement runs), where a failure simply fails the
+        // operation, same as `FailFast`.
+        let err =
+            apply_chunk_result(ChunkExecutionPolicy::Atomic, 0, Err(query_error("constraint violated"))).unwrap_err();
+
+        assert!(matches!(err, SqlError::ChunkedWriteFailed { .. }));
+    }
+}
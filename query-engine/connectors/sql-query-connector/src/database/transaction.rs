@@ -2,12 +2,14 @@ use super::catch;
 use crate::{database::operations::*, SqlError};
 use async_trait::async_trait;
 use connector::ConnectionLike;
-use connector_interface::{self as connector, AggregationRow, ReadOperations, Transaction, WriteOperations};
+use connector_interface::{
+    self as connector, AggregationRow, ReadOperations, Transaction, WriteManyResult, WriteOperations,
+};
 use prisma_value::PrismaValue;
 use quaint::prelude::ConnectionInfo;
 use query_structure::{
-    prelude::*, AggregationSelection, Filter, QueryArguments, RecordFilter, RelationLoadStrategy, SelectionResult,
-    WriteArgs,
+    prelude::*, AggregationSelection, ChunkExecutionPolicy, Filter, QueryArguments, RecordFilter,
+    RelationLoadStrategy, SelectionResult, WriteArgs,
 };
 use sql_query_builder::Context;
 use std::collections::HashMap;
@@ -216,18 +218,118 @@ impl WriteOperations for SqlConnectorTransaction<'_> {
         .await
     }
 
+    async fn create_records_returning_ids(
+        &mut self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<ManyRecords> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(
+            &self.connection_info,
+            write::create_records_returning_ids(
+                self.inner.as_queryable(),
+                &self.connection_info.sql_family(),
+                model,
+                args,
+                skip_duplicates,
+                &ctx,
+            ),
+        )
+        .await
+    }
+
+    async fn create_records_with_skip_report(
+        &mut self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<(usize, Option<connector::SkipDuplicatesReport>)> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(
+            &self.connection_info,
+            write::create_records_with_skip_report(self.inner.as_queryable(), model, args, skip_duplicates, &ctx),
+        )
+        .await
+    }
+
+    async fn create_records_collecting_errors(
+        &mut self,
+        model: &Model,
+        args: Vec<WriteArgs>,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<connector::CreateManyErrorReport> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(
+            &self.connection_info,
+            write::create_records_collecting_errors(self.inner.as_queryable(), model, args, &ctx),
+        )
+        .await
+    }
+
+    async fn allocate_ids(
+        &mut self,
+        model: &Model,
+        count: usize,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<Vec<i64>> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        catch(
+            &self.connection_info,
+            write::allocate_ids(self.inner.as_queryable(), model, count, &ctx),
+        )
+        .await
+    }
+
     async fn update_records(
         &mut self,
         model: &Model,
         record_filter: RecordFilter,
         args: WriteArgs,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
         traceparent: Option<TraceParent>,
     ) -> connector::Result<usize> {
         let ctx = Context::new(&self.connection_info, traceparent);
         catch(
             &self.connection_info,
-            write::update_records(self.inner.as_queryable(), model, record_filter, args, limit, &ctx),
+            write::update_records(self.inner.as_queryable(), model, record_filter, args, order_by, limit, &ctx),
+        )
+        .await
+    }
+
+    async fn update_records_with_chunks(
+        &mut self,
+        model: &Model,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+        order_by: Vec<OrderBy>,
+        limit: Option<usize>,
+        chunk_execution_policy: ChunkExecutionPolicy,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<WriteManyResult> {
+        let ctx = Context::new(&self.connection_info, traceparent);
+        // Every statement this call executes is already part of the surrounding interactive
+        // transaction, so it's already atomic as a unit; there's nothing extra to enforce here.
+        let chunk_execution_policy = if chunk_execution_policy.is_atomic() {
+            ChunkExecutionPolicy::FailFast
+        } else {
+            chunk_execution_policy
+        };
+        catch(
+            &self.connection_info,
+            write::update_records_with_chunks(
+                self.inner.as_queryable(),
+                model,
+                record_filter,
+                args,
+                order_by,
+                limit,
+                chunk_execution_policy,
+                &ctx,
+            ),
         )
         .await
     }
@@ -238,6 +340,7 @@ impl WriteOperations for SqlConnectorTransaction<'_> {
         record_filter: RecordFilter,
         args: WriteArgs,
         selected_fields: FieldSelection,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
         traceparent: Option<TraceParent>,
     ) -> connector::Result<ManyRecords> {
@@ -250,6 +353,7 @@ impl WriteOperations for SqlConnectorTransaction<'_> {
                 record_filter,
                 args,
                 selected_fields,
+                order_by,
                 limit,
                 &ctx,
             ),
@@ -284,12 +388,45 @@ impl WriteOperations for SqlConnectorTransaction<'_> {
         &mut self,
         model: &Model,
         record_filter: RecordFilter,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
         traceparent: Option<TraceParent>,
     ) -> connector::Result<usize> {
         catch(&self.connection_info, async {
             let ctx = Context::new(&self.connection_info, traceparent);
-            write::delete_records(self.inner.as_queryable(), model, record_filter, limit, &ctx).await
+            write::delete_records(self.inner.as_queryable(), model, record_filter, order_by, limit, &ctx).await
+        })
+        .await
+    }
+
+    async fn delete_records_with_chunks(
+        &mut self,
+        model: &Model,
+        record_filter: RecordFilter,
+        order_by: Vec<OrderBy>,
+        limit: Option<usize>,
+        chunk_execution_policy: ChunkExecutionPolicy,
+        traceparent: Option<TraceParent>,
+    ) -> connector::Result<WriteManyResult> {
+        // Every statement this call executes is already part of the surrounding interactive
+        // transaction, so it's already atomic as a unit; there's nothing extra to enforce here.
+        let chunk_execution_policy = if chunk_execution_policy.is_atomic() {
+            ChunkExecutionPolicy::FailFast
+        } else {
+            chunk_execution_policy
+        };
+        catch(&self.connection_info, async {
+            let ctx = Context::new(&self.connection_info, traceparent);
+            write::delete_records_with_chunks(
+                self.inner.as_queryable(),
+                model,
+                record_filter,
+                order_by,
+                limit,
+                chunk_execution_policy,
+                &ctx,
+            )
+            .await
         })
         .await
     }
@@ -327,7 +464,7 @@ impl WriteOperations for SqlConnectorTransaction<'_> {
         parent_id: &SelectionResult,
         child_ids: &[SelectionResult],
         traceparent: Option<TraceParent>,
-    ) -> connector::Result<()> {
+    ) -> connector::Result<usize> {
         catch(&self.connection_info, async {
             let ctx = Context::new(&self.connection_info, traceparent);
             write::m2m_connect(self.inner.as_queryable(), field, parent_id, child_ids, &ctx).await
@@ -207,6 +207,9 @@ pub enum SqlError {
     #[error("Query parameter limit exceeded error: {0}.")]
     QueryParameterLimitExceeded(String),
 
+    #[error("Query returned more than {limit} rows, which is the maximum allowed")]
+    TooManyRows { limit: usize },
+
     #[error("Cannot find a fulltext index to use for the search")]
     MissingFullTextSearchIndex,
 
@@ -215,6 +218,22 @@ pub enum SqlError {
 
     #[error("Too many DB connections opened")]
     TooManyConnections(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("Unsupported connector feature: {0}")]
+    Unsupported(String),
+
+    #[error(
+        "The columns returned by the database ({:?}) do not match the columns expected by the query ({:?}). The schema may have changed concurrently.",
+        actual,
+        expected
+    )]
+    SchemaDrift { expected: Vec<String>, actual: Vec<String> },
+
+    /// A chunked `updateMany`/`deleteMany` (one logical write split into several statements, e.g.
+    /// because of a bind-parameter limit) failed under `ChunkExecutionPolicy::FailFast` after
+    /// `affected` rows had already been committed by earlier chunks.
+    #[error("{source} ({affected} row(s) were already affected by earlier chunks before this one failed)")]
+    ChunkedWriteFailed { source: Box<SqlError>, affected: usize },
 }
 
 impl SqlError {
@@ -241,6 +260,7 @@ impl SqlError {
                 user_facing_error: user_facing_errors::quaint::render_quaint_error(&e, connection_info).map(Box::new),
                 kind: ErrorKind::ConnectionError(e.into()),
                 transient: false,
+                chunked_write_progress: None,
             },
             SqlError::ColumnReadFailure(e) => ConnectorError::from_kind(ErrorKind::ColumnReadFailure(e)),
             SqlError::FieldCannotBeNull { field } => ConnectorError::from_kind(ErrorKind::FieldCannotBeNull { field }),
@@ -282,6 +302,7 @@ impl SqlError {
                         .map(Box::new),
                         kind: ErrorKind::QueryError(e),
                         transient: false,
+                        chunked_write_progress: None,
                     },
                     None => ConnectorError::from_kind(ErrorKind::QueryError(e)),
                 }
@@ -298,12 +319,20 @@ impl SqlError {
             SqlError::QueryParameterLimitExceeded(e) => {
                 ConnectorError::from_kind(ErrorKind::QueryParameterLimitExceeded(e))
             }
+            SqlError::TooManyRows { limit } => ConnectorError::from_kind(ErrorKind::TooManyRows { limit }),
             SqlError::MissingFullTextSearchIndex => {
                 ConnectorError::from_kind(ErrorKind::MissingNativeFullTextSearchIndex)
             }
             SqlError::InvalidIsolationLevel(msg) => ConnectorError::from_kind(ErrorKind::InternalConversionError(msg)),
             SqlError::ExternalError(error_id) => ConnectorError::from_kind(ErrorKind::ExternalError(error_id)),
             SqlError::TooManyConnections(e) => ConnectorError::from_kind(ErrorKind::TooManyConnections(e)),
+            SqlError::Unsupported(feature) => ConnectorError::from_kind(ErrorKind::UnsupportedFeature(feature)),
+            SqlError::SchemaDrift { expected, actual } => {
+                ConnectorError::from_kind(ErrorKind::SchemaDrift { expected, actual })
+            }
+            SqlError::ChunkedWriteFailed { source, affected } => source
+                .into_connector_error(connection_info)
+                .with_chunked_write_progress(affected),
         }
     }
 }
@@ -377,6 +406,9 @@ impl From<quaint::error::Error> for SqlError {
             e @ QuaintKind::SocketTimeout => SqlError::ConnectionError(e),
             e @ QuaintKind::OpaqueAsRawValue { .. } => SqlError::ConversionError(e.into()),
             e @ QuaintKind::RanQueryWithOpaqueParam { .. } => SqlError::ConversionError(e.into()),
+            QuaintKind::TransactionSnapshotUnsupported => {
+                Self::Unsupported("Importing a transaction snapshot is not supported by this connector".to_owned())
+            }
         }
     }
 }
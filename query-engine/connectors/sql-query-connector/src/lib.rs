@@ -3,6 +3,8 @@
 
 mod database;
 mod error;
+#[cfg(feature = "metrics")]
+mod metrics;
 mod query_ext;
 mod row;
 mod ser_raw;
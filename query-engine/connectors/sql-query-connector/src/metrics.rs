@@ -0,0 +1,93 @@
+//! Metrics instrumentation for chunked statement fan-out and bind-parameter counts.
+//!
+//! This module (and its call sites) is compiled out entirely when the `metrics` feature is
+//! disabled, which is the case on wasm builds where the `metrics` crate facade isn't available.
+
+use prisma_metrics::{
+    counter, histogram, PRISMA_DATASOURCE_CHUNKED_STATEMENTS_TOTAL, PRISMA_DATASOURCE_QUERY_PARAMETER_COUNT,
+    PRISMA_DATASOURCE_SCHEMA_DRIFT_TOTAL,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum WriteOperation {
+    Insert,
+    Update,
+    Delete,
+}
+
+impl WriteOperation {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Insert => "insert",
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// Records the number of bind parameters a batch of generated statements ends up using, and,
+/// when the batch had to be split into more than one statement to stay under `PARAMETER_LIMIT`,
+/// that it was chunked.
+pub(crate) fn record_write(operation: WriteOperation, statement_count: usize, parameter_count: usize) {
+    let op = operation.as_str();
+
+    histogram!(PRISMA_DATASOURCE_QUERY_PARAMETER_COUNT, "operation" => op).record(parameter_count as f64);
+
+    if statement_count > 1 {
+        counter!(PRISMA_DATASOURCE_CHUNKED_STATEMENTS_TOTAL, "operation" => op).increment(1);
+    }
+}
+
+/// Records that a query result's columns didn't match the columns the query was built to expect,
+/// which means the underlying table was altered concurrently with the query running.
+pub(crate) fn record_schema_drift() {
+    counter!(PRISMA_DATASOURCE_SCHEMA_DRIFT_TOTAL).increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prisma_metrics::{MetricRecorder, MetricRegistry, WithMetricsInstrumentation};
+
+    const TESTING_ACCEPT_LIST: &[&str] = &[
+        PRISMA_DATASOURCE_QUERY_PARAMETER_COUNT,
+        PRISMA_DATASOURCE_CHUNKED_STATEMENTS_TOTAL,
+        PRISMA_DATASOURCE_SCHEMA_DRIFT_TOTAL,
+    ];
+
+    #[test]
+    fn records_chunked_delete_many() {
+        let registry = MetricRegistry::new_with_accept_list(TESTING_ACCEPT_LIST.to_vec());
+        let recorder = MetricRecorder::new(registry.clone());
+
+        futures::executor::block_on(
+            async {
+                record_write(WriteOperation::Delete, 3, 6000);
+                record_write(WriteOperation::Delete, 1, 1);
+
+                let counter = registry
+                    .counter_value(PRISMA_DATASOURCE_CHUNKED_STATEMENTS_TOTAL)
+                    .unwrap();
+                assert_eq!(counter, 1);
+            }
+            .with_recorder(recorder),
+        );
+    }
+
+    #[test]
+    fn records_schema_drift() {
+        let registry = MetricRegistry::new_with_accept_list(TESTING_ACCEPT_LIST.to_vec());
+        let recorder = MetricRecorder::new(registry.clone());
+
+        futures::executor::block_on(
+            async {
+                record_schema_drift();
+                record_schema_drift();
+
+                let counter = registry.counter_value(PRISMA_DATASOURCE_SCHEMA_DRIFT_TOTAL).unwrap();
+                assert_eq!(counter, 2);
+            }
+            .with_recorder(recorder),
+        );
+    }
+}
@@ -5,7 +5,9 @@ use futures::future::FutureExt;
 use itertools::Itertools;
 use prisma_value::Placeholder as PrismaValuePlaceholder;
 use quaint::{ast::*, connector::Queryable};
+use query_builder::DbQuery;
 use query_structure::*;
+use query_template::QueryTemplate;
 use sql_query_builder::value::{GeneratorCall, Placeholder};
 use sql_query_builder::{column_metadata, AsColumns, AsTable, ColumnMetadata, Context, FilterBuilder, SqlTraceComment};
 use std::{collections::HashMap, panic::AssertUnwindSafe};
@@ -23,7 +25,7 @@ impl<Q: Queryable + ?Sized> QueryExt for Q {
         let span = info_span!("prisma:engine:filter_read_query");
 
         let q = match q {
-            Query::Select(x) => Query::Select(Box::from(x.add_traceparent(ctx.traceparent()))),
+            Query::Select(x) => Query::Select(Box::from(x.add_traceparent(ctx.traceparent(), ctx.trace_comment_mode()))),
             q => q,
         };
 
@@ -38,6 +40,21 @@ impl<Q: Queryable + ?Sized> QueryExt for Q {
         Ok(sql_rows)
     }
 
+    async fn filter_db_query(&self, query: DbQuery, idents: &[ColumnMetadata<'_>]) -> crate::Result<Vec<SqlRow>> {
+        let span = info_span!("prisma:engine:filter_read_query");
+
+        let (sql, params) = render_db_query(query)?;
+        let result_set = self.query_raw_typed(&sql, &params).instrument(span).await?;
+
+        let mut sql_rows = Vec::new();
+
+        for row in result_set {
+            sql_rows.push(row.to_sql_row(idents)?);
+        }
+
+        Ok(sql_rows)
+    }
+
     async fn raw_json<'a>(
         &'a self,
         mut inputs: HashMap<String, PrismaValue>,
@@ -73,7 +90,9 @@ impl<Q: Queryable + ?Sized> QueryExt for Q {
             .catch_unwind()
             .await??;
 
-        Ok(changes as usize)
+        usize::try_from(changes).map_err(|_| {
+            RawError::ConversionError(anyhow::anyhow!("Affected row count {changes} does not fit in usize"))
+        })
     }
 
     async fn find(&self, q: Select<'_>, meta: &[ColumnMetadata<'_>], ctx: &Context<'_>) -> crate::Result<SqlRow> {
@@ -93,7 +112,12 @@ impl<Q: Queryable + ?Sized> QueryExt for Q {
         ctx: &Context<'_>,
     ) -> crate::Result<Vec<SelectionResult>> {
         if let Some(selectors) = record_filter.selectors {
-            Ok(selectors)
+            let id_selection = model.shard_aware_primary_identifier();
+
+            Ok(selectors
+                .into_iter()
+                .map(|selector| selector.project(&id_selection))
+                .collect())
         } else {
             self.filter_ids(model, record_filter.filter, ctx).await
         }
@@ -111,7 +135,7 @@ impl<Q: Queryable + ?Sized> QueryExt for Q {
 
         let select = Select::from_table(model.as_table(ctx))
             .columns(id_cols)
-            .add_traceparent(ctx.traceparent())
+            .add_traceparent(ctx.traceparent(), ctx.trace_comment_mode())
             .so_that(condition);
 
         self.select_ids(select, model_id, ctx).await
@@ -132,8 +156,17 @@ impl<Q: Queryable + ?Sized> QueryExt for Q {
             })
             .collect();
 
+        let generated: Vec<_> = model_id
+            .fields()
+            .flat_map(|f| match f {
+                Field::Scalar(sf) => vec![sf.is_computed()],
+                Field::Relation(rf) => vec![false; rf.type_identifiers_with_arities().len()],
+                Field::Composite(_) => unimplemented!(),
+            })
+            .collect();
+
         let field_names: Vec<_> = model_id.fields().map(|field| field.name()).collect();
-        let meta = column_metadata::create(field_names.as_slice(), &idents);
+        let meta = column_metadata::create_with_generated(field_names.as_slice(), &idents, &generated);
 
         let rows = self.filter(select.into(), &meta, ctx).await?;
         let result = rows
@@ -160,6 +193,11 @@ pub(crate) trait QueryExt {
         ctx: &Context<'_>,
     ) -> crate::Result<Vec<SqlRow>>;
 
+    /// Execute an already-built [`DbQuery`] (e.g. one produced by
+    /// [`sql_query_builder::SqlQueryBuilder`], the same builder the query compiler uses) and map
+    /// the resulting rows with the given identifiers.
+    async fn filter_db_query(&self, query: DbQuery, idents: &[ColumnMetadata<'_>]) -> crate::Result<Vec<SqlRow>>;
+
     /// Execute a singular SQL query in the database, returning an arbitrary
     /// JSON `Value` as a result.
     async fn raw_json<'a>(
@@ -199,6 +237,39 @@ pub(crate) trait QueryExt {
     ) -> crate::Result<Vec<SelectionResult>>;
 }
 
+/// Renders a [`DbQuery`] into the `(sql, params)` pair `Queryable::query_raw_typed` expects. For
+/// `TemplateSql`, this is the same rendering the query compiler hands to driver adapters
+/// ([`QueryTemplate::to_sql`]), so the interpreted engine stops maintaining its own copy of that
+/// logic once a caller builds its query through `SqlQueryBuilder` instead of `quaint::ast::Query`.
+fn render_db_query<'a>(query: DbQuery) -> crate::Result<(String, Vec<Value<'a>>)> {
+    match query {
+        DbQuery::RawSql { sql, params } => Ok((
+            sql,
+            params.into_iter().map(convert_prisma_value_to_quaint_lossy).collect(),
+        )),
+        DbQuery::TemplateSql {
+            fragments,
+            params,
+            placeholder_format,
+        } => {
+            let template = QueryTemplate {
+                fragments,
+                parameters: params.into_iter().map(convert_prisma_value_to_quaint_lossy).collect::<Vec<_>>(),
+                placeholder_format,
+            };
+
+            let sql = template.to_sql().map_err(|_| {
+                SqlError::Unsupported(
+                    "TemplateSql queries with tuple parameters aren't supported by the interpreted query engine yet"
+                        .to_owned(),
+                )
+            })?;
+
+            Ok((sql, template.parameters))
+        }
+    }
+}
+
 /// Attempts to convert a PrismaValue to a database value without any additional type information.
 /// Can't reliably map Null values.
 pub fn convert_prisma_value_to_quaint_lossy<'a>(pv: PrismaValue) -> Value<'a> {
@@ -245,5 +316,6 @@ pub fn convert_prisma_type_to_opaque_type(pt: &PrismaValueType) -> OpaqueType {
         PrismaValueType::Object => OpaqueType::Json,
         PrismaValueType::Bytes => OpaqueType::Bytes,
         PrismaValueType::Enum { .. } => OpaqueType::Text,
+        PrismaValueType::Nullable(t) => OpaqueType::Nullable(Box::new(convert_prisma_type_to_opaque_type(t))),
     }
 }
@@ -77,6 +77,11 @@ pub(crate) trait ToSqlRow {
     /// Conversion from a database specific row to an allocated `SqlRow`. To
     /// help deciding the right types, the provided `ColumnMetadata`s should map
     /// to the returned columns in the right order.
+    ///
+    /// When every `ColumnMetadata` has a name, values are looked up by the column name the
+    /// database actually reported rather than by position, and a mismatch between the expected
+    /// and actual column names returns `SqlError::SchemaDrift` instead of silently decoding the
+    /// wrong column.
     fn to_sql_row(self, meta: &[ColumnMetadata<'_>]) -> crate::Result<SqlRow>;
 }
 
@@ -87,34 +92,73 @@ impl ToSqlRow for ResultRow {
 
         row.values.reserve(row_width);
 
-        for (i, p_value) in self.into_iter().enumerate().take(row_width) {
-            let pv = match (meta[i].identifier(), meta[i].arity()) {
-                (type_identifier, FieldArity::List) => match p_value.typed {
-                    value if value.is_null() => Ok(PrismaValue::List(Vec::new())),
-                    ValueType::Array(None) => Ok(PrismaValue::List(Vec::new())),
-                    ValueType::Array(Some(l)) => l
-                        .into_iter()
-                        .map(|val| row_value_to_prisma_value(val, meta[i]))
-                        .collect::<crate::Result<Vec<_>>>()
-                        .map(PrismaValue::List),
-                    _ => {
-                        let error = io::Error::new(
-                            io::ErrorKind::InvalidData,
-                            format!("List field did not return an Array from database. Type identifier was {:?}. Value was {:?}.", &type_identifier, &p_value),
-                        );
-                        return Err(SqlError::ConversionError(error.into()));
+        // Aggregations and raw queries build their metadata with `column_metadata::create_anonymous`,
+        // which has no names to check or look up by, so they keep relying on positional decoding.
+        // Everything else goes through a name-based lookup: a long-lived engine can observe a schema
+        // change (e.g. a column dropped and another of the same type added) between the query being
+        // built and its result arriving, and positional decoding alone would silently read that new
+        // column's value into the wrong field.
+        if meta.iter().all(|m| m.name().is_some()) {
+            let actual_columns = self.columns().to_vec();
+            let mut values: Vec<Option<Value>> = self.into_iter().map(Some).collect();
+
+            for m in meta {
+                let name = m.name().expect("checked above");
+                let p_value = actual_columns
+                    .iter()
+                    .position(|column| column == name)
+                    .and_then(|idx| values[idx].take());
+
+                let p_value = match p_value {
+                    Some(p_value) => p_value,
+                    None => {
+                        crate::metrics::record_schema_drift();
+
+                        return Err(SqlError::SchemaDrift {
+                            expected: meta.iter().map(|m| m.name().expect("checked above").to_owned()).collect(),
+                            actual: actual_columns,
+                        });
                     }
-                },
-                _ => row_value_to_prisma_value(p_value, meta[i]),
-            }?;
+                };
+
+                row.values.push(convert_row_value(p_value, *m)?);
+            }
+
+            return Ok(row);
+        }
 
-            row.values.push(pv);
+        for (i, p_value) in self.into_iter().enumerate().take(row_width) {
+            row.values.push(convert_row_value(p_value, meta[i])?);
         }
 
         Ok(row)
     }
 }
 
+fn convert_row_value(p_value: Value, meta: ColumnMetadata<'_>) -> crate::Result<PrismaValue> {
+    match (meta.identifier(), meta.arity()) {
+        (type_identifier, FieldArity::List) => match p_value.typed {
+            value if value.is_null() => Ok(PrismaValue::List(Vec::new())),
+            ValueType::Array(None) => Ok(PrismaValue::List(Vec::new())),
+            ValueType::Array(Some(l)) => l
+                .into_iter()
+                .map(|val| row_value_to_prisma_value(val, meta))
+                .collect::<crate::Result<Vec<_>>>()
+                .map(PrismaValue::List),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "List field did not return an Array from database. Type identifier was {type_identifier:?}. Value was {p_value:?}."
+                    ),
+                );
+                Err(SqlError::ConversionError(error.into()))
+            }
+        },
+        _ => row_value_to_prisma_value(p_value, meta),
+    }
+}
+
 fn row_value_to_prisma_value(p_value: Value, meta: ColumnMetadata<'_>) -> Result<PrismaValue, SqlError> {
     let create_error = |value: &Value| {
         let message = match meta.name() {
@@ -356,6 +400,60 @@ pub(crate) fn big_decimal_to_i64(dec: BigDecimal, to: &'static str) -> Result<i6
 #[cfg(test)]
 mod test {
     use super::*;
+    use quaint::connector::{ColumnType, ResultSet};
+    use sql_query_builder::column_metadata;
+
+    #[test]
+    fn to_sql_row_detects_schema_drift_on_column_swap() {
+        // `meta` was built when the query was planned, expecting columns `id` and `name`...
+        let idents = [
+            (TypeIdentifier::Int, FieldArity::Required),
+            (TypeIdentifier::String, FieldArity::Required),
+        ];
+        let field_names = ["id", "name"];
+        let meta = column_metadata::create(&field_names, &idents);
+
+        // ...but by the time the row comes back, `name` has been dropped and replaced with `email`,
+        // a column of the same type at the same position.
+        let result_set = ResultSet::new(
+            vec!["id".to_owned(), "email".to_owned()],
+            vec![ColumnType::Int32, ColumnType::Text],
+            vec![vec![Value::from(1), Value::from("alice@example.com")]],
+        );
+        let row = result_set.into_single().unwrap();
+
+        let err = row.to_sql_row(&meta).unwrap_err();
+
+        match err {
+            SqlError::SchemaDrift { expected, actual } => {
+                assert_eq!(expected, vec!["id".to_owned(), "name".to_owned()]);
+                assert_eq!(actual, vec!["id".to_owned(), "email".to_owned()]);
+            }
+            other => panic!("expected SqlError::SchemaDrift, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn to_sql_row_decodes_by_name_when_driver_reorders_columns() {
+        let idents = [
+            (TypeIdentifier::Int, FieldArity::Required),
+            (TypeIdentifier::String, FieldArity::Required),
+        ];
+        let field_names = ["id", "name"];
+        let meta = column_metadata::create(&field_names, &idents);
+
+        // The driver reports the same columns, just in a different order than expected.
+        let result_set = ResultSet::new(
+            vec!["name".to_owned(), "id".to_owned()],
+            vec![ColumnType::Text, ColumnType::Int32],
+            vec![vec![Value::from("alice"), Value::from(1)]],
+        );
+        let row = result_set.into_single().unwrap();
+
+        let sql_row = row.to_sql_row(&meta).unwrap();
+
+        assert_eq!(sql_row.values, vec![PrismaValue::Int(1), PrismaValue::String("alice".to_owned())]);
+    }
 
     #[test]
     fn quaint_bytes_to_integer_conversion_works() {
@@ -1,7 +1,7 @@
 use crate::row::{sanitize_f32, sanitize_f64};
 use bigdecimal::{BigDecimal, FromPrimitive};
 use chrono::{DateTime, NaiveDate, Utc};
-use quaint::ValueType;
+use quaint::{ast::CompositeValue, ValueType};
 use query_structure::PrismaValue;
 use sql_query_builder::{
     opaque_type_to_prisma_type,
@@ -112,6 +112,14 @@ pub fn to_prisma_value<'a, T: Into<ValueType<'a>>>(qv: T) -> crate::Result<Prism
                     args: call.args().to_vec(),
                     return_type: opaque_type_to_prisma_type(opaque.typ()),
                 }
+            } else if let Some(composite) = opaque.downcast_ref::<CompositeValue>() {
+                let mut fields = Vec::with_capacity(composite.fields().len());
+
+                for (name, value) in composite.fields() {
+                    fields.push((name.clone(), to_prisma_value(value.clone())?));
+                }
+
+                PrismaValue::Object(fields)
             } else {
                 panic!("Received an unsupported opaque value")
             }
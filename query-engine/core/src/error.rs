@@ -1,4 +1,4 @@
-use crate::{InterpreterError, QueryGraphBuilderError, RelationViolation, TransactionError};
+use crate::{CompensationLog, InterpreterError, QueryGraphBuilderError, RelationViolation, TransactionError};
 use connector::error::ConnectorError;
 use query_structure::DomainError;
 use thiserror::Error;
@@ -67,6 +67,15 @@ pub enum CoreError {
 
     #[error("Query timed out")]
     QueryTimeout,
+
+    /// A non-transactional, multi-statement write failed after one or more of its statements had
+    /// already committed. `log` lists what was left behind so the caller can clean it up; the
+    /// engine never executes it itself.
+    #[error("{}", source)]
+    NonTransactionalWriteFailed {
+        source: Box<CoreError>,
+        log: CompensationLog,
+    },
 }
 
 impl CoreError {
@@ -80,9 +89,30 @@ impl CoreError {
         match self {
             CoreError::InterpreterError(InterpreterError::ConnectorError(err)) => err.is_transient(),
             CoreError::ConnectorError(err) => err.is_transient(),
+            CoreError::NonTransactionalWriteFailed { source, .. } => source.is_transient(),
             _ => false,
         }
     }
+
+    /// The compensating actions for a non-transactional write that failed after some of its
+    /// statements already committed, if any were recorded.
+    pub fn compensation_log(&self) -> Option<&CompensationLog> {
+        match self {
+            CoreError::NonTransactionalWriteFailed { log, .. } => Some(log),
+            _ => None,
+        }
+    }
+
+    /// The number of rows already affected by earlier chunks of a chunked `updateMany`/
+    /// `deleteMany` run under [`query_structure::ChunkExecutionPolicy::FailFast`], when this error
+    /// comes from one of its later chunks failing.
+    pub fn chunked_write_progress(&self) -> Option<usize> {
+        match self {
+            CoreError::ConnectorError(err) => err.chunked_write_progress,
+            CoreError::InterpreterError(InterpreterError::ConnectorError(err)) => err.chunked_write_progress,
+            _ => None,
+        }
+    }
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -106,6 +136,8 @@ impl From<InterpreterError> for CoreError {
 impl From<CoreError> for user_facing_errors::Error {
     fn from(err: CoreError) -> user_facing_errors::Error {
         match err {
+            CoreError::NonTransactionalWriteFailed { source, .. } => (*source).into(),
+
             CoreError::TransactionError(err) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InteractiveTransactionError {
                     error: err.to_string(),
@@ -29,11 +29,21 @@ pub async fn execute_single_operation(
     conn: &mut dyn ConnectionLike,
     operation: &Operation,
     traceparent: Option<TraceParent>,
+    in_interactive_transaction: bool,
 ) -> crate::Result<ResponseData> {
     let operation_timer = ElapsedTimeCounter::start();
 
     let (graph, serializer) = build_graph(&query_schema, operation.clone())?;
-    let result = execute_on(conn, graph, serializer, query_schema.as_ref(), traceparent).await;
+    let result = execute_on(
+        conn,
+        graph,
+        serializer,
+        query_schema.as_ref(),
+        traceparent,
+        true,
+        in_interactive_transaction,
+    )
+    .await;
 
     #[cfg(feature = "metrics")]
     histogram!(PRISMA_CLIENT_QUERIES_DURATION_HISTOGRAM_MS).record(operation_timer.elapsed_time());
@@ -46,6 +56,7 @@ pub async fn execute_many_operations(
     conn: &mut dyn ConnectionLike,
     operations: &[Operation],
     traceparent: Option<TraceParent>,
+    in_interactive_transaction: bool,
 ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
     let queries = operations
         .iter()
@@ -56,7 +67,16 @@ pub async fn execute_many_operations(
 
     for (i, (graph, serializer)) in queries.into_iter().enumerate() {
         let operation_timer = ElapsedTimeCounter::start();
-        let result = execute_on(conn, graph, serializer, query_schema.as_ref(), traceparent).await;
+        let result = execute_on(
+            conn,
+            graph,
+            serializer,
+            query_schema.as_ref(),
+            traceparent,
+            true,
+            in_interactive_transaction,
+        )
+        .await;
 
         #[cfg(feature = "metrics")]
         histogram!(PRISMA_CLIENT_QUERIES_DURATION_HISTOGRAM_MS).record(operation_timer.elapsed_time());
@@ -192,7 +212,16 @@ async fn execute_self_contained_without_retry<'a>(
         return execute_in_tx(&mut conn, graph, serializer, query_schema, traceparent).await;
     }
 
-    execute_on(conn.as_connection_like(), graph, serializer, query_schema, traceparent).await
+    execute_on(
+        conn.as_connection_like(),
+        graph,
+        serializer,
+        query_schema,
+        traceparent,
+        false,
+        false,
+    )
+    .await
 }
 
 // As suggested by the MongoDB documentation
@@ -237,6 +266,8 @@ async fn execute_self_contained_with_retry(
             serializer,
             query_schema.as_ref(),
             traceparent,
+            false,
+            false,
         )
         .await
     }
@@ -249,8 +280,22 @@ async fn execute_in_tx<'a>(
     query_schema: &'a QuerySchema,
     traceparent: Option<TraceParent>,
 ) -> crate::Result<ResponseData> {
-    let mut tx = conn.start_transaction(None).await?;
-    let result = execute_on(tx.as_connection_like(), graph, serializer, query_schema, traceparent).await;
+    let mut tx = conn.start_transaction(None, None).await?;
+    // `tx` is a real, engine-managed transaction: once one statement in it fails, the connector
+    // may consider the whole transaction poisoned (e.g. Postgres aborts it until rolled back), so
+    // retrying a single query node in place is just as unsafe here as inside a client-managed
+    // interactive transaction. Any retry of the overall operation has to roll back, re-open a new
+    // transaction and rebuild the graph instead (see `execute_self_contained_with_retry`).
+    let result = execute_on(
+        tx.as_connection_like(),
+        graph,
+        serializer,
+        query_schema,
+        traceparent,
+        true,
+        true,
+    )
+    .await;
 
     if result.is_ok() {
         tx.commit().await?;
@@ -262,17 +307,30 @@ async fn execute_in_tx<'a>(
 }
 
 // Simplest execution on anything that's a ConnectionLike. Caller decides handling of connections and transactions.
+//
+// `transactional` tells the interpreter whether `conn` is already wrapped in a transaction: when
+// it isn't, a mid-sequence write failure can leave committed statements behind, so the
+// interpreter keeps a compensation log of what it already wrote in case the caller wants to clean
+// it up.
+//
+// `in_interactive_transaction` tells the interpreter whether `conn` belongs to a multi-statement
+// transaction that's already open - whether client-managed (an interactive transaction) or
+// engine-managed (`execute_in_tx`) - in which case it must not retry a failed query node in place:
+// whoever observed the failure has already seen the transaction fail and it may be poisoned by it,
+// so any retry has to roll back, start a fresh transaction and rebuild the graph instead.
 async fn execute_on<'a>(
     conn: &mut dyn ConnectionLike,
     graph: QueryGraph,
     serializer: IrSerializer<'a>,
     query_schema: &'a QuerySchema,
     traceparent: Option<TraceParent>,
+    transactional: bool,
+    in_interactive_transaction: bool,
 ) -> crate::Result<ResponseData> {
     #[cfg(feature = "metrics")]
     counter!(PRISMA_CLIENT_QUERIES_TOTAL).increment(1);
 
-    let interpreter = QueryInterpreter::new(conn);
+    let interpreter = QueryInterpreter::new(conn, !transactional, in_interactive_transaction);
     QueryPipeline::new(graph, interpreter, serializer)
         .execute(query_schema, traceparent)
         .await
@@ -107,10 +107,16 @@ where
                     "db.system" = self.connector.name(),
                 );
                 let mut conn = self.connector.get_connection().instrument(conn_span).await?;
-                let mut tx = conn.start_transaction(transaction.isolation_level()).await?;
+                let mut tx = conn.start_transaction(transaction.isolation_level(), None).await?;
 
-                let results =
-                    execute_many_operations(query_schema, tx.as_connection_like(), &operations, traceparent).await;
+                let results = execute_many_operations(
+                    query_schema,
+                    tx.as_connection_like(),
+                    &operations,
+                    traceparent,
+                    false,
+                )
+                .await;
 
                 if results.is_err() {
                     tx.rollback().await?;
@@ -153,6 +159,7 @@ where
     ) -> crate::Result<TxId> {
         super::with_request_context(engine_protocol, async move {
             let isolation_level = tx_opts.isolation_level;
+            let snapshot_id = tx_opts.snapshot_id;
             let valid_for_millis = tx_opts.valid_for_millis;
             let id = tx_opts.new_tx_id.unwrap_or_default();
 
@@ -176,6 +183,7 @@ where
                     id.clone(),
                     conn,
                     isolation_level,
+                    snapshot_id,
                     Duration::from_millis(valid_for_millis),
                 )
                 .await?;
@@ -71,6 +71,12 @@ pub struct TransactionOptions {
     /// Isolation level to use for the transaction.
     pub isolation_level: Option<String>,
 
+    /// An exported snapshot to import into the transaction, so its reads observe the database as
+    /// it was when the snapshot was taken. Only supported on PostgreSQL, and only in combination
+    /// with `RepeatableRead` or `Serializable` isolation.
+    #[serde(default)]
+    pub snapshot_id: Option<String>,
+
     /// An optional pre-defined transaction id. Some value might be provided in case we want to generate
     /// a new id at the beginning of the transaction
     #[serde(skip)]
@@ -83,6 +89,7 @@ impl TransactionOptions {
             max_acquisition_millis,
             valid_for_millis,
             isolation_level,
+            snapshot_id: None,
             new_tx_id: None,
         }
     }
@@ -94,6 +101,13 @@ impl TransactionOptions {
         self.new_tx_id = Some(tx_id.clone());
         self
     }
+
+    /// Sets a transaction snapshot to import, so the transaction's reads observe the database
+    /// exactly as it was when the snapshot was taken.
+    pub fn with_snapshot_id(mut self, snapshot_id: Option<String>) -> Self {
+        self.snapshot_id = snapshot_id;
+        self
+    }
 }
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
@@ -1,4 +1,4 @@
-use crate::{Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, ResponseData};
+use crate::{CoreError, Env, Expressionista, IrSerializer, QueryGraph, QueryInterpreter, ResponseData};
 use schema::QuerySchema;
 use telemetry::TraceParent;
 use tracing::Instrument;
@@ -40,6 +40,28 @@ impl<'conn, 'schema> QueryPipeline<'conn, 'schema> {
             .await;
 
         trace!("{}", self.interpreter.log_output());
-        serializer.serialize(result?, query_schema)
+
+        let result = match result {
+            Ok(result) => result,
+            Err(err) => {
+                let log = self.interpreter.take_compensation_log();
+                let err: CoreError = err.into();
+
+                return Err(if log.is_empty() {
+                    err
+                } else {
+                    CoreError::NonTransactionalWriteFailed {
+                        source: Box::new(err),
+                        log,
+                    }
+                });
+            }
+        };
+
+        let mut response = serializer.serialize(result, query_schema)?;
+        let timings = self.interpreter.take_plan_timings();
+        response.timings = (!timings.is_empty()).then_some(timings);
+
+        Ok(response)
     }
 }
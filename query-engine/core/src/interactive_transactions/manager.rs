@@ -109,6 +109,7 @@ impl ItxManager {
         tx_id: TxId,
         conn: Box<dyn Connection + Send + Sync>,
         isolation_level: Option<String>,
+        snapshot_id: Option<String>,
         timeout: Duration,
     ) -> crate::Result<()> {
         // This task notifies the task spawned in `new()` method that the timeout for this
@@ -122,8 +123,15 @@ impl ItxManager {
             }
         });
 
-        let transaction =
-            InteractiveTransaction::new(tx_id.clone(), conn, timeout, query_schema, isolation_level).await?;
+        let transaction = InteractiveTransaction::new(
+            tx_id.clone(),
+            conn,
+            timeout,
+            query_schema,
+            isolation_level,
+            snapshot_id,
+        )
+        .await?;
 
         self.transactions
             .write()
@@ -67,6 +67,7 @@ impl TransactionState {
     async fn start_transaction(
         conn: Box<dyn Connection + Send + Sync>,
         isolation_level: Option<String>,
+        snapshot_id: Option<String>,
     ) -> crate::Result<Self> {
         // Note: This method creates a self-referential struct, which is why we need unsafe. Field
         // `tx` is referencing field `conn` in the `Self::Open` variant.
@@ -76,7 +77,8 @@ impl TransactionState {
         let conn_mut: &mut (dyn Connection + Send + Sync) = unsafe { conn.as_mut().get_unchecked_mut() };
 
         // This creates a transaction, which borrows from the connection.
-        let tx_borrowed_from_conn: Box<dyn Transaction> = conn_mut.start_transaction(isolation_level).await?;
+        let tx_borrowed_from_conn: Box<dyn Transaction> =
+            conn_mut.start_transaction(isolation_level, snapshot_id).await?;
 
         // SAFETY: This transmute only erases the lifetime from `conn_mut`. Normally, borrow checker
         // guarantees that the borrowed value is not dropped. In this case, we guarantee ourselves
@@ -149,12 +151,13 @@ impl InteractiveTransaction {
         timeout: Duration,
         query_schema: QuerySchemaRef,
         isolation_level: Option<String>,
+        snapshot_id: Option<String>,
     ) -> crate::Result<Self> {
         Span::current().record("itx_id", id.to_string());
 
         Ok(Self {
             id,
-            state: TransactionState::start_transaction(conn, isolation_level).await?,
+            state: TransactionState::start_transaction(conn, isolation_level, snapshot_id).await?,
             start_time: ElapsedTimeCounter::start(),
             timeout,
             query_schema,
@@ -173,6 +176,7 @@ impl InteractiveTransaction {
                 conn.as_connection_like(),
                 operation,
                 traceparent,
+                true,
             )
             .instrument(info_span!("prisma:engine:itx_execute_single"))
             .await
@@ -191,6 +195,7 @@ impl InteractiveTransaction {
                 conn.as_connection_like(),
                 operations,
                 traceparent,
+                true,
             )
             .instrument(info_span!("prisma:engine:itx_execute_batch"))
             .await
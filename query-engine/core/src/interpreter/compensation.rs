@@ -0,0 +1,91 @@
+use indexmap::IndexMap;
+use query_structure::{PrismaValue, SelectionResult};
+use serde::Serialize;
+
+/// The inverse of a single write statement that has already committed while running outside of a
+/// transaction: "this row was created and can be deleted again".
+///
+/// Only creates are recorded today, since those are the only writes that can orphan a row the
+/// caller doesn't already know about; a failed update or delete either touched nothing or touched
+/// rows the caller already had a handle on.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompensatingAction {
+    /// The model the row was written to.
+    model: String,
+    /// The selected fields identifying the created row, keyed by their Prisma field name.
+    id: IndexMap<String, PrismaValue>,
+}
+
+impl CompensatingAction {
+    fn for_created_row(model: String, selection: &SelectionResult) -> Self {
+        let id = selection
+            .pairs
+            .iter()
+            .map(|(field, value)| (field.to_string(), value.clone()))
+            .collect();
+
+        Self { model, id }
+    }
+}
+
+/// Accumulates the compensating actions for a non-transactional, multi-statement write while it
+/// runs, so that a failure partway through can report exactly what was left behind.
+///
+/// The engine never executes these itself; they're returned to the caller as a machine-readable
+/// plan it can choose to run (e.g. deleting the rows back out) to restore pre-write state.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CompensationLog {
+    actions: Vec<CompensatingAction>,
+}
+
+impl CompensationLog {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    pub(crate) fn record_created_rows(&mut self, model: String, selections: &[SelectionResult]) {
+        self.actions
+            .extend(selections.iter().map(|selection| CompensatingAction::for_created_row(model.clone(), selection)));
+    }
+}
+
+#[cfg(test)]
+fn test_selection_result(id: i64) -> SelectionResult {
+    let schema = psl::validate(
+        r#"
+        datasource db {
+          provider = "postgresql"
+          url      = "postgresql://"
+        }
+
+        model TestModel {
+          id Int @id
+        }
+        "#
+        .into(),
+    );
+
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    let internal_data_model = query_structure::convert(std::sync::Arc::new(schema));
+    let model = internal_data_model.find_model("TestModel").unwrap();
+    let id_field = model.fields().scalar().next().unwrap();
+
+    SelectionResult::new(vec![(id_field, PrismaValue::Int(id))])
+}
+
+#[test]
+fn log_only_lists_rows_committed_before_the_failure() {
+    let mut log = CompensationLog::default();
+    assert!(log.is_empty());
+
+    // Only the first two of three attempted creates succeeded before the third one failed.
+    log.record_created_rows(
+        "TestModel".to_owned(),
+        &[test_selection_result(1), test_selection_result(2)],
+    );
+
+    assert!(!log.is_empty());
+    assert_eq!(log.actions.len(), 2);
+    assert!(log.actions.iter().all(|action| action.model == "TestModel"));
+}
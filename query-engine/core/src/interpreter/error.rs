@@ -25,6 +25,12 @@ pub enum InterpreterError {
     Generic(String),
 }
 
+impl InterpreterError {
+    pub(crate) fn is_transient(&self) -> bool {
+        matches!(self, Self::ConnectorError(err) if err.is_transient())
+    }
+}
+
 impl fmt::Display for InterpreterError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -6,12 +6,34 @@ pub(crate) enum Expression {
         seq: Vec<Expression>,
     },
 
+    /// Like `Sequence`, but the expressions have no data dependency on one another and can be run
+    /// concurrently. Evaluates to the result of the last expression in `seq`, like `Sequence`.
+    Concurrent {
+        seq: Vec<Expression>,
+    },
+
     Func {
+        /// The query graph node's id, as assigned by `Expressionista` - the same id used as the
+        /// `Let` binding name for this node's result. Used to attribute per-node timings back to
+        /// a specific plan node when the interpreter is built with timing collection enabled.
+        node_id: String,
+
         func: Box<dyn FnOnce(Env) -> InterpretationResult<Expression> + Send + Sync + 'static>,
     },
 
     Query {
+        /// The query graph node's id, as assigned by `Expressionista` - the same id used as the
+        /// `Let` binding name for this node's result. Used to attribute per-node timings back to
+        /// a specific plan node when the interpreter is built with timing collection enabled.
+        node_id: String,
+
         query: Box<Query>,
+
+        /// The input path the underlying query graph node was built from (e.g.
+        /// `data.orders.create[2].items.createMany.data`), if the graph builder recorded one.
+        /// Attached to the resulting connector error's metadata on failure so clients can tell
+        /// which part of a deeply nested write caused it.
+        path: Option<String>,
     },
 
     Let {
@@ -25,6 +47,10 @@ pub(crate) enum Expression {
 
     GetFirstNonEmpty {
         binding_names: Vec<String>,
+
+        /// Returned instead of an empty result when every binding in `binding_names` is either
+        /// absent from the environment or itself empty.
+        default: Option<Box<ExpressionResult>>,
     },
 
     If {
@@ -1,11 +1,9 @@
 use query_structure::IntoFilter;
 
 use super::{expression::*, Env, ExpressionResult, InterpretationResult, InterpreterError};
-use crate::{query_graph::*, Query};
-use std::{
-    collections::{HashSet, VecDeque},
-    convert::TryInto,
-};
+use crate::{query_graph::*, Query, ReadQuery};
+use indexmap::IndexSet;
+use std::{collections::VecDeque, convert::TryInto};
 
 pub(crate) struct Expressionista;
 
@@ -59,13 +57,19 @@ impl Expressionista {
 
         let is_result = graph.is_result_node(node);
         let node_id = node.id();
+        let path = graph.node_path(node).map(str::to_owned);
         let node = graph.pluck_node(node);
-        let into_expr = Box::new(|node: Node| {
+        let query_node_id = node_id.clone();
+        let into_expr = Box::new(move |node: Node| {
             let query: Box<Query> = Box::new(node.try_into()?);
-            Ok(Expression::Query { query })
+            Ok(Expression::Query {
+                node_id: query_node_id,
+                query,
+                path,
+            })
         });
 
-        let expr = Self::transform_node(graph, parent_edges, node, into_expr)?;
+        let expr = Self::transform_node(graph, parent_edges, node, node_id.clone(), into_expr)?;
 
         if child_expressions.is_empty() {
             Ok(expr)
@@ -116,9 +120,14 @@ impl Expressionista {
             .map(|pos| child_pairs.remove(pos))
             .collect();
 
+        // Determined before any of the siblings are visited/plucked, since building one sibling's
+        // expression can remove the very edges we need to inspect for the others.
+        let siblings: Vec<NodeRef> = child_pairs.iter().map(|(_, node)| *node).collect();
+        let siblings_are_independent = Self::siblings_are_independent(graph, &siblings);
+
         // Because we split from right to left, everything remaining in `child_pairs`
         // doesn't belong into results, and is executed before all result scopes.
-        let mut expressions: Vec<Expression> = child_pairs
+        let non_result_expressions: Vec<Expression> = child_pairs
             .into_iter()
             .map(|(_, node)| {
                 let edges = graph.incoming_edges(&node);
@@ -126,6 +135,20 @@ impl Expressionista {
             })
             .collect::<InterpretationResult<Vec<Expression>>>()?;
 
+        let mut expressions = if non_result_expressions.len() > 1 {
+            if siblings_are_independent {
+                vec![Expression::Concurrent {
+                    seq: non_result_expressions,
+                }]
+            } else {
+                vec![Expression::Sequence {
+                    seq: non_result_expressions,
+                }]
+            }
+        } else {
+            non_result_expressions
+        };
+
         // Fold result scopes into one expression.
         if !result_subgraphs.is_empty() {
             let result_exp = Self::fold_result_scopes(graph, result_subgraphs)?;
@@ -135,6 +158,19 @@ impl Expressionista {
         Ok(expressions)
     }
 
+    /// Sibling nodes are independent if none of them consumes data produced by one of the others,
+    /// i.e. none has a graph edge whose source is another sibling in the same group. Data
+    /// dependencies like this are what make a query graph node wait on another one's result, so
+    /// their absence is what allows a set of siblings to run concurrently.
+    fn siblings_are_independent(graph: &QueryGraph, siblings: &[NodeRef]) -> bool {
+        siblings.iter().all(|node| {
+            graph
+                .incoming_edges(node)
+                .iter()
+                .all(|edge| !siblings.contains(&graph.edge_source(edge)))
+        })
+    }
+
     fn build_empty_expression(
         graph: &mut QueryGraph,
         node: &NodeRef,
@@ -148,8 +184,9 @@ impl Expressionista {
             .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node)))
             .collect::<InterpretationResult<_>>()?;
 
+        let node_id = node.id();
         let into_expr = Box::new(move |_node: Node| Ok(Expression::Sequence { seq: exprs }));
-        Self::transform_node(graph, parent_edges, Node::Empty, into_expr)
+        Self::transform_node(graph, parent_edges, Node::Empty, node_id, into_expr)
     }
 
     fn build_computation_expression(
@@ -168,12 +205,17 @@ impl Expressionista {
             .collect::<InterpretationResult<_>>()?;
 
         let node = graph.pluck_node(node);
+        let func_node_id = node_id.clone();
         let into_expr = Box::new(move |node: Node| {
             Ok(Expression::Func {
+                node_id: func_node_id,
                 func: Box::new(move |_| match node {
                     Node::Computation(Computation::DiffLeftToRight(DiffNode { left, right })) => {
-                        let left: HashSet<_> = left.into_iter().collect();
-                        let right: HashSet<_> = right.into_iter().collect();
+                        // `IndexSet` (rather than `HashSet`) keeps the diff in a deterministic,
+                        // insertion-order-derived order so that compiling the same graph twice
+                        // yields identical expression trees.
+                        let left: IndexSet<_> = left.into_iter().collect();
+                        let right: IndexSet<_> = right.into_iter().collect();
 
                         let diff = left.difference(&right);
 
@@ -183,8 +225,8 @@ impl Expressionista {
                     }
 
                     Node::Computation(Computation::DiffRightToLeft(DiffNode { left, right })) => {
-                        let left: HashSet<_> = left.into_iter().collect();
-                        let right: HashSet<_> = right.into_iter().collect();
+                        let left: IndexSet<_> = left.into_iter().collect();
+                        let right: IndexSet<_> = right.into_iter().collect();
 
                         let diff = right.difference(&left);
 
@@ -193,12 +235,24 @@ impl Expressionista {
                         })
                     }
 
+                    Node::Computation(Computation::SymmetricDiff(DiffNode { left, right })) => {
+                        let left: IndexSet<_> = left.into_iter().collect();
+                        let right: IndexSet<_> = right.into_iter().collect();
+
+                        let to_add = right.difference(&left).cloned().collect();
+                        let to_remove = left.difference(&right).cloned().collect();
+
+                        Ok(Expression::Return {
+                            result: Box::new(ExpressionResult::SymmetricDiffResult { to_add, to_remove }),
+                        })
+                    }
+
                     _ => unreachable!(),
                 }),
             })
         });
 
-        let expr = Self::transform_node(graph, parent_edges, node, into_expr)?;
+        let expr = Self::transform_node(graph, parent_edges, node, node_id.clone(), into_expr)?;
 
         if exprs.is_empty() {
             Ok(expr)
@@ -252,6 +306,56 @@ impl Expressionista {
             .then
             .expect("Expected if-node to always have a then edge to another node.");
 
+        // If the rule doesn't depend on runtime data (e.g. an if-node built with `Flow::if_false()`),
+        // we already know which branch will be taken, so only build that one instead of eagerly
+        // compiling both arms.
+        let constant = match graph.node_content(node) {
+            Some(Node::Flow(Flow::If { rule, .. })) => rule.as_constant(),
+            _ => None,
+        };
+
+        let node_id = node.id();
+
+        if let Some(take_then) = constant {
+            let taken_expr = if take_then {
+                Some(Self::build_expression(graph, &then_pair.1, graph.incoming_edges(&then_pair.1))?)
+            } else {
+                if_node_info
+                    ._else
+                    .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node)))
+                    .transpose()?
+            };
+
+            let child_expressions = Self::process_children(graph, if_node_info.other)?;
+
+            let node = graph.pluck_node(node);
+            let binding_node_id = node_id.clone();
+            let into_expr = Box::new(move |node: Node| {
+                let flow: Flow = node.try_into()?;
+                let Flow::If { .. } = flow else { unreachable!() };
+
+                let taken = Expression::Sequence {
+                    seq: taken_expr.into_iter().collect(),
+                };
+
+                let expr = if !child_expressions.is_empty() {
+                    Expression::Let {
+                        bindings: vec![Binding {
+                            name: binding_node_id,
+                            expr: taken,
+                        }],
+                        expressions: child_expressions,
+                    }
+                } else {
+                    taken
+                };
+
+                Ok(expr)
+            });
+
+            return Self::transform_node(graph, parent_edges, node, node_id, into_expr);
+        }
+
         // Build expressions for both arms.
         let then_expr = Self::build_expression(graph, &then_pair.1, graph.incoming_edges(&then_pair.1))?;
         let else_expr = if_node_info
@@ -262,8 +366,8 @@ impl Expressionista {
 
         let child_expressions = Self::process_children(graph, if_node_info.other)?;
 
-        let node_id = node.id();
         let node = graph.pluck_node(node);
+        let binding_node_id = node_id.clone();
         let into_expr = Box::new(move |node: Node| {
             let flow: Flow = node.try_into()?;
 
@@ -277,7 +381,7 @@ impl Expressionista {
                 let expr = if !child_expressions.is_empty() {
                     Expression::Let {
                         bindings: vec![Binding {
-                            name: node_id,
+                            name: binding_node_id,
                             expr: if_expr,
                         }],
                         expressions: child_expressions,
@@ -292,7 +396,7 @@ impl Expressionista {
             }
         });
 
-        Self::transform_node(graph, parent_edges, node, into_expr)
+        Self::transform_node(graph, parent_edges, node, node_id, into_expr)
     }
 
     fn translate_return_node(
@@ -317,7 +421,7 @@ impl Expressionista {
 
         let node_binding_name = node.id();
         let node = graph.pluck_node(node);
-        let expr = Self::transform_node(graph, parent_edges, node, into_expr)?;
+        let expr = Self::transform_node(graph, parent_edges, node, node_binding_name.clone(), into_expr)?;
 
         if child_expressions.is_empty() {
             Ok(expr)
@@ -338,6 +442,7 @@ impl Expressionista {
         graph: &mut QueryGraph,
         parent_edges: Vec<EdgeRef>,
         node: Node,
+        node_id: String,
         into_expr: Box<dyn FnOnce(Node) -> InterpretationResult<Expression> + Send + Sync + 'static>,
     ) -> InterpretationResult<Expression> {
         if parent_edges.is_empty() {
@@ -352,6 +457,7 @@ impl Expressionista {
                 into_expr(node)
             } else {
                 Ok(Expression::Func {
+                    node_id,
                     func: Box::new(move |env: Env| {
                         // Run transformers in order on the query to retrieve the final, transformed, query.
                         let node: InterpretationResult<Node> =
@@ -482,7 +588,7 @@ impl Expressionista {
     ) -> InterpretationResult<Expression> {
         // if the subgraphs all point to the same result node, we fold them in sequence
         // if not, we can separate them with a getfirstnonempty
-        let bindings: Vec<Binding> = result_subgraphs
+        let mut bindings: Vec<Binding> = result_subgraphs
             .into_iter()
             .map(|(_, node)| {
                 let name = node.id();
@@ -497,6 +603,13 @@ impl Expressionista {
         let result_nodes: Vec<NodeRef> = graph.result_nodes().collect();
 
         if result_nodes.len() == 1 {
+            // Only safe in this branch: the bindings below get folded into a chain of nested `Let`s
+            // (each binding's expressions contains the next), so a later binding's `Get` can always
+            // see an earlier one. In the `else` branch all bindings live in a single flat `Let` and
+            // are evaluated independently against the same outer environment, so they can't reference
+            // each other this way.
+            Self::dedup_pure_read_bindings(&mut bindings);
+
             let mut exprs: VecDeque<Expression> = bindings
                 .into_iter()
                 .map(|binding| Expression::Let {
@@ -533,8 +646,327 @@ impl Expressionista {
                 bindings,
                 expressions: vec![Expression::GetFirstNonEmpty {
                     binding_names: result_binding_names,
+                    default: None,
                 }],
             })
         }
     }
+
+    /// Common-subexpression elimination for sibling result bindings: when two bindings evaluate a
+    /// structurally identical, side-effect-free read (see `reads_are_duplicate`), the later one is
+    /// rewritten to `Expression::Get` of the earlier one's binding name instead of re-running the
+    /// query. Deliberately conservative — see the caller for why this can only run when the bindings
+    /// end up folded into a nested `Let` chain.
+    fn dedup_pure_read_bindings(bindings: &mut [Binding]) {
+        for i in 0..bindings.len() {
+            for j in (i + 1)..bindings.len() {
+                if Self::reads_are_duplicate(&bindings[i].expr, &bindings[j].expr) {
+                    bindings[j].expr = Expression::Get {
+                        binding_name: bindings[i].name.clone(),
+                    };
+                }
+            }
+        }
+    }
+
+    /// True if both expressions are leaf (no `nested`) `RecordQuery` or `ManyRecordsQuery` reads over
+    /// the same model, filter/arguments and field selection. Only these two variants are considered:
+    /// they can't have side effects and, with `nested` empty, carry no sub-reads that themselves would
+    /// need deduplicating. `RelatedRecordsQuery`, `AggregateRecordsQuery` and anything with `nested`
+    /// reads are left alone. `name`, `alias` and `selection_order` are ignored because they're
+    /// identifiers assigned by the query graph builder, not part of what the read actually does.
+    fn reads_are_duplicate(a: &Expression, b: &Expression) -> bool {
+        match (a, b) {
+            (Expression::Query { query: a, .. }, Expression::Query { query: b, .. }) => match (a.as_ref(), b.as_ref()) {
+                (Query::Read(ReadQuery::RecordQuery(a)), Query::Read(ReadQuery::RecordQuery(b))) => {
+                    a.nested.is_empty()
+                        && b.nested.is_empty()
+                        && a.model == b.model
+                        && a.filter == b.filter
+                        && a.selected_fields == b.selected_fields
+                        && a.relation_load_strategy == b.relation_load_strategy
+                }
+                (Query::Read(ReadQuery::ManyRecordsQuery(a)), Query::Read(ReadQuery::ManyRecordsQuery(b))) => {
+                    a.nested.is_empty()
+                        && b.nested.is_empty()
+                        && a.model == b.model
+                        && a.args == b.args
+                        && a.selected_fields == b.selected_fields
+                        && a.relation_load_strategy == b.relation_load_strategy
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+fn test_selection_result(id: i64) -> query_structure::SelectionResult {
+    let schema = psl::validate(
+        r#"
+        datasource db {
+          provider = "postgresql"
+          url      = "postgresql://"
+        }
+
+        model TestModel {
+          id Int @id
+        }
+        "#
+        .into(),
+    );
+
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    let internal_data_model = query_structure::convert(std::sync::Arc::new(schema));
+    let model = internal_data_model.find_model("TestModel").unwrap();
+    let id_field = model.fields().scalar().next().unwrap();
+
+    query_structure::SelectionResult::new(vec![(id_field, query_structure::PrismaValue::Int(id))])
+}
+
+#[test]
+fn symmetric_diff_computes_additions_and_removals() {
+    let mut graph = QueryGraph::new();
+    let node = graph.create_node(Node::Computation(Computation::empty_symmetric_diff()));
+
+    if let Some(Node::Computation(Computation::SymmetricDiff(diff_node))) = graph.node_content_mut(&node) {
+        diff_node.left = vec![test_selection_result(1), test_selection_result(2)];
+        diff_node.right = vec![test_selection_result(2), test_selection_result(3)];
+    } else {
+        panic!("expected a freshly created SymmetricDiff node");
+    }
+
+    let expr = Expressionista::build_computation_expression(&mut graph, &node, vec![]).unwrap();
+
+    let Expression::Func { func, .. } = expr else {
+        panic!("expected a Func expression");
+    };
+
+    let Expression::Return { result } = func(Env::default()).unwrap() else {
+        panic!("expected a Return expression");
+    };
+
+    let ExpressionResult::SymmetricDiffResult { to_add, to_remove } = *result else {
+        panic!("expected a SymmetricDiffResult, got {result:?}");
+    };
+
+    assert_eq!(to_add, vec![test_selection_result(3)]);
+    assert_eq!(to_remove, vec![test_selection_result(1)]);
+}
+
+#[test]
+fn constant_true_if_node_only_builds_then_branch() {
+    let mut graph = QueryGraph::new();
+
+    let if_node = graph.create_node(Flow::if_true());
+    let then_node = graph.create_node(Flow::Return(vec![test_selection_result(1)]));
+    let else_node = graph.create_node(Flow::Return(vec![test_selection_result(2)]));
+
+    graph.create_edge(&if_node, &then_node, QueryGraphDependency::Then).unwrap();
+    graph.create_edge(&if_node, &else_node, QueryGraphDependency::Else).unwrap();
+
+    let expr = Expressionista::build_flow_expression(&mut graph, &if_node, vec![]).unwrap();
+
+    let Expression::Sequence { mut seq } = expr else {
+        panic!("expected a Sequence expression");
+    };
+
+    // Only the then-branch was compiled: no `Expression::If` wrapper and no trace of the else node.
+    assert_eq!(seq.len(), 1, "expected only the then branch to be compiled");
+
+    let Expression::Return { result } = seq.remove(0) else {
+        panic!("expected a Return expression");
+    };
+
+    let ExpressionResult::FixedResult(data) = *result else {
+        panic!("expected a FixedResult");
+    };
+
+    assert_eq!(data, vec![test_selection_result(1)]);
+}
+
+#[test]
+fn diff_computation_is_deterministic_across_repeated_compiles() {
+    let left = vec![test_selection_result(1), test_selection_result(2), test_selection_result(3)];
+    let right = vec![test_selection_result(2), test_selection_result(3), test_selection_result(4)];
+
+    let results: Vec<Vec<query_structure::SelectionResult>> = (0..50)
+        .map(|_| {
+            let mut graph = QueryGraph::new();
+            let node = graph.create_node(Node::Computation(Computation::empty_diff_left_to_right()));
+
+            if let Some(Node::Computation(Computation::DiffLeftToRight(diff_node))) = graph.node_content_mut(&node) {
+                diff_node.left = left.clone();
+                diff_node.right = right.clone();
+            } else {
+                panic!("expected a freshly created DiffLeftToRight node");
+            }
+
+            let expr = Expressionista::build_computation_expression(&mut graph, &node, vec![]).unwrap();
+
+            let Expression::Func { func, .. } = expr else {
+                panic!("expected a Func expression");
+            };
+
+            let Expression::Return { result } = func(Env::default()).unwrap() else {
+                panic!("expected a Return expression");
+            };
+
+            let ExpressionResult::FixedResult(data) = *result else {
+                panic!("expected a FixedResult");
+            };
+
+            data
+        })
+        .collect();
+
+    let first = &results[0];
+    assert!(
+        results.iter().all(|data| data == first),
+        "expected every compile of the same diff to produce the same ordering"
+    );
+}
+
+#[test]
+fn independent_siblings_are_compiled_into_concurrent() {
+    let mut graph = QueryGraph::new();
+
+    let root = graph.create_node(Node::Empty);
+    let sibling_a = graph.create_node(Flow::Return(vec![test_selection_result(1)]));
+    let sibling_b = graph.create_node(Flow::Return(vec![test_selection_result(2)]));
+
+    graph
+        .create_edge(&root, &sibling_a, QueryGraphDependency::ExecutionOrder)
+        .unwrap();
+    graph
+        .create_edge(&root, &sibling_b, QueryGraphDependency::ExecutionOrder)
+        .unwrap();
+
+    let child_pairs = graph.direct_child_pairs(&root);
+    let exprs = Expressionista::process_children(&mut graph, child_pairs).unwrap();
+
+    assert_eq!(exprs.len(), 1, "expected the two siblings to fold into a single expression");
+
+    let Expression::Concurrent { seq } = &exprs[0] else {
+        panic!("expected a Concurrent expression");
+    };
+
+    assert_eq!(seq.len(), 2, "expected both independent siblings in the Concurrent seq");
+}
+
+#[cfg(test)]
+fn test_record_read(name: &str, model: &query_structure::Model) -> Expression {
+    let record_query = crate::RecordQuery {
+        name: name.to_owned(),
+        alias: None,
+        model: model.clone(),
+        filter: None,
+        selected_fields: model.primary_identifier(),
+        nested: vec![],
+        selection_order: vec![],
+        options: crate::QueryOptions::none(),
+        relation_load_strategy: query_structure::RelationLoadStrategy::Query,
+    };
+
+    Expression::Query {
+        node_id: name.to_owned(),
+        query: Box::new(Query::Read(ReadQuery::RecordQuery(record_query))),
+        path: None,
+    }
+}
+
+#[test]
+fn identical_child_reads_are_deduplicated_into_a_single_query() {
+    let schema = psl::validate(
+        r#"
+        datasource db {
+          provider = "postgresql"
+          url      = "postgresql://"
+        }
+
+        model TestModel {
+          id Int @id
+        }
+        "#
+        .into(),
+    );
+
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    let internal_data_model = query_structure::convert(std::sync::Arc::new(schema));
+    let model = internal_data_model.find_model("TestModel").unwrap();
+
+    let mut bindings = vec![
+        Binding {
+            name: "read_a".to_string(),
+            expr: test_record_read("read_a", &model),
+        },
+        Binding {
+            name: "read_b".to_string(),
+            expr: test_record_read("read_b", &model),
+        },
+    ];
+
+    Expressionista::dedup_pure_read_bindings(&mut bindings);
+
+    assert!(
+        matches!(bindings[0].expr, Expression::Query { .. }),
+        "expected the first occurrence to keep its Query expression"
+    );
+
+    match &bindings[1].expr {
+        Expression::Get { binding_name } => {
+            assert_eq!(binding_name, "read_a", "expected the duplicate to be rewritten into a Get of the first occurrence");
+        }
+        _ => panic!("expected the duplicate read to be rewritten into a Get"),
+    }
+}
+
+#[test]
+fn reads_over_different_filters_are_not_deduplicated() {
+    let schema = psl::validate(
+        r#"
+        datasource db {
+          provider = "postgresql"
+          url      = "postgresql://"
+        }
+
+        model TestModel {
+          id Int @id
+        }
+        "#
+        .into(),
+    );
+
+    assert!(!schema.diagnostics.has_errors(), "{:?}", schema.diagnostics);
+
+    let internal_data_model = query_structure::convert(std::sync::Arc::new(schema));
+    let model = internal_data_model.find_model("TestModel").unwrap();
+
+    let mut read_b = test_record_read("read_b", &model);
+    if let Expression::Query { query, .. } = &mut read_b {
+        if let Query::Read(ReadQuery::RecordQuery(rq)) = query.as_mut() {
+            rq.filter = Some(query_structure::Filter::and(vec![]));
+        }
+    }
+
+    let mut bindings = vec![
+        Binding {
+            name: "read_a".to_string(),
+            expr: test_record_read("read_a", &model),
+        },
+        Binding {
+            name: "read_b".to_string(),
+            expr: read_b,
+        },
+    ];
+
+    Expressionista::dedup_pure_read_bindings(&mut bindings);
+
+    assert!(
+        matches!(bindings[1].expr, Expression::Query { .. }),
+        "reads with different filters must not be collapsed into one another"
+    );
 }
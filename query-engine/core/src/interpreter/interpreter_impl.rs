@@ -1,13 +1,18 @@
 use super::{
+    compensation::CompensationLog,
     expression::*,
     query_interpreters::{read, write},
+    retry::{NoRetryPolicy, RetryDecision, RetryPolicy, TransientErrorRetryPolicy},
+    timings::{NodeTiming, PlanTimings},
     InterpretationResult, InterpreterError,
 };
-use crate::{Query, QueryResult};
+use crate::{Query, QueryResult, WriteQuery};
 use connector::ConnectionLike;
 use futures::future::BoxFuture;
 use query_structure::prelude::*;
-use std::{collections::HashMap, fmt, slice};
+#[cfg(test)]
+use query_structure::{Filter, QueryArguments, RecordFilter, RelationLoadStrategy, WriteArgs};
+use std::{collections::HashMap, fmt, slice, sync::Arc, time::Instant};
 use telemetry::TraceParent;
 use tracing::Instrument;
 
@@ -19,6 +24,13 @@ pub enum ExpressionResult {
     /// A fixed result returned in the query graph.
     FixedResult(Vec<SelectionResult>),
 
+    /// The result of a `Computation::SymmetricDiff`: the rows to add (present on the right but not
+    /// the left) and the rows to remove (present on the left but not the right).
+    SymmetricDiffResult {
+        to_add: Vec<SelectionResult>,
+        to_remove: Vec<SelectionResult>,
+    },
+
     /// An empty result
     Empty,
 }
@@ -28,6 +40,7 @@ impl ExpressionResult {
         match self {
             Self::Query(result) => result.returned_row_count(),
             Self::FixedResult(results) => Some(results.len()),
+            Self::SymmetricDiffResult { to_add, to_remove } => Some(to_add.len() + to_remove.len()),
             Self::Empty => Some(0),
         }
     }
@@ -36,6 +49,7 @@ impl ExpressionResult {
         match self {
             Self::Query(result) => result.affected_row_count(),
             Self::FixedResult(_) => None,
+            Self::SymmetricDiffResult { .. } => None,
             Self::Empty => Some(0),
         }
     }
@@ -110,9 +124,13 @@ impl ExpressionResult {
     }
 }
 
+/// The interpreter's set of bound variables. Cloning an `Env` (done on every branch of the
+/// expression tree, since each branch needs its own view of the bindings) is just an `Arc` bump;
+/// the underlying map is only actually copied the first time a clone diverges from its sibling by
+/// inserting or removing a key.
 #[derive(Default, Debug, Clone)]
 pub(crate) struct Env {
-    env: HashMap<String, ExpressionResult>,
+    env: Arc<HashMap<String, ExpressionResult>>,
 }
 
 impl Env {
@@ -121,11 +139,11 @@ impl Env {
     }
 
     pub(crate) fn insert(&mut self, key: String, value: ExpressionResult) {
-        self.env.insert(key, value);
+        Arc::make_mut(&mut self.env).insert(key, value);
     }
 
     pub(crate) fn remove(&mut self, key: &str) -> InterpretationResult<ExpressionResult> {
-        match self.env.remove(key) {
+        match Arc::make_mut(&mut self.env).remove(key) {
             Some(val) => Ok(val),
             None => Err(InterpreterError::EnvVarNotFound(key.to_owned())),
         }
@@ -135,6 +153,22 @@ impl Env {
 pub(crate) struct QueryInterpreter<'conn> {
     pub(crate) conn: &'conn mut dyn ConnectionLike,
     log: Vec<String>,
+
+    /// Set when the interpreter is running outside of a transaction, so that a mid-sequence
+    /// failure can still report which writes already committed and need cleanup.
+    track_compensation: bool,
+    compensation: CompensationLog,
+
+    /// Governs whether, and how, a failed `Expression::Query` node gets retried. Always
+    /// `NoRetryPolicy` inside any multi-statement transaction that's already open - client-managed
+    /// (an interactive transaction) or engine-managed - since the transaction may already be
+    /// poisoned by the failed statement.
+    retry_policy: Box<dyn RetryPolicy>,
+
+    /// Checked once at construction (see [`Self::timings_enabled`]) rather than per node, so the
+    /// disabled path through `interpret` is a single boolean read.
+    collect_timings: bool,
+    timings: PlanTimings,
 }
 
 impl fmt::Debug for QueryInterpreter<'_> {
@@ -148,14 +182,85 @@ impl<'conn> QueryInterpreter<'conn> {
         tracing::level_filters::STATIC_MAX_LEVEL == tracing::level_filters::LevelFilter::TRACE
     }
 
-    pub(crate) fn new(conn: &'conn mut dyn ConnectionLike) -> QueryInterpreter<'conn> {
+    /// Whether per-node execution timing should be collected for this interpreter. Gated on a
+    /// dedicated tracing target rather than a request-level flag, the same way `log_enabled`
+    /// gates the human-readable interpretation log: an operator turns it on for a request (or a
+    /// whole deployment) by enabling `prisma:engine:node_timing=debug` on their tracing
+    /// subscriber, without the core crate needing to plumb a new option through every caller.
+    fn timings_enabled() -> bool {
+        tracing::enabled!(target: "prisma:engine:node_timing", tracing::Level::DEBUG)
+    }
+
+    pub(crate) fn new(
+        conn: &'conn mut dyn ConnectionLike,
+        track_compensation: bool,
+        in_interactive_transaction: bool,
+    ) -> QueryInterpreter<'conn> {
         let mut log = Vec::new();
 
         if Self::log_enabled() {
             log.push("\n".to_string());
         }
 
-        Self { conn, log }
+        let retry_policy: Box<dyn RetryPolicy> = if in_interactive_transaction {
+            Box::new(NoRetryPolicy)
+        } else {
+            Box::new(TransientErrorRetryPolicy::default())
+        };
+
+        Self {
+            conn,
+            log,
+            track_compensation,
+            compensation: CompensationLog::default(),
+            retry_policy,
+            collect_timings: Self::timings_enabled(),
+            timings: PlanTimings::default(),
+        }
+    }
+
+    /// Takes the compensation log accumulated so far, leaving an empty one in its place.
+    ///
+    /// Only ever non-empty when this interpreter was constructed with `track_compensation: true`
+    /// and at least one write already committed before the sequence failed.
+    pub(crate) fn take_compensation_log(&mut self) -> CompensationLog {
+        std::mem::take(&mut self.compensation)
+    }
+
+    /// Takes the per-node timings accumulated so far, leaving an empty [`PlanTimings`] in its
+    /// place. Only ever non-empty when [`Self::timings_enabled`] was true at construction.
+    pub(crate) fn take_plan_timings(&mut self) -> PlanTimings {
+        std::mem::take(&mut self.timings)
+    }
+
+    /// Records one node's timing if collection is enabled; a no-op otherwise. `result` is only
+    /// consulted for its row counts, never consumed, so the caller keeps ownership either way.
+    fn record_timing(&mut self, node_id: String, start: Instant, result: &InterpretationResult<ExpressionResult>) {
+        if !self.collect_timings {
+            return;
+        }
+
+        let duration = start.elapsed();
+        let (affected_row_count, returned_row_count) = match result {
+            Ok(result) => (result.affected_row_count(), result.returned_row_count()),
+            Err(_) => (None, None),
+        };
+
+        debug!(
+            target: "prisma:engine:node_timing",
+            node_id = %node_id,
+            duration_ms = duration.as_secs_f64() * 1000.0,
+            ?affected_row_count,
+            ?returned_row_count,
+            "plan node timing",
+        );
+
+        self.timings.nodes.push(NodeTiming {
+            node_id,
+            duration,
+            affected_row_count,
+            returned_row_count,
+        });
     }
 
     pub(crate) fn interpret(
@@ -166,12 +271,16 @@ impl<'conn> QueryInterpreter<'conn> {
         traceparent: Option<TraceParent>,
     ) -> BoxFuture<'_, InterpretationResult<ExpressionResult>> {
         match exp {
-            Expression::Func { func } => {
+            Expression::Func { node_id, func } => {
                 let expr = func(env.clone());
+                let start = self.collect_timings.then(Instant::now);
 
                 Box::pin(async move {
                     self.log_line(level, || "execute <lambda function> {");
                     let result = self.interpret(expr?, env, level + 1, traceparent).await;
+                    if let Some(start) = start {
+                        self.record_timing(node_id, start, &result);
+                    }
                     self.log_line(level, || "}");
                     result
                 })
@@ -200,6 +309,35 @@ impl<'conn> QueryInterpreter<'conn> {
                 })
             }
 
+            // `self.conn` is a single `&mut dyn ConnectionLike`, so we can't yet hand out
+            // overlapping borrows to actually run these concurrently. Run them sequentially like
+            // `Sequence` until the interpreter can operate over more than one connection at a
+            // time; the distinction from `Sequence` still lets the query graph record (and a
+            // future connection-pooled interpreter exploit) the fact that these expressions don't
+            // depend on one another.
+            Expression::Concurrent { seq } if seq.is_empty() => Box::pin(async move {
+                self.log_line(level, || "<>");
+                Ok(ExpressionResult::Empty)
+            }),
+
+            Expression::Concurrent { seq } => {
+                Box::pin(async move {
+                    self.log_line(level, || "<");
+
+                    let mut results = Vec::with_capacity(seq.len());
+
+                    for expr in seq {
+                        results.push(self.interpret(expr, env.clone(), level + 1, traceparent).await?);
+                        self.log_line(level + 1, || ",");
+                    }
+
+                    self.log_line(level, || ">");
+
+                    // Last result gets returned
+                    Ok(results.pop().unwrap())
+                })
+            }
+
             Expression::Let {
                 bindings,
                 mut expressions,
@@ -233,26 +371,31 @@ impl<'conn> QueryInterpreter<'conn> {
                 })
             }
 
-            Expression::Query { query } => Box::pin(async move {
-                match *query {
-                    Query::Read(read) => {
-                        self.log_line(level, || format!("readExecute {read}"));
-                        let span = info_span!("prisma:engine:read-execute");
-                        Ok(read::execute(self.conn, read, None, traceparent)
-                            .instrument(span)
-                            .await
-                            .map(ExpressionResult::Query)?)
+            Expression::Query { node_id, query, path } => Box::pin(async move {
+                let mut attempts = 0u32;
+                let start = self.collect_timings.then(Instant::now);
+
+                let result = loop {
+                    match self.run_query((*query).clone(), &path, level, traceparent).await {
+                        Ok(result) => break Ok(result),
+                        Err(err) => match self.retry_policy.retry_on(&err, attempts) {
+                            RetryDecision::GiveUp => break Err(err),
+                            RetryDecision::RetryAfter(backoff) => {
+                                attempts += 1;
+                                self.log_line(level, || {
+                                    format!("retrying after transient error (attempt {attempts}): {err}")
+                                });
+                                crosstarget_utils::time::sleep(backoff).await;
+                            }
+                        },
                     }
+                };
 
-                    Query::Write(write) => {
-                        self.log_line(level, || format!("writeExecute {write}"));
-                        let span = info_span!("prisma:engine:write-execute");
-                        Ok(write::execute(self.conn, write, traceparent)
-                            .instrument(span)
-                            .await
-                            .map(ExpressionResult::Query)?)
-                    }
+                if let Some(start) = start {
+                    self.record_timing(node_id, start, &result);
                 }
+
+                result
             }),
 
             Expression::Get { binding_name } => Box::pin(async move {
@@ -260,17 +403,11 @@ impl<'conn> QueryInterpreter<'conn> {
                 env.clone().remove(&binding_name)
             }),
 
-            Expression::GetFirstNonEmpty { binding_names } => Box::pin(async move {
+            Expression::GetFirstNonEmpty { binding_names, default } => Box::pin(async move {
                 self.log_line(level, || format!("getFirstNonEmpty {binding_names:?}"));
 
-                Ok(binding_names
-                    .into_iter()
-                    .find_map(|binding_name| {
-                        env.get(&binding_name)
-                            .map(|_| env.clone().remove(&binding_name).unwrap())
-                            .filter(|result| !matches!(result, ExpressionResult::Empty))
-                    })
-                    .unwrap_or(ExpressionResult::Empty))
+                let mut env = env;
+                resolve_first_non_empty(&mut env, &binding_names, default.map(|d| *d))
             }),
 
             Expression::If {
@@ -299,6 +436,55 @@ impl<'conn> QueryInterpreter<'conn> {
         }
     }
 
+    /// Executes a single `Expression::Query` node. Takes `query` by value (rather than the
+    /// `Box<Query>` the `Expression::Query` variant stores it as) so that a retry can pass in a
+    /// fresh clone without re-interpreting the expression tree that produced it.
+    async fn run_query(
+        &mut self,
+        query: Query,
+        path: &Option<String>,
+        level: usize,
+        traceparent: Option<TraceParent>,
+    ) -> InterpretationResult<ExpressionResult> {
+        match query {
+            Query::Read(read) => {
+                self.log_line(level, || format!("readExecute {read}"));
+                let span = info_span!("prisma:engine:read-execute");
+                Ok(read::execute(self.conn, read, None, traceparent)
+                    .instrument(span)
+                    .await
+                    .map(ExpressionResult::Query)?)
+            }
+
+            Query::Write(write) => {
+                self.log_line(level, || format!("writeExecute {write}"));
+                let span = info_span!("prisma:engine:write-execute");
+
+                let compensation_target = self.track_compensation.then(|| created_rows_target(&write)).flatten();
+
+                let result = write::execute(self.conn, write, traceparent)
+                    .instrument(span)
+                    .await
+                    .map_err(|err| match (err, path) {
+                        (InterpreterError::ConnectorError(err), Some(path)) => {
+                            InterpreterError::ConnectorError(err.with_path(path))
+                        }
+                        (err, _) => err,
+                    })?;
+
+                if let Some((model, field_selection)) = compensation_target {
+                    if let QueryResult::RecordSelection(Some(ref rs)) = result {
+                        if let Ok(created) = rs.records.extract_selection_results_from_db_name(&field_selection) {
+                            self.compensation.record_created_rows(model, &created);
+                        }
+                    }
+                }
+
+                Ok(ExpressionResult::Query(result))
+            }
+        }
+    }
+
     pub(crate) fn log_output(&self) -> String {
         let mut output = String::with_capacity(self.log.len() * 30);
 
@@ -320,3 +506,316 @@ impl<'conn> QueryInterpreter<'conn> {
         }
     }
 }
+
+/// If `write` is a create whose result carries back the created rows, returns the model name and
+/// the field selection to extract their ids with. `None` for writes that can't orphan a row the
+/// caller doesn't already know about, or whose connector doesn't return the created rows.
+fn created_rows_target(write: &WriteQuery) -> Option<(String, FieldSelection)> {
+    match write {
+        WriteQuery::CreateRecord(cr) => Some((cr.model.name().to_owned(), cr.selected_fields.clone())),
+        WriteQuery::CreateManyRecords(cmr) => cmr
+            .selected_fields
+            .as_ref()
+            .map(|fields| (cmr.model.name().to_owned(), fields.fields.clone())),
+        _ => None,
+    }
+}
+
+/// Resolves a `GetFirstNonEmpty` expression: removes and returns the first binding in
+/// `binding_names` that's present in `env` and non-empty, or `default` if every one of them is
+/// either absent or itself empty.
+fn resolve_first_non_empty(
+    env: &mut Env,
+    binding_names: &[String],
+    default: Option<ExpressionResult>,
+) -> InterpretationResult<ExpressionResult> {
+    for binding_name in binding_names {
+        match env.get(binding_name) {
+            Some(ExpressionResult::Empty) | None => continue,
+            Some(_) => return env.remove(binding_name),
+        }
+    }
+
+    Ok(default.unwrap_or(ExpressionResult::Empty))
+}
+
+/// A `ConnectionLike` whose `execute_raw` fails with a transient connector error the first
+/// `fail_times` calls it receives, then succeeds. Every other operation is unreachable from the
+/// `Expression::Query { query: Query::Write(WriteQuery::ExecuteRaw(_)), .. }` node the tests below
+/// drive through the interpreter.
+#[cfg(test)]
+struct FlakyConnection {
+    fail_times: u32,
+    calls: u32,
+}
+
+#[cfg(test)]
+impl FlakyConnection {
+    fn new(fail_times: u32) -> Self {
+        Self { fail_times, calls: 0 }
+    }
+
+    fn transient_error() -> connector::error::ConnectorError {
+        connector::error::ConnectorError::from_kind(connector::error::ErrorKind::TransactionWriteConflict)
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl connector::ReadOperations for FlakyConnection {
+    async fn get_single_record(
+        &mut self,
+        _model: &Model,
+        _filter: &Filter,
+        _selected_fields: &FieldSelection,
+        _relation_load_strategy: RelationLoadStrategy,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<Option<SingleRecord>> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn get_many_records(
+        &mut self,
+        _model: &Model,
+        _query_arguments: QueryArguments,
+        _selected_fields: &FieldSelection,
+        _relation_load_strategy: RelationLoadStrategy,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<ManyRecords> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn get_related_m2m_record_ids(
+        &mut self,
+        _from_field: &RelationFieldRef,
+        _from_record_ids: &[SelectionResult],
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<Vec<(SelectionResult, SelectionResult)>> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn aggregate_records(
+        &mut self,
+        _model: &Model,
+        _query_arguments: QueryArguments,
+        _selections: Vec<connector::AggregationSelection>,
+        _group_by: Vec<ScalarFieldRef>,
+        _having: Option<Filter>,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<Vec<connector::AggregationRow>> {
+        unreachable!("not exercised by these tests")
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl connector::WriteOperations for FlakyConnection {
+    async fn create_record(
+        &mut self,
+        _model: &Model,
+        _args: WriteArgs,
+        _selected_fields: FieldSelection,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<SingleRecord> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn create_records(
+        &mut self,
+        _model: &Model,
+        _args: Vec<WriteArgs>,
+        _skip_duplicates: bool,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<usize> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn create_records_returning(
+        &mut self,
+        _model: &Model,
+        _args: Vec<WriteArgs>,
+        _skip_duplicates: bool,
+        _selected_fields: FieldSelection,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<ManyRecords> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn update_records(
+        &mut self,
+        _model: &Model,
+        _record_filter: RecordFilter,
+        _args: WriteArgs,
+        _order_by: Vec<OrderBy>,
+        _limit: Option<usize>,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<usize> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn update_records_returning(
+        &mut self,
+        _model: &Model,
+        _record_filter: RecordFilter,
+        _args: WriteArgs,
+        _selected_fields: FieldSelection,
+        _order_by: Vec<OrderBy>,
+        _limit: Option<usize>,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<ManyRecords> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn update_record(
+        &mut self,
+        _model: &Model,
+        _record_filter: RecordFilter,
+        _args: WriteArgs,
+        _selected_fields: Option<FieldSelection>,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<Option<SingleRecord>> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn native_upsert_record(
+        &mut self,
+        _upsert: connector::NativeUpsert,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<SingleRecord> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn delete_records(
+        &mut self,
+        _model: &Model,
+        _record_filter: RecordFilter,
+        _order_by: Vec<OrderBy>,
+        _limit: Option<usize>,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<usize> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn delete_record(
+        &mut self,
+        _model: &Model,
+        _record_filter: RecordFilter,
+        _selected_fields: FieldSelection,
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<SingleRecord> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn m2m_connect(
+        &mut self,
+        _field: &RelationFieldRef,
+        _parent_id: &SelectionResult,
+        _child_ids: &[SelectionResult],
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<usize> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn m2m_disconnect(
+        &mut self,
+        _field: &RelationFieldRef,
+        _parent_id: &SelectionResult,
+        _child_ids: &[SelectionResult],
+        _traceparent: Option<TraceParent>,
+    ) -> connector::Result<()> {
+        unreachable!("not exercised by these tests")
+    }
+
+    async fn execute_raw(&mut self, _inputs: HashMap<String, query_structure::PrismaValue>) -> connector::Result<usize> {
+        self.calls += 1;
+
+        if self.calls <= self.fail_times {
+            return Err(Self::transient_error());
+        }
+
+        Ok(self.calls as usize)
+    }
+
+    async fn query_raw(
+        &mut self,
+        _model: Option<&Model>,
+        _inputs: HashMap<String, query_structure::PrismaValue>,
+        _query_type: Option<String>,
+    ) -> connector::Result<query_structure::RawJson> {
+        unreachable!("not exercised by these tests")
+    }
+}
+
+#[cfg(test)]
+impl ConnectionLike for FlakyConnection {}
+
+#[cfg(test)]
+fn execute_raw_query() -> Expression {
+    Expression::Query {
+        node_id: "0".to_owned(),
+        query: Box::new(Query::Write(WriteQuery::ExecuteRaw(crate::RawQuery {
+            model: None,
+            inputs: HashMap::new(),
+            query_type: None,
+        }))),
+        path: None,
+    }
+}
+
+/// Outside of any transaction, a failed query node is retried in place and the operation succeeds
+/// once the underlying connector recovers.
+#[tokio::test]
+async fn transient_error_is_retried_outside_a_transaction() {
+    let mut conn = FlakyConnection::new(1);
+    let mut interpreter = QueryInterpreter::new(&mut conn, true, false);
+
+    let result = interpreter
+        .interpret(execute_raw_query(), Env::default(), 0, None)
+        .await
+        .unwrap();
+
+    assert!(matches!(result, ExpressionResult::Query(QueryResult::RawJson(_))));
+    assert_eq!(conn.calls, 2, "should fail once and then succeed on retry");
+}
+
+/// Inside an engine-managed or client-managed transaction, the same failure is not retried: the
+/// transaction may already be poisoned by it, so the error propagates and the caller has to roll
+/// back and start over instead.
+#[tokio::test]
+async fn transient_error_is_not_retried_inside_a_transaction() {
+    let mut conn = FlakyConnection::new(1);
+    let mut interpreter = QueryInterpreter::new(&mut conn, true, true);
+
+    let result = interpreter.interpret(execute_raw_query(), Env::default(), 0, None).await;
+
+    assert!(result.is_err());
+    assert_eq!(conn.calls, 1, "should give up after the first failure");
+}
+
+#[test]
+fn get_first_non_empty_returns_default_when_every_binding_is_empty() {
+    let mut env = Env::default();
+    env.insert("a".to_owned(), ExpressionResult::Empty);
+    env.insert("b".to_owned(), ExpressionResult::Empty);
+
+    let default = ExpressionResult::FixedResult(vec![]);
+    let binding_names = ["a".to_owned(), "b".to_owned()];
+
+    let result = resolve_first_non_empty(&mut env, &binding_names, Some(default)).unwrap();
+
+    assert!(matches!(result, ExpressionResult::FixedResult(rows) if rows.is_empty()));
+}
+
+#[test]
+fn get_first_non_empty_prefers_the_first_non_empty_binding() {
+    let mut env = Env::default();
+    env.insert("a".to_owned(), ExpressionResult::Empty);
+    env.insert("b".to_owned(), ExpressionResult::FixedResult(vec![]));
+
+    let binding_names = ["a".to_owned(), "b".to_owned()];
+
+    let result = resolve_first_non_empty(&mut env, &binding_names, None).unwrap();
+
+    assert!(matches!(result, ExpressionResult::FixedResult(_)));
+    assert!(env.get("b").is_none(), "the chosen binding should be consumed");
+}
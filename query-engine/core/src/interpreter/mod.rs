@@ -1,11 +1,16 @@
+mod compensation;
 mod error;
 mod expression;
 mod expressionista;
 mod interpreter_impl;
 mod query_interpreters;
+mod retry;
+mod timings;
 
+pub(crate) use compensation::CompensationLog;
 pub(crate) use error::*;
 pub(crate) use expressionista::*;
 pub(crate) use interpreter_impl::*;
+pub(crate) use timings::{NodeTiming, PlanTimings};
 
 type InterpretationResult<T> = std::result::Result<T, InterpreterError>;
@@ -309,6 +309,7 @@ fn record_not_found() -> InterpretationResult<QueryResult> {
         ),
         kind: connector::error::ErrorKind::RecordDoesNotExist { cause },
         transient: false,
+        chunked_write_progress: None,
     }
     .into())
 }
@@ -89,6 +89,22 @@ async fn create_many(
         };
 
         Ok(QueryResult::RecordSelection(Some(Box::new(selection))))
+    } else if q.collect_errors {
+        let report = tx
+            .create_records_collecting_errors(&q.model, q.args, traceparent)
+            .await?;
+        let inserted = report.inserted;
+
+        Ok(QueryResult::CountWithConflicts(inserted, report))
+    } else if q.return_skipped {
+        let (affected_records, report) = tx
+            .create_records_with_skip_report(&q.model, q.args, q.skip_duplicates, traceparent)
+            .await?;
+
+        Ok(match report {
+            Some(report) => QueryResult::CountWithSkipped(affected_records, report),
+            None => QueryResult::Count(affected_records),
+        })
     } else {
         let affected_records = tx
             .create_records(&q.model, q.args, q.skip_duplicates, traceparent)
@@ -152,14 +168,91 @@ async fn create_many_split_by_shape(
         };
 
         Ok(QueryResult::RecordSelection(Some(Box::new(selection))))
+    } else if q.collect_errors {
+        let mut inserted: usize = 0;
+        let mut conflicts = Vec::new();
+        let mut conflict_count: usize = 0;
+        let mut truncated = false;
+
+        for args in split_write_args_by_shape(&q.model, q.args) {
+            let batch = tx.create_records_collecting_errors(&q.model, args, traceparent).await?;
+
+            inserted = inserted.checked_add(batch.inserted).ok_or_else(|| {
+                InterpreterError::Generic(
+                    "Affected row count overflowed while summing `createMany` batches".to_owned(),
+                )
+            })?;
+            conflicts.extend(batch.conflicts);
+            conflict_count += batch.conflict_count;
+            truncated |= batch.truncated;
+        }
+
+        truncated |= conflicts.len() > connector::MAX_REPORTED_CREATE_MANY_CONFLICTS;
+        conflicts.truncate(connector::MAX_REPORTED_CREATE_MANY_CONFLICTS);
+
+        Ok(QueryResult::CountWithConflicts(
+            inserted,
+            connector::CreateManyErrorReport {
+                inserted,
+                conflicts,
+                conflict_count,
+                truncated,
+            },
+        ))
+    } else if q.return_skipped {
+        let mut inserted: usize = 0;
+        let mut skipped = Vec::new();
+        let mut skipped_count: usize = 0;
+        let mut truncated = false;
+        let mut any_report = false;
+
+        for args in split_write_args_by_shape(&q.model, q.args) {
+            let (batch_inserted, batch_report) = tx
+                .create_records_with_skip_report(&q.model, args, q.skip_duplicates, traceparent)
+                .await?;
+
+            inserted = inserted.checked_add(batch_inserted).ok_or_else(|| {
+                InterpreterError::Generic(
+                    "Affected row count overflowed while summing `createMany` batches".to_owned(),
+                )
+            })?;
+
+            if let Some(batch_report) = batch_report {
+                any_report = true;
+                skipped.extend(batch_report.skipped);
+                skipped_count += batch_report.skipped_count;
+                truncated |= batch_report.truncated;
+            }
+        }
+
+        Ok(if any_report {
+            truncated |= skipped.len() > connector::MAX_REPORTED_SKIPPED_ROWS;
+            skipped.truncate(connector::MAX_REPORTED_SKIPPED_ROWS);
+
+            QueryResult::CountWithSkipped(
+                inserted,
+                connector::SkipDuplicatesReport {
+                    inserted,
+                    skipped,
+                    skipped_count,
+                    truncated,
+                },
+            )
+        } else {
+            QueryResult::Count(inserted)
+        })
     } else {
-        let mut result = 0;
+        let mut result: usize = 0;
 
         for args in split_write_args_by_shape(&q.model, q.args) {
             let affected_records = tx
                 .create_records(&q.model, args, q.skip_duplicates, traceparent)
                 .await?;
-            result += affected_records;
+            result = result.checked_add(affected_records).ok_or_else(|| {
+                InterpreterError::Generic(
+                    "Affected row count overflowed while summing `createMany` batches".to_owned(),
+                )
+            })?;
         }
 
         Ok(QueryResult::Count(result))
@@ -247,7 +340,7 @@ async fn delete_one(
 
         Ok(QueryResult::RecordSelection(Some(Box::new(selection))))
     } else {
-        let result = tx.delete_records(&q.model, filter, None, traceparent).await?;
+        let result = tx.delete_records(&q.model, filter, vec![], None, traceparent).await?;
         Ok(QueryResult::Count(result))
     }
 }
@@ -264,6 +357,7 @@ async fn update_many(
                 q.record_filter,
                 q.args,
                 selected_fields.fields,
+                q.order_by,
                 q.limit,
                 traceparent,
             )
@@ -283,11 +377,22 @@ async fn update_many(
 
         Ok(QueryResult::RecordSelection(Some(Box::new(selection))))
     } else {
-        let affected_records = tx
-            .update_records(&q.model, q.record_filter, q.args, q.limit, traceparent)
+        let result = tx
+            .update_records_with_chunks(
+                &q.model,
+                q.record_filter,
+                q.args,
+                q.order_by,
+                q.limit,
+                q.chunk_execution_policy,
+                traceparent,
+            )
             .await?;
 
-        Ok(QueryResult::Count(affected_records))
+        Ok(match result.chunks {
+            Some(chunks) => QueryResult::CountWithChunks(result.count, chunks),
+            None => QueryResult::Count(result.count),
+        })
     }
 }
 
@@ -296,11 +401,21 @@ async fn delete_many(
     q: DeleteManyRecords,
     traceparent: Option<TraceParent>,
 ) -> InterpretationResult<QueryResult> {
-    let res = tx
-        .delete_records(&q.model, q.record_filter, q.limit, traceparent)
+    let result = tx
+        .delete_records_with_chunks(
+            &q.model,
+            q.record_filter,
+            q.order_by,
+            q.limit,
+            q.chunk_execution_policy,
+            traceparent,
+        )
         .await?;
 
-    Ok(QueryResult::Count(res))
+    Ok(match result.chunks {
+        Some(chunks) => QueryResult::CountWithChunks(result.count, chunks),
+        None => QueryResult::Count(result.count),
+    })
 }
 
 async fn connect(
@@ -308,15 +423,21 @@ async fn connect(
     q: ConnectRecords,
     traceparent: Option<TraceParent>,
 ) -> InterpretationResult<QueryResult> {
-    tx.m2m_connect(
-        &q.relation_field,
-        &q.parent_id.expect("Expected parent record ID to be set for connect"),
-        &q.child_ids,
-        traceparent,
-    )
-    .await?;
+    let return_affected_count = q.return_affected_count;
+    let count = tx
+        .m2m_connect(
+            &q.relation_field,
+            &q.parent_id.expect("Expected parent record ID to be set for connect"),
+            &q.child_ids,
+            traceparent,
+        )
+        .await?;
 
-    Ok(QueryResult::Unit)
+    if return_affected_count {
+        Ok(QueryResult::Count(count))
+    } else {
+        Ok(QueryResult::Unit)
+    }
 }
 
 async fn disconnect(
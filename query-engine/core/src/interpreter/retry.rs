@@ -0,0 +1,113 @@
+use super::InterpreterError;
+use std::time::Duration;
+
+/// What the interpreter should do after a failed [`crate::interpreter::expression::Expression::Query`]
+/// node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RetryDecision {
+    /// Retry the node after waiting the given duration.
+    RetryAfter(Duration),
+    /// Give up and propagate the error to the caller.
+    GiveUp,
+}
+
+/// Decides whether a failed query node is worth retrying, and how long to wait before doing so.
+pub(crate) trait RetryPolicy: Send + Sync {
+    /// `attempts` is the number of attempts already made for this node, not counting the one that
+    /// just failed with `err`.
+    fn retry_on(&self, err: &InterpreterError, attempts: u32) -> RetryDecision;
+}
+
+/// Retries transient connector errors (serialization failures, deadlocks) with exponential
+/// backoff, up to `max_attempts` retries.
+pub(crate) struct TransientErrorRetryPolicy {
+    max_attempts: u32,
+    base_backoff: Duration,
+}
+
+impl Default for TransientErrorRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_backoff: Duration::from_millis(5),
+        }
+    }
+}
+
+impl RetryPolicy for TransientErrorRetryPolicy {
+    fn retry_on(&self, err: &InterpreterError, attempts: u32) -> RetryDecision {
+        if attempts >= self.max_attempts || !err.is_transient() {
+            return RetryDecision::GiveUp;
+        }
+
+        RetryDecision::RetryAfter(self.base_backoff * 2u32.pow(attempts))
+    }
+}
+
+/// Used for queries running inside an interactive transaction. A retry there would replay a
+/// statement against a transaction that the client already observed failing, which may have left
+/// it poisoned (e.g. Postgres aborts the whole transaction on error until it's rolled back), so
+/// retries must stay disabled for the entire duration of the transaction.
+pub(crate) struct NoRetryPolicy;
+
+impl RetryPolicy for NoRetryPolicy {
+    fn retry_on(&self, _err: &InterpreterError, _attempts: u32) -> RetryDecision {
+        RetryDecision::GiveUp
+    }
+}
+
+#[cfg(test)]
+fn transient_error() -> InterpreterError {
+    use connector::error::{ConnectorError, ErrorKind};
+
+    InterpreterError::ConnectorError(ConnectorError::from_kind(ErrorKind::TransactionWriteConflict))
+}
+
+#[cfg(test)]
+fn non_transient_error() -> InterpreterError {
+    InterpreterError::Generic("not a connector error".to_owned())
+}
+
+#[test]
+fn transient_error_retries_until_max_attempts() {
+    let policy = TransientErrorRetryPolicy::default();
+
+    assert!(matches!(
+        policy.retry_on(&transient_error(), 0),
+        RetryDecision::RetryAfter(_)
+    ));
+    assert!(matches!(
+        policy.retry_on(&transient_error(), 1),
+        RetryDecision::RetryAfter(_)
+    ));
+    assert!(matches!(
+        policy.retry_on(&transient_error(), 2),
+        RetryDecision::RetryAfter(_)
+    ));
+    assert_eq!(policy.retry_on(&transient_error(), 3), RetryDecision::GiveUp);
+}
+
+#[test]
+fn transient_error_retry_backs_off_exponentially() {
+    let policy = TransientErrorRetryPolicy::default();
+
+    let RetryDecision::RetryAfter(first) = policy.retry_on(&transient_error(), 0) else {
+        panic!("expected a retry");
+    };
+    let RetryDecision::RetryAfter(second) = policy.retry_on(&transient_error(), 1) else {
+        panic!("expected a retry");
+    };
+
+    assert_eq!(second, first * 2);
+}
+
+#[test]
+fn non_transient_error_is_never_retried() {
+    let policy = TransientErrorRetryPolicy::default();
+    assert_eq!(policy.retry_on(&non_transient_error(), 0), RetryDecision::GiveUp);
+}
+
+#[test]
+fn no_retry_policy_always_gives_up() {
+    assert_eq!(NoRetryPolicy.retry_on(&transient_error(), 0), RetryDecision::GiveUp);
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+/// Wall-clock timing and row-count data for one executed `Expression::Query`/`Func` node,
+/// attributed by the node's binding name (the same id `Expressionista` assigns it as a `Let`
+/// binding). Collected by [`super::QueryInterpreter`] when timing collection is enabled.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NodeTiming {
+    pub node_id: String,
+    pub duration: Duration,
+    pub affected_row_count: Option<usize>,
+    pub returned_row_count: Option<usize>,
+}
+
+/// Per-node execution timings for a single interpreted plan, for attributing slow queries to a
+/// specific node of a (possibly deeply nested) write. Empty unless the interpreter that produced
+/// it was built with timing collection enabled.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct PlanTimings {
+    pub nodes: Vec<NodeTiming>,
+}
+
+impl PlanTimings {
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+#[test]
+fn plan_timings_is_empty_until_a_node_is_pushed() {
+    let mut timings = PlanTimings::default();
+    assert!(timings.is_empty());
+
+    timings.nodes.push(NodeTiming {
+        node_id: "0".to_owned(),
+        duration: Duration::from_millis(5),
+        affected_row_count: Some(1),
+        returned_row_count: None,
+    });
+
+    assert!(!timings.is_empty());
+}
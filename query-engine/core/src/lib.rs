@@ -15,6 +15,7 @@ pub use self::{
     error::{CoreError, ExtendedUserFacingError, FieldConversionError},
     executor::{with_sync_unevaluated_request_context, QueryExecutor, TransactionOptions},
     interactive_transactions::{TransactionError, TxId},
+    interpreter::{CompensationLog, NodeTiming, PlanTimings},
     query_ast::*,
     query_document::*,
     query_graph::*,
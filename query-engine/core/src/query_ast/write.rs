@@ -2,7 +2,7 @@
 use super::{FilteredNestedMutation, FilteredQuery};
 use crate::{ReadQuery, RecordQuery, ToGraphviz};
 use connector::NativeUpsert;
-use query_structure::{prelude::*, Filter, RecordFilter, WriteArgs};
+use query_structure::{prelude::*, ChunkExecutionPolicy, Filter, RecordFilter, WriteArgs};
 use std::{borrow::Cow, collections::HashMap, slice};
 
 #[derive(Debug, Clone)]
@@ -259,6 +259,14 @@ pub struct CreateManyRecords {
     pub model: Model,
     pub args: Vec<WriteArgs>,
     pub skip_duplicates: bool,
+    /// If set, per-row unique/null constraint conflicts are reported instead of aborting the
+    /// whole batch on the first one. Only meaningful when `selected_fields` is `None`: a
+    /// `createMany...AndReturn` has no way to both report a row as failed and return it.
+    pub collect_errors: bool,
+    /// If set, rows skipped by `skip_duplicates` are reported individually instead of only being
+    /// reflected in the affected-row count. Only meaningful when `selected_fields` is `None`, for
+    /// the same reason as `collect_errors`.
+    pub return_skipped: bool,
     /// Fields of created records that client has requested to return.
     /// `None` if the connector does not support returning the created rows.
     pub selected_fields: Option<CreateManyRecordsFields>,
@@ -359,6 +367,10 @@ pub struct UpdateManyRecords {
     /// `None` if the connector does not support returning the updated rows.
     pub selected_fields: Option<UpdateManyRecordsFields>,
     pub limit: Option<usize>,
+    pub order_by: Vec<OrderBy>,
+    /// How to behave when this update has to be split into more than one statement and one of
+    /// them fails partway through.
+    pub chunk_execution_policy: ChunkExecutionPolicy,
 }
 
 #[derive(Debug, Clone)]
@@ -389,6 +401,10 @@ pub struct DeleteManyRecords {
     pub model: Model,
     pub record_filter: RecordFilter,
     pub limit: Option<usize>,
+    pub order_by: Vec<OrderBy>,
+    /// How to behave when this delete has to be split into more than one statement and one of
+    /// them fails partway through.
+    pub chunk_execution_policy: ChunkExecutionPolicy,
 }
 
 #[derive(Debug, Clone)]
@@ -396,6 +412,10 @@ pub struct ConnectRecords {
     pub parent_id: Option<SelectionResult>,
     pub child_ids: Vec<SelectionResult>,
     pub relation_field: RelationFieldRef,
+    /// If `true`, the query result carries the number of links actually created (as opposed to
+    /// already existing) instead of being discarded. Off by default since most callers (nested
+    /// connects) have no use for it.
+    pub return_affected_count: bool,
 }
 
 #[derive(Debug, Clone)]
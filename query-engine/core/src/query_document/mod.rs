@@ -60,7 +60,9 @@ impl QueryDocument {
 #[derive(Debug)]
 pub enum BatchDocument {
     Multi(Vec<Operation>, Option<BatchDocumentTransaction>),
-    Compact(CompactedDocument),
+    /// One [`CompactedDocument`] per distinct model/shape found in the batch (see
+    /// [`BatchDocument::compact`]), in first-seen order.
+    Compact(Vec<CompactedDocument>),
 }
 
 impl BatchDocument {
@@ -105,31 +107,16 @@ impl BatchDocument {
     }
 
     /// Checks whether a BatchDocument can be compacted.
+    ///
+    /// Every operation must be a compactable `findUnique` (`invalid_compact_filter` already
+    /// requires that of each one), but they no longer need to all target the same model/shape:
+    /// `compact` groups those separately, so a batch mixing e.g. `findUnique` on `User` and `Org`
+    /// still compacts, just into one `findMany` per model instead of one for the whole batch.
     fn can_compact(&self, schema: &QuerySchema) -> bool {
         match self {
             Self::Multi(operations, _) => match operations.split_first() {
-                Some((first, rest)) if first.is_find_unique(schema) => {
-                    // If any of the operation has an "invalid" compact filter (see documentation of `invalid_compact_filter`),
-                    // we do not compact the queries.
-                    let has_invalid_compact_filter =
-                        operations.iter().any(|op| Self::invalid_compact_filter(op, schema));
-
-                    if has_invalid_compact_filter {
-                        return false;
-                    }
-
-                    let first_rls = first.argument(args::RELATION_LOAD_STRATEGY);
-
-                    rest.iter().all(|op| {
-                        op.is_find_unique(schema)
-                            && first.name() == op.name()
-                            && first.nested_selections().len() == op.nested_selections().len()
-                            && first_rls == op.argument(args::RELATION_LOAD_STRATEGY)
-                            && first
-                                .nested_selections()
-                                .iter()
-                                .all(|fop| op.nested_selections().contains(fop))
-                    })
+                Some((first, _)) if first.is_find_unique(schema) => {
+                    !operations.iter().any(|op| Self::invalid_compact_filter(op, schema))
                 }
                 _ => false,
             },
@@ -140,7 +127,7 @@ impl BatchDocument {
     pub fn compact(self, schema: &QuerySchema) -> Self {
         match self {
             Self::Multi(operations, _) if self.can_compact(schema) => {
-                Self::Compact(CompactedDocument::from_operations(operations, schema))
+                Self::Compact(CompactedDocument::compact_by_shape(operations, schema))
             }
             _ => self,
         }
@@ -175,6 +162,10 @@ pub struct CompactedDocument {
     pub operation: Operation,
     pub keys: Vec<String>,
     pub original_query_options: crate::QueryOptions,
+    /// For each entry in `arguments` (in the same order), its index in the original,
+    /// pre-grouping batch. Lets a caller that dispatches one [`CompactedDocument`] per model/shape
+    /// put every individual result back where the client expects it in the overall batch response.
+    pub original_indices: Vec<usize>,
     name: String,
 }
 
@@ -195,8 +186,40 @@ impl CompactedDocument {
         format!("findMany{}", self.name)
     }
 
+    /// Groups `operations` by model/shape (same query name, relation load strategy and nested
+    /// selections - the same criteria `BatchDocument::can_compact` used to require of the *whole*
+    /// batch), preserving first-seen group order, and compacts each group independently. Callers
+    /// (e.g. a request handler) can dispatch the resulting documents concurrently and use
+    /// [`CompactedDocument::original_indices`] to restore the original batch order.
+    fn compact_by_shape(operations: Vec<Operation>, schema: &QuerySchema) -> Vec<Self> {
+        let mut groups: Vec<(Vec<usize>, Vec<Operation>)> = Vec::new();
+
+        for (index, op) in operations.into_iter().enumerate() {
+            let matching_group = groups.iter_mut().find(|(_, ops)| {
+                let first = &ops[0];
+                first.name() == op.name()
+                    && first.argument(args::RELATION_LOAD_STRATEGY) == op.argument(args::RELATION_LOAD_STRATEGY)
+                    && first.nested_selections().len() == op.nested_selections().len()
+                    && first.nested_selections().iter().all(|fop| op.nested_selections().contains(fop))
+            });
+
+            match matching_group {
+                Some((indices, ops)) => {
+                    indices.push(index);
+                    ops.push(op);
+                }
+                None => groups.push((vec![index], vec![op])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(indices, ops)| Self::from_operations(ops, indices, schema))
+            .collect()
+    }
+
     /// Here be the dragons. Ay caramba!
-    pub fn from_operations(ops: Vec<Operation>, schema: &QuerySchema) -> Self {
+    fn from_operations(ops: Vec<Operation>, original_indices: Vec<usize>, schema: &QuerySchema) -> Self {
         let field = schema.find_query_field(ops.first().unwrap().name()).unwrap();
         let model = schema.internal_data_model.clone().zip(field.model().unwrap());
         // Unpack all read queries (an enum) into a collection of selections.
@@ -315,6 +338,7 @@ impl CompactedDocument {
             nested_selection,
             keys,
             original_query_options,
+            original_indices,
         }
     }
 }
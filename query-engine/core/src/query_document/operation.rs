@@ -1,8 +1,14 @@
 use super::Selection;
 use crate::ArgumentValue;
 use schema::QuerySchema;
+use serde::Serialize;
 
-#[derive(Debug, Clone)]
+/// The root of a single query: a read or write [`Selection`], built either by parsing a query
+/// document from the GraphQL/JSON protocol, or directly via [`Selection::with_name`] and friends.
+/// [`crate::QueryGraphBuilder::build`] takes either the same way, so constructing one by hand is a
+/// supported way to skip protocol parsing entirely - the schema validation it does happens while
+/// building the query graph either way.
+#[derive(Debug, Clone, Serialize)]
 pub enum Operation {
     Read(Selection),
     Write(Selection),
@@ -395,6 +395,7 @@ impl QueryDocumentParser {
             (PrismaValue::String(s), ScalarType::JsonList) => {
                 self.parse_json_list_from_str(selection_path, argument_path, &s)
             }
+            (PrismaValue::String(s), ScalarType::Int) => self.parse_int(selection_path, argument_path, s),
             (PrismaValue::String(s), ScalarType::Bytes) => self.parse_bytes(selection_path, argument_path, s),
             (PrismaValue::String(s), ScalarType::Decimal) => self.parse_decimal(selection_path, argument_path, s),
             (PrismaValue::String(s), ScalarType::BigInt) => self.parse_bigint(selection_path, argument_path, s),
@@ -509,6 +510,20 @@ impl QueryDocumentParser {
         })
     }
 
+    /// Only coerces a string into an `Int` when the whole string parses, e.g. `"123"`, never a
+    /// prefix of it like `"123abc"`.
+    fn parse_int(&self, selection_path: &Path, argument_path: &Path, value: String) -> QueryParserResult<PrismaValue> {
+        value.parse::<i64>().map(PrismaValue::Int).map_err(|err| {
+            ValidationError::invalid_argument_value(
+                selection_path.segments(),
+                argument_path.segments(),
+                value,
+                "integer String",
+                Some(Box::new(err)),
+            )
+        })
+    }
+
     fn parse_json_list_from_str(
         &self,
         selection_path: &Path,
@@ -701,6 +716,15 @@ impl QueryDocumentParser {
                                 {
                                     default_now.clone()
                                 }
+                                // The connector can generate `uuid(7)` itself (e.g. Postgres with
+                                // the `pg_uuidv7` extension declared in the datasource); leave the
+                                // field unset so the SQL builder inlines the database-side function
+                                // call instead of an engine-generated value.
+                                DefaultKind::Expression(ref expr)
+                                    if expr.is_uuid_v7() && query_schema.can_generate_uuid_v7_server_side() =>
+                                {
+                                    return None;
+                                }
                                 _ => default_value.get_evaluated()?,
                             },
                             Self::WithoutEagerDefaultEvaluation => default_value.get()?,
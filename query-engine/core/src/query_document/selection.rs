@@ -2,11 +2,23 @@ use crate::{ArgumentValue, ArgumentValueObject};
 use indexmap::IndexMap;
 use itertools::Itertools;
 use schema::constants::filters;
+use serde::Serialize;
 use std::iter;
 
 pub type SelectionArgument = (String, ArgumentValue);
 
-#[derive(Clone)]
+/// A single selected field in an [`crate::Operation`], together with its arguments and nested
+/// selections.
+///
+/// Constructing one of these directly (via [`Selection::with_name`]/[`Selection::new`] and the
+/// `push_*` methods below) instead of going through a query document parsed from the GraphQL or
+/// JSON protocol is a supported way to build an [`crate::Operation`]: [`QueryGraphBuilder`](crate::QueryGraphBuilder)
+/// validates field and argument names against the [`schema::QuerySchema`] the same way either
+/// way, so a hand-built `Selection` that names a field the schema doesn't have still produces the
+/// usual schema-validation error rather than a panic. `Serialize` is derived for callers that want
+/// to log, cache, or otherwise inspect a `Selection` tree; it isn't the wire format either
+/// protocol parses from, so there's no matching `Deserialize`.
+#[derive(Clone, Serialize)]
 pub struct Selection {
     name: String,
     alias: Option<String>,
@@ -42,7 +54,7 @@ impl std::fmt::Debug for Selection {
 }
 
 /// Represents a field that's excluded.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Exclusion {
     pub name: String,
 }
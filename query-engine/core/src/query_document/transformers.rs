@@ -7,7 +7,7 @@
 use super::*;
 use bigdecimal::ToPrimitive;
 use chrono::prelude::*;
-use query_structure::{OrderBy, PrismaValue, RelationLoadStrategy, ScalarFieldRef};
+use query_structure::{ChunkExecutionPolicy, OrderBy, PrismaValue, RelationLoadStrategy, ScalarFieldRef};
 use std::convert::TryInto;
 use user_facing_errors::query_engine::validation::ValidationError;
 
@@ -229,3 +229,20 @@ impl<'a> TryFrom<ParsedInputValue<'a>> for RelationLoadStrategy {
         }
     }
 }
+
+impl<'a> TryFrom<ParsedInputValue<'a>> for ChunkExecutionPolicy {
+    type Error = ValidationError;
+
+    fn try_from(value: ParsedInputValue<'a>) -> QueryParserResult<ChunkExecutionPolicy> {
+        let prisma_value = PrismaValue::try_from(value)?;
+
+        match prisma_value {
+            PrismaValue::Enum(e) if e == chunk_execution_policy::ATOMIC => Ok(ChunkExecutionPolicy::Atomic),
+            PrismaValue::Enum(e) if e == chunk_execution_policy::BEST_EFFORT => Ok(ChunkExecutionPolicy::BestEffort),
+            PrismaValue::Enum(e) if e == chunk_execution_policy::FAIL_FAST => Ok(ChunkExecutionPolicy::FailFast),
+            v => Err(ValidationError::unexpected_runtime_error(format!(
+                "Attempted conversion of ParsedInputValue ({v:?}) into chunk execution policy enum value failed."
+            ))),
+        }
+    }
+}
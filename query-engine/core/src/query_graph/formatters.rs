@@ -66,6 +66,7 @@ impl Display for Computation {
         match self {
             Self::DiffLeftToRight(_) => write!(f, "DiffLeftToRight"),
             Self::DiffRightToLeft(_) => write!(f, "DiffRightToLeft"),
+            Self::SymmetricDiff(_) => write!(f, "SymmetricDiff"),
         }
     }
 }
@@ -3,7 +3,7 @@ mod formatters;
 mod guard;
 mod transformers;
 
-use std::fmt;
+use std::{collections::HashMap, fmt};
 
 pub use error::*;
 use psl::datamodel_connector::{ConnectorCapabilities, ConnectorCapability};
@@ -21,6 +21,7 @@ use petgraph::{
     visit::{EdgeRef as PEdgeRef, NodeIndexable},
     *,
 };
+use query_builder::Warning;
 use query_structure::{FieldSelection, Filter, IntoFilter, QueryArguments, SelectionResult, WriteArgs};
 
 pub type QueryGraphResult<T> = std::result::Result<T, QueryGraphError>;
@@ -95,12 +96,23 @@ impl Flow {
             data: Vec::new(),
         }
     }
+
+    pub fn if_true() -> Self {
+        Self::If {
+            rule: DataRule::Always,
+            data: Vec::new(),
+        }
+    }
 }
 
 // Current limitation: We need to narrow it down to ID diffs for Hash and EQ.
 pub enum Computation {
     DiffLeftToRight(DiffNode),
     DiffRightToLeft(DiffNode),
+
+    /// Computes both directions of a diff at once, e.g. for relation sync where the same pair of
+    /// sets needs to know what was added (`right - left`) and what was removed (`left - right`).
+    SymmetricDiff(DiffNode),
 }
 
 impl Computation {
@@ -111,6 +123,10 @@ impl Computation {
     pub fn empty_diff_right_to_left() -> Self {
         Self::DiffRightToLeft(DiffNode::default())
     }
+
+    pub fn empty_symmetric_diff() -> Self {
+        Self::SymmetricDiff(DiffNode::default())
+    }
 }
 
 #[derive(Default)]
@@ -281,6 +297,8 @@ pub enum DataRule {
     AffectedRowCountEq(usize),
     /// Expect the edge to not be taken and never match any data.
     Never,
+    /// Expect the edge to always be taken, regardless of the data it depends on.
+    Always,
 }
 
 impl DataRule {
@@ -290,6 +308,18 @@ impl DataRule {
             Self::RowCountNeq(expected) => result.returned_row_count().is_some_and(|count| count != *expected),
             Self::AffectedRowCountEq(expected) => result.affected_row_count().is_some_and(|count| count == *expected),
             Self::Never => false,
+            Self::Always => true,
+        }
+    }
+
+    /// Returns `Some(bool)` when the rule doesn't actually depend on the data it's evaluated
+    /// against, so that the expressionista can fold away the branch that's never taken instead of
+    /// emitting a runtime check for it.
+    pub fn as_constant(&self) -> Option<bool> {
+        match self {
+            Self::Never => Some(false),
+            Self::Always => Some(true),
+            Self::RowCountEq(_) | Self::RowCountNeq(_) | Self::AffectedRowCountEq(_) => None,
         }
     }
 }
@@ -301,6 +331,7 @@ impl fmt::Display for DataRule {
             Self::RowCountNeq(expected) => write!(f, "rowCountNeq {expected}"),
             Self::AffectedRowCountEq(expected) => write!(f, "affectedRowCountEq {expected}"),
             Self::Never => write!(f, "never"),
+            Self::Always => write!(f, "always"),
         }
     }
 }
@@ -357,6 +388,15 @@ pub struct QueryGraph {
     /// Nodes are visited during query graph processing.
     /// Influences traversal rules and how child nodes are treated.
     visited: Vec<NodeIndex>,
+
+    /// Warnings about plan decisions made while building the graph (e.g. falling back to an
+    /// emulated upsert), surfaced by `query_compiler::compile_with_diagnostics`.
+    diagnostics: Vec<Warning>,
+
+    /// The input path a node was built from (e.g. `data.orders.create[2]`), recorded for nodes
+    /// built from a nested write so failures can be attributed to the part of the input that
+    /// caused them. Not every node has a path: only (nested) write operations track one.
+    node_paths: HashMap<NodeIndex, String>,
 }
 
 impl fmt::Debug for QueryGraph {
@@ -368,6 +408,8 @@ impl fmt::Debug for QueryGraph {
             .field("finalized", &self.finalized)
             .field("needs_transaction", &self.needs_transaction)
             .field("visited", &self.visited)
+            .field("diagnostics", &self.diagnostics)
+            .field("node_paths", &self.node_paths)
             .finish()
     }
 }
@@ -499,6 +541,27 @@ impl QueryGraph {
         self.needs_transaction
     }
 
+    /// Records a warning about a plan decision made while building the graph.
+    pub(crate) fn flag_diagnostic(&mut self, warning: Warning) {
+        self.diagnostics.push(warning);
+    }
+
+    /// Records the input path a node was built from (e.g. `data.orders.create[2]`), so that a
+    /// failure on that node can later be attributed to the part of the input that caused it.
+    pub(crate) fn set_node_path(&mut self, node: &NodeRef, path: impl Into<String>) {
+        self.node_paths.insert(node.node_ix, path.into());
+    }
+
+    /// The input path the given node was built from, if one was recorded.
+    pub(crate) fn node_path(&self, node: &NodeRef) -> Option<&str> {
+        self.node_paths.get(&node.node_ix).map(String::as_str)
+    }
+
+    /// Warnings about plan decisions made while building this graph.
+    pub fn diagnostics(&self) -> &[Warning] {
+        &self.diagnostics
+    }
+
     /// Returns a reference to the content of `node`, if the content is still present.
     pub fn node_content(&self, node: &NodeRef) -> Option<&Node> {
         self.graph.node_weight(node.node_ix).unwrap().borrow()
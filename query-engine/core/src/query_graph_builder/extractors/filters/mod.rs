@@ -36,7 +36,7 @@ pub fn extract_unique_filter(value_map: ParsedInputMap<'_>, model: &Model) -> Qu
     let unique_filters = internal_extract_unique_filter(unique_map, model)?;
     let rest_filters = extract_filter(rest_map, model)?;
 
-    Ok(Filter::and(vec![unique_filters, rest_filters]))
+    Ok(Filter::and(vec![unique_filters, rest_filters]).normalize())
 }
 
 /// Extracts a filter for a unique selector, i.e. a filter that selects exactly one record.
@@ -186,7 +186,9 @@ where
     let filter = extract_filter(value_map, &container, 0)?;
     let filter = merge_search_filters(filter);
 
-    Ok(filter)
+    // Normalize once here so every caller - scalar filters, nested relation filters, writes -
+    // hands the builders a canonical tree, instead of each of them remembering to do it.
+    Ok(filter.normalize())
 }
 
 /// Search filters that have the same query and that are in the same condition block
@@ -161,6 +161,21 @@ impl<'a> ScalarFilterParser<'a> {
             filters::SEARCH if self.reverse() => Ok(vec![field.not_search(self.as_condition_value(input, false)?)]),
             filters::SEARCH => Ok(vec![field.search(self.as_condition_value(input, false)?)]),
 
+            filters::ANCESTOR_OF if self.reverse() => {
+                Ok(vec![field.not_ancestor_of(self.as_ltree_path_value(input)?)])
+            }
+            filters::ANCESTOR_OF => Ok(vec![field.ancestor_of(self.as_ltree_path_value(input)?)]),
+
+            filters::DESCENDANT_OF if self.reverse() => {
+                Ok(vec![field.not_descendant_of(self.as_ltree_path_value(input)?)])
+            }
+            filters::DESCENDANT_OF => Ok(vec![field.descendant_of(self.as_ltree_path_value(input)?)]),
+
+            filters::MATCHES_LQUERY if self.reverse() => {
+                Ok(vec![field.not_matches_lquery(self.as_lquery_value(input)?)])
+            }
+            filters::MATCHES_LQUERY => Ok(vec![field.matches_lquery(self.as_lquery_value(input)?)]),
+
             filters::IS_SET if self.reverse() => {
                 let is_set: bool = input.try_into()?;
 
@@ -390,12 +405,80 @@ impl<'a> ScalarFilterParser<'a> {
                 JsonTargetType::String,
             )]),
 
+            // Spatial filters (MySQL only)
+            filters::GEO_CONTAINS if self.reverse() => Ok(vec![field.not_geo_contains(self.as_geojson_value(input)?)]),
+            filters::GEO_CONTAINS => Ok(vec![field.geo_contains(self.as_geojson_value(input)?)]),
+
+            filters::WITHIN if self.reverse() => {
+                let (point, distance_meters) = self.as_within_distance_args(input)?;
+
+                Ok(vec![field.not_within_distance(point, distance_meters)])
+            }
+            filters::WITHIN => {
+                let (point, distance_meters) = self.as_within_distance_args(input)?;
+
+                Ok(vec![field.within_distance(point, distance_meters)])
+            }
+
             _ => Err(QueryGraphBuilderError::InputError(format!(
                 "{filter_name} is not a valid scalar filter operation"
             ))),
         }
     }
 
+    /// Parses a value for `ancestorOf`/`descendantOf`, rejecting literal strings that are not
+    /// valid ltree paths before they can be bound to the query and cause a confusing database error.
+    fn as_ltree_path_value(&self, input: ParsedInputValue<'_>) -> QueryGraphBuilderResult<ConditionValue> {
+        let value = self.as_condition_value(input, false)?;
+
+        if let ConditionValue::Value(PrismaValue::String(ref path)) = value {
+            validate_ltree_path(path)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Parses a value for `matchesLquery`, rejecting literal strings that are not valid `lquery`
+    /// patterns before they can be bound to the query and cause a confusing database error.
+    fn as_lquery_value(&self, input: ParsedInputValue<'_>) -> QueryGraphBuilderResult<ConditionValue> {
+        let value = self.as_condition_value(input, false)?;
+
+        if let ConditionValue::Value(PrismaValue::String(ref pattern)) = value {
+            validate_lquery(pattern)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Parses a value for `geoContains`, rejecting literal JSON values that are not valid GeoJSON
+    /// geometries before they can be bound to the query and cause a confusing database error.
+    fn as_geojson_value(&self, input: ParsedInputValue<'_>) -> QueryGraphBuilderResult<ConditionValue> {
+        let value = self.as_condition_value(input, false)?;
+
+        if let ConditionValue::Value(PrismaValue::Json(ref json)) = value {
+            validate_geojson(json)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Parses the `within` filter's `{ point, distanceMeters }` argument object.
+    fn as_within_distance_args(
+        &self,
+        input: ParsedInputValue<'_>,
+    ) -> QueryGraphBuilderResult<(ConditionValue, ConditionValue)> {
+        let mut map: ParsedInputMap<'_> = input.try_into()?;
+
+        let point = self.as_geojson_value(map.swap_remove(filters::POINT).unwrap())?;
+        let distance_meters = self.internal_as_condition_value(
+            map.swap_remove(filters::DISTANCE_METERS).unwrap(),
+            false,
+            &TypeIdentifier::Float,
+        )?;
+
+        Ok((point, distance_meters))
+    }
+
     fn as_condition_value(
         &self,
         input: ParsedInputValue<'_>,
@@ -529,6 +612,15 @@ impl<'a> ScalarFilterParser<'a> {
                     ))),
                 }
             }
+            ParsedInputValue::Single(PrismaValue::Placeholder(mut placeholder)) => {
+                // The whole list argument was parameterized as a single bind value (e.g. by the
+                // query compiler) rather than one placeholder per element: re-type it as an array
+                // of the field's own type so connectors know to bind it as such.
+                placeholder.r#type = field.type_info().to_prisma_type();
+
+                Ok(ConditionListValue::value(PrismaValue::Placeholder(placeholder)))
+            }
+
             _ => {
                 let vals: Vec<PrismaValue> = input.try_into()?;
 
@@ -610,9 +702,130 @@ fn parse_json_path(input: ParsedInputValue<'_>) -> QueryGraphBuilderResult<JsonF
     }
 }
 
+/// Validates that `path` is a syntactically valid Postgres `ltree` value: one or more
+/// dot-separated labels, each made up of letters, digits and underscores, at most 256 bytes long.
+fn validate_ltree_path(path: &str) -> QueryGraphBuilderResult<()> {
+    let is_valid_label = |label: &str| {
+        !label.is_empty() && label.len() <= 256 && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+    };
+
+    if !path.split('.').all(is_valid_label) {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "`{path}` is not a valid ltree path: it must be one or more dot-separated labels of letters, digits and underscores, each at most 256 characters long."
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validates that `pattern` is a syntactically valid `lquery` pattern: like an ltree path, but
+/// labels may additionally use the `lquery` wildcard syntax (`*`, `%`, `@`, `!`, `{n,m}`, `|`).
+fn validate_lquery(pattern: &str) -> QueryGraphBuilderResult<()> {
+    let is_valid_label = |label: &str| {
+        !label.is_empty()
+            && label
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'*' | b'%' | b'@' | b'!' | b'|' | b'{' | b'}' | b',' | b'-'))
+    };
+
+    if pattern.is_empty() || !pattern.split('.').all(is_valid_label) {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "`{pattern}` is not a valid lquery pattern."
+        )));
+    }
+
+    Ok(())
+}
+
+/// The GeoJSON geometry types accepted by `@db.Point`/`@db.Geometry` columns.
+const GEOJSON_GEOMETRY_TYPES: &[&str] = &[
+    "Point",
+    "LineString",
+    "Polygon",
+    "MultiPoint",
+    "MultiLineString",
+    "MultiPolygon",
+    "GeometryCollection",
+];
+
+/// Validates that `json` is a syntactically plausible GeoJSON geometry object, i.e. an object
+/// with a `type` member set to one of the GeoJSON geometry types and a `coordinates` member
+/// (or, for `GeometryCollection`, a `geometries` member). This does not validate coordinate
+/// values, winding order, or SRID consistency; the database is the source of truth for that.
+fn validate_geojson(json: &str) -> QueryGraphBuilderResult<()> {
+    let invalid = || {
+        QueryGraphBuilderError::InputError(format!(
+            "`{json}` is not a valid GeoJSON geometry: it must be an object with a `type` member set to one of {GEOJSON_GEOMETRY_TYPES:?} and a matching `coordinates` (or `geometries`) member."
+        ))
+    };
+
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|_| invalid())?;
+    let object = value.as_object().ok_or_else(invalid)?;
+
+    match object.get("type").and_then(|t| t.as_str()) {
+        Some("GeometryCollection") if object.contains_key("geometries") => Ok(()),
+        Some(typ) if GEOJSON_GEOMETRY_TYPES.contains(&typ) && object.contains_key("coordinates") => Ok(()),
+        _ => Err(invalid()),
+    }
+}
+
 fn coerce_json_null(value: ConditionValue) -> ConditionValue {
     match value {
         ConditionValue::Value(PrismaValue::Null) => ConditionValue::value(PrismaValue::Json("null".to_owned())),
         _ => value,
     }
 }
+
+#[cfg(test)]
+mod geojson_tests {
+    use super::validate_geojson;
+
+    #[test]
+    fn accepts_a_point() {
+        assert!(validate_geojson(r#"{"type":"Point","coordinates":[1.0,2.0]}"#).is_ok());
+    }
+
+    #[test]
+    fn accepts_a_polygon() {
+        assert!(validate_geojson(r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[1,1],[0,0]]]}"#).is_ok());
+    }
+
+    #[test]
+    fn accepts_a_geometry_collection() {
+        assert!(validate_geojson(
+            r#"{"type":"GeometryCollection","geometries":[{"type":"Point","coordinates":[1.0,2.0]}]}"#
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn round_trips_through_serde_json() {
+        let original = serde_json::json!({ "type": "Point", "coordinates": [1.0, 2.0] });
+        let serialized = original.to_string();
+
+        assert!(validate_geojson(&serialized).is_ok());
+
+        let parsed: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(original, parsed);
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        assert!(validate_geojson("not json").is_err());
+    }
+
+    #[test]
+    fn rejects_a_missing_type() {
+        assert!(validate_geojson(r#"{"coordinates":[1.0,2.0]}"#).is_err());
+    }
+
+    #[test]
+    fn rejects_an_unknown_geometry_type() {
+        assert!(validate_geojson(r#"{"type":"Feature","coordinates":[1.0,2.0]}"#).is_err());
+    }
+
+    #[test]
+    fn rejects_a_point_missing_coordinates() {
+        assert!(validate_geojson(r#"{"type":"Point"}"#).is_err());
+    }
+}
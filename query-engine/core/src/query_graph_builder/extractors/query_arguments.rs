@@ -3,7 +3,7 @@ use crate::{
     query_document::{ParsedArgument, ParsedInputMap},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use query_structure::{prelude::*, QueryArguments};
+use query_structure::{prelude::*, ConditionListValue, Filter, QueryArguments, ScalarCondition};
 use schema::constants::{aggregations, args, ordering};
 use std::convert::TryInto;
 
@@ -14,6 +14,20 @@ pub fn extract_query_args(
     arguments: Vec<ParsedArgument<'_>>,
     model: &Model,
 ) -> QueryGraphBuilderResult<QueryArguments> {
+    // `orderBy: { _inputOrder: true }` needs to see the `where` filter to know which field's `in`
+    // list order to preserve. Arguments can appear in any order in the request, so resolve the
+    // filter up front instead of relying on `where` having already been folded in by the time
+    // `orderBy` is processed below.
+    let filter_for_input_order = arguments
+        .iter()
+        .find(|arg| arg.name.as_str() == args::WHERE)
+        .map(|arg| -> QueryGraphBuilderResult<Option<Filter>> {
+            let val: Option<ParsedInputMap<'_>> = arg.value.clone().try_into()?;
+            val.map(|m| extract_filter(m, model)).transpose()
+        })
+        .transpose()?
+        .flatten();
+
     let query_args = arguments.into_iter().try_fold(
         QueryArguments::new(model.clone()),
         |result, arg| -> QueryGraphBuilderResult<QueryArguments> {
@@ -37,7 +51,7 @@ pub fn extract_query_args(
                 }),
 
                 args::ORDER_BY => Ok(QueryArguments {
-                    order_by: extract_order_by(&model.into(), arg.value)?,
+                    order_by: extract_order_by(&model.into(), arg.value, filter_for_input_order.as_ref())?,
                     ..result
                 }),
 
@@ -70,19 +84,24 @@ pub fn extract_query_args(
     Ok(finalize_arguments(query_args, model))
 }
 
-/// Extracts order by conditions in order of appearance.
-fn extract_order_by(container: &ParentContainer, value: ParsedInputValue<'_>) -> QueryGraphBuilderResult<Vec<OrderBy>> {
+/// Extracts order by conditions in order of appearance. `filter` is the query's already-resolved
+/// `where` argument, consulted only to validate and resolve `_inputOrder`.
+pub(crate) fn extract_order_by(
+    container: &ParentContainer,
+    value: ParsedInputValue<'_>,
+    filter: Option<&Filter>,
+) -> QueryGraphBuilderResult<Vec<OrderBy>> {
     match value {
         ParsedInputValue::List(list) => list
             .into_iter()
             .map(|list_value| {
                 let object: ParsedInputMap<'_> = list_value.try_into()?;
-                process_order_object(container, object, vec![], None)
+                process_order_object(container, object, vec![], None, filter)
             })
             .collect::<QueryGraphBuilderResult<Vec<_>>>()
             .map(|results| results.into_iter().flatten().collect()),
 
-        ParsedInputValue::Map(map) => Ok(match process_order_object(container, map, vec![], None)? {
+        ParsedInputValue::Map(map) => Ok(match process_order_object(container, map, vec![], None, filter)? {
             Some(order) => vec![order],
             None => vec![],
         }),
@@ -96,6 +115,7 @@ fn process_order_object(
     object: ParsedInputMap<'_>,
     mut path: Vec<OrderByHop>,
     parent_sort_aggregation: Option<SortAggregation>,
+    filter: Option<&Filter>,
 ) -> QueryGraphBuilderResult<Option<OrderBy>> {
     match object.into_iter().next() {
         None => Ok(None),
@@ -106,10 +126,19 @@ fn process_order_object(
                 return extract_order_by_relevance(container, object, path);
             }
 
+            if field_name.as_ref() == ordering::UNDERSCORE_INPUT_ORDER {
+                let requested: PrismaValue = field_value.try_into()?;
+
+                return match requested.as_boolean() {
+                    Some(true) => extract_order_by_input_order(filter).map(Some),
+                    _ => Ok(None),
+                };
+            }
+
             if let Some(sort_aggr) = extract_sort_aggregation(field_name.as_ref()) {
                 let object: ParsedInputMap<'_> = field_value.try_into()?;
 
-                return process_order_object(container, object, path, Some(sort_aggr));
+                return process_order_object(container, object, path, Some(sort_aggr), filter);
             }
 
             let field = container
@@ -134,7 +163,7 @@ fn process_order_object(
                     let object: ParsedInputMap<'_> = field_value.try_into()?;
                     path.push((&rf).into());
 
-                    process_order_object(&rf.related_model().into(), object, path, None)
+                    process_order_object(&rf.related_model().into(), object, path, None, filter)
                 }
 
                 Field::Scalar(sf) => {
@@ -165,13 +194,42 @@ fn process_order_object(
                     let object: ParsedInputMap<'_> = field_value.try_into()?;
                     path.push((&cf).into());
 
-                    process_order_object(&cf.typ().into(), object, path, None)
+                    process_order_object(&cf.typ().into(), object, path, None, filter)
                 }
             }
         }
     }
 }
 
+/// Resolves `orderBy: { _inputOrder: true }` against the query's `where` filter: valid only when
+/// `where` is exactly a single `in` filter over one unique scalar field with a concrete list of
+/// values (not a placeholder or a field reference), in which case the result is ordered to match
+/// that list instead of the column's own value.
+fn extract_order_by_input_order(filter: Option<&Filter>) -> QueryGraphBuilderResult<OrderBy> {
+    let invalid = || {
+        QueryGraphBuilderError::InputError(
+            "`_inputOrder` requires `where` to be a single `in` filter over one unique field.".to_owned(),
+        )
+    };
+
+    let Filter::Scalar(scalar_filter) = filter.ok_or_else(invalid)? else {
+        return Err(invalid());
+    };
+
+    let field = scalar_filter.projection.as_single().ok_or_else(invalid)?;
+
+    if !field.unique() {
+        return Err(invalid());
+    }
+
+    let values = match &scalar_filter.condition {
+        ScalarCondition::In(ConditionListValue::List(values)) => values.clone(),
+        _ => return Err(invalid()),
+    };
+
+    Ok(OrderBy::input_order(field.clone(), values))
+}
+
 fn extract_order_by_relevance(
     container: &ParentContainer,
     object: ParsedInputMap<'_>,
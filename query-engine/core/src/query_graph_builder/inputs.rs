@@ -67,12 +67,15 @@ pub(crate) struct LeftSideDiffInput;
 
 impl NodeInputField<Vec<SelectionResult>> for LeftSideDiffInput {
     fn node_input_field<'a>(&self, node: &'a mut Node) -> &'a mut Vec<SelectionResult> {
-        if let Node::Computation(Computation::DiffLeftToRight(diff_node) | Computation::DiffRightToLeft(diff_node)) =
-            node
+        if let Node::Computation(
+            Computation::DiffLeftToRight(diff_node)
+            | Computation::DiffRightToLeft(diff_node)
+            | Computation::SymmetricDiff(diff_node),
+        ) = node
         {
             &mut diff_node.left
         } else {
-            panic!("LeftSideDiffInput can only be used with DiffLeftToRight or DiffRightToLeft node")
+            panic!("LeftSideDiffInput can only be used with DiffLeftToRight, DiffRightToLeft or SymmetricDiff node")
         }
     }
 }
@@ -82,12 +85,15 @@ pub(crate) struct RightSideDiffInput;
 
 impl NodeInputField<Vec<SelectionResult>> for RightSideDiffInput {
     fn node_input_field<'a>(&self, node: &'a mut Node) -> &'a mut Vec<SelectionResult> {
-        if let Node::Computation(Computation::DiffLeftToRight(diff_node) | Computation::DiffRightToLeft(diff_node)) =
-            node
+        if let Node::Computation(
+            Computation::DiffLeftToRight(diff_node)
+            | Computation::DiffRightToLeft(diff_node)
+            | Computation::SymmetricDiff(diff_node),
+        ) = node
         {
             &mut diff_node.right
         } else {
-            panic!("RightSideDiffInput can only be used with DiffLeftToRight or DiffRightToLeft node")
+            panic!("RightSideDiffInput can only be used with DiffLeftToRight, DiffRightToLeft or SymmetricDiff node")
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::{utils::get_relation_load_strategy, *};
+use super::{utils::get_relation_load_strategy_with_args, *};
 use crate::{query_document::ParsedField, ManyRecordsQuery, QueryOption, QueryOptions, ReadQuery};
 use query_structure::Model;
 use schema::QuerySchema;
@@ -37,8 +37,14 @@ fn find_many_with_options(
     let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
     let selected_fields = utils::merge_cursor_fields(selected_fields, &args.cursor);
 
-    let relation_load_strategy =
-        get_relation_load_strategy(args.relation_load_strategy, args.cursor.as_ref(), &nested, query_schema)?;
+    let relation_load_strategy = get_relation_load_strategy_with_args(
+        args.relation_load_strategy,
+        args.cursor.as_ref(),
+        &nested,
+        Some(&args),
+        Some(&selected_fields),
+        query_schema,
+    )?;
 
     Ok(ReadQuery::ManyRecordsQuery(ManyRecordsQuery {
         name,
@@ -3,7 +3,7 @@ use std::sync::LazyLock;
 use super::*;
 use crate::{ArgumentListLookup, FieldPair, ParsedField, ReadQuery};
 use psl::datamodel_connector::JoinStrategySupport;
-use query_structure::{prelude::*, RelationLoadStrategy};
+use query_structure::{prelude::*, QueryArguments, RelationLoadStrategy};
 use schema::{
     constants::{aggregations::*, args},
     QuerySchema,
@@ -258,6 +258,21 @@ pub(crate) fn get_relation_load_strategy(
     cursor: Option<&SelectionResult>,
     nested_queries: &[ReadQuery],
     query_schema: &QuerySchema,
+) -> QueryGraphBuilderResult<RelationLoadStrategy> {
+    get_relation_load_strategy_with_args(requested_strategy, cursor, nested_queries, None, None, query_schema)
+}
+
+/// Like `get_relation_load_strategy`, but also takes the `QueryArguments`/`FieldSelection` of the
+/// operation (when it has any, e.g. `findMany`) so that `orderBy`/`distinct` shapes that the join
+/// relation load strategy can't resolve are detected up-front instead of surfacing as a query
+/// build failure.
+pub(crate) fn get_relation_load_strategy_with_args(
+    requested_strategy: Option<RelationLoadStrategy>,
+    cursor: Option<&SelectionResult>,
+    nested_queries: &[ReadQuery],
+    query_arguments: Option<&QueryArguments>,
+    selected_fields: Option<&FieldSelection>,
+    query_schema: &QuerySchema,
 ) -> QueryGraphBuilderResult<RelationLoadStrategy> {
     static DEFAULT_RELATION_LOAD_STRATEGY: LazyLock<Option<RelationLoadStrategy>> = LazyLock::new(|| {
         std::env::var("PRISMA_RELATION_LOAD_STRATEGY")
@@ -266,11 +281,39 @@ pub(crate) fn get_relation_load_strategy(
             .or_else(|| option_env!("PRISMA_RELATION_LOAD_STRATEGY").map(|var| var.try_into().unwrap()))
     });
 
+    // Whether an incompatibility between the query shape and the `join` strategy should be
+    // surfaced as a hard error instead of silently falling back to the `query` strategy.
+    static STRICT_JOIN_COMPATIBILITY: LazyLock<bool> = LazyLock::new(|| {
+        std::env::var("PRISMA_STRICT_JOIN_COMPATIBILITY")
+            .map(|var| var == "true" || var == "1")
+            .unwrap_or(false)
+    });
+
+    let root_has_distinct = query_arguments.is_some_and(|args| args.distinct.is_some());
+    let join_incompatibility = query_arguments
+        .and_then(order_by_incompatible_with_joins)
+        .or_else(|| {
+            selected_fields.and_then(|selected_fields| {
+                nested_distinct_incompatible_with_joins(selected_fields, root_has_distinct)
+            })
+        });
+
     match query_schema.join_strategy_support() {
         // Connector and database version supports the `Join` strategy...
         JoinStrategySupport::Yes => match requested_strategy {
             // But incoming query cannot be resolved with joins.
-            _ if !query_can_be_resolved_with_joins(cursor, nested_queries) => {
+            _ if !query_can_be_resolved_with_joins(cursor, nested_queries) || join_incompatibility.is_some() => {
+                if matches!(requested_strategy, Some(RelationLoadStrategy::Join)) && *STRICT_JOIN_COMPATIBILITY {
+                    let reason = join_incompatibility.unwrap_or("a cursor on a nested to-many relation");
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "`relationLoadStrategy: join` was requested, but the query cannot be resolved with joins because of {reason}.",
+                    )));
+                }
+
+                if let Some(reason) = join_incompatibility {
+                    tracing::warn!("falling back to `relationLoadStrategy: query`: {reason}");
+                }
+
                 // So we fallback to the `Query` one.
                 Ok(RelationLoadStrategy::Query)
             }
@@ -308,6 +351,41 @@ fn query_can_be_resolved_with_joins(cursor: Option<&SelectionResult>, nested_que
         })
 }
 
+/// Returns a human-readable reason when `order_by` sorts by an aggregate of a to-many relation
+/// reached through one or more `include`d relations, a shape the join relation load strategy
+/// cannot translate (see `sql_query_builder::select::is_join_compatible`, which enforces the
+/// same rule when it is actually reachable from this crate).
+fn order_by_incompatible_with_joins(args: &QueryArguments) -> Option<&'static str> {
+    let has_nested_aggregation_order_by = args.order_by.iter().any(|order_by| match order_by {
+        query_structure::OrderBy::ToManyAggregation(o) => o.intermediary_hops().len() > 1,
+        _ => false,
+    });
+
+    has_nested_aggregation_order_by.then_some("an orderBy on a to-many relation aggregate nested inside an include")
+}
+
+/// Returns a human-readable reason when a nested relation is `distinct`ed while an ancestor (the
+/// root query or another nested relation) is also `distinct`ed, a combination the JSON-aggregated
+/// join query has no way to express.
+fn nested_distinct_incompatible_with_joins(
+    selected_fields: &FieldSelection,
+    root_has_distinct: bool,
+) -> Option<&'static str> {
+    fn has_conflicting_nested_distinct<'a>(
+        relations: impl Iterator<Item = &'a query_structure::RelationSelection>,
+        ancestor_has_distinct: bool,
+    ) -> bool {
+        relations.into_iter().any(|rs| {
+            let has_distinct = rs.args.distinct.is_some();
+            (ancestor_has_distinct && has_distinct)
+                || has_conflicting_nested_distinct(rs.relations(), ancestor_has_distinct || has_distinct)
+        })
+    }
+
+    has_conflicting_nested_distinct(selected_fields.relations(), root_has_distinct)
+        .then_some("a distinct on a nested relation combined with a distinct on an ancestor")
+}
+
 pub(crate) fn extract_selected_fields(
     nested_fields: Vec<FieldPair<'_>>,
     model: &Model,
@@ -49,6 +49,7 @@ pub(crate) fn connect_records_node(
         parent_id: None,
         child_ids: vec![],
         relation_field: parent_relation_field.clone(),
+        return_affected_count: false,
     });
 
     let connect_node = graph.create_node(Query::Write(connect));
@@ -23,14 +23,24 @@ pub(crate) fn create_record(
         None => ParsedInputMap::default(),
     };
 
-    if can_use_atomic_create(query_schema, &model, &data_map, &field) {
-        let create_node = create::atomic_create_record_node(graph, query_schema, model, data_map, field)?;
+    // When set, the create only returns the primary identifier from the database; any other
+    // client-requested field is fetched with the normal follow-up read below instead of being
+    // selected back from the `INSERT` itself.
+    let return_minimal: bool = match field.arguments.lookup(args::RETURN_MINIMAL) {
+        Some(arg) => arg.value.try_into()?,
+        None => false,
+    };
+
+    let path = QueryPath::new(args::DATA);
+
+    if !return_minimal && can_use_atomic_create(query_schema, &model, &data_map, &field) {
+        let create_node = create::atomic_create_record_node(graph, query_schema, model, data_map, field, path)?;
 
         graph.add_result_node(&create_node);
     } else {
         graph.flag_transactional();
 
-        let create_node = create::create_record_node(graph, query_schema, model.clone(), data_map)?;
+        let create_node = create::create_record_node(graph, query_schema, model.clone(), data_map, path)?;
 
         // Follow-up read query on the write
         let read_query = read::find_unique(field, model.clone(), query_schema)?;
@@ -73,6 +83,16 @@ pub(crate) fn create_many_records(
         None => false,
     };
 
+    let collect_errors: bool = match field.arguments.lookup(args::COLLECT_ERRORS) {
+        Some(arg) => arg.value.try_into()?,
+        None => false,
+    };
+
+    let return_skipped: bool = match field.arguments.lookup(args::RETURN_SKIPPED) {
+        Some(arg) => arg.value.try_into()?,
+        None => false,
+    };
+
     let args = data_list
         .into_iter()
         .map(|data_value| {
@@ -102,11 +122,14 @@ pub(crate) fn create_many_records(
         model,
         args,
         skip_duplicates,
+        collect_errors,
+        return_skipped,
         selected_fields,
         split_by_shape: !query_schema.has_capability(ConnectorCapability::SupportsDefaultInInsert),
     };
 
-    graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
+    let node = graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
+    graph.set_node_path(&node, QueryPath::new(args::DATA));
 
     Ok(())
 }
@@ -116,10 +139,11 @@ pub fn create_record_node(
     query_schema: &QuerySchema,
     model: Model,
     data_map: ParsedInputMap<'_>,
+    path: QueryPath,
 ) -> QueryGraphBuilderResult<NodeRef> {
     let mut parser = WriteArgsParser::from(&model, data_map)?;
     parser.args.add_datetimes(&model);
-    create_record_node_from_args(graph, query_schema, model, parser.args, parser.nested)
+    create_record_node_from_args(graph, query_schema, model, parser.args, parser.nested, path)
 }
 
 pub(crate) fn create_record_node_from_args(
@@ -128,6 +152,7 @@ pub(crate) fn create_record_node_from_args(
     model: Model,
     args: WriteArgs,
     nested: Vec<(Zipper<RelationFieldId>, ParsedInputMap<'_>)>,
+    path: QueryPath,
 ) -> QueryGraphBuilderResult<NodeRef> {
     let selected_fields = model.shard_aware_primary_identifier();
     let selection_order = selected_fields.db_names().collect();
@@ -142,9 +167,18 @@ pub(crate) fn create_record_node_from_args(
     };
 
     let create_node = graph.create_node(Query::Write(WriteQuery::CreateRecord(cr)));
+    graph.set_node_path(&create_node, path.clone());
 
     for (relation_field, data_map) in nested {
-        nested::connect_nested_query(graph, query_schema, create_node, relation_field, data_map)?;
+        let field_name = relation_field.name().to_owned();
+        nested::connect_nested_query(
+            graph,
+            query_schema,
+            create_node,
+            relation_field,
+            data_map,
+            path.field(&field_name),
+        )?;
     }
 
     Ok(create_node)
@@ -187,6 +221,7 @@ fn atomic_create_record_node(
     model: Model,
     data_map: ParsedInputMap<'_>,
     field: ParsedField<'_>,
+    path: QueryPath,
 ) -> QueryGraphBuilderResult<NodeRef> {
     let create_args = WriteArgsParser::from(&model, data_map)?;
     let mut args = create_args.args;
@@ -206,9 +241,20 @@ fn atomic_create_record_node(
     };
 
     let create_node = graph.create_node(Query::Write(WriteQuery::CreateRecord(cr)));
+    graph.set_node_path(&create_node, path.clone());
 
+    // An atomic create never has nested operations (see `can_use_atomic_create`), but we still
+    // thread the path through for consistency should that constraint ever loosen.
     for (relation_field, data_map) in create_args.nested {
-        nested::connect_nested_query(graph, query_schema, create_node, relation_field, data_map)?;
+        let field_name = relation_field.name().to_owned();
+        nested::connect_nested_query(
+            graph,
+            query_schema,
+            create_node,
+            relation_field,
+            data_map,
+            path.field(&field_name),
+        )?;
     }
 
     Ok(create_node)
@@ -7,7 +7,7 @@ use crate::{
 };
 use crate::{DataExpectation, RowSink};
 use psl::datamodel_connector::ConnectorCapability;
-use query_structure::{Filter, Model};
+use query_structure::{ChunkExecutionPolicy, Filter, Model};
 use schema::{constants::args, QuerySchema};
 use std::convert::TryInto;
 
@@ -123,6 +123,14 @@ pub fn delete_many_records(
     };
 
     let limit = validate_limit(field.arguments.lookup(args::LIMIT))?;
+    let order_by = match field.arguments.lookup(args::ORDER_BY) {
+        Some(order_by_arg) => extract_order_by(&model.clone().into(), order_by_arg.value, Some(&filter))?,
+        None => vec![],
+    };
+    let chunk_execution_policy = match field.arguments.lookup(args::CHUNK_EXECUTION_POLICY) {
+        Some(policy_arg) => policy_arg.value.try_into()?,
+        None => ChunkExecutionPolicy::default(),
+    };
 
     let model_id = model.shard_aware_primary_identifier();
     let record_filter = filter.clone().into();
@@ -130,6 +138,8 @@ pub fn delete_many_records(
         model: model.clone(),
         record_filter,
         limit,
+        order_by: order_by.clone(),
+        chunk_execution_policy,
     });
 
     let delete_many_node = graph.create_node(Query::Write(delete_many));
@@ -137,7 +147,7 @@ pub fn delete_many_records(
     if query_schema.relation_mode().is_prisma() {
         graph.flag_transactional();
 
-        let read_query = utils::read_ids_infallible(model.clone(), model_id.clone(), filter);
+        let read_query = utils::read_ids_infallible_ordered(model.clone(), model_id.clone(), filter, order_by, limit);
         let read_query_node = graph.create_node(read_query);
 
         let dependencies = utils::insert_emulated_on_delete(graph, query_schema, &model, &read_query_node)?;
@@ -4,11 +4,14 @@ mod delete;
 mod disconnect;
 mod limit;
 mod nested;
+mod path;
 mod raw;
 mod update;
 mod upsert;
 mod write_args_parser;
 
+pub(crate) use path::QueryPath;
+
 pub(crate) mod utils;
 
 use super::*;
@@ -18,7 +18,10 @@ pub fn nested_connect(
     parent_relation_field: &RelationFieldRef,
     value: ParsedInputValue<'_>,
     child_model: &Model,
+    path: QueryPath,
 ) -> QueryGraphBuilderResult<()> {
+    utils::ensure_connect_disconnect_allowed(parent_relation_field)?;
+
     let relation = parent_relation_field.relation();
 
     // Build all filters upfront.
@@ -37,7 +40,7 @@ pub fn nested_connect(
         let filter = Filter::or(filters);
 
         if relation.is_many_to_many() {
-            handle_many_to_many(graph, parent_node, parent_relation_field, filter, child_model)
+            handle_many_to_many(graph, parent_node, parent_relation_field, filter, child_model, path)
         } else if relation.is_one_to_many() {
             handle_one_to_many(graph, parent_node, parent_relation_field, filter, child_model)
         } else {
@@ -77,6 +80,7 @@ fn handle_many_to_many(
     parent_relation_field: &RelationFieldRef,
     filter: Filter,
     child_model: &Model,
+    path: QueryPath,
 ) -> QueryGraphBuilderResult<()> {
     let expected_connects = filter.size();
     let child_read_query = utils::read_ids_infallible(
@@ -87,13 +91,14 @@ fn handle_many_to_many(
     let child_node = graph.create_node(child_read_query);
 
     graph.create_edge(&parent_node, &child_node, QueryGraphDependency::ExecutionOrder)?;
-    connect::connect_records_node(
+    let connect_node = connect::connect_records_node(
         graph,
         &parent_node,
         &child_node,
         parent_relation_field,
         expected_connects,
     )?;
+    graph.set_node_path(&connect_node, path);
 
     Ok(())
 }
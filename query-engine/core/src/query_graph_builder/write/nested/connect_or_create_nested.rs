@@ -114,7 +114,7 @@ fn handle_many_to_many(
             filter,
         ));
 
-        let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_map)?;
+        let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_map, QueryPath::new(args::DATA))?;
         let if_node = graph.create_node(Flow::if_non_empty());
 
         let connect_exists_node =
@@ -272,7 +272,7 @@ fn one_to_many_inlined_child(
 
         let if_node = graph.create_node(Flow::if_non_empty());
         let update_child_node = utils::update_records_node_placeholder(graph, filter, child_model.clone());
-        let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_map)?;
+        let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_map, QueryPath::new(args::DATA))?;
 
         graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
         graph.create_edge(&if_node, &update_child_node, QueryGraphDependency::Then)?;
@@ -395,7 +395,7 @@ fn one_to_many_inlined_parent(
     graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
 
     let if_node = graph.create_node(Flow::if_non_empty());
-    let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_map)?;
+    let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_map, QueryPath::new(args::DATA))?;
     let return_existing = graph.create_node(Flow::Return(Vec::new()));
     let return_create = graph.create_node(Flow::Return(Vec::new()));
 
@@ -528,7 +528,7 @@ fn one_to_one_inlined_parent(
     graph.create_edge(&parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
 
     let if_node = graph.create_node(Flow::if_non_empty());
-    let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_data)?;
+    let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_data, QueryPath::new(args::DATA))?;
     let return_existing = graph.create_node(Flow::Return(Vec::new()));
     let return_create = graph.create_node(Flow::Return(Vec::new()));
 
@@ -707,7 +707,7 @@ fn one_to_one_inlined_child(
     graph.create_edge(&parent_node, &read_new_child_node, QueryGraphDependency::ExecutionOrder)?;
 
     let if_node = graph.create_node(Flow::if_non_empty());
-    let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_data)?;
+    let create_node = create::create_record_node(graph, query_schema, child_model.clone(), create_data, QueryPath::new(args::DATA))?;
 
     // Edge: Read new child -> if node
     graph.create_edge(
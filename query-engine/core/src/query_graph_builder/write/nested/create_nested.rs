@@ -21,6 +21,7 @@ pub fn nested_create(
     parent_relation_field: &RelationFieldRef,
     value: ParsedInputValue<'_>,
     child_model: &Model,
+    path: QueryPath,
 ) -> QueryGraphBuilderResult<()> {
     let relation = parent_relation_field.relation();
 
@@ -76,10 +77,15 @@ pub fn nested_create(
             model: child_model.clone(),
             args: data_maps.into_iter().map(|(args, _nested)| args).collect(),
             skip_duplicates: false,
+            collect_errors: false,
+            return_skipped: false,
             selected_fields,
             split_by_shape: !query_schema.has_capability(ConnectorCapability::SupportsDefaultInInsert),
         };
         let create_many_node = graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
+        // Bulk creation issues a single multi-row INSERT, so a failing row can't be attributed to
+        // a specific array index at the connector level: the path stops at the array itself.
+        graph.set_node_path(&create_many_node, path.clone());
 
         if relation.is_one_to_many() {
             handle_one_to_many_bulk(graph, parent_node, parent_relation_field, create_many_node)?;
@@ -93,11 +99,16 @@ pub fn nested_create(
             )?;
         }
     } else {
-        // Create each child record separately.
+        // Create each child record separately: when there's more than one, each gets its own
+        // array-indexed path, unlike the bulk branch above. A single `create: { ... }` (not an
+        // array) keeps the parent path as-is, since there's no index to point to.
+        let indexed = child_records_count > 1;
         let creates = data_maps
             .into_iter()
-            .map(|(args, nested)| {
-                create::create_record_node_from_args(graph, query_schema, child_model.clone(), args, nested)
+            .enumerate()
+            .map(|(i, (args, nested))| {
+                let child_path = if indexed { path.index(i) } else { path.clone() };
+                create::create_record_node_from_args(graph, query_schema, child_model.clone(), args, nested, child_path)
             })
             .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
 
@@ -533,6 +544,7 @@ pub fn nested_create_many(
     parent_relation_field: &RelationFieldRef,
     value: ParsedInputValue<'_>,
     child_model: &Model,
+    path: QueryPath,
 ) -> QueryGraphBuilderResult<()> {
     // Nested input is an object of { data: [...], skipDuplicates: bool }
     let mut obj: ParsedInputMap<'_> = value.try_into()?;
@@ -559,11 +571,16 @@ pub fn nested_create_many(
         model: child_model.clone(),
         args,
         skip_duplicates,
+        collect_errors: false,
+        return_skipped: false,
         selected_fields: None,
         split_by_shape: !query_schema.has_capability(ConnectorCapability::SupportsDefaultInInsert),
     };
 
     let create_node = graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
+    // Same per-row attribution limitation as the bulk branch of `nested_create`: a single
+    // multi-row INSERT can't report which row failed, so the path stops at the `data` array.
+    graph.set_node_path(&create_node, path.field(args::DATA));
 
     // Currently, `createMany` is only supported for 1-many relations. This is checked during parsing.
     handle_one_to_many_bulk(graph, parent_node, parent_relation_field, create_node)
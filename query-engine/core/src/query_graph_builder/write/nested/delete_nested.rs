@@ -4,7 +4,7 @@ use crate::{
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
     DataExpectation, ParsedInputMap, ParsedInputValue,
 };
-use query_structure::{Filter, Model, PrismaValue, RecordFilter, RelationFieldRef};
+use query_structure::{ChunkExecutionPolicy, Filter, Model, PrismaValue, RecordFilter, RelationFieldRef};
 use std::convert::TryInto;
 
 /// Adds a delete (single) record node to the graph and connects it to the parent.
@@ -44,6 +44,8 @@ pub fn nested_delete(
             model: child_model.clone(),
             record_filter: or_filter.clone().into(),
             limit: None,
+            order_by: Vec::new(),
+            chunk_execution_policy: ChunkExecutionPolicy::default(),
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
@@ -153,6 +155,8 @@ pub fn nested_delete_many(
             model: child_model.clone(),
             record_filter: RecordFilter::empty(),
             limit: None,
+            order_by: Vec::new(),
+            chunk_execution_policy: ChunkExecutionPolicy::default(),
         });
 
         let delete_many_node = graph.create_node(Query::Write(delete_many));
@@ -19,6 +19,8 @@ pub fn nested_disconnect(
     value: ParsedInputValue<'_>,
     child_model: &Model,
 ) -> QueryGraphBuilderResult<()> {
+    utils::ensure_connect_disconnect_allowed(parent_relation_field)?;
+
     let relation = parent_relation_field.relation();
 
     if relation.is_many_to_many() {
@@ -74,6 +76,38 @@ pub fn nested_disconnect(
     }
 }
 
+/// Handles nested disconnect-by-filter ("disconnectMany") cases.
+///
+/// Unlike [`nested_disconnect`], which resolves the input to a set of unique filters, this
+/// disconnects every currently related child record that matches an arbitrary `where` filter,
+/// mirroring how [`super::nested_delete_many`] deletes by filter instead of by id. The input can
+/// be a single filter object or a list of them; each one is resolved and applied independently,
+/// exactly like "deleteMany" and "updateMany" do.
+pub fn nested_disconnect_many(
+    graph: &mut QueryGraph,
+    parent_node: NodeRef,
+    parent_relation_field: &RelationFieldRef,
+    value: ParsedInputValue<'_>,
+    child_model: &Model,
+) -> QueryGraphBuilderResult<()> {
+    utils::ensure_connect_disconnect_allowed(parent_relation_field)?;
+
+    let relation = parent_relation_field.relation();
+
+    for value in utils::coerce_vec(value) {
+        let as_map: ParsedInputMap<'_> = value.try_into()?;
+        let filter = extract_filter(as_map, child_model)?;
+
+        if relation.is_many_to_many() {
+            handle_many_to_many(graph, &parent_node, parent_relation_field, filter)?;
+        } else {
+            handle_one_to_x(graph, &parent_node, parent_relation_field, filter)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Handles a nested many-to-many disconnect.
 ///
 /// Creates a disconnect node in the graph and creates edges to `parent_node` and `child_node`.
@@ -30,18 +30,20 @@ pub fn connect_nested_query(
     parent: NodeRef,
     parent_relation_field: RelationFieldRef,
     data_map: ParsedInputMap<'_>,
+    path: QueryPath,
 ) -> QueryGraphBuilderResult<()> {
     let child_model = parent_relation_field.related_model();
 
     for (field_name, value) in data_map {
         match field_name.as_ref() {
-            operations::CREATE => nested_create(graph, query_schema,parent, &parent_relation_field, value, &child_model)?,
-            operations::CREATE_MANY => nested_create_many(graph, query_schema, parent, &parent_relation_field, value, &child_model)?,
+            operations::CREATE => nested_create(graph, query_schema,parent, &parent_relation_field, value, &child_model, path.field(operations::CREATE))?,
+            operations::CREATE_MANY => nested_create_many(graph, query_schema, parent, &parent_relation_field, value, &child_model, path.field(operations::CREATE_MANY))?,
             operations::UPDATE => nested_update(graph, query_schema, &parent, &parent_relation_field, value, &child_model)?,
             operations::UPSERT => nested_upsert(graph, query_schema, parent, &parent_relation_field, value)?,
             operations::DELETE => nested_delete(graph, query_schema, &parent, &parent_relation_field, value, &child_model)?,
-            operations::CONNECT => nested_connect(graph, parent, &parent_relation_field, value, &child_model)?,
+            operations::CONNECT => nested_connect(graph, parent, &parent_relation_field, value, &child_model, path.field(operations::CONNECT))?,
             operations::DISCONNECT => nested_disconnect(graph, parent, &parent_relation_field, value, &child_model)?,
+            operations::DISCONNECT_MANY => nested_disconnect_many(graph, parent, &parent_relation_field, value, &child_model)?,
             operations::SET => nested_set(graph, &parent, &parent_relation_field, value, &child_model)?,
             operations::UPDATE_MANY => nested_update_many(graph, query_schema, &parent, &parent_relation_field, value, &child_model)?,
             operations::DELETE_MANY => nested_delete_many(graph, query_schema, &parent, &parent_relation_field, value, &child_model)?,
@@ -6,7 +6,7 @@ use crate::{
     ParsedInputValue,
 };
 use crate::{DataExpectation, RowSink};
-use query_structure::{Filter, Model, RelationFieldRef};
+use query_structure::{ChunkExecutionPolicy, Filter, Model, RelationFieldRef};
 use schema::constants::args;
 use std::convert::TryInto;
 
@@ -145,6 +145,8 @@ pub fn nested_update_many(
                 name: None,
                 nested_field_selection: None,
                 limit: None,
+                order_by: Vec::new(),
+                chunk_execution_policy: ChunkExecutionPolicy::default(),
             },
         )?;
 
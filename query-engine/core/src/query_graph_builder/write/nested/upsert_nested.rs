@@ -137,7 +137,7 @@ pub fn nested_upsert(
 
         let if_node = graph.create_node(Flow::if_non_empty());
         let create_node =
-            create::create_record_node(graph, query_schema, child_model.clone(), create_input.try_into()?)?;
+            create::create_record_node(graph, query_schema, child_model.clone(), create_input.try_into()?, QueryPath::new(args::DATA))?;
         let update_node = update::update_record_node(
             graph,
             query_schema,
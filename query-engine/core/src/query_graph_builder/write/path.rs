@@ -0,0 +1,66 @@
+use std::fmt;
+
+/// Tracks the input path a query graph node was built from, e.g.
+/// `data.orders.create[2].items.createMany.data`. Recorded on nodes at construction time so that,
+/// if the node later fails, the interpreter can attach the path to the user-facing error, letting
+/// clients pinpoint which part of a deeply nested write caused the failure.
+#[derive(Debug, Clone)]
+pub(crate) struct QueryPath(String);
+
+impl QueryPath {
+    /// Starts a new path at the root of a write query, e.g. `QueryPath::new("data")`.
+    pub(crate) fn new(root: &str) -> Self {
+        Self(root.to_owned())
+    }
+
+    /// Appends a field segment, e.g. a relation or operation name.
+    pub(crate) fn field(&self, name: &str) -> Self {
+        Self(format!("{}.{name}", self.0))
+    }
+
+    /// Appends an array index, e.g. the position of a record within a nested `create` list.
+    pub(crate) fn index(&self, idx: usize) -> Self {
+        Self(format!("{}[{idx}]", self.0))
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for QueryPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<QueryPath> for String {
+    fn from(path: QueryPath) -> Self {
+        path.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_nested_create_many_paths() {
+        let path = QueryPath::new("data")
+            .field("orders")
+            .field("create")
+            .index(2)
+            .field("items")
+            .field("createMany")
+            .field("data");
+
+        assert_eq!(path.as_str(), "data.orders.create[2].items.createMany.data");
+    }
+
+    #[test]
+    fn builds_connect_paths() {
+        let path = QueryPath::new("data").field("author").field("connect");
+
+        assert_eq!(path.as_str(), "data.author.connect");
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
 };
 use crate::{DataExpectation, ParsedObject, RowSink};
 use psl::datamodel_connector::ConnectorCapability;
-use query_structure::{Filter, Model};
+use query_structure::{ChunkExecutionPolicy, Filter, Model, OrderBy};
 use schema::{constants::args, QuerySchema};
 use std::convert::TryInto;
 
@@ -28,7 +28,15 @@ pub(crate) fn update_record(
     let data_argument = field.arguments.lookup(args::DATA).unwrap();
     let data_map: ParsedInputMap<'_> = data_argument.value.try_into()?;
 
-    let can_use_atomic_update = can_use_atomic_update(query_schema, &model, &data_map, &field);
+    // When set, the update only returns the primary identifier from the database; any other
+    // client-requested field is fetched with the normal follow-up read below instead of being
+    // selected back from the update itself.
+    let return_minimal: bool = match field.arguments.lookup(args::RETURN_MINIMAL) {
+        Some(arg) => arg.value.try_into()?,
+        None => false,
+    };
+
+    let can_use_atomic_update = !return_minimal && can_use_atomic_update(query_schema, &model, &data_map, &field);
 
     let update_node = update_record_node(
         graph,
@@ -36,7 +44,7 @@ pub(crate) fn update_record(
         filter.clone(),
         model.clone(),
         data_map,
-        Some(&field),
+        if return_minimal { None } else { Some(&field) },
     )?;
 
     if !query_schema.has_capability(ConnectorCapability::UpdateReturning) || query_schema.relation_mode().is_prisma() {
@@ -125,6 +133,18 @@ pub fn update_many_records(
     // "limit"
     let limit = validate_limit(field.arguments.lookup(args::LIMIT))?;
 
+    // "orderBy"
+    let order_by = match field.arguments.lookup(args::ORDER_BY) {
+        Some(order_by_arg) => extract_order_by(&model.clone().into(), order_by_arg.value, Some(&filter))?,
+        None => vec![],
+    };
+
+    // "chunkExecutionPolicy"
+    let chunk_execution_policy = match field.arguments.lookup(args::CHUNK_EXECUTION_POLICY) {
+        Some(policy_arg) => policy_arg.value.try_into()?,
+        None => ChunkExecutionPolicy::default(),
+    };
+
     // "data"
     let data_argument = field.arguments.lookup(args::DATA).unwrap();
     let data_map: ParsedInputMap<'_> = data_argument.value.try_into()?;
@@ -140,13 +160,17 @@ pub fn update_many_records(
                 name: Some(field.name),
                 nested_field_selection: field.nested_fields.filter(|_| with_field_selection),
                 limit,
+                order_by,
+                chunk_execution_policy,
             },
         )?;
     } else {
-        let pre_read_node = graph.create_node(utils::read_ids_infallible(
+        let pre_read_node = graph.create_node(utils::read_ids_infallible_ordered(
             model.clone(),
             model.shard_aware_primary_identifier(),
             filter,
+            order_by.clone(),
+            limit,
         ));
         let update_many_node = update_many_record_node(
             graph,
@@ -158,6 +182,8 @@ pub fn update_many_records(
                 name: Some(field.name),
                 nested_field_selection: field.nested_fields.filter(|_| with_field_selection),
                 limit,
+                order_by,
+                chunk_execution_policy,
             },
         )?;
 
@@ -251,7 +277,14 @@ where
     let update_node = graph.create_node(update_parent);
 
     for (relation_field, data_map) in update_args.nested {
-        nested::connect_nested_query(graph, query_schema, update_node, relation_field, data_map)?;
+        nested::connect_nested_query(
+            graph,
+            query_schema,
+            update_node,
+            relation_field,
+            data_map,
+            QueryPath::new(args::DATA),
+        )?;
     }
 
     Ok(update_node)
@@ -298,12 +331,21 @@ where
         args,
         selected_fields,
         limit: additional_args.limit,
+        order_by: additional_args.order_by,
+        chunk_execution_policy: additional_args.chunk_execution_policy,
     };
 
     let update_many_node = graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(update_many)));
 
     for (relation_field, data_map) in update_args.nested {
-        nested::connect_nested_query(graph, query_schema, update_many_node, relation_field, data_map)?;
+        nested::connect_nested_query(
+            graph,
+            query_schema,
+            update_many_node,
+            relation_field,
+            data_map,
+            QueryPath::new(args::DATA),
+        )?;
     }
 
     Ok(update_many_node)
@@ -343,4 +385,6 @@ pub struct UpdateManyRecordNodeOptionals<'a> {
     pub name: Option<String>,
     pub nested_field_selection: Option<ParsedObject<'a>>,
     pub limit: Option<usize>,
+    pub order_by: Vec<OrderBy>,
+    pub chunk_execution_policy: ChunkExecutionPolicy,
 }
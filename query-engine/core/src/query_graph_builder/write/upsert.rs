@@ -5,8 +5,9 @@ use crate::{
     query_graph::{Flow, QueryGraph, QueryGraphDependency},
     DataExpectation, ParsedField, ParsedInputMap, ParsedInputValue, ParsedObject, RowSink,
 };
+use query_builder::Warning;
 use query_structure::Model;
-use schema::QuerySchema;
+use schema::{constants::args, QuerySchema};
 
 /// Handles a top-level upsert
 ///
@@ -61,7 +62,7 @@ pub(crate) fn upsert_record(
     let update_argument = field.update_arg()?.unwrap();
     let selection = &field.nested_fields;
 
-    let can_use_native_upsert = can_use_connector_native_upsert(
+    let native_upsert_ineligibility_reason = native_upsert_ineligibility_reason(
         &model,
         &where_argument,
         &create_argument,
@@ -69,6 +70,7 @@ pub(crate) fn upsert_record(
         selection,
         query_schema,
     );
+    let can_use_native_upsert = native_upsert_ineligibility_reason.is_none();
 
     let filter = extract_unique_filter(where_argument, &model)?;
     let read_query = read::find_unique(field.clone(), model.clone(), query_schema)?;
@@ -95,13 +97,24 @@ pub(crate) fn upsert_record(
     }
 
     graph.flag_transactional();
+    graph.flag_diagnostic(Warning::EmulatedUpsert {
+        reason: native_upsert_ineligibility_reason
+            .unwrap_or("read query for the upsert selection set is not a single-record query")
+            .to_owned(),
+    });
 
     let model_id = model.shard_aware_primary_identifier();
 
     let read_parent_records = utils::read_ids_infallible(model.clone(), model_id.clone(), filter.clone());
     let read_parent_records_node = graph.create_node(read_parent_records);
 
-    let create_node = create::create_record_node(graph, query_schema, model.clone(), create_argument)?;
+    let create_node = create::create_record_node(
+        graph,
+        query_schema,
+        model.clone(),
+        create_argument,
+        QueryPath::new(args::DATA),
+    )?;
 
     let update_node = update::update_record_node(
         graph,
@@ -191,14 +204,17 @@ pub(crate) fn upsert_record(
 // 2. The create and update arguments do not have any nested queries
 // 3. There is only 1 unique field in the where clause
 // 4. The unique field defined in where clause has the same value as defined in the create arguments
-fn can_use_connector_native_upsert<'a>(
+//
+// Returns `None` when all conditions hold (native upsert can be used), or `Some(reason)`
+// describing the first condition that doesn't, for surfacing as a `Warning::EmulatedUpsert`.
+fn native_upsert_ineligibility_reason<'a>(
     model: &Model,
     where_field: &ParsedInputMap<'a>,
     create_argument: &ParsedInputMap<'a>,
     update_argument: &ParsedInputMap<'a>,
     selection: &Option<ParsedObject<'_>>,
     query_schema: &QuerySchema,
-) -> bool {
+) -> Option<&'static str> {
     let has_nested_selects = has_nested_selects(selection);
 
     let has_nested_create = create_argument
@@ -221,14 +237,25 @@ fn can_use_connector_native_upsert<'a>(
         .iter()
         .all(|(field_name, input)| where_and_create_equal(field_name, input, create_argument));
 
-    query_schema.can_native_upsert()
-        && has_one_unique
-        && !has_nested_create
-        && !has_nested_update
-        && !empty_update
-        && !has_nested_selects
-        && where_values_same_as_create
-        && !query_schema.relation_mode().is_prisma()
+    if !query_schema.can_native_upsert() {
+        Some("the connector does not support native upsert")
+    } else if !has_one_unique {
+        Some("the where clause does not select exactly one unique field")
+    } else if has_nested_create {
+        Some("the create argument has nested creates")
+    } else if has_nested_update {
+        Some("the update argument has nested updates")
+    } else if empty_update {
+        Some("the update argument is empty")
+    } else if has_nested_selects {
+        Some("the selection set has nested selects")
+    } else if !where_values_same_as_create {
+        Some("the where clause values differ from the create argument")
+    } else if query_schema.relation_mode().is_prisma() {
+        Some("relation mode is emulated by Prisma rather than enforced by the connector")
+    } else {
+        None
+    }
 }
 
 fn is_unique_field(field_name: &str, model: &Model) -> bool {
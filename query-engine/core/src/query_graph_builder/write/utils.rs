@@ -2,14 +2,14 @@ use crate::{
     inputs::{IfInput, LeftSideDiffInput, ReturnInput, RightSideDiffInput, UpdateManyRecordsSelectorsInput},
     query_ast::*,
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
-    Computation, DataExpectation, DataOperation, MissingRelatedRecord, ParsedInputValue, QueryGraphBuilderResult,
-    RelationViolation, RowSink,
+    Computation, DataExpectation, DataOperation, MissingRelatedRecord, ParsedInputValue, QueryGraphBuilderError,
+    QueryGraphBuilderResult, RelationViolation, RowSink,
 };
 use indexmap::IndexMap;
 use psl::parser_database::ReferentialAction;
 use query_structure::{
-    DatasourceFieldName, FieldSelection, Filter, Model, PrismaValue, RecordFilter, RelationFieldRef, SelectionResult,
-    WriteArgs, WriteOperation,
+    ChunkExecutionPolicy, DatasourceFieldName, FieldSelection, Filter, Model, OrderBy, PrismaValue, RecordFilter,
+    RelationFieldRef, SelectionResult, WriteArgs, WriteOperation,
 };
 use schema::QuerySchema;
 
@@ -23,6 +23,23 @@ pub(crate) fn coerce_vec(val: ParsedInputValue<'_>) -> Vec<ParsedInputValue<'_>>
     }
 }
 
+/// `connect`/`disconnect` require a stable, unique handle on the related row to link or unlink.
+/// Relations whose `references` were allowed to target a non-unique column (`relationMode =
+/// "prisma"` with the `relationsToNonUniqueColumns` preview feature) don't have one, so those
+/// mutations are rejected here; the relation can still be read and filtered.
+pub(crate) fn ensure_connect_disconnect_allowed(
+    parent_relation_field: &RelationFieldRef,
+) -> QueryGraphBuilderResult<()> {
+    if parent_relation_field.references_unique_fields() {
+        return Ok(());
+    }
+
+    Err(QueryGraphBuilderError::InputError(format!(
+        "The relation field `{}` references a non-unique column and can only be read, not connected or disconnected.",
+        parent_relation_field.name()
+    )))
+}
+
 pub(crate) fn node_is_create(graph: &QueryGraph, node: &NodeRef) -> bool {
     matches!(
         graph.node_content(node).unwrap(),
@@ -32,17 +49,37 @@ pub(crate) fn node_is_create(graph: &QueryGraph, node: &NodeRef) -> bool {
 
 /// Produces a non-failing read query that fetches the requested selection of records for a given filterable.
 pub(crate) fn read_ids_infallible<T>(model: Model, selection: FieldSelection, filter: T) -> Query
+where
+    T: Into<Filter>,
+{
+    read_ids_infallible_ordered(model, selection, filter, vec![], None)
+}
+
+/// Like [`read_ids_infallible`], but additionally orders and limits the records that are read.
+/// Used by the emulated-relation-mode `updateMany`/`deleteMany` pre-read so that the records
+/// picked to satisfy a `take` match the ones the native `ORDER BY ... LIMIT` pushdown would pick.
+pub(crate) fn read_ids_infallible_ordered<T>(
+    model: Model,
+    selection: FieldSelection,
+    filter: T,
+    order_by: Vec<OrderBy>,
+    limit: Option<usize>,
+) -> Query
 where
     T: Into<Filter>,
 {
     let selected_fields = get_selected_fields(&model, selection);
     let filter: Filter = filter.into();
 
+    let mut args: query_structure::QueryArguments = (model.clone(), filter).into();
+    args.order_by = order_by;
+    args.take = limit.map(|limit| limit as i64).into();
+
     let read_query = ReadQuery::ManyRecordsQuery(ManyRecordsQuery {
         name: "read_ids_infallible".into(), // this name only eases debugging
         alias: None,
-        model: model.clone(),
-        args: (model, filter).into(),
+        model,
+        args,
         selected_fields,
         nested: vec![],
         selection_order: vec![],
@@ -252,6 +289,8 @@ where
         args,
         selected_fields: None,
         limit: None,
+        order_by: Vec::new(),
+        chunk_execution_policy: ChunkExecutionPolicy::default(),
     };
 
     graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(ur)))
@@ -534,6 +573,8 @@ pub fn emulate_on_delete_cascade(
         model: dependent_model.clone(),
         record_filter: RecordFilter::empty(),
         limit: None,
+        order_by: Vec::new(),
+        chunk_execution_policy: ChunkExecutionPolicy::default(),
     });
 
     let delete_dependents_node = graph.create_node(Query::Write(delete_query));
@@ -632,6 +673,8 @@ pub fn emulate_on_delete_set_null(
         args: WriteArgs::new(child_update_args, crate::executor::get_request_now()),
         selected_fields: None,
         limit: None,
+        order_by: Vec::new(),
+        chunk_execution_policy: ChunkExecutionPolicy::default(),
     });
 
     let set_null_dependents_node = graph.create_node(Query::Write(set_null_query));
@@ -786,6 +829,8 @@ pub fn emulate_on_update_set_null(
         args: WriteArgs::new(child_update_args, crate::executor::get_request_now()),
         selected_fields: None,
         limit: None,
+        order_by: Vec::new(),
+        chunk_execution_policy: ChunkExecutionPolicy::default(),
     });
 
     let set_null_dependents_node = graph.create_node(Query::Write(set_null_query));
@@ -1107,6 +1152,8 @@ pub fn emulate_on_update_cascade(
         ),
         selected_fields: None,
         limit: None,
+        order_by: Vec::new(),
+        chunk_execution_policy: ChunkExecutionPolicy::default(),
     });
 
     let update_dependents_node = graph.create_node(Query::Write(update_query));
@@ -52,12 +52,21 @@ pub(crate) fn serialize_internal(
             serialize_record_selection_with_relations(*rs, field, field.field_type(), is_list)
         }
         QueryResult::RecordAggregations(ras) => serialize_aggregations(field, ras),
-        QueryResult::Count(c) => {
+        QueryResult::Count(c)
+        | QueryResult::CountWithChunks(c, _)
+        | QueryResult::CountWithConflicts(c, _)
+        | QueryResult::CountWithSkipped(c, _) => {
             // Todo needs a real implementation or needs to move to RecordAggregation
+            let count = i64::try_from(c).map_err(|_| CoreError::ConversionError {
+                value: c.to_string(),
+                from_type: "usize".to_owned(),
+                to_type: "i64".to_owned(),
+            })?;
+
             let mut map: Map = IndexMap::with_capacity(1);
             let mut result = CheckedItemsWithParents::new();
 
-            map.insert(AFFECTED_COUNT.into(), Item::Value(PrismaValue::Int(c as i64)));
+            map.insert(AFFECTED_COUNT.into(), Item::Value(PrismaValue::Int(count)));
             result.insert(None, Item::Map(map));
 
             Ok(result)
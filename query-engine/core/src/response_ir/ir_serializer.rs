@@ -26,6 +26,18 @@ impl<'a> IrSerializer<'a> {
             }
 
             ExpressionResult::Query(r) => {
+                let chunks = match &r {
+                    QueryResult::CountWithChunks(_, chunks) => Some(chunks.clone()),
+                    _ => None,
+                };
+                let conflicts = match &r {
+                    QueryResult::CountWithConflicts(_, conflicts) => Some(conflicts.clone()),
+                    _ => None,
+                };
+                let skipped = match &r {
+                    QueryResult::CountWithSkipped(_, skipped) => Some(skipped.clone()),
+                    _ => None,
+                };
                 let serialized = serialize_internal(r, &self.output_field, false, query_schema)?;
 
                 // On the top level, each result boils down to a exactly a single serialized result.
@@ -51,7 +63,12 @@ impl<'a> IrSerializer<'a> {
                     item
                 };
 
-                Ok(ResponseData::new(self.key.clone(), result))
+                Ok(match (chunks, conflicts, skipped) {
+                    (Some(chunks), _, _) => ResponseData::with_chunks(self.key.clone(), result, chunks),
+                    (None, Some(conflicts), _) => ResponseData::with_conflicts(self.key.clone(), result, conflicts),
+                    (None, None, Some(skipped)) => ResponseData::with_skipped(self.key.clone(), result, skipped),
+                    (None, None, None) => ResponseData::new(self.key.clone(), result),
+                })
             }
 
             ExpressionResult::Empty => panic!("Internal error: Attempted to serialize empty result."),
@@ -1,4 +1,6 @@
 use super::*;
+use crate::PlanTimings;
+use connector::{ChunkBreakdown, CreateManyErrorReport, SkipDuplicatesReport};
 
 #[derive(Debug)]
 pub struct ResponseData {
@@ -7,10 +9,67 @@ pub struct ResponseData {
 
     /// The actual response data.
     pub data: Item,
+
+    /// Per-statement breakdown, set when this response comes from an `updateMany`/`deleteMany`
+    /// that had to be split into more than one statement.
+    pub chunks: Option<ChunkBreakdown>,
+
+    /// Set when this response comes from a `createMany` run with `collectErrors: true`: reports
+    /// which rows, if any, were skipped because of a unique or null constraint conflict.
+    pub conflicts: Option<CreateManyErrorReport>,
+
+    /// Set when this response comes from a `createMany` run with `returnSkipped: true`: reports
+    /// which rows, if any, `skipDuplicates` skipped.
+    pub skipped: Option<SkipDuplicatesReport>,
+
+    /// Per-node execution timings, set when the interpreter that produced this response was built
+    /// with timing collection enabled. `None` rather than an empty [`PlanTimings`] when disabled,
+    /// so callers can tell "not collected" apart from "plan had no timed nodes".
+    pub timings: Option<PlanTimings>,
 }
 
 impl ResponseData {
     pub fn new(key: String, data: Item) -> Self {
-        Self { key, data }
+        Self {
+            key,
+            data,
+            chunks: None,
+            conflicts: None,
+            skipped: None,
+            timings: None,
+        }
+    }
+
+    pub fn with_chunks(key: String, data: Item, chunks: ChunkBreakdown) -> Self {
+        Self {
+            key,
+            data,
+            chunks: Some(chunks),
+            conflicts: None,
+            skipped: None,
+            timings: None,
+        }
+    }
+
+    pub fn with_conflicts(key: String, data: Item, conflicts: CreateManyErrorReport) -> Self {
+        Self {
+            key,
+            data,
+            chunks: None,
+            conflicts: Some(conflicts),
+            skipped: None,
+            timings: None,
+        }
+    }
+
+    pub fn with_skipped(key: String, data: Item, skipped: SkipDuplicatesReport) -> Self {
+        Self {
+            key,
+            data,
+            chunks: None,
+            conflicts: None,
+            skipped: Some(skipped),
+            timings: None,
+        }
     }
 }
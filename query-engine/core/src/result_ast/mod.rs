@@ -5,6 +5,15 @@ use query_structure::{ManyRecords, Model, RawJson, SelectionResult, VirtualSelec
 pub enum QueryResult {
     Id(Option<SelectionResult>),
     Count(usize),
+    /// Like `Count`, but for an `updateMany`/`deleteMany` that had to be split into more than one
+    /// statement: carries a per-statement breakdown alongside the total affected count.
+    CountWithChunks(usize, connector::ChunkBreakdown),
+    /// Like `Count`, but for a `createMany` run with `collectErrors: true`: carries the inserted
+    /// count alongside every row that was skipped because of a unique or null constraint conflict.
+    CountWithConflicts(usize, connector::CreateManyErrorReport),
+    /// Like `Count`, but for a `createMany` run with `returnSkipped: true`: carries the inserted
+    /// count alongside every row that `skipDuplicates` skipped.
+    CountWithSkipped(usize, connector::SkipDuplicatesReport),
     RecordSelection(Option<Box<RecordSelection>>),
     RecordSelectionWithRelations(Box<RecordSelectionWithRelations>),
     RawJson(RawJson),
@@ -27,6 +36,9 @@ impl QueryResult {
     pub fn affected_row_count(&self) -> Option<usize> {
         match self {
             QueryResult::Count(count) => Some(*count),
+            QueryResult::CountWithChunks(count, _) => Some(*count),
+            QueryResult::CountWithConflicts(count, _) => Some(*count),
+            QueryResult::CountWithSkipped(count, _) => Some(*count),
             _ => None,
         }
     }
@@ -1,6 +1,6 @@
 use query_structure::{
-    AggregationSelection, FieldSelection, Filter, Model, Placeholder, PrismaValue, QueryArguments, RecordFilter,
-    RelationField, RelationLoadStrategy, ScalarCondition, ScalarField, SelectedField, SelectionResult,
+    AggregationSelection, FieldSelection, Filter, Model, OrderBy, Placeholder, PrismaValue, QueryArguments,
+    RecordFilter, RelationField, RelationLoadStrategy, ScalarCondition, ScalarField, SelectedField, SelectionResult,
     TaggedPrismaValue, WriteArgs,
 };
 use serde::Serialize;
@@ -13,7 +13,79 @@ mod query_arguments_ext;
 pub use query_arguments_ext::QueryArgumentsExt;
 use query_template::{Fragment, PlaceholderFormat};
 
+/// A diagnostic describing a query plan that a `QueryBuilder` had to silently degrade, e.g. by
+/// falling back to in-memory processing or splitting a single logical statement into several.
+/// Surfaced end to end by `query_compiler::compile_with_diagnostics`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Warning {
+    /// A `distinct` couldn't be pushed down into the query and was applied in memory instead.
+    InMemoryDistinct { model: String },
+    /// An `upsert` couldn't use the connector's native `ON CONFLICT`-style statement and was
+    /// rewritten into a read-then-branch query graph instead.
+    EmulatedUpsert { reason: String },
+    /// A single logical write was split into several statements, e.g. because the number of
+    /// affected records exceeded the connector's bind parameter limit.
+    ChunkedStatements { count: usize },
+    /// A `deleteMany`/`updateMany` filter can't be proven to exclude any row until a placeholder
+    /// inside it is resolved, so the builder let it through without knowing whether it is a
+    /// full-table write. Only reported when the builder is configured to forbid unfiltered writes.
+    PossiblyUnfilteredWrite { model: String, operation: UnfilteredWriteOperation },
+}
+
+/// The write operation an [`UnfilteredWriteError`] or [`Warning::PossiblyUnfilteredWrite`] was
+/// raised for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnfilteredWriteOperation {
+    UpdateMany,
+    DeleteMany,
+}
+
+impl fmt::Display for UnfilteredWriteOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UpdateMany => write!(f, "updateMany"),
+            Self::DeleteMany => write!(f, "deleteMany"),
+        }
+    }
+}
+
+/// Returned by [`QueryBuilder::build_updates`]/[`QueryBuilder::build_deletes`] when the builder is
+/// configured to forbid unfiltered writes and the given filter is statically known to match every
+/// row of `model`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnfilteredWriteError {
+    pub model: String,
+    pub operation: UnfilteredWriteOperation,
+}
+
+impl fmt::Display for UnfilteredWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Aborting `{}` on `{}`: the filter does not exclude any row and unfiltered writes are forbidden. \
+             Pass an explicit filter, or a `limit`, to proceed.",
+            self.operation, self.model
+        )
+    }
+}
+
+impl std::error::Error for UnfilteredWriteError {}
+
 pub trait QueryBuilder {
+    /// Warnings accumulated while building queries through this builder, e.g. via [`Warning`]
+    /// reported through [`QueryBuilder::report_warning`]. Drains the accumulated warnings,
+    /// leaving the builder's warning list empty.
+    fn drain_warnings(&self) -> Vec<Warning> {
+        Vec::new()
+    }
+
+    /// Called by translation code that detects a degraded query plan the builder itself can't
+    /// see (e.g. an in-memory distinct decided from `QueryArguments`). Builders that collect
+    /// warnings should store it for a later [`QueryBuilder::drain_warnings`] call.
+    fn report_warning(&self, _warning: Warning) {}
+
     fn build_get_records(
         &self,
         model: &Model,
@@ -69,6 +141,7 @@ pub trait QueryBuilder {
         record_filter: RecordFilter,
         args: WriteArgs,
         selected_fields: Option<&FieldSelection>,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
     ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>>;
 
@@ -107,6 +180,7 @@ pub trait QueryBuilder {
         &self,
         model: &Model,
         filter: RecordFilter,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
     ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>>;
 
@@ -125,9 +199,10 @@ pub struct CreateRecord {
     /// The query to run prior to the insert in order to create default column values.
     /// This is used in some cases where the database does not support returning default values.
     pub select_defaults: Option<CreateRecordDefaultsQuery>,
-    /// The field in the model of the record that corresponds to the last inserted ID, if
-    /// required by the database.
-    pub last_insert_id_field: Option<ScalarField>,
+    /// The fields in the model of the record that correspond to the last inserted ID, if
+    /// required by the database. Usually at most one field, but a shard-aware primary identifier
+    /// can have more than one autoincrement-backed column (e.g. an id plus a shard column).
+    pub last_insert_id_fields: Vec<ScalarField>,
     /// The values to merge into the resulting record after insertion. These are inferred from the
     /// input arguments.
     pub merge_values: Vec<(SelectedField, PrismaValue)>,
@@ -257,6 +332,9 @@ impl fmt::Display for DbQuery {
                         Fragment::Parameter => {
                             placeholder_format.write(formatter, &mut number)?;
                         }
+                        Fragment::ParameterRef { index } => {
+                            placeholder_format.write(formatter, &mut (*index as i32 + 1))?;
+                        }
                         Fragment::ParameterTuple => {
                             write!(formatter, "[")?;
                             placeholder_format.write(formatter, &mut number)?;
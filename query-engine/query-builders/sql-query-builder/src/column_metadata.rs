@@ -6,6 +6,7 @@ pub struct ColumnMetadata<'a> {
     identifier: &'a TypeIdentifier,
     name: Option<&'a str>,
     arity: FieldArity,
+    generated: bool,
 }
 
 impl<'a> ColumnMetadata<'a> {
@@ -14,6 +15,7 @@ impl<'a> ColumnMetadata<'a> {
             identifier,
             name: None,
             arity,
+            generated: false,
         }
     }
 
@@ -23,6 +25,15 @@ impl<'a> ColumnMetadata<'a> {
         self
     }
 
+    /// Marks the column as a database-computed (e.g. `@computedSql`) column, which is only ever
+    /// read back, never written. Doesn't change how the column is read; write builders consult
+    /// this to know which of their selected columns must never appear in an `INSERT`/`UPDATE`
+    /// column list.
+    fn set_generated(mut self, generated: bool) -> Self {
+        self.generated = generated;
+        self
+    }
+
     /// The type of the column.
     pub fn identifier(self) -> &'a TypeIdentifier {
         self.identifier
@@ -37,6 +48,11 @@ impl<'a> ColumnMetadata<'a> {
     pub fn arity(self) -> FieldArity {
         self.arity
     }
+
+    /// True if this column is database-computed and must be excluded from write column lists.
+    pub fn generated(self) -> bool {
+        self.generated
+    }
 }
 
 /// Create a set of metadata objects, combining column names and type
@@ -54,6 +70,32 @@ where
         .collect()
 }
 
+/// Like [`create`], but also marks which of the columns are database-computed (see
+/// [`ColumnMetadata::generated`]), for callers that already know which of their fields are
+/// `@computedSql` columns.
+pub fn create_with_generated<'a, T>(
+    field_names: &'a [T],
+    idents: &'a [(TypeIdentifier, FieldArity)],
+    generated: &[bool],
+) -> Vec<ColumnMetadata<'a>>
+where
+    T: AsRef<str>,
+{
+    assert_eq!(field_names.len(), idents.len());
+    assert_eq!(field_names.len(), generated.len());
+
+    idents
+        .iter()
+        .zip(field_names.iter())
+        .zip(generated.iter())
+        .map(|(((identifier, arity), name), generated)| {
+            ColumnMetadata::new(identifier, *arity)
+                .set_name(name.as_ref())
+                .set_generated(*generated)
+        })
+        .collect()
+}
+
 /// Create a set of metadata objects.
 pub fn create_anonymous(idents: &[(TypeIdentifier, FieldArity)]) -> Vec<ColumnMetadata<'_>> {
     idents
@@ -61,3 +103,34 @@ pub fn create_anonymous(idents: &[(TypeIdentifier, FieldArity)]) -> Vec<ColumnMe
         .map(|(identifier, arity)| ColumnMetadata::new(identifier, *arity))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_defaults_generated_to_false() {
+        let idents = [(TypeIdentifier::Int, FieldArity::Required)];
+        let field_names = ["id"];
+
+        let meta = create(&field_names, &idents);
+
+        assert!(!meta[0].generated());
+    }
+
+    #[test]
+    fn create_with_generated_marks_computed_columns() {
+        let idents = [
+            (TypeIdentifier::Int, FieldArity::Required),
+            (TypeIdentifier::String, FieldArity::Required),
+        ];
+        let field_names = ["id", "fullName"];
+        let generated = [false, true];
+
+        let meta = create_with_generated(&field_names, &idents, &generated);
+
+        assert!(!meta[0].generated());
+        assert!(meta[1].generated());
+        assert_eq!(meta[1].name(), Some("fullName"));
+    }
+}
@@ -0,0 +1,8 @@
+/// The placeholder that `@computedSql` expressions use to refer to the current table alias.
+const SELF_PLACEHOLDER: &str = "{{self}}";
+
+/// Substitutes the `{{self}}` placeholder in a `@computedSql` expression with the table alias (or
+/// bare table name) the field is currently being selected or ordered through.
+pub fn render_computed_sql(expr: &str, alias: &str) -> String {
+    expr.replace(SELF_PLACEHOLDER, alias)
+}
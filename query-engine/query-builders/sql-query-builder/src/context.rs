@@ -1,10 +1,32 @@
-use std::sync::{self, atomic::AtomicUsize};
+use std::sync::{self, atomic::AtomicUsize, Arc, LazyLock, Mutex};
 
 use quaint::prelude::{ConnectionInfo, SqlFamily};
+use query_builder::Warning;
 use telemetry::TraceParent;
 
 use crate::filter::alias::Alias;
 use crate::dynamic_schema::DynamicSchema;
+use crate::query_metrics::{QueryMetricsCollector, QueryMetricsEvent};
+use crate::sql_trace::TraceCommentMode;
+
+/// Default `TraceCommentMode`, taken from the `PRISMA_TRACE_COMMENT_MODE` env var
+/// (`disabled`/`static`/`full`) when set, falling back to `TraceCommentMode::Full` otherwise.
+/// Callers that already know the desired mode (e.g. the query compiler's client-provided config)
+/// should set it explicitly via `Context::with_trace_comment_mode` instead of relying on this.
+static DEFAULT_TRACE_COMMENT_MODE: LazyLock<TraceCommentMode> = LazyLock::new(|| {
+    match std::env::var("PRISMA_TRACE_COMMENT_MODE").ok().as_deref() {
+        Some("disabled") => TraceCommentMode::Disabled,
+        Some("static") => TraceCommentMode::Static,
+        _ => TraceCommentMode::Full,
+    }
+});
+
+/// Default `max_result_rows`, taken from the `PRISMA_MAX_RESULT_ROWS` env var when set to a valid
+/// `usize`, falling back to unlimited (`None`) otherwise. Callers that already know the desired
+/// limit (e.g. the query compiler's client-provided config) should set it explicitly via
+/// `Context::with_max_result_rows` instead of relying on this.
+static DEFAULT_MAX_RESULT_ROWS: LazyLock<Option<usize>> =
+    LazyLock::new(|| std::env::var("PRISMA_MAX_RESULT_ROWS").ok().and_then(|v| v.parse().ok()));
 
 /**
  * Changed by @vetching-corporation
@@ -21,10 +43,39 @@ pub struct Context<'a> {
     /// Maximum number of bind parameters allowed for a single query.
     /// None is unlimited.
     pub(crate) max_bind_values: Option<usize>,
+    /// Maximum number of rows a single read query is allowed to return.
+    /// None is unlimited.
+    max_result_rows: Option<usize>,
 
     dynamic_schema: DynamicSchema,
 
+    trace_comment_mode: TraceCommentMode,
+
+    /// Whether write builders should run `WriteArgs::validate_against` before turning the args
+    /// into SQL. Off by default, since it's an extra pass over every write and existing callers
+    /// may not expect the resulting `FieldConversionError`s.
+    validate_write_args: bool,
+
+    /// Whether `build_updates`/`build_deletes` should reject filters that are statically known to
+    /// match every row of the model. Off by default, since existing callers may rely on an empty
+    /// filter being a valid (if dangerous) way to update/delete an entire table.
+    forbid_unfiltered_writes: bool,
+
+    /// Whether `convert_query` should collapse repeated identical bind values into a single
+    /// parameter. Off by default, since it changes the parameter list callers see even though
+    /// the resulting query is semantically equivalent.
+    reuse_duplicate_parameters: bool,
+
     alias_counter: AtomicUsize,
+
+    /// Warnings about degraded query plans, collected during query building and drained by
+    /// `compile_with_diagnostics`. See [`Context::push_warning`].
+    warnings: Mutex<Vec<Warning>>,
+
+    /// Optional sink for [`QueryMetricsEvent`]s reported by `build_get_records`/
+    /// `chunked_conditions`. `None` by default, so reporting is a no-op unless a caller opts in
+    /// via [`Context::with_metrics_collector`].
+    metrics_collector: Option<Arc<dyn QueryMetricsCollector>>,
 }
 
 impl<'a> Context<'a> {
@@ -37,11 +88,69 @@ impl<'a> Context<'a> {
             traceparent,
             max_insert_rows,
             max_bind_values: Some(max_bind_values),
+            max_result_rows: *DEFAULT_MAX_RESULT_ROWS,
             dynamic_schema: DynamicSchema::default(),
+            trace_comment_mode: *DEFAULT_TRACE_COMMENT_MODE,
+            validate_write_args: false,
+            forbid_unfiltered_writes: false,
+            reuse_duplicate_parameters: false,
             alias_counter: Default::default(),
+            warnings: Default::default(),
+            metrics_collector: None,
         }
     }
 
+    /// Sets the sink that `build_get_records`/`chunked_conditions` report [`QueryMetricsEvent`]s
+    /// to. Left unset, reporting is a no-op, so callers that don't care about query metrics (e.g.
+    /// tests, or builds of this crate without a metrics backend available) pay nothing for it.
+    pub fn with_metrics_collector(mut self, collector: Arc<dyn QueryMetricsCollector>) -> Self {
+        self.metrics_collector = Some(collector);
+        self
+    }
+
+    pub(crate) fn record_query_metrics(&self, event: QueryMetricsEvent) {
+        if let Some(collector) = &self.metrics_collector {
+            collector.record(event);
+        }
+    }
+
+    /// Enables pre-validating `WriteArgs` against their target column types (see
+    /// `WriteArgs::validate_against`) before `build_create_record`, `build_inserts` and
+    /// `build_update` turn them into SQL.
+    pub fn with_validate_write_args(mut self, validate: bool) -> Self {
+        self.validate_write_args = validate;
+        self
+    }
+
+    pub fn validate_write_args(&self) -> bool {
+        self.validate_write_args
+    }
+
+    /// Enables rejecting `updateMany`/`deleteMany` calls whose filter is statically known to match
+    /// every row, e.g. `{}` or a filter containing an always-true `OR` branch. See
+    /// `query_builder::UnfilteredWriteError`.
+    pub fn with_forbid_unfiltered_writes(mut self, forbid: bool) -> Self {
+        self.forbid_unfiltered_writes = forbid;
+        self
+    }
+
+    pub fn forbid_unfiltered_writes(&self) -> bool {
+        self.forbid_unfiltered_writes
+    }
+
+    /// Enables collapsing repeated identical bind values in `convert_query` into a single
+    /// parameter, reusing its placeholder instead of binding a duplicate (see
+    /// `crate::dedup::dedupe_parameters`). Off by default; only ever applied for placeholder
+    /// syntaxes that support referencing the same bound parameter more than once.
+    pub fn with_reuse_duplicate_parameters(mut self, reuse: bool) -> Self {
+        self.reuse_duplicate_parameters = reuse;
+        self
+    }
+
+    pub fn reuse_duplicate_parameters(&self) -> bool {
+        self.reuse_duplicate_parameters
+    }
+
     /**
      * Changed by @vetching-corporation
      * Author: nfl1ryxditimo12@gmail.com
@@ -54,6 +163,18 @@ impl<'a> Context<'a> {
         ctx
     }
 
+    /// Overrides the `TraceCommentMode` this context would otherwise default to (see
+    /// `PRISMA_TRACE_COMMENT_MODE`). Intended for callers that already know the desired mode from
+    /// their own configuration, e.g. the query compiler's client-provided config.
+    pub fn with_trace_comment_mode(mut self, mode: TraceCommentMode) -> Self {
+        self.trace_comment_mode = mode;
+        self
+    }
+
+    pub fn trace_comment_mode(&self) -> TraceCommentMode {
+        self.trace_comment_mode
+    }
+
     pub fn traceparent(&self) -> Option<TraceParent> {
         self.traceparent
     }
@@ -62,6 +183,33 @@ impl<'a> Context<'a> {
         self.connection_info.schema_name()
     }
 
+    /**
+     * Changed by @vetching-corporation
+     * Author: nfl1ryxditimo12@gmail.com
+     * Date: 2025-06-16
+     * Note: Add search_path-based dynamic schema support for the native Postgres connector
+     */
+    /// Whether a dynamically remapped schema should be applied via `SET search_path` instead of
+    /// being inlined into table references. Only ever true for Postgres connections, since
+    /// `search_path` is a Postgres-specific session setting.
+    pub(crate) fn use_search_path_for_dynamic_schema(&self) -> bool {
+        self.dynamic_schema.via_search_path() && self.sql_family() == SqlFamily::Postgres
+    }
+
+    /// True if `origin_schema` is remapped by the dynamic schema and that remapping is applied
+    /// via `search_path`, meaning table references for it should omit the schema prefix entirely.
+    pub(crate) fn is_dynamically_remapped_schema(&self, origin_schema: &str) -> bool {
+        self.use_search_path_for_dynamic_schema() && self.dynamic_schema.contains_key(origin_schema)
+    }
+
+    /// The `SET search_path` statement argument to emit before the query, if the dynamic schema
+    /// is configured to apply via search_path and has any remapping to apply.
+    pub fn dynamic_schema_search_path(&self) -> Option<String> {
+        self.use_search_path_for_dynamic_schema()
+            .then(|| self.dynamic_schema.search_path())
+            .flatten()
+    }
+
     pub fn sql_family(&self) -> SqlFamily {
         self.connection_info.sql_family()
     }
@@ -74,6 +222,18 @@ impl<'a> Context<'a> {
         self.max_bind_values
     }
 
+    /// Overrides the maximum number of rows a single read query is allowed to return (see
+    /// `PRISMA_MAX_RESULT_ROWS`). Intended for callers that already know the desired limit from
+    /// their own configuration, e.g. the query compiler's client-provided config.
+    pub fn with_max_result_rows(mut self, max_result_rows: Option<usize>) -> Self {
+        self.max_result_rows = max_result_rows;
+        self
+    }
+
+    pub fn max_result_rows(&self) -> Option<usize> {
+        self.max_result_rows
+    }
+
     pub(crate) fn next_table_alias(&self) -> Alias {
         Alias::Table(self.alias_counter.fetch_add(1, sync::atomic::Ordering::SeqCst))
     }
@@ -82,6 +242,25 @@ impl<'a> Context<'a> {
         Alias::Join(self.alias_counter.fetch_add(1, sync::atomic::Ordering::SeqCst))
     }
 
+    /// Resets the table/join alias counter back to zero, so the next `next_table_alias`/
+    /// `next_join_alias` call produces `t0`/`j0` again. Aliases are otherwise process-lifetime
+    /// monotonic within a `Context`, which makes the generated SQL depend on how many aliases
+    /// were already handed out before a given query was built, e.g. in snapshot tests that build
+    /// more than one query against the same context. Call this between queries that are expected
+    /// to produce identical, order-independent SQL.
+    pub fn reset_aliases(&self) {
+        self.alias_counter.store(0, sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Records a degraded-query-plan warning, to be drained later by [`Context::drain_warnings`].
+    pub(crate) fn push_warning(&self, warning: Warning) {
+        self.warnings.lock().unwrap().push(warning);
+    }
+
+    pub(crate) fn drain_warnings(&self) -> Vec<Warning> {
+        std::mem::take(&mut *self.warnings.lock().unwrap())
+    }
+
     /**
      * Changed by @vetching-corporation
      * Author: nfl1ryxditimo12@gmail.com
@@ -95,4 +274,106 @@ impl<'a> Context<'a> {
 
         self.dynamic_schema.get(origin_schema).map(|s| s.to_owned())
     }
+
+    /**
+     * Changed by @vetching-corporation
+     * Author: nfl1ryxditimo12@gmail.com
+     * Date: 2025-06-16
+     * Note: Remap the schema component of a schema-qualified function call in a
+     * `@default(dbgenerated(...))` expression (e.g. `app.generate_code()`) the same way table
+     * references are remapped, so tenants don't end up calling another tenant's function.
+     */
+    pub fn rewrite_dbgenerated_schema(&self, expr: &str) -> String {
+        crate::dbgenerated::rewrite_schema_qualified_call(expr, |schema| self.target_schema(schema))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::{
+        ast::{Column, Select, Table},
+        prelude::ExternalConnectionInfo,
+        visitor::{Postgres, Visitor},
+    };
+
+    fn connection_info() -> ConnectionInfo {
+        ConnectionInfo::External(ExternalConnectionInfo::new(
+            SqlFamily::Postgres,
+            Some("public".to_owned()),
+            None,
+            true,
+        ))
+    }
+
+    /// Builds the same trivial query against `ctx`, using up one table and one join alias.
+    fn render_query(ctx: &Context<'_>) -> String {
+        let table = Table::from("users").alias(ctx.next_table_alias().to_string());
+        let select = Select::from_table(table).column(Column::from("id").table(ctx.next_join_alias().to_string()));
+
+        let (sql, _) = Postgres::build(select).unwrap();
+        sql
+    }
+
+    #[test]
+    fn reset_aliases_produces_identical_sql_to_a_fresh_context() {
+        let connection_info = connection_info();
+
+        let reused_ctx = Context::new(&connection_info, None);
+        // Simulate aliases already handed out by earlier query building on the same context.
+        reused_ctx.next_table_alias();
+        reused_ctx.next_join_alias();
+        reused_ctx.reset_aliases();
+
+        let fresh_ctx = Context::new(&connection_info, None);
+
+        assert_eq!(render_query(&reused_ctx), render_query(&fresh_ctx));
+    }
+
+    #[test]
+    fn record_query_metrics_is_a_no_op_without_a_collector() {
+        let connection_info = connection_info();
+        let ctx = Context::new(&connection_info, None);
+
+        // Must not panic: there's simply nowhere for the event to go.
+        ctx.record_query_metrics(QueryMetricsEvent {
+            bind_values: 1,
+            chunks: 1,
+            relation_strategy: None,
+        });
+    }
+
+    #[test]
+    fn rewrite_dbgenerated_schema_remaps_through_dynamic_schema() {
+        let connection_info = connection_info();
+        let mut dynamic_schema = DynamicSchema::new();
+        dynamic_schema.insert("app".to_owned(), "tenant1".to_owned());
+
+        let ctx = Context::new_with_dynamic_schema(&connection_info, dynamic_schema, None);
+
+        assert_eq!(
+            ctx.rewrite_dbgenerated_schema("app.generate_code()"),
+            "tenant1.generate_code()"
+        );
+    }
+
+    #[test]
+    fn record_query_metrics_reports_chunk_count_to_the_collector() {
+        use crate::query_metrics::test_collector::TestMetricsCollector;
+
+        let connection_info = connection_info();
+        let collector = Arc::new(TestMetricsCollector::default());
+        let ctx = Context::new(&connection_info, None).with_metrics_collector(collector.clone());
+
+        ctx.record_query_metrics(QueryMetricsEvent {
+            bind_values: 6000,
+            chunks: 3,
+            relation_strategy: None,
+        });
+
+        let events = collector.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].chunks, 3);
+        assert_eq!(events[0].bind_values, 6000);
+    }
 }
@@ -19,6 +19,10 @@ struct CursorOrderDefinition {
     pub(crate) order_fks: Option<Vec<CursorOrderForeignKey>>,
     /// Indicates whether the ordering is performed on nullable field(s)
     pub(crate) on_nullable_fields: bool,
+    /// Where NULLs sort relative to non-null values, as requested via `orderBy: { nulls: ... }`.
+    /// `None` means the client didn't specify it, in which case we fall back to treating a NULL on
+    /// either side of the comparison as always matching (see `map_orderby_condition`).
+    pub(crate) nulls_order: Option<NullsOrder>,
 }
 
 #[derive(Debug)]
@@ -356,12 +360,18 @@ fn map_orderby_condition(
     }
     .into();
 
-    // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
-    // statement over their order relative to the cursor.
+    // If we have null values in the ordering or comparison row, we need to account for where NULLs
+    // sort relative to non-null values (see `nulls_position_condition`) so that rows with a NULL on
+    // either side are included in exactly one page, instead of being skipped or duplicated.
     let order_expr = if order_definition.on_nullable_fields {
+        let reversed_nulls_order = reverse_nulls_order(order_definition.nulls_order.as_ref(), reverse);
+
         order_expr
-            .or(cloned_order_column.is_null())
-            .or(Expression::from(cloned_cmp_column).is_null())
+            .or(nulls_position_condition(
+                reversed_nulls_order,
+                cloned_order_column,
+                cloned_cmp_column,
+            ))
             .into()
     } else {
         order_expr
@@ -388,6 +398,43 @@ fn map_orderby_condition(
     order_expr
 }
 
+/// Flips `nulls_order` when `reverse` is set, mirroring how `ordering::into_order` flips the
+/// NULLS position whenever the whole ORDER BY is reversed (e.g. for `take: -N`): reversing ASC
+/// NULLS FIRST yields DESC NULLS LAST, so NULLs that used to sort first now sort last.
+fn reverse_nulls_order(nulls_order: Option<&NullsOrder>, reverse: bool) -> Option<NullsOrder> {
+    match (nulls_order, reverse) {
+        (Some(NullsOrder::First), true) => Some(NullsOrder::Last),
+        (Some(NullsOrder::Last), true) => Some(NullsOrder::First),
+        (other, _) => other.cloned(),
+    }
+}
+
+/// Builds the condition under which a NULL on either side of the comparison should count as
+/// "sorts after the cursor", given where NULLs actually sort relative to non-null values.
+///
+/// This only decides what happens to NULLs; `map_orderby_condition` already chose the right
+/// `<`/`>`/`<=`/`>=` comparison for non-null values, including handling `reverse`.
+fn nulls_position_condition(
+    nulls_order: Option<NullsOrder>,
+    order_column: Expression<'static>,
+    cmp_column: Select<'static>,
+) -> Expression<'static> {
+    let cmp_expr: Expression<'static> = cmp_column.into();
+
+    match nulls_order {
+        // NULLs sort after every non-null value, so a NULL row is "after" the cursor unless the
+        // cursor itself is NULL too (NULL is already the last possible value, nothing is after it).
+        Some(NullsOrder::Last) => order_column.is_null().and(cmp_expr.is_not_null()).into(),
+        // NULLs sort before every non-null value, so any non-null row is "after" a NULL cursor,
+        // while a NULL row can never be "after" a non-null cursor.
+        Some(NullsOrder::First) => cmp_expr.is_null().and(order_column.is_not_null()).into(),
+        // The client didn't request a NULLS order: keep the previous, connector-agnostic behavior
+        // of treating a NULL on either side as always matching, since we can't know which way the
+        // connector's own default would have sorted it.
+        None => order_column.is_null().or(cmp_expr.is_null()).into(),
+    }
+}
+
 fn map_equality_condition(
     order_subquery: &Select<'static>,
     order_definition: &CursorOrderDefinition,
@@ -395,14 +442,14 @@ fn map_equality_condition(
     let cmp_column = order_subquery.clone().value(order_definition.order_column.clone());
     let order_column = order_definition.order_column.to_owned();
 
-    // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
-    // statement over their order relative to the cursor.
+    // NULL isn't equal to anything in SQL, including another NULL, so a plain `=` would never
+    // match two NULLs against each other. Treat them as equal here ourselves, but only when both
+    // sides are NULL: if only one side is, the rows are *not* equal, no matter which side it is.
     if order_definition.on_nullable_fields {
         order_column
             .clone()
             .equals(cmp_column.clone())
-            .or(Expression::from(cmp_column).is_null())
-            .or(order_column.is_null())
+            .or(order_column.is_null().and(Expression::from(cmp_column).is_null()))
             .into()
     } else {
         order_column.equals(cmp_column).into()
@@ -430,6 +477,7 @@ fn order_definitions(
                 order_column: f.as_column(ctx).into(),
                 order_fks: None,
                 on_nullable_fields: !f.is_required(),
+                nulls_order: None,
             })
             .collect();
     }
@@ -444,6 +492,7 @@ fn order_definitions(
             OrderBy::ScalarAggregation(order_by) => cursor_order_def_aggregation_scalar(order_by, order_by_def),
             OrderBy::ToManyAggregation(order_by) => cursor_order_def_aggregation_rel(order_by, order_by_def),
             OrderBy::Relevance(order_by) => cursor_order_def_relevance(order_by, order_by_def),
+            OrderBy::InputOrder(order_by) => cursor_order_def_input_order(order_by, order_by_def),
         })
         .collect_vec()
 }
@@ -460,6 +509,11 @@ fn cursor_order_def_scalar(order_by: &OrderByScalar, order_by_def: &OrderByDefin
         order_column: order_by_def.order_column.clone(),
         order_fks: fks,
         on_nullable_fields: !order_by.field.is_required(),
+        // Mirrors `ordering::build_order_scalar`'s default so the cursor `WHERE` clause agrees
+        // with the main query's `ORDER BY` on where NULLs from an optional relation hop land,
+        // instead of falling back to `nulls_position_condition`'s connector-agnostic "NULL always
+        // matches" behavior for a case we can now actually pin down.
+        nulls_order: order_by.effective_nulls_order(),
     }
 }
 
@@ -479,6 +533,7 @@ fn cursor_order_def_aggregation_scalar(
         order_column: order_column.clone(),
         order_fks: None,
         on_nullable_fields: false,
+        nulls_order: None,
     }
 }
 
@@ -502,6 +557,7 @@ fn cursor_order_def_aggregation_rel(
         order_column: order_column.clone(),
         order_fks: fks,
         on_nullable_fields: false,
+        nulls_order: None,
     }
 }
 
@@ -514,6 +570,22 @@ fn cursor_order_def_relevance(order_by: &OrderByRelevance, order_by_def: &OrderB
         order_column: order_column.clone(),
         order_fks: None,
         on_nullable_fields: false,
+        nulls_order: None,
+    }
+}
+
+/// Build a CursorOrderDefinition for an order by input order.
+fn cursor_order_def_input_order(
+    order_by: &OrderByInputOrder,
+    order_by_def: &OrderByDefinition,
+) -> CursorOrderDefinition {
+    CursorOrderDefinition {
+        sort_order: SortOrder::Ascending,
+        order_column: order_by_def.order_column.clone(),
+        order_fks: None,
+        on_nullable_fields: !order_by.field.is_required(),
+        // `_inputOrder` has no `nulls` option of its own.
+        nulls_order: None,
     }
 }
 
@@ -581,3 +653,144 @@ fn take_last_two_elem<T>(slice: &[T]) -> (Option<&T>, Option<&T>) {
         _ => (slice.get(len - 2), slice.get(len - 1)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::{
+        prelude::{ConnectionInfo, ExternalConnectionInfo, SqlFamily},
+        visitor::{Sqlite, Visitor},
+    };
+
+    #[test]
+    fn reverse_nulls_order_flips_only_when_reversed() {
+        assert_eq!(reverse_nulls_order(None, false), None);
+        assert_eq!(reverse_nulls_order(None, true), None);
+        assert_eq!(
+            reverse_nulls_order(Some(&NullsOrder::First), false),
+            Some(NullsOrder::First)
+        );
+        assert_eq!(
+            reverse_nulls_order(Some(&NullsOrder::Last), false),
+            Some(NullsOrder::Last)
+        );
+        assert_eq!(
+            reverse_nulls_order(Some(&NullsOrder::First), true),
+            Some(NullsOrder::Last)
+        );
+        assert_eq!(
+            reverse_nulls_order(Some(&NullsOrder::Last), true),
+            Some(NullsOrder::First)
+        );
+    }
+
+    fn order_definition(sort_order: SortOrder, nulls_order: Option<NullsOrder>) -> CursorOrderDefinition {
+        CursorOrderDefinition {
+            sort_order,
+            order_column: Column::from(("Model", "field")).into(),
+            order_fks: None,
+            on_nullable_fields: true,
+            nulls_order,
+        }
+    }
+
+    fn render(expr: Expression<'static>) -> String {
+        let select = Select::from_table("Model").so_that(ConditionTree::single(expr));
+        let (sql, _) = Sqlite::build(select).unwrap();
+        sql
+    }
+
+    // asc + nulls first/last, desc + nulls first/last, each with a null and a non-null cursor value.
+    #[test]
+    fn map_orderby_condition_ascending_nulls_first_non_null_cursor() {
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Sqlite, None, None, false));
+        let ctx = Context::new(&connection_info, None);
+        let order_subquery = Select::from_table("Model").value(Value::from(1).raw());
+        let order_definition = order_definition(SortOrder::Ascending, Some(NullsOrder::First));
+
+        let sql = render(map_orderby_condition(&order_subquery, &order_definition, false, true, &ctx));
+
+        assert!(sql.contains(">="), "expected a `>=` comparison, got: {sql}");
+        assert!(sql.contains("IS NULL"), "expected a NULLS handling branch, got: {sql}");
+    }
+
+    #[test]
+    fn map_orderby_condition_ascending_nulls_last_non_null_cursor() {
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Sqlite, None, None, false));
+        let ctx = Context::new(&connection_info, None);
+        let order_subquery = Select::from_table("Model").value(Value::from(1).raw());
+        let order_definition = order_definition(SortOrder::Ascending, Some(NullsOrder::Last));
+
+        let sql = render(map_orderby_condition(&order_subquery, &order_definition, false, true, &ctx));
+
+        assert!(sql.contains(">="), "expected a `>=` comparison, got: {sql}");
+        assert!(sql.contains("IS NULL"), "expected a NULLS handling branch, got: {sql}");
+    }
+
+    #[test]
+    fn map_orderby_condition_descending_nulls_first_non_null_cursor() {
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Sqlite, None, None, false));
+        let ctx = Context::new(&connection_info, None);
+        let order_subquery = Select::from_table("Model").value(Value::from(1).raw());
+        let order_definition = order_definition(SortOrder::Descending, Some(NullsOrder::First));
+
+        let sql = render(map_orderby_condition(&order_subquery, &order_definition, false, true, &ctx));
+
+        assert!(sql.contains("<="), "expected a `<=` comparison, got: {sql}");
+        assert!(sql.contains("IS NULL"), "expected a NULLS handling branch, got: {sql}");
+    }
+
+    #[test]
+    fn map_orderby_condition_descending_nulls_last_non_null_cursor() {
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Sqlite, None, None, false));
+        let ctx = Context::new(&connection_info, None);
+        let order_subquery = Select::from_table("Model").value(Value::from(1).raw());
+        let order_definition = order_definition(SortOrder::Descending, Some(NullsOrder::Last));
+
+        let sql = render(map_orderby_condition(&order_subquery, &order_definition, false, true, &ctx));
+
+        assert!(sql.contains("<="), "expected a `<=` comparison, got: {sql}");
+        assert!(sql.contains("IS NULL"), "expected a NULLS handling branch, got: {sql}");
+    }
+
+    #[test]
+    fn nulls_position_condition_nulls_last_matches_only_null_order_with_non_null_cursor() {
+        let order_column: Expression<'static> = Column::from(("Model", "field")).into();
+        let cmp_column = Select::from_table("Model").value(Value::from(1).raw());
+
+        let sql = render(nulls_position_condition(
+            Some(NullsOrder::Last),
+            order_column,
+            cmp_column,
+        ));
+
+        assert!(sql.contains("IS NULL"));
+        assert!(sql.contains("IS NOT NULL"));
+    }
+
+    #[test]
+    fn nulls_position_condition_nulls_first_matches_null_cursor_against_non_null_order() {
+        let order_column: Expression<'static> = Column::from(("Model", "field")).into();
+        let cmp_column = Select::from_table("Model").value(Value::null_text().raw());
+
+        let sql = render(nulls_position_condition(
+            Some(NullsOrder::First),
+            order_column,
+            cmp_column,
+        ));
+
+        assert!(sql.contains("IS NULL"));
+        assert!(sql.contains("IS NOT NULL"));
+    }
+
+    #[test]
+    fn nulls_position_condition_without_nulls_order_matches_either_side() {
+        let order_column: Expression<'static> = Column::from(("Model", "field")).into();
+        let cmp_column = Select::from_table("Model").value(Value::null_text().raw());
+
+        let sql = render(nulls_position_condition(None, order_column, cmp_column));
+
+        assert_eq!(sql.matches("IS NULL").count(), 2, "both sides should be checked for NULL: {sql}");
+        assert!(!sql.contains("IS NOT NULL"), "fallback shouldn't require non-null on either side: {sql}");
+    }
+}
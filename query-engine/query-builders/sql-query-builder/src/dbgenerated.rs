@@ -0,0 +1,195 @@
+/**
+ * Changed by @vetching-corporation
+ * Author: nfl1ryxditimo12@gmail.com
+ * Date: 2025-06-16
+ * Note: Rewrite the schema component of a schema-qualified function call inside a
+ * `@default(dbgenerated(...))` expression, so tenants using DynamicSchema remapping don't end up
+ * calling another tenant's helper function (or one that doesn't exist under their schema).
+ */
+
+/// Recognizes exactly `<schema>.<function>(<args>)`, where `<schema>` and `<function>` are each
+/// either a bare identifier or a double-quoted identifier, and `<args>` is balanced-parenthesis
+/// text that may itself contain nested calls and single-quoted string literals. Anything else
+/// about `expr` - leading/trailing text, an unterminated quote, unbalanced parens - leaves it
+/// completely unparsed, and `rewrite_schema_qualified_call` returns it byte-identical: skipping a
+/// rewrite we can't confidently parse is always safer than corrupting a default expression.
+struct SchemaQualifiedCall<'a> {
+    /// The schema identifier's unquoted value.
+    schema: String,
+    /// Whether `schema` was written with double quotes in `expr`.
+    schema_quoted: bool,
+    /// Everything in `expr` from the `.` separator onwards, byte-identical to the input - the
+    /// function name, its parentheses and its arguments are never rewritten.
+    rest: &'a str,
+}
+
+/// Rewrites the schema component of a schema-qualified function call default expression through
+/// `target_schema`, leaving everything else - including the whole expression when it isn't a
+/// schema-qualified call, or when `target_schema` has nothing to say about this particular schema
+/// - byte-identical.
+pub(crate) fn rewrite_schema_qualified_call(expr: &str, target_schema: impl Fn(&str) -> Option<String>) -> String {
+    let Some(call) = parse_schema_qualified_call(expr) else {
+        return expr.to_owned();
+    };
+
+    match target_schema(&call.schema) {
+        Some(target) if target != call.schema => format!("{}{}", quote_if(&target, call.schema_quoted), call.rest),
+        _ => expr.to_owned(),
+    }
+}
+
+fn quote_if(identifier: &str, quoted: bool) -> String {
+    if quoted {
+        format!("\"{}\"", identifier.replace('"', "\"\""))
+    } else {
+        identifier.to_owned()
+    }
+}
+
+fn parse_schema_qualified_call(expr: &str) -> Option<SchemaQualifiedCall<'_>> {
+    let (schema, schema_quoted, after_schema) = parse_identifier(expr)?;
+    let after_dot = after_schema.strip_prefix('.')?;
+    let (_function, _function_quoted, after_function) = parse_identifier(after_dot)?;
+    let after_open_paren = after_function.strip_prefix('(')?;
+    let close_paren_offset = find_matching_close_paren(after_open_paren)?;
+
+    // Nothing may follow the call's closing paren: anything else means `expr` isn't just this one
+    // call, which is outside the minimal grammar we rewrite.
+    if close_paren_offset + 1 != after_open_paren.len() {
+        return None;
+    }
+
+    let dot_offset = expr.len() - after_dot.len() - 1;
+
+    Some(SchemaQualifiedCall {
+        schema,
+        schema_quoted,
+        rest: &expr[dot_offset..],
+    })
+}
+
+/// Parses a bare or double-quoted identifier at the start of `s`, returning its unquoted value,
+/// whether it was quoted, and the remainder of `s` after it.
+fn parse_identifier(s: &str) -> Option<(String, bool, &str)> {
+    if let Some(rest) = s.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = rest.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            if c != '"' {
+                value.push(c);
+                continue;
+            }
+
+            // `""` is an escaped quote inside the identifier; anything else is the closing quote.
+            if rest[i + 1..].starts_with('"') {
+                value.push('"');
+                chars.next();
+            } else {
+                return Some((value, true, &rest[i + 1..]));
+            }
+        }
+
+        None
+    } else {
+        let end = s.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(s.len());
+        if end == 0 {
+            return None;
+        }
+
+        Some((s[..end].to_owned(), false, &s[end..]))
+    }
+}
+
+/// Given the text right after a call's opening `(`, finds the byte offset of the matching closing
+/// `)`, treating nested parens and single-quoted string literals (where `''` is an escaped quote)
+/// as opaque to balancing. Returns `None` if the parens never balance.
+fn find_matching_close_paren(s: &str) -> Option<usize> {
+    let mut depth = 1usize;
+    let mut chars = s.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            '\'' => loop {
+                match chars.next() {
+                    Some((j, '\'')) if s[j + 1..].starts_with('\'') => {
+                        chars.next();
+                    }
+                    Some((_, '\'')) => break,
+                    Some(_) => continue,
+                    None => return None,
+                }
+            },
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrites_simple_schema_qualified_call() {
+        let rewritten = rewrite_schema_qualified_call("app.generate_code()", |s| {
+            (s == "app").then(|| "tenant1".to_owned())
+        });
+
+        assert_eq!(rewritten, "tenant1.generate_code()");
+    }
+
+    #[test]
+    fn rewrites_quoted_schema_preserving_quoting() {
+        let rewritten = rewrite_schema_qualified_call(r#""My App".generate_code()"#, |s| {
+            (s == "My App").then(|| "tenant one".to_owned())
+        });
+
+        assert_eq!(rewritten, r#""tenant one".generate_code()"#);
+    }
+
+    #[test]
+    fn rewrites_call_with_nested_call_and_string_literal_argument() {
+        let rewritten = rewrite_schema_qualified_call("app.wrap(other.func(1, 'a)b'), 2)", |s| {
+            (s == "app").then(|| "tenant1".to_owned())
+        });
+
+        assert_eq!(rewritten, "tenant1.wrap(other.func(1, 'a)b'), 2)");
+    }
+
+    #[test]
+    fn leaves_unmapped_schema_untouched() {
+        let original = "app.generate_code()";
+        let rewritten = rewrite_schema_qualified_call(original, |_| None);
+
+        assert_eq!(rewritten, original);
+    }
+
+    #[test]
+    fn leaves_expression_with_trailing_content_untouched() {
+        let original = "app.generate_code() -- extra";
+        let rewritten = rewrite_schema_qualified_call(original, |s| {
+            (s == "app").then(|| "tenant1".to_owned())
+        });
+
+        assert_eq!(rewritten, original);
+    }
+
+    #[test]
+    fn leaves_unterminated_quote_untouched() {
+        let original = "\"app.generate_code()";
+        let rewritten = rewrite_schema_qualified_call(original, |s| {
+            (s == "app").then(|| "tenant1".to_owned())
+        });
+
+        assert_eq!(rewritten, original);
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use prisma_value::PrismaValue;
+use query_template::Fragment;
+
+/// Collapses repeated identical bind values into a single parameter, rewriting later
+/// `Fragment::Parameter`s that bind the same value to `Fragment::ParameterRef` instead.
+///
+/// Only call this for placeholder syntaxes that allow a bound parameter to be referenced more
+/// than once (numbered placeholders like Postgres/MSSQL's `$1`); positional-only syntaxes
+/// (MySQL/SQLite's `?`) can't reuse a slot and must be left alone.
+///
+/// `PrismaValue::Null`, `PrismaValue::GeneratorCall` and `PrismaValue::Placeholder` are never
+/// deduplicated: a `NULL`'s wire representation can depend on the column it targets, and a
+/// generator call or an unresolved placeholder stands for a value that is only decided later,
+/// so two occurrences that look alike now aren't guaranteed to stay identical.
+pub(crate) fn dedupe_parameters(fragments: Vec<Fragment>, params: Vec<PrismaValue>) -> (Vec<Fragment>, Vec<PrismaValue>) {
+    let mut deduped_params = Vec::with_capacity(params.len());
+    let mut first_index_of: HashMap<PrismaValue, usize> = HashMap::new();
+    let mut params = params.into_iter();
+
+    let fragments = fragments
+        .into_iter()
+        .map(|fragment| match &fragment {
+            Fragment::Parameter => {
+                let value = params.next().expect("one parameter per `Fragment::Parameter`");
+
+                if is_dedupable(&value) {
+                    if let Some(&index) = first_index_of.get(&value) {
+                        return Fragment::ParameterRef { index };
+                    }
+                    first_index_of.insert(value.clone(), deduped_params.len());
+                }
+
+                deduped_params.push(value);
+                fragment
+            }
+            Fragment::ParameterTuple | Fragment::ParameterTupleList { .. } => {
+                deduped_params.push(params.next().expect("one parameter per tuple fragment"));
+                fragment
+            }
+            _ => fragment,
+        })
+        .collect();
+
+    (fragments, deduped_params)
+}
+
+fn is_dedupable(value: &PrismaValue) -> bool {
+    !matches!(
+        value,
+        PrismaValue::Null | PrismaValue::GeneratorCall { .. } | PrismaValue::Placeholder(_)
+    )
+}
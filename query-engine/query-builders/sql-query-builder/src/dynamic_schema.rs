@@ -7,18 +7,55 @@
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Default)]
-pub struct DynamicSchema(HashMap<String, String>);
+pub struct DynamicSchema {
+    mapping: HashMap<String, String>,
+
+    /// Opt-in: apply the remapping via a `SET search_path` statement emitted before the query,
+    /// instead of inlining the target schema into every qualified table reference. Keeps the
+    /// generated SQL text identical across tenants that only differ by schema, so the connector's
+    /// prepared statement cache doesn't thrash. The caller is responsible for resetting the
+    /// session's search_path when the connection returns to its pool.
+    via_search_path: bool,
+}
 
 impl DynamicSchema {
     pub fn new() -> Self {
-        Self(HashMap::new())
+        Self::default()
     }
 
     pub fn from_str(s: Option<String>) -> Self {
         if s.is_none() {
             return Self::new();
         }
-        Self(serde_json::from_str(&s.unwrap()).unwrap_or_default())
+        Self {
+            mapping: serde_json::from_str(&s.unwrap()).unwrap_or_default(),
+            via_search_path: false,
+        }
+    }
+
+    /// Opts into applying the remapping via a `SET search_path` statement instead of inlining the
+    /// target schema into every table reference. Only takes effect against Postgres connections.
+    pub fn with_search_path(mut self, via_search_path: bool) -> Self {
+        self.via_search_path = via_search_path;
+        self
+    }
+
+    pub(crate) fn via_search_path(&self) -> bool {
+        self.via_search_path
+    }
+
+    /// The distinct target schemas this mapping remaps to, quoted and comma-separated in a
+    /// stable order, for use in a `SET search_path` statement. `None` if there's nothing to remap.
+    pub(crate) fn search_path(&self) -> Option<String> {
+        if self.mapping.is_empty() {
+            return None;
+        }
+
+        let mut targets: Vec<&str> = self.mapping.values().map(String::as_str).collect();
+        targets.sort_unstable();
+        targets.dedup();
+
+        Some(targets.iter().map(|schema| format!("\"{schema}\"")).collect::<Vec<_>>().join(", "))
     }
 }
 
@@ -27,12 +64,12 @@ impl std::ops::Deref for DynamicSchema {
     type Target = HashMap<String, String>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.mapping
     }
 }
 
 impl std::ops::DerefMut for DynamicSchema {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.mapping
     }
 }
@@ -6,6 +6,7 @@ use psl::datamodel_connector::ConnectorCapability;
 use psl::reachable_only_with_capability;
 use quaint::ast::concat;
 use quaint::ast::*;
+use quaint::prelude::SqlFamily;
 use query_structure::{filter::*, prelude::*};
 use std::convert::TryInto;
 
@@ -339,6 +340,40 @@ impl FilterVisitorExt for FilterVisitor {
                     _ => unreachable!(),
                 };
 
+                // A lone field (nothing extra to search across) that isn't covered by a declared
+                // full-text operator-class index, e.g. `ops: raw("tsvector_ops")`, can't use `@@`
+                // for free: it would still return correct results, but by recomputing
+                // `to_tsvector` for every row instead of using an index. Fall back to a `Contains`
+                // instead, which is at least as cheap without one, and goes through the same
+                // `LIKE`/`ILIKE` rendering `contains` filters already use for every `QueryMode`.
+                if projections.is_empty() {
+                    if let Some(field) = filter.projection.as_single() {
+                        let indexed = field
+                            .index_operator_class()
+                            .is_some_and(|class| class.is_full_text_search());
+
+                        if !indexed {
+                            let comparable: Expression = field.aliased_col(self.parent_alias(), ctx).into();
+                            let fallback = match filter.condition {
+                                ScalarCondition::Search(value, _) => ScalarCondition::Contains(value),
+                                ScalarCondition::NotSearch(value, _) => ScalarCondition::NotContains(value),
+                                _ => unreachable!(),
+                            };
+
+                            return convert_scalar_filter(
+                                comparable,
+                                fallback,
+                                self.reverse(),
+                                filter.mode,
+                                &[],
+                                self.parent_alias(),
+                                false,
+                                ctx,
+                            );
+                        }
+                    }
+                }
+
                 projections.push(filter.projection);
 
                 let columns: Vec<Column> = projections
@@ -589,12 +624,18 @@ impl FilterVisitorExt for FilterVisitor {
             ScalarListCondition::ContainsEvery(ConditionListValue::List(vals)) => {
                 comparable.compare_raw("@>", convert_list_pv(field, vals, ctx))
             }
+            ScalarListCondition::ContainsEvery(ConditionListValue::Value(val)) => {
+                comparable.compare_raw("@>", field.array_value(val, ctx))
+            }
             ScalarListCondition::ContainsEvery(ConditionListValue::FieldRef(field_ref)) => {
                 comparable.compare_raw("@>", field_ref.aliased_col(alias, ctx))
             }
             ScalarListCondition::ContainsSome(ConditionListValue::List(vals)) => {
                 comparable.compare_raw("&&", convert_list_pv(field, vals, ctx))
             }
+            ScalarListCondition::ContainsSome(ConditionListValue::Value(val)) => {
+                comparable.compare_raw("&&", field.array_value(val, ctx))
+            }
             ScalarListCondition::ContainsSome(ConditionListValue::FieldRef(field_ref)) => {
                 comparable.compare_raw("&&", field_ref.aliased_col(alias, ctx))
             }
@@ -905,12 +946,22 @@ pub(crate) fn default_scalar_filter(
 
                 comparable.in_selection(sql_values)
             }
+            // A single-column `IN` with many values would otherwise bind one placeholder per
+            // value, bloating the query text and risking `max_bind_values`. Postgres can bind the
+            // whole list as a single array parameter instead and compare with `= ANY(..)`.
+            Some(_) if fields.len() == 1 && ctx.sql_family() == SqlFamily::Postgres => {
+                comparable.equals(convert_list_pv(fields.first().unwrap(), values, ctx).any())
+            }
             _ => comparable.in_selection(convert_pvs(fields, values, ctx)),
         },
         ScalarCondition::In(ConditionListValue::FieldRef(field_ref)) => {
             // This code path is only reachable for connectors with `ScalarLists` capability
             comparable.equals(Expression::from(field_ref.aliased_col(alias, ctx)).any())
         }
+        ScalarCondition::In(ConditionListValue::Value(value)) => {
+            let sql_value = convert_first_value(fields, value, alias, ctx);
+            comparable.in_selection(into_placeholder_row(sql_value, fields))
+        }
         ScalarCondition::NotIn(ConditionListValue::List(values)) => match values.split_first() {
             Some((PrismaValue::List(_), _)) => {
                 let mut sql_values = Values::with_capacity(values.len());
@@ -922,20 +973,27 @@ pub(crate) fn default_scalar_filter(
 
                 comparable.not_in_selection(sql_values)
             }
+            Some(_) if fields.len() == 1 && ctx.sql_family() == SqlFamily::Postgres => {
+                comparable.not_equals(convert_list_pv(fields.first().unwrap(), values, ctx).all())
+            }
             _ => comparable.not_in_selection(convert_pvs(fields, values, ctx)),
         },
         ScalarCondition::NotIn(ConditionListValue::FieldRef(field_ref)) => {
             // This code path is only reachable for connectors with `ScalarLists` capability
             comparable.not_equals(Expression::from(field_ref.aliased_col(alias, ctx)).all())
         }
+        ScalarCondition::NotIn(ConditionListValue::Value(value)) => {
+            let sql_value = convert_first_value(fields, value, alias, ctx);
+            comparable.not_in_selection(into_placeholder_row(sql_value, fields))
+        }
         ScalarCondition::InTemplate(ConditionValue::Value(value)) => {
             let sql_value = convert_first_value(fields, value, alias, ctx);
-            comparable.in_selection(sql_value.into_parameterized_row())
+            comparable.in_selection(into_placeholder_row(sql_value, fields))
         }
         ScalarCondition::InTemplate(ConditionValue::FieldRef(_)) => todo!(),
         ScalarCondition::NotInTemplate(ConditionValue::Value(value)) => {
             let sql_value = convert_first_value(fields, value, alias, ctx);
-            comparable.not_in_selection(sql_value.into_parameterized_row())
+            comparable.not_in_selection(into_placeholder_row(sql_value, fields))
         }
         ScalarCondition::NotInTemplate(ConditionValue::FieldRef(_)) => todo!(),
         ScalarCondition::Search(value, _) => {
@@ -958,6 +1016,50 @@ pub(crate) fn default_scalar_filter(
 
             comparable.not_matches(query)
         }
+        ScalarCondition::AncestorOf(value) => {
+            reachable_only_with_capability!(ConnectorCapability::LtreeFilters);
+            comparable.compare_raw("@>", convert_first_value(fields, value, alias, ctx))
+        }
+        ScalarCondition::NotAncestorOf(value) => {
+            reachable_only_with_capability!(ConnectorCapability::LtreeFilters);
+            return ConditionTree::not(comparable.compare_raw("@>", convert_first_value(fields, value, alias, ctx)));
+        }
+        ScalarCondition::DescendantOf(value) => {
+            reachable_only_with_capability!(ConnectorCapability::LtreeFilters);
+            comparable.compare_raw("<@", convert_first_value(fields, value, alias, ctx))
+        }
+        ScalarCondition::NotDescendantOf(value) => {
+            reachable_only_with_capability!(ConnectorCapability::LtreeFilters);
+            return ConditionTree::not(comparable.compare_raw("<@", convert_first_value(fields, value, alias, ctx)));
+        }
+        ScalarCondition::MatchesLquery(value) => {
+            reachable_only_with_capability!(ConnectorCapability::LtreeFilters);
+            comparable.compare_raw("~", convert_first_value(fields, value, alias, ctx))
+        }
+        ScalarCondition::NotMatchesLquery(value) => {
+            reachable_only_with_capability!(ConnectorCapability::LtreeFilters);
+            return ConditionTree::not(comparable.compare_raw("~", convert_first_value(fields, value, alias, ctx)));
+        }
+        ScalarCondition::GeoContains(value) => {
+            reachable_only_with_capability!(ConnectorCapability::SpatialFiltering);
+            comparable.geo_contains(convert_first_value(fields, value, alias, ctx))
+        }
+        ScalarCondition::NotGeoContains(value) => {
+            reachable_only_with_capability!(ConnectorCapability::SpatialFiltering);
+            return ConditionTree::not(comparable.geo_contains(convert_first_value(fields, value, alias, ctx)));
+        }
+        ScalarCondition::WithinDistance(within) => {
+            reachable_only_with_capability!(ConnectorCapability::SpatialFiltering);
+            let point = convert_first_value(fields, within.point, alias, ctx);
+            let distance = convert_first_value(fields, within.distance_meters, alias, ctx);
+            comparable.within_distance(point, distance)
+        }
+        ScalarCondition::NotWithinDistance(within) => {
+            reachable_only_with_capability!(ConnectorCapability::SpatialFiltering);
+            let point = convert_first_value(fields, within.point, alias, ctx);
+            let distance = convert_first_value(fields, within.distance_meters, alias, ctx);
+            return ConditionTree::not(comparable.within_distance(point, distance));
+        }
         ScalarCondition::JsonCompare(_) => unreachable!(),
         ScalarCondition::IsSet(_) => unreachable!(),
     };
@@ -1089,6 +1191,11 @@ fn insensitive_scalar_filter(
             // This code path is only reachable for connectors with `ScalarLists` capability
             comparable.compare_raw("ILIKE", Expression::from(field_ref.aliased_col(alias, ctx)).any())
         }
+        ScalarCondition::In(ConditionListValue::Value(value)) => {
+            let comparable: Expression = lower_if(comparable, !is_parent_aggregation);
+            let sql_value = convert_first_value(fields, value, alias, ctx);
+            comparable.in_selection(sql_value.into_parameterized_row())
+        }
         ScalarCondition::NotIn(ConditionListValue::List(values)) => match values.split_first() {
             Some((PrismaValue::List(_), _)) => {
                 let mut sql_values = Values::with_capacity(values.len());
@@ -1120,6 +1227,11 @@ fn insensitive_scalar_filter(
             // This code path is only reachable for connectors with `ScalarLists` capability
             comparable.compare_raw("NOT ILIKE", Expression::from(field_ref.aliased_col(alias, ctx)).all())
         }
+        ScalarCondition::NotIn(ConditionListValue::Value(value)) => {
+            let comparable: Expression = lower(comparable).into();
+            let sql_value = convert_first_value(fields, value, alias, ctx);
+            comparable.not_in_selection(sql_value.into_parameterized_row())
+        }
         ScalarCondition::InTemplate(ConditionValue::Value(value)) => {
             let comparable = Expression::from(lower(comparable));
             let sql_value = convert_first_value(fields, value, alias, ctx);
@@ -1152,6 +1264,18 @@ fn insensitive_scalar_filter(
 
             comparable.not_matches(query)
         }
+        ScalarCondition::AncestorOf(_)
+        | ScalarCondition::NotAncestorOf(_)
+        | ScalarCondition::DescendantOf(_)
+        | ScalarCondition::NotDescendantOf(_)
+        | ScalarCondition::MatchesLquery(_)
+        | ScalarCondition::NotMatchesLquery(_) => unreachable!("ltree filters do not support case-insensitive mode"),
+        ScalarCondition::GeoContains(_)
+        | ScalarCondition::NotGeoContains(_)
+        | ScalarCondition::WithinDistance(_)
+        | ScalarCondition::NotWithinDistance(_) => {
+            unreachable!("spatial filters do not support case-insensitive mode")
+        }
         ScalarCondition::JsonCompare(_) => unreachable!(),
         ScalarCondition::IsSet(_) => unreachable!(),
     };
@@ -1179,6 +1303,17 @@ fn convert_value<'a>(
     }
 }
 
+/// Turns a whole-list placeholder value into the right kind of parameterized right-hand side for
+/// an `IN`/`NOT IN` comparison: a single parameterized row for a `Single` projection, or a
+/// parameterized list of rows (tuples) for a `Compound` one.
+fn into_placeholder_row<'a>(sql_value: Expression<'a>, fields: &[ScalarFieldRef]) -> Expression<'a> {
+    if fields.len() > 1 {
+        sql_value.into_parameterized_row_list()
+    } else {
+        sql_value.into_parameterized_row()
+    }
+}
+
 fn convert_first_value<'a>(
     fields: &[ScalarFieldRef],
     value: impl Into<ConditionValue>,
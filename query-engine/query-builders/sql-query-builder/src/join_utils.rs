@@ -51,6 +51,11 @@ pub(crate) fn compute_aggr_join(
 
 /// Computes a one-to-many join for an aggregation (in aggregation selections, order by...).
 ///
+/// The subquery only ever selects the join's own `<fk>` columns alongside the aggregate, and
+/// groups by those same columns, so it never needs MySQL/Postgres functional-dependency inference
+/// (unsupported by MSSQL, and limited to primary keys under MySQL's `ONLY_FULL_GROUP_BY`) to
+/// justify a selected column that isn't grouped.
+///
 /// Preview of the rendered SQL:
 /// ```sql
 /// LEFT JOIN (
@@ -1,7 +1,10 @@
 pub mod column_metadata;
+pub mod computed;
 mod context;
-mod convert;
+pub mod value_convert;
 mod cursor_condition;
+mod dbgenerated;
+mod dedup;
 mod filter;
 mod dynamic_schema;
 mod join_utils;
@@ -9,6 +12,7 @@ pub mod limit;
 mod model_extensions;
 mod nested_aggregations;
 mod ordering;
+pub mod query_metrics;
 pub mod read;
 #[cfg(feature = "relation_joins")]
 pub mod select;
@@ -31,22 +35,79 @@ use quaint::{
 };
 use query_builder::{CreateRecord, CreateRecordDefaultsQuery, DbQuery, QueryBuilder};
 use query_structure::{
-    AggregationSelection, DatasourceFieldName, FieldSelection, Filter, Model, ModelProjection, QueryArguments,
-    RecordFilter, RelationField, RelationLoadStrategy, ScalarField, SelectionResult, WriteArgs, WriteOperation,
+    AggregationSelection, DatasourceFieldName, Field, FieldSelection, Filter, Model, ModelProjection, OrderBy,
+    QueryArguments, RecordFilter, RelationField, RelationLoadStrategy, ScalarField, SelectionResult, StaticFilterShape,
+    WriteArgs, WriteOperation,
 };
 
 pub use column_metadata::ColumnMetadata;
 pub use context::Context;
-pub use convert::opaque_type_to_prisma_type;
+pub use value_convert::opaque_type_to_prisma_type;
 pub use filter::FilterBuilder;
 pub use dynamic_schema::DynamicSchema;
 pub use model_extensions::{AsColumn, AsColumns, AsTable, RelationFieldExt, SelectionResultExt};
 use read::alias_with_db_name;
-pub use sql_trace::SqlTraceComment;
+pub use sql_trace::{SqlTraceComment, TraceCommentMode};
 use value::GeneratorCall;
 
 const PARAMETER_LIMIT: usize = 2000;
 
+/// A many-to-many linkage that [`SqlQueryBuilder::build_get_related_records`] cannot translate to
+/// SQL because the implicit join table it relies on doesn't have enough columns to represent it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum M2mLinkageError {
+    /// The related model's identifier spans more than one scalar field, but the join table only
+    /// has a single link column ("A"/"B") for that side of the relation.
+    CompoundIdentifier(String),
+}
+
+impl std::fmt::Display for M2mLinkageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CompoundIdentifier(model) => write!(
+                f,
+                "Cannot build a many-to-many relation query for `{model}`: its identifier has more than one \
+                 field, but the implicit many-to-many join table only has a single link column per side."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for M2mLinkageError {}
+
+/// Returned by [`SqlQueryBuilder::build_get_records`] when the query's relation load strategy is
+/// `Join`, but this build of the crate doesn't have the `relation_joins` feature compiled in.
+/// Callers that can tell the engine was built without that feature should surface this as a
+/// typed, user-facing error instead of it turning into an `unreachable!()` panic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JoinStrategyNotSupported;
+
+impl std::fmt::Display for JoinStrategyNotSupported {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the `join` relation load strategy requires the `relation_joins` feature to be compiled in"
+        )
+    }
+}
+
+impl std::error::Error for JoinStrategyNotSupported {}
+
+/// An internal invariant of [`SqlQueryBuilder`] was violated: a helper that, given its caller's
+/// already-validated arguments, is only ever supposed to produce a single query instead produced
+/// zero or more than one. Surfaced as a typed error instead of an `expect()` panic so a violation
+/// degrades to a request-level error instead of taking down the whole engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct UnexpectedQueryCount(&'static str);
+
+impl std::fmt::Display for UnexpectedQueryCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected exactly one query to be generated for {}", self.0)
+    }
+}
+
+impl std::error::Error for UnexpectedQueryCount {}
+
 pub struct SqlQueryBuilder<'a, Visitor> {
     context: Context<'a>,
     phantom: PhantomData<fn(Visitor)>,
@@ -69,18 +130,67 @@ impl<'a, V> SqlQueryBuilder<'a, V> {
         let params = template
             .parameters
             .into_iter()
-            .map(|v| convert::quaint_value_to_prisma_value(v, self.context.sql_family()))
+            .map(|v| value_convert::quaint_value_to_prisma_value(v, self.context.sql_family()))
             .collect::<Vec<_>>();
 
+        let (fragments, params) = if self.context.reuse_duplicate_parameters() && template.placeholder_format.has_numbering
+        {
+            dedup::dedupe_parameters(template.fragments, params)
+        } else {
+            (template.fragments, params)
+        };
+
         Ok(DbQuery::TemplateSql {
-            fragments: template.fragments,
+            fragments,
             placeholder_format: template.placeholder_format,
             params,
         })
     }
+
+    /// When [`Context::forbid_unfiltered_writes`] is enabled, rejects `updateMany`/`deleteMany`
+    /// calls whose filter is statically known to match every row. A filter that only resolves to
+    /// "matches every row" once a placeholder inside it is bound (see [`Filter::static_shape`])
+    /// can't be rejected up front, so it's surfaced as a [`query_builder::Warning`] instead.
+    ///
+    /// `record_filter.selectors`, when present, take precedence over the filter (see
+    /// [`RecordFilter`]'s docs), so a selector-driven write is never considered unfiltered.
+    fn check_unfiltered_write(
+        &self,
+        model: &Model,
+        record_filter: &RecordFilter,
+        limit: Option<usize>,
+        operation: query_builder::UnfilteredWriteOperation,
+    ) -> Result<(), query_builder::UnfilteredWriteError> {
+        if !self.context.forbid_unfiltered_writes() || record_filter.has_selectors() || limit.is_some() {
+            return Ok(());
+        }
+
+        match record_filter.filter.static_shape() {
+            StaticFilterShape::Unconditional => Err(query_builder::UnfilteredWriteError {
+                model: model.name().to_owned(),
+                operation,
+            }),
+            StaticFilterShape::DependsOnPlaceholder => {
+                self.context.push_warning(query_builder::Warning::PossiblyUnfilteredWrite {
+                    model: model.name().to_owned(),
+                    operation,
+                });
+                Ok(())
+            }
+            StaticFilterShape::Restricted => Ok(()),
+        }
+    }
 }
 
 impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
+    fn drain_warnings(&self) -> Vec<query_builder::Warning> {
+        self.context.drain_warnings()
+    }
+
+    fn report_warning(&self, warning: query_builder::Warning) {
+        self.context.push_warning(warning);
+    }
+
     fn build_get_records(
         &self,
         model: &Model,
@@ -91,9 +201,12 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         let query = match relation_load_strategy {
             RelationLoadStrategy::Join => {
                 #[cfg(not(feature = "relation_joins"))]
-                unreachable!();
+                return Err(Box::new(JoinStrategyNotSupported));
                 #[cfg(feature = "relation_joins")]
-                select::SelectBuilder::build(query_arguments, selected_fields, &self.context)
+                {
+                    select::is_join_compatible(&query_arguments, selected_fields)?;
+                    select::SelectBuilder::build(query_arguments, selected_fields, &self.context)
+                }
             }
             RelationLoadStrategy::Query => read::get_records(
                 model,
@@ -105,7 +218,18 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
                 &self.context,
             ),
         };
-        self.convert_query(query)
+        let db_query = self.convert_query(query)?;
+        let bind_values = match &db_query {
+            DbQuery::TemplateSql { params, .. } | DbQuery::RawSql { params, .. } => params.len(),
+        };
+
+        self.context.record_query_metrics(query_metrics::QueryMetricsEvent {
+            bind_values,
+            chunks: 1,
+            relation_strategy: Some(relation_load_strategy),
+        });
+
+        Ok(db_query)
     }
 
     #[cfg(feature = "relation_joins")]
@@ -139,28 +263,38 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
             .m2m_column(&self.context)
             .table(m2m_alias.to_string());
 
+        // The implicit many-to-many join table only ever has a single link column per side ("A"
+        // and "B" above), so every field participating in the linkage has to be comparable
+        // through that one column. This holds for every relation PSL lets through today (implicit
+        // and embedded many-to-many relations are both validated to have a single-field identifier
+        // on each side), but we still report it instead of panicking in case that ever changes.
         let left_scalar = rf
             .related_field()
             .left_scalars()
             .into_iter()
             .exactly_one()
-            .expect("should have one left scalar in m2m relation");
-        let (_, conditions) = conditions_per_field
-            .exactly_one()
-            .expect("should have one field in m2m relation");
-
-        let filter = conditions
-            .into_iter()
-            .map(|cond| {
-                default_scalar_filter(
-                    m2m_col.clone().into(),
-                    cond,
-                    slice::from_ref(&left_scalar),
-                    None,
-                    &self.context,
-                )
+            .map_err(|_| M2mLinkageError::CompoundIdentifier(rf.related_model().name().to_owned()))?;
+
+        let filter = conditions_per_field
+            .map(|(_, conditions)| {
+                conditions
+                    .into_iter()
+                    .map(|cond| {
+                        default_scalar_filter(
+                            m2m_col.clone().into(),
+                            cond,
+                            slice::from_ref(&left_scalar),
+                            None,
+                            &self.context,
+                        )
+                    })
+                    .reduce(|l, r| l.and(r))
             })
-            .reduce(|l, r| l.and(r));
+            .reduce(|acc, cur| match (acc, cur) {
+                (Some(acc), Some(cur)) => Some(acc.and(cur)),
+                (acc, cur) => acc.or(cur),
+            })
+            .flatten();
 
         let columns = ModelProjection::from(selected_fields)
             .as_columns(&self.context)
@@ -218,13 +352,23 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         mut args: WriteArgs,
         selected_fields: &FieldSelection,
     ) -> Result<CreateRecord, Box<dyn std::error::Error + Send + Sync>> {
+        if self.context.validate_write_args() {
+            args.validate_against(&model_scalar_projection(model))?;
+        }
+
         let id_selection = model.shard_aware_primary_identifier();
+        let sql_family = self.context.sql_family();
 
-        let (select_defaults, last_insert_id_field, merge_values) = if self.context.sql_family().is_mysql() {
+        let (select_defaults, last_insert_id_fields, merge_values) = if sql_family.is_mysql() {
             let (field_placeholders, query): (Vec<_>, Select<'static>) =
                 write::defaults_for_mysql_write_args(&id_selection, &args)
-                    .map(|(field, arg)| {
-                        let ph = Placeholder::new(field.name().to_owned(), field.type_info().to_prisma_type());
+                    .enumerate()
+                    .map(|(idx, (field, arg))| {
+                        // Suffix with the field's position among the placeholders so that two fields
+                        // that happen to share a name (e.g. after `@map`) don't produce colliding
+                        // placeholders; `field_placeholders` keeps the field alongside its placeholder
+                        // so callers never need to look one up by name.
+                        let ph = Placeholder::new(format!("{}_{idx}", field.name()), field.type_info().to_prisma_type());
                         ((field, ph), arg)
                     })
                     .unzip();
@@ -244,7 +388,10 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
                 None
             };
 
-            let last_insert_id_field = id_selection.scalars().find(|sf| sf.is_autoincrement()).cloned();
+            // With a shard-aware primary identifier, more than one column of the identifier can
+            // be autoincrement-backed (e.g. an autoincrement id alongside an autoincrement shard
+            // column), so collect all of them instead of stopping at the first match.
+            let last_insert_id_fields: Vec<_> = id_selection.scalars().filter(|sf| sf.is_autoincrement()).cloned().collect();
 
             // Return all arguments that are a part of the primary identifier as values to merge
             // into the created record.
@@ -253,16 +400,25 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
                 .map(|res| res.pairs)
                 .unwrap_or_default();
 
-            (select_defaults, last_insert_id_field, merge_values)
+            (select_defaults, last_insert_id_fields, merge_values)
+        } else if sql_family.is_sqlite() {
+            // SQLite supports `RETURNING`, which already reports generated defaults through
+            // `selected_fields`, but the autoincrement id isn't always part of that selection
+            // (e.g. when used internally to link a freshly created record). Fall back to
+            // `last_insert_rowid()` so the id is always reliably reported.
+            let last_insert_id_fields: Vec<_> = id_selection.scalars().filter(|sf| sf.is_autoincrement()).cloned().collect();
+
+            (None, last_insert_id_fields, vec![])
         } else {
-            (None, None, vec![])
+            // Postgres always has the generated id available through `RETURNING`.
+            (None, vec![], vec![])
         };
 
         let query = write::create_record(model, args, &selected_fields.into(), &self.context);
         Ok(CreateRecord {
             select_defaults,
             insert_query: self.convert_query(query)?,
-            last_insert_id_field,
+            last_insert_id_fields,
             merge_values,
         })
     }
@@ -274,9 +430,16 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         skip_duplicates: bool,
         selected_fields: Option<&FieldSelection>,
     ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>> {
+        if self.context.validate_write_args() {
+            let scalar_projection = model_scalar_projection(model);
+            for write_args in &args {
+                write_args.validate_against(&scalar_projection)?;
+            }
+        }
+
         let projection = selected_fields.map(ModelProjection::from);
         let query = write::generate_insert_statements(model, args, skip_duplicates, projection.as_ref(), &self.context);
-        query.into_iter().map(|q| self.convert_query(q)).collect()
+        query.into_iter().map(|(_, q)| self.convert_query(q)).collect()
     }
 
     fn build_update(
@@ -286,6 +449,10 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         args: WriteArgs,
         selected_fields: Option<&FieldSelection>,
     ) -> Result<DbQuery, Box<dyn std::error::Error + Send + Sync>> {
+        if self.context.validate_write_args() {
+            args.validate_against(&model_scalar_projection(model))?;
+        }
+
         match selected_fields {
             Some(selected_fields) => {
                 let projection = ModelProjection::from(selected_fields);
@@ -306,7 +473,7 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
                 )
                 .into_iter()
                 .exactly_one()
-                .expect("should generate exactly one update query");
+                .map_err(|_| UnexpectedQueryCount("a selector-driven update"))?;
 
                 self.convert_query(query)
             }
@@ -319,13 +486,29 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         record_filter: RecordFilter,
         args: WriteArgs,
         selected_fields: Option<&FieldSelection>,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
     ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>> {
+        self.check_unfiltered_write(
+            model,
+            &record_filter,
+            limit,
+            query_builder::UnfilteredWriteOperation::UpdateMany,
+        )?;
+
         let projection = selected_fields.map(ModelProjection::from);
-        write::generate_update_statements(model, record_filter, args, projection.as_ref(), limit, &self.context)
-            .into_iter()
-            .map(|query| self.convert_query(query))
-            .collect::<Result<Vec<_>, _>>()
+        write::generate_update_statements(
+            model,
+            record_filter,
+            args,
+            projection.as_ref(),
+            &order_by,
+            limit,
+            &self.context,
+        )
+        .into_iter()
+        .map(|query| self.convert_query(query))
+        .collect::<Result<Vec<_>, _>>()
     }
 
     fn build_upsert(
@@ -390,10 +573,10 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         let query = if let Some(selected_fields) = selected_fields {
             write::delete_returning(model, record_filter.filter, &selected_fields.into(), &self.context)
         } else {
-            write::generate_delete_statements(model, record_filter, None, &self.context)
+            write::generate_delete_statements(model, record_filter, &[], None, &self.context)
                 .into_iter()
                 .exactly_one()
-                .expect("should generate exactly one delete")
+                .map_err(|_| UnexpectedQueryCount("a selector-driven delete"))?
         };
         self.convert_query(query)
     }
@@ -402,9 +585,17 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
         &self,
         model: &Model,
         record_filter: RecordFilter,
+        order_by: Vec<OrderBy>,
         limit: Option<usize>,
     ) -> Result<Vec<DbQuery>, Box<dyn std::error::Error + Send + Sync>> {
-        let queries = write::generate_delete_statements(model, record_filter, limit, &self.context)
+        self.check_unfiltered_write(
+            model,
+            &record_filter,
+            limit,
+            query_builder::UnfilteredWriteOperation::DeleteMany,
+        )?;
+
+        let queries = write::generate_delete_statements(model, record_filter, &order_by, limit, &self.context)
             .into_iter()
             .map(|q| self.convert_query(q))
             .collect::<Result<Vec<_>, _>>()?;
@@ -424,6 +615,12 @@ impl<'a, V: Visitor<'a>> QueryBuilder for SqlQueryBuilder<'a, V> {
     }
 }
 
+/// All scalar fields of `model`, as a `ModelProjection`, used to validate `WriteArgs` against
+/// their target columns regardless of which fields the query happens to select back out.
+fn model_scalar_projection(model: &Model) -> ModelProjection {
+    ModelProjection::new(model.fields().scalar().map(Field::from).collect())
+}
+
 pub fn chunked_conditions<F, Q>(
     columns: &[Column<'static>],
     records: &[SelectionResult],
@@ -434,13 +631,79 @@ where
     Q: Into<Query<'static>>,
     F: Fn(ConditionTree<'static>) -> Q,
 {
-    records
+    let chunks: Vec<_> = records
         .chunks(PARAMETER_LIMIT)
         .map(|chunk| {
             let tree = in_conditions(columns, chunk, ctx);
             f(tree).into()
         })
-        .collect()
+        .collect();
+
+    if chunks.len() > 1 {
+        ctx.push_warning(query_builder::Warning::ChunkedStatements { count: chunks.len() });
+    }
+
+    ctx.record_query_metrics(query_metrics::QueryMetricsEvent {
+        bind_values: columns.len() * records.len(),
+        chunks: chunks.len(),
+        relation_strategy: None,
+    });
+
+    chunks
+}
+
+/// Builds a `ConditionTree` asserting that none of `records` match `columns`, chunking the
+/// comparison into `AND`-combined `NOT IN` trees so that no single tree exceeds `PARAMETER_LIMIT`
+/// bind parameters. Unlike [`chunked_conditions`], which produces one query per chunk (each chunk
+/// executed independently), a `NOT IN` over many values must hold for every chunk at once, so the
+/// chunks are combined with `AND` into a single tree instead.
+pub fn chunked_not_in_conditions(
+    columns: &[Column<'static>],
+    records: &[SelectionResult],
+    ctx: &Context<'_>,
+) -> ConditionTree<'static> {
+    records
+        .chunks(PARAMETER_LIMIT)
+        .map(|chunk| not_in_conditions(columns, chunk, ctx))
+        .reduce(|l, r| l.and(r))
+        .unwrap_or(ConditionTree::NoCondition)
+}
+
+pub fn not_in_conditions<'a>(
+    columns: &'a [Column<'static>],
+    results: impl IntoIterator<Item = &'a SelectionResult>,
+    ctx: &Context<'_>,
+) -> ConditionTree<'static> {
+    let iter = match results
+        .into_iter()
+        .exactly_one()
+        .map_err(Either::Left)
+        .and_then(|res| res.as_placeholders().ok_or(Either::Right(iter::once(res))))
+    {
+        Ok(pairs) => {
+            return pairs
+                .into_iter()
+                .zip(columns)
+                .map(|((sf, value), col)| {
+                    ConditionTree::from(
+                        Row::from((col.clone(),))
+                            .not_in_selection(ExpressionKind::ParameterizedRow(sf.value(value.clone(), ctx))),
+                    )
+                })
+                .reduce(|l, r| l.and(r))
+                .expect("should have at least one column")
+        }
+        Err(items) => items,
+    };
+
+    let mut values = Values::empty();
+
+    for result in iter {
+        let vals: Vec<_> = result.db_values(ctx);
+        values.push(vals)
+    }
+
+    Row::from(columns.to_vec()).not_in_selection(values).into()
 }
 
 pub fn in_conditions<'a>(
@@ -1,10 +1,27 @@
-use crate::{model_extensions::*, Context};
+use crate::{model_extensions::*, ordering::into_order, Context};
 use quaint::ast::*;
 use query_structure::*;
 
+/// Builds the `ORDER BY` definitions for a `updateMany`/`deleteMany` `take`. The `orderBy`
+/// argument for these operations is restricted to scalar fields of the model itself (no
+/// relations or aggregations), so every entry is guaranteed to be `OrderBy::Scalar`.
+pub(crate) fn build_take_ordering(order_by: &[OrderBy], ctx: &Context<'_>) -> Vec<OrderDefinition<'static>> {
+    order_by
+        .iter()
+        .map(|order_by| match order_by {
+            OrderBy::Scalar(order_by) => {
+                let order = Some(into_order(&order_by.sort_order, order_by.nulls_order.as_ref(), false));
+                (order_by.field.as_column(ctx).into(), order)
+            }
+            _ => unreachable!("updateMany/deleteMany orderBy is restricted to scalar fields"),
+        })
+        .collect()
+}
+
 pub fn wrap_with_limit_subquery_if_needed<'a>(
     model: &Model,
     filter_condition: ConditionTree<'a>,
+    order_by: &[OrderBy],
     limit: Option<usize>,
     ctx: &Context,
 ) -> ConditionTree<'a> {
@@ -17,14 +34,15 @@ pub fn wrap_with_limit_subquery_if_needed<'a>(
             .map(|f| f.as_column(ctx))
             .collect::<Vec<_>>();
 
-        ConditionTree::from(
-            Row::from(columns.clone()).in_selection(
-                Select::from_table(model.as_table(ctx))
-                    .columns(columns)
-                    .so_that(filter_condition)
-                    .limit(limit),
-            ),
-        )
+        let mut select = Select::from_table(model.as_table(ctx))
+            .columns(columns.clone())
+            .so_that(filter_condition);
+
+        for order_definition in build_take_ordering(order_by, ctx) {
+            select = select.order_by(order_definition);
+        }
+
+        ConditionTree::from(Row::from(columns).in_selection(select.limit(limit)))
     } else {
         filter_condition
     }
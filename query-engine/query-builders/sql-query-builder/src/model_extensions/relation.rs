@@ -78,10 +78,16 @@ impl AsTable for Relation {
                 // Author: nfl1ryxditimo12@gmail.com
                 // Date: 2025-06-16
                 // Note: Add `target_schema` function to support dynamic schema
-                let prefix = m.model_a().schema_name()
-                    .and_then(|origin_schema| ctx.target_schema(origin_schema))
-                    .unwrap_or_else(|| ctx.schema_name().unwrap_or_default().to_owned());
-                table = table.database(prefix);
+                let origin_schema = m.model_a().schema_name();
+
+                // Remapped via `search_path`: the unqualified table name resolves through the
+                // session's search_path, so skip adding a schema prefix here.
+                if !origin_schema.is_some_and(|schema| ctx.is_dynamically_remapped_schema(schema)) {
+                    let prefix = origin_schema
+                        .and_then(|origin_schema| ctx.target_schema(origin_schema))
+                        .unwrap_or_else(|| ctx.schema_name().unwrap_or_default().to_owned());
+                    table = table.database(prefix);
+                }
                 table.add_unique_index(vec![Column::from("A"), Column::from("B")])
             }
             walkers::RefinedRelationWalker::Inline(ref m) => {
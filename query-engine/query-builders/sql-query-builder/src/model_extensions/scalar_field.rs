@@ -8,10 +8,15 @@ use quaint::{
     ast::{EnumName, OpaqueType, Value, ValueType},
     prelude::{EnumVariant, TypeDataLength, TypeFamily},
 };
-use query_structure::{ScalarField, TypeIdentifier};
+use query_structure::{InternalEnum, ScalarField, TypeIdentifier};
 
 pub(crate) trait ScalarFieldExt {
     fn value<'a>(&self, pv: PrismaValue, ctx: &Context<'_>) -> Value<'a>;
+    /// Like [`ScalarFieldExt::value`], but renders a `PrismaValue::Placeholder` as a single bind
+    /// value typed as an array of this field's scalar type, rather than as one scalar element.
+    /// Used when an entire scalar-list filter argument (e.g. `hasSome`) is parameterized as one
+    /// placeholder instead of one placeholder per element.
+    fn array_value<'a>(&self, pv: PrismaValue, ctx: &Context<'_>) -> Value<'a>;
     fn type_family(&self) -> TypeFamily;
 }
 
@@ -25,7 +30,7 @@ impl ScalarFieldExt for ScalarField {
             (PrismaValue::Enum(e), TypeIdentifier::Enum(enum_id)) => {
                 let enum_walker = self.dm.clone().zip(enum_id);
                 let enum_name = enum_walker.db_name().to_owned();
-                let schema_name = enum_walker.schema_name().or(ctx.schema_name()).map(ToOwned::to_owned);
+                let schema_name = enum_schema_name(&enum_walker, ctx);
                 Value::enum_variant_with_name(e, EnumName::new(enum_name, schema_name))
             }
             (PrismaValue::List(vals), TypeIdentifier::Enum(enum_id)) => {
@@ -37,7 +42,7 @@ impl ScalarFieldExt for ScalarField {
                     .collect();
 
                 let enum_name = enum_walker.db_name().to_owned();
-                let schema_name = enum_walker.schema_name().or(ctx.schema_name()).map(ToOwned::to_owned);
+                let schema_name = enum_schema_name(&enum_walker, ctx);
 
                 Value::enum_array_with_name(variants, EnumName::new(enum_name, schema_name))
             }
@@ -57,7 +62,7 @@ impl ScalarFieldExt for ScalarField {
                 TypeIdentifier::Enum(enum_id) => {
                     let enum_walker = self.dm.clone().zip(enum_id);
                     let enum_name = enum_walker.db_name().to_owned();
-                    let schema_name = enum_walker.schema_name().or(ctx.schema_name()).map(ToOwned::to_owned);
+                    let schema_name = enum_schema_name(&enum_walker, ctx);
                     ValueType::Enum(None, Some(EnumName::new(enum_name, schema_name))).into_value()
                 }
                 TypeIdentifier::Json => Value::null_json(),
@@ -69,12 +74,11 @@ impl ScalarFieldExt for ScalarField {
                 TypeIdentifier::Unsupported => unreachable!("No unsupported field should reach this path"),
             },
             (PrismaValue::Placeholder(PrismaValuePlaceholder { name, .. }), ident) => {
-                Value::opaque(Placeholder::new(name), convert_type_identifier_to_opaque_type(&ident))
+                Value::opaque(Placeholder::new(name), opaque_type_for_placeholder(self, ident))
+            }
+            (PrismaValue::GeneratorCall { name, args, .. }, ident) => {
+                Value::opaque(GeneratorCall::new(name, args), opaque_type_for_placeholder(self, ident))
             }
-            (PrismaValue::GeneratorCall { name, args, .. }, ident) => Value::opaque(
-                GeneratorCall::new(name, args),
-                convert_type_identifier_to_opaque_type(&ident),
-            ),
         };
 
         let nt_col_type = self.native_type().map(|nt| (nt.name(), parse_scalar_length(self)));
@@ -82,6 +86,17 @@ impl ScalarFieldExt for ScalarField {
         value.with_native_column_type(nt_col_type)
     }
 
+    fn array_value<'a>(&self, pv: PrismaValue, ctx: &Context<'_>) -> Value<'a> {
+        match pv {
+            PrismaValue::Placeholder(PrismaValuePlaceholder { name, .. }) => {
+                let opaque_type = OpaqueType::Array(Box::new(convert_type_identifier_to_opaque_type(&self.type_identifier())));
+
+                Value::opaque(Placeholder::new(name), opaque_type)
+            }
+            pv => self.value(pv, ctx),
+        }
+    }
+
     fn type_family(&self) -> TypeFamily {
         match self.type_identifier() {
             TypeIdentifier::String => TypeFamily::Text(parse_scalar_length(self)),
@@ -108,6 +123,41 @@ impl ScalarFieldExt for ScalarField {
     }
 }
 
+/**
+ * Changed by @vetching-corporation
+ * Author: nfl1ryxditimo12@gmail.com
+ * Date: 2025-06-16
+ * Note: Route enum type casts through `Context::target_schema` the same way table references
+ * are remapped, so a cast like `::"app"."Color"` targets the remapped tenant schema instead of
+ * the origin schema.
+ */
+fn enum_schema_name(enum_walker: &InternalEnum, ctx: &Context<'_>) -> Option<String> {
+    let origin_schema = enum_walker.schema_name();
+
+    if origin_schema.is_some_and(|schema| ctx.is_dynamically_remapped_schema(schema)) {
+        return None;
+    }
+
+    origin_schema
+        .and_then(|origin_schema| ctx.target_schema(origin_schema).or(Some(origin_schema.to_owned())))
+        .or_else(|| ctx.schema_name().map(ToOwned::to_owned))
+}
+
+/// The [`OpaqueType`] a placeholder or generator call targeting `field` should be bound as. Unlike
+/// [`convert_type_identifier_to_opaque_type`], this also wraps the type in [`OpaqueType::Nullable`]
+/// when the field is optional, so that the value's declared type still accepts `NULL` once it's
+/// round-tripped back into a [`prisma_value::PrismaValueType`] for a compiled plan's parameter
+/// metadata, even though the field's own `TypeIdentifier` doesn't carry arity.
+fn opaque_type_for_placeholder(field: &ScalarField, identifier: TypeIdentifier) -> OpaqueType {
+    let opaque_type = convert_type_identifier_to_opaque_type(&identifier);
+
+    if field.arity().is_optional() {
+        OpaqueType::Nullable(Box::new(opaque_type))
+    } else {
+        opaque_type
+    }
+}
+
 fn convert_type_identifier_to_opaque_type(identifier: &TypeIdentifier) -> OpaqueType {
     match identifier {
         TypeIdentifier::String => OpaqueType::Text,
@@ -9,11 +9,17 @@ use query_structure::Model;
  * Note: Add `target_schema` function to support dynamic schema
  */
 pub(crate) fn db_name_with_schema(model: &Model, ctx: &Context<'_>) -> Table<'static> {
-    let schema_prefix = model
-        .walker()
-        .schema_name()
-        .and_then(|origin_schema| ctx.target_schema(origin_schema).or(Some(origin_schema.to_owned())))
-        .or_else(|| ctx.schema_name().map(ToOwned::to_owned));
+    let origin_schema = model.walker().schema_name();
+
+    // When the origin schema is remapped via `search_path` (see `Context::use_search_path_for_dynamic_schema`),
+    // the unqualified table name resolves through the session's search_path, so no prefix is needed here.
+    let schema_prefix = if origin_schema.is_some_and(|schema| ctx.is_dynamically_remapped_schema(schema)) {
+        None
+    } else {
+        origin_schema
+            .and_then(|origin_schema| ctx.target_schema(origin_schema).or(Some(origin_schema.to_owned())))
+            .or_else(|| ctx.schema_name().map(ToOwned::to_owned))
+    };
 
     let model_db_name = model.db_name().to_owned();
 
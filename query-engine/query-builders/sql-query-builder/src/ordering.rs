@@ -53,6 +53,7 @@ impl OrderByBuilder {
                     reachable_only_with_capability!(ConnectorCapability::NativeFullTextSearch);
                     self.build_order_relevance(order_by, needs_reversed_order, ctx)
                 }
+                OrderBy::InputOrder(order_by) => self.build_order_input_order(order_by, ctx),
             })
             .collect_vec()
     }
@@ -66,7 +67,7 @@ impl OrderByBuilder {
         let (joins, order_column) = self.compute_joins_scalar(order_by, ctx);
         let order: Option<Order> = Some(into_order(
             &order_by.sort_order,
-            order_by.nulls_order.as_ref(),
+            order_by.effective_nulls_order().as_ref(),
             needs_reversed_order,
         ));
         let order_definition: OrderDefinition = (order_column.clone().into(), order);
@@ -95,6 +96,25 @@ impl OrderByBuilder {
         }
     }
 
+    /// `_inputOrder` has no relation path and no asc/desc/nulls semantics: it always preserves the
+    /// order of the `values` list it was derived from, so `needs_reversed_order` (used for
+    /// cursor-based backward pagination) does not apply to it.
+    fn build_order_input_order(&mut self, order_by: &OrderByInputOrder, ctx: &Context<'_>) -> OrderByDefinition {
+        let order_column = order_by.field.as_column(ctx);
+        let values = order_by
+            .values
+            .iter()
+            .map(|pv| order_by.field.value(pv.clone(), ctx))
+            .collect();
+        let order_definition: OrderDefinition = (value_position(order_column.clone(), values).into(), None);
+
+        OrderByDefinition {
+            order_column: order_column.into(),
+            order_definition,
+            joins: vec![],
+        }
+    }
+
     fn build_order_aggr_scalar(
         &mut self,
         order_by: &OrderByScalarAggregation,
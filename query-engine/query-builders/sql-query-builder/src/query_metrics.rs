@@ -0,0 +1,53 @@
+//! Optional hook for observing how expensive a query the builder produced, without the builder
+//! itself depending on any particular metrics backend.
+//!
+//! `sql-query-builder` is also compiled for wasm targets where a metrics facade may not be
+//! available, so instead of recording metrics directly (the way `sql-query-connector`'s
+//! `crate::metrics` does via `prisma_metrics`), it reports through a caller-supplied
+//! [`QueryMetricsCollector`] trait object. [`Context`](crate::Context) holds an optional one and
+//! is a no-op when none is set.
+
+use query_structure::RelationLoadStrategy;
+
+/// A single query-building event: either one query (`chunks: 1`) or, for a batch that had to be
+/// split to stay under the connector's bind-parameter limit, the whole batch (`chunks` > 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryMetricsEvent {
+    /// Total number of bind parameters across all chunks.
+    pub bind_values: usize,
+    /// Number of statements the query was split into. `1` for a query that wasn't chunked.
+    pub chunks: usize,
+    /// The relation load strategy used, for read queries. `None` for chunked write conditions,
+    /// which have no relation load strategy of their own.
+    pub relation_strategy: Option<RelationLoadStrategy>,
+}
+
+/// Receives [`QueryMetricsEvent`]s reported by [`Context`](crate::Context). Implementations are
+/// expected to be cheap and non-blocking, since they're invoked synchronously from the query
+/// building hot path.
+pub trait QueryMetricsCollector: Send + Sync {
+    fn record(&self, event: QueryMetricsEvent);
+}
+
+#[cfg(test)]
+pub(crate) mod test_collector {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct TestMetricsCollector {
+        events: Mutex<Vec<QueryMetricsEvent>>,
+    }
+
+    impl TestMetricsCollector {
+        pub(crate) fn events(&self) -> Vec<QueryMetricsEvent> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    impl QueryMetricsCollector for TestMetricsCollector {
+        fn record(&self, event: QueryMetricsEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+}
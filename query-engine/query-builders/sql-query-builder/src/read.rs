@@ -106,7 +106,7 @@ impl SelectDefinition for QueryArguments {
         let select_ast = Select::from_table(joined_table)
             .so_that(conditions)
             .offset(skip as usize)
-            .add_traceparent(ctx.traceparent);
+            .add_traceparent(ctx.traceparent, ctx.trace_comment_mode());
 
         let select_ast = order_by_definitions
             .iter()
@@ -143,7 +143,7 @@ where
     let (select, additional_selection_set) = query_arguments.into_select(model, virtual_selections, ctx);
     let select = columns.fold(select, |acc, col| acc.column(col));
 
-    let select = select.add_traceparent(ctx.traceparent);
+    let select = select.add_traceparent(ctx.traceparent, ctx.trace_comment_mode());
 
     additional_selection_set
         .into_iter()
@@ -188,7 +188,7 @@ pub fn aggregate(
     let sub_table = Table::from(sub_query).alias("sub");
 
     selections.iter().fold(
-        Select::from_table(sub_table).add_traceparent(ctx.traceparent),
+        Select::from_table(sub_table).add_traceparent(ctx.traceparent, ctx.trace_comment_mode()),
         |select, next_op| match next_op {
             AggregationSelection::Field(field) => select.column(
                 alias
@@ -254,6 +254,13 @@ pub fn aggregate(
     )
 }
 
+/// `group_by` is rendered verbatim as the `GROUP BY` clause, and `selections` may only contain an
+/// `AggregationSelection::Field` for fields already present in `group_by` (enforced by
+/// `verify_selections` in the query-graph builder before this is ever called). This means the
+/// query never needs to rely on MySQL's or Postgres' functional-dependency inference (which
+/// MSSQL doesn't support at all, and which MySQL only extends to primary keys under
+/// `ONLY_FULL_GROUP_BY`) to justify a selected, non-aggregated column — every such column is
+/// always explicitly grouped.
 pub fn group_by_aggregate(
     model: &Model,
     args: QueryArguments,
@@ -320,7 +327,7 @@ pub fn group_by_aggregate(
 
     let grouped = group_by
         .into_iter()
-        .fold(select_query.add_traceparent(ctx.traceparent), |query, field| {
+        .fold(select_query.add_traceparent(ctx.traceparent, ctx.trace_comment_mode()), |query, field| {
             query.group_by(field.as_column(ctx))
         });
 
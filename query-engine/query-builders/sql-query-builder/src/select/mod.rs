@@ -35,6 +35,83 @@ impl SelectBuilder {
     }
 }
 
+/// A reason why a query cannot be resolved with the `Join` relation load strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IncompatibilityReason {
+    /// `orderBy` sorts by an aggregate of a to-many relation reached through one or more
+    /// `include`d relations, instead of a to-many relation directly off the root model.
+    NestedToManyAggregationOrderBy,
+    /// A nested relation is `distinct`ed while an ancestor (the root query or another nested
+    /// relation) is also `distinct`ed. The JSON-aggregated join query has no way to express
+    /// distinctness at more than one level at once.
+    NestedDistinct,
+    /// A nested relation paginates with a cursor, which the join query cannot express because it
+    /// has no intermediary result set to apply the cursor condition to.
+    NestedCursor,
+}
+
+impl std::fmt::Display for IncompatibilityReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            Self::NestedToManyAggregationOrderBy => {
+                "orderBy on a to-many relation aggregate nested inside an include"
+            }
+            Self::NestedDistinct => "distinct on a nested relation combined with a distinct on an ancestor",
+            Self::NestedCursor => "cursor pagination on a nested relation",
+        };
+
+        write!(f, "{msg} is not supported by the `join` relation load strategy")
+    }
+}
+
+impl std::error::Error for IncompatibilityReason {}
+
+/// Checks whether `args`/`selected_fields` describe a query that `SelectBuilder::build` can
+/// actually translate to SQL. Some argument combinations that are valid for the `Query` relation
+/// load strategy either produce invalid SQL or can't be expressed at all through joins.
+///
+/// Callers should fall back to `RelationLoadStrategy::Query` when this returns `Err`, unless the
+/// user asked for strict `join` enforcement, in which case the reason should be surfaced as an
+/// error.
+pub fn is_join_compatible(
+    args: &QueryArguments,
+    selected_fields: &FieldSelection,
+) -> Result<(), IncompatibilityReason> {
+    if args.order_by.iter().any(is_nested_to_many_aggregation_order_by) {
+        return Err(IncompatibilityReason::NestedToManyAggregationOrderBy);
+    }
+
+    check_nested_relations(selected_fields.relations(), args.distinct.is_some())
+}
+
+fn is_nested_to_many_aggregation_order_by(order_by: &OrderBy) -> bool {
+    match order_by {
+        OrderBy::ToManyAggregation(o) => o.intermediary_hops().len() > 1,
+        _ => false,
+    }
+}
+
+fn check_nested_relations<'a>(
+    relations: impl Iterator<Item = &'a RelationSelection>,
+    ancestor_has_distinct: bool,
+) -> Result<(), IncompatibilityReason> {
+    for rs in relations {
+        let has_distinct = rs.args.distinct.is_some();
+
+        if ancestor_has_distinct && has_distinct {
+            return Err(IncompatibilityReason::NestedDistinct);
+        }
+
+        if rs.args.cursor.is_some() {
+            return Err(IncompatibilityReason::NestedCursor);
+        }
+
+        check_nested_relations(rs.relations(), ancestor_has_distinct || has_distinct)?;
+    }
+
+    Ok(())
+}
+
 pub(crate) trait JoinSelectBuilder {
     /// Build the select query for the given query arguments and selected fields.
     /// This is the entry point for building a select query. `build_default_select` can be used to get a default select query.
@@ -268,7 +345,7 @@ pub(crate) trait JoinSelectBuilder {
             .with_ordering(args, Some(table_alias.to_string()), ctx)
             .with_filters(args.filter.clone(), Some(table_alias), ctx)
             .with_pagination(args, None)
-            .add_traceparent(ctx.traceparent);
+            .add_traceparent(ctx.traceparent, ctx.trace_comment_mode());
 
         (select, table_alias)
     }
@@ -595,6 +672,8 @@ fn order_by_selection(rs: &RelationSelection) -> FieldSelection {
             // This is necessary because the order by is done on a different join. The following hops are handled by the order by builder.
             OrderBy::ToManyAggregation(x) => first_hop_linking_fields(x.intermediary_hops()),
             OrderBy::ScalarAggregation(x) => vec![x.field.clone()],
+            // No path to traverse: the field is selected on the outer select like any other scalar.
+            OrderBy::InputOrder(x) => vec![x.field.clone()],
         })
         .collect();
 
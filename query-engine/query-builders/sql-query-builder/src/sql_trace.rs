@@ -1,22 +1,49 @@
 use quaint::ast::{Delete, Insert, Select, Update};
 use telemetry::TraceParent;
 
+/// Controls how (or whether) a `traceparent` SQL comment is appended to generated statements.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TraceCommentMode {
+    /// No trace comment is appended.
+    ///
+    /// Useful for connection poolers such as PgBouncer in transaction pooling mode, where
+    /// server-side prepared statement caching keys on the exact statement text: a comment with a
+    /// random trace id on every statement would make each one unique and defeat the cache.
+    Disabled,
+    /// A trace comment is appended, but it carries a fixed marker instead of the (random) trace
+    /// id, preserving cacheability while still tagging the statement as coming from Prisma.
+    Static,
+    /// The full `traceparent` (including the trace id) is appended. This is the default and
+    /// matches the historical behavior.
+    #[default]
+    Full,
+}
+
+/// A fixed, non-random stand-in for a `traceparent` value, used by `TraceCommentMode::Static`.
+const STATIC_TRACEPARENT_COMMENT: &str = "traceparent='00-00000000000000000000000000000000-0000000000000000-00'";
+
 pub trait SqlTraceComment: Sized {
-    fn add_traceparent(self, traceparent: Option<TraceParent>) -> Self;
+    fn add_traceparent(self, traceparent: Option<TraceParent>, mode: TraceCommentMode) -> Self;
 }
 
 macro_rules! sql_trace {
     ($what:ty) => {
         impl SqlTraceComment for $what {
-            fn add_traceparent(self, traceparent: Option<TraceParent>) -> Self {
-                let Some(traceparent) = traceparent else {
-                    return self;
-                };
-
-                if traceparent.sampled() {
-                    self.comment(format!("traceparent='{traceparent}'"))
-                } else {
-                    self
+            fn add_traceparent(self, traceparent: Option<TraceParent>, mode: TraceCommentMode) -> Self {
+                match mode {
+                    TraceCommentMode::Disabled => self,
+                    TraceCommentMode::Static => self.comment(STATIC_TRACEPARENT_COMMENT),
+                    TraceCommentMode::Full => {
+                        let Some(traceparent) = traceparent else {
+                            return self;
+                        };
+
+                        if traceparent.sampled() {
+                            self.comment(format!("traceparent='{traceparent}'"))
+                        } else {
+                            self
+                        }
+                    }
                 }
             }
         }
@@ -1,27 +1,43 @@
 use quaint::ast::{Query, Update};
-use query_structure::{Filter, IntoFilter, Model, ModelProjection, RecordFilter, SelectionResult, WriteArgs};
+use quaint::prelude::SqlFamily;
+use query_structure::{Filter, IntoFilter, Model, ModelProjection, OrderBy, RecordFilter, SelectionResult, WriteArgs};
 
 use crate::{limit, write, AsColumns, Context, FilterBuilder};
 
 // Generates a query like this:
 //  UPDATE "public"."User" SET "name" = $1 WHERE "public"."User"."age" > $1
+//
+// MySQL and SQLite support `ORDER BY`/`LIMIT` directly on `UPDATE`, so an ordered, row-limited
+// update uses that instead of the `WHERE id IN (SELECT id ... ORDER BY ... LIMIT n)` subquery the
+// other connectors need: MySQL rejects a subquery that reads from the same table an
+// `UPDATE`/`DELETE` is writing to (error 1093), and we use the same strategy for SQLite for
+// consistency since it supports the native form too.
 pub fn update_many_from_filter(
     model: &Model,
     filter: Filter,
     args: WriteArgs,
     selected_fields: Option<&ModelProjection>,
+    order_by: &[OrderBy],
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> Query<'static> {
     let update = write::build_update_and_set_query(model, args, None, ctx);
-    let filter_condition = limit::wrap_with_limit_subquery_if_needed(
-        model,
-        FilterBuilder::without_top_level_joins().visit_filter(filter, ctx),
-        limit,
-        ctx,
-    );
+    let filter_condition = FilterBuilder::without_top_level_joins().visit_filter(filter, ctx);
+
+    let update = if ctx.sql_family().is_mysql() || ctx.sql_family().is_sqlite() {
+        let mut update = update.so_that(filter_condition);
+        for order_definition in limit::build_take_ordering(order_by, ctx) {
+            update = update.order_by(order_definition);
+        }
+        match limit {
+            Some(limit) => update.limit(limit),
+            None => update,
+        }
+    } else {
+        let filter_condition = limit::wrap_with_limit_subquery_if_needed(model, filter_condition, order_by, limit, ctx);
+        update.so_that(filter_condition)
+    };
 
-    let update = update.so_that(filter_condition);
     if let Some(selected_fields) = selected_fields {
         update
             .returning(selected_fields.as_columns(ctx).map(|c| c.set_is_selected(true)))
@@ -51,7 +67,15 @@ pub fn update_many_from_ids_and_filter(
     write::chunk_update_with_ids(update, model, selections, filter_condition, ctx)
 }
 
-/// Creates an update with an explicit selection set.
+/// Creates an update with an explicit selection set, relying on the `RETURNING` clause to read
+/// back `selected_fields` in the same statement.
+///
+/// Callers must only reach this function for connectors that support `RETURNING` on `UPDATE`
+/// (Postgres, SQLite) — the `UpdateRecord::WithSelection` vs. `WithoutSelection` split upstream
+/// already guarantees this based on `ConnectorCapability::UpdateReturning`. MySQL has no
+/// `RETURNING` support, so it always goes through `WithoutSelection` and never calls this
+/// function; we still guard against it here so a future caller can't silently generate a
+/// statement the connector would reject.
 pub fn update_one_with_selection(
     model: &Model,
     record_filter: RecordFilter,
@@ -59,21 +83,38 @@ pub fn update_one_with_selection(
     selected_fields: &ModelProjection,
     ctx: &Context<'_>,
 ) -> Update<'static> {
-    let cond = FilterBuilder::without_top_level_joins().visit_filter(build_update_one_filter(record_filter), ctx);
+    debug_assert!(
+        supports_update_returning(ctx),
+        "update_one_with_selection was called for a connector without RETURNING support on UPDATE"
+    );
+
+    let cond = FilterBuilder::without_top_level_joins().visit_filter(build_update_one_filter(model, record_filter), ctx);
     write::build_update_and_set_query(model, args, Some(selected_fields), ctx).so_that(cond)
 }
 
+fn supports_update_returning(ctx: &Context<'_>) -> bool {
+    !matches!(ctx.sql_family(), SqlFamily::Mysql | SqlFamily::Mssql)
+}
+
 /// Given a record filter, builds a ConditionTree composed of:
 /// 1. The `RecordFilter.filter`
-/// 2. The `RecordFilter.selectors`, if any are present, transformed to an `In()` filter
+/// 2. The `RecordFilter.selectors`, if any are present, projected down to the shard-aware primary
+///    identifier and transformed to an `In()` filter. Selectors are only used for identity here,
+///    so any extra pairs they carry (e.g. inherited from a broader parent selection) are dropped
+///    rather than turned into additional conditions.
 ///
 /// Both filters are 'AND'ed.
 ///
 /// Note: This function should only be called for update_one filters. It is not chunking the filters into multiple queries.
 /// Note: Using this function to render an update_many filter could exceed the maximum query parameters available for a connector.
-fn build_update_one_filter(record_filter: RecordFilter) -> Filter {
+fn build_update_one_filter(model: &Model, record_filter: RecordFilter) -> Filter {
     match record_filter.selectors {
-        Some(selectors) => Filter::and(vec![selectors.filter(), record_filter.filter]),
+        Some(selectors) => {
+            let id_selection = model.shard_aware_primary_identifier();
+            let ids: Vec<SelectionResult> = selectors.into_iter().map(|s| s.project(&id_selection)).collect();
+
+            Filter::and(vec![ids.filter(), record_filter.filter])
+        }
         None => record_filter.filter,
     }
 }
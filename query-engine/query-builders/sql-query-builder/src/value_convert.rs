@@ -1,6 +1,16 @@
+//! Conversions between [`quaint::Value`] (what the database driver produces/consumes) and
+//! [`PrismaValue`] (what the rest of the query engine, and external consumers of compiled plans
+//! such as driver adapters, work with). Reading a value back out is [`SqlFamily`]-aware because
+//! the same column value can come back differently shaped per family - e.g. MySQL and SQL Server
+//! dates and times are rendered as family-specific strings rather than a structured date/time
+//! value, and non-SQLite numerics are rendered as strings to avoid floating-point rounding.
+
 use bigdecimal::{BigDecimal, FromPrimitive};
-use prisma_value::{PrismaValue, PrismaValueType};
-use quaint::{ast::OpaqueType, prelude::SqlFamily};
+use prisma_value::{Placeholder as PrismaValuePlaceholder, PrismaValue, PrismaValueType};
+use quaint::{
+    ast::{CompositeValue, OpaqueType},
+    prelude::SqlFamily,
+};
 
 use crate::value::{GeneratorCall, Placeholder};
 
@@ -8,7 +18,10 @@ const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
 const DATE_FORMAT: &str = "%Y-%m-%d";
 const TIME_FORMAT: &str = "%H:%M:%S%.f";
 
-pub(crate) fn quaint_value_to_prisma_value(value: quaint::Value<'_>, family: SqlFamily) -> PrismaValue {
+/// Converts a value coming out of the database driver into a [`PrismaValue`], applying the
+/// family-specific rendering rules (e.g. MySQL/SQL Server dates and times are formatted as
+/// strings rather than kept as a structured date/time value).
+pub fn quaint_value_to_prisma_value(value: quaint::Value<'_>, family: SqlFamily) -> PrismaValue {
     match value.typed {
         quaint::ValueType::Int32(Some(i)) => PrismaValue::Int(i.into()),
         quaint::ValueType::Int32(None) => PrismaValue::Null,
@@ -91,6 +104,14 @@ pub(crate) fn quaint_value_to_prisma_value(value: quaint::Value<'_>, family: Sql
                     args: call.args().to_vec(),
                     return_type: opaque_type_to_prisma_type(opaque.typ()),
                 }
+            } else if let Some(composite) = opaque.downcast_ref::<CompositeValue>() {
+                PrismaValue::Object(
+                    composite
+                        .fields()
+                        .iter()
+                        .map(|(name, value)| (name.clone(), quaint_value_to_prisma_value(value.clone(), family)))
+                        .collect(),
+                )
             } else {
                 panic!("Received an unsupported opaque value")
             }
@@ -98,6 +119,69 @@ pub(crate) fn quaint_value_to_prisma_value(value: quaint::Value<'_>, family: Sql
     }
 }
 
+/// Converts a [`PrismaValue`] into a [`quaint::Value`] suitable for binding as a query parameter.
+///
+/// This is the inverse of [`quaint_value_to_prisma_value`] for the variants where that's actually
+/// well-defined. `family` doesn't change the value built here: per-family rendering of the final
+/// SQL (e.g. SQL Server encoding booleans as `1`/`0`) is handled downstream by the connector's own
+/// visitor, the same way it already is for values built anywhere else in this crate. It's taken so
+/// the signature mirrors `quaint_value_to_prisma_value` and callers don't have to special-case it
+/// if that ever changes.
+///
+/// This conversion can't be a true round trip for every value `quaint_value_to_prisma_value` can
+/// produce: once a date, time, or (on every family but SQLite) a decimal column value has been
+/// turned into a `PrismaValue::String`, the information needed to turn it back into a structured
+/// `quaint::Value` is gone - the caller has to already know the target type from the model's
+/// field, the same way [`crate::model_extensions::ScalarFieldExt::value`] does. This function
+/// only handles `PrismaValue` variants that keep their native representation through the trip.
+pub fn prisma_value_to_quaint_value(value: PrismaValue, family: SqlFamily) -> quaint::Value<'static> {
+    match value {
+        PrismaValue::String(s) => s.into(),
+        PrismaValue::Boolean(b) => b.into(),
+        PrismaValue::Enum(e) => e.into(),
+        PrismaValue::Int(i) => i.into(),
+        PrismaValue::BigInt(i) => i.into(),
+        PrismaValue::Float(f) => f.into(),
+        PrismaValue::Uuid(u) => u.to_string().into(),
+        PrismaValue::DateTime(d) => d.with_timezone(&chrono::Utc).into(),
+        PrismaValue::Bytes(b) => quaint::Value::bytes(b),
+        PrismaValue::Json(s) => quaint::Value::json(serde_json::from_str(&s).expect("invalid JSON in PrismaValue::Json")),
+        PrismaValue::List(l) => quaint::Value::array(l.into_iter().map(|v| prisma_value_to_quaint_value(v, family))),
+        PrismaValue::Null => quaint::Value::null_int32(),
+        PrismaValue::Object(_) => unimplemented!("PrismaValue::Object has no quaint::Value representation"),
+        PrismaValue::Placeholder(PrismaValuePlaceholder { name, r#type }) => {
+            quaint::Value::opaque(Placeholder::new(name), prisma_type_to_opaque_type(&r#type))
+        }
+        PrismaValue::GeneratorCall { name, args, return_type } => {
+            quaint::Value::opaque(GeneratorCall::new(name, args), prisma_type_to_opaque_type(&return_type))
+        }
+    }
+}
+
+/// The inverse of [`opaque_type_to_prisma_type`], used to round-trip the declared type of
+/// [`PrismaValue::Placeholder`]/[`PrismaValue::GeneratorCall`] values back into an [`OpaqueType`]
+/// when re-encoding them as a [`quaint::Value`].
+pub fn prisma_type_to_opaque_type(pt: &PrismaValueType) -> OpaqueType {
+    match pt {
+        PrismaValueType::Any => OpaqueType::Unknown,
+        PrismaValueType::String => OpaqueType::Text,
+        PrismaValueType::Int => OpaqueType::Int32,
+        PrismaValueType::BigInt => OpaqueType::Int64,
+        PrismaValueType::Float => OpaqueType::Numeric,
+        PrismaValueType::Boolean => OpaqueType::Boolean,
+        PrismaValueType::Decimal => OpaqueType::Numeric,
+        PrismaValueType::Date => OpaqueType::DateTime,
+        PrismaValueType::Time => OpaqueType::Time,
+        PrismaValueType::Array(t) => OpaqueType::Array(Box::new(prisma_type_to_opaque_type(t))),
+        PrismaValueType::Object => OpaqueType::Json,
+        PrismaValueType::Bytes => OpaqueType::Bytes,
+        PrismaValueType::Enum(_) => OpaqueType::Text,
+        PrismaValueType::Nullable(t) => OpaqueType::Nullable(Box::new(prisma_type_to_opaque_type(t))),
+    }
+}
+
+/// Maps the [`OpaqueType`] tag carried by an opaque [`quaint::Value`] (placeholders and generator
+/// calls) to the [`PrismaValueType`] it corresponds to.
 pub fn opaque_type_to_prisma_type(vt: &OpaqueType) -> PrismaValueType {
     match vt {
         OpaqueType::Unknown => PrismaValueType::Any,
@@ -118,5 +202,6 @@ pub fn opaque_type_to_prisma_type(vt: &OpaqueType) -> PrismaValueType {
         OpaqueType::DateTime => PrismaValueType::Date,
         OpaqueType::Date => PrismaValueType::Date,
         OpaqueType::Time => PrismaValueType::Time,
+        OpaqueType::Nullable(t) => PrismaValueType::Nullable(Box::new(opaque_type_to_prisma_type(t))),
     }
 }
@@ -1,4 +1,4 @@
-use crate::limit::wrap_with_limit_subquery_if_needed;
+use crate::limit::{build_take_ordering, wrap_with_limit_subquery_if_needed};
 use crate::{model_extensions::*, sql_trace::SqlTraceComment, Context};
 use crate::{update, FilterBuilder};
 use itertools::Itertools;
@@ -7,6 +7,60 @@ use query_structure::*;
 use std::collections::HashMap;
 use std::{collections::HashSet, convert::TryInto};
 
+/// If `field`'s default is `@default(autoincrement())` or `@default(sequence())`, returns the name
+/// of the database sequence backing it.
+///
+/// Neither PSL function carries an explicit sequence name, so we derive the same name Postgres
+/// gives the sequence it automatically creates for a `SERIAL`-like autoincrement column:
+/// `<table>_<column>_seq`. This matches what schema-engine's Postgres flavour names the sequence it
+/// creates for either default.
+pub fn sequence_name_for_field(model: &Model, field: &ScalarField) -> Option<String> {
+    match field.default_value() {
+        Some(DefaultKind::Expression(generator)) if generator.is_autoincrement() => {
+            Some(format!("{}_{}_seq", model.db_name(), field.db_name()).to_lowercase())
+        }
+        _ => None,
+    }
+}
+
+/// If `field`'s default is `@default(sequence())`, returns the `nextval('...')` expression that
+/// should be used in its place when no explicit value was provided.
+///
+/// `sequence()` is currently only recognized for CockroachDB, which speaks the `Postgres`
+/// `SqlFamily`; other connectors fall back to ordinary autoincrement/`DEFAULT` handling.
+fn sequence_nextval(model: &Model, field: &ScalarField, ctx: &Context<'_>) -> Option<Expression<'static>> {
+    if !ctx.sql_family().is_postgres() {
+        return None;
+    }
+
+    match field.default_value() {
+        Some(DefaultKind::Expression(generator)) if generator.name() == "sequence" => {
+            sequence_name_for_field(model, field).map(|sequence_name| nextval(sequence_name).into())
+        }
+        _ => None,
+    }
+}
+
+/// If `field`'s default is `@default(uuid(7))`, returns the database function call that generates
+/// it, so it can be inlined into the `INSERT` instead of generating the value in the engine.
+///
+/// A field only reaches here without an explicit or engine-generated value when the query parser
+/// has already determined (via `QuerySchema::can_generate_uuid_v7_server_side`) that the datasource
+/// declares the extension needed to generate it itself, so this only needs to pick the right
+/// function for the `SqlFamily` - currently just Postgres' `pg_uuidv7` extension.
+fn server_generated_uuid_default(field: &ScalarField, ctx: &Context<'_>) -> Option<Expression<'static>> {
+    if !ctx.sql_family().is_postgres() {
+        return None;
+    }
+
+    match field.default_value() {
+        Some(DefaultKind::Expression(generator)) if generator.is_uuid_v7() => {
+            Some(db_function_call("uuid_generate_v7").into())
+        }
+        _ => None,
+    }
+}
+
 /// `INSERT` a new record to the database. Resulting an `INSERT` ast and an
 /// optional `RecordProjection` if available from the arguments or model.
 pub fn create_record(
@@ -18,24 +72,37 @@ pub fn create_record(
     let fields: Vec<_> = model
         .fields()
         .scalar()
-        .filter(|field| args.has_arg_for(field.db_name()))
+        .filter(|field| {
+            args.has_arg_for(field.db_name())
+                || sequence_nextval(model, field, ctx).is_some()
+                || server_generated_uuid_default(field, ctx).is_some()
+        })
         .collect();
 
     let insert = fields
         .into_iter()
         .fold(Insert::single_into(model.as_table(ctx)), |insert, field| {
             let db_name = field.db_name();
-            let value = args.take_field_value(db_name).unwrap();
-            let value: PrismaValue = value
-                .try_into()
-                .expect("Create calls can only use PrismaValue write expressions (right now).");
 
-            insert.value(db_name.to_owned(), field.value(value, ctx))
+            let value = match args.take_field_value(db_name) {
+                Some(write_op) => {
+                    let value: PrismaValue = write_op
+                        .try_into()
+                        .expect("Create calls can only use PrismaValue write expressions (right now).");
+
+                    field.value(value, ctx)
+                }
+                None => sequence_nextval(model, &field, ctx)
+                    .or_else(|| server_generated_uuid_default(&field, ctx))
+                    .expect("field was selected for its sequence or server-generated default"),
+            };
+
+            insert.value(db_name.to_owned(), value)
         });
 
     Insert::from(insert)
         .returning(selected_fields.as_columns(ctx).map(|c| c.set_is_selected(true)))
-        .add_traceparent(ctx.traceparent)
+        .add_traceparent(ctx.traceparent, ctx.trace_comment_mode())
 }
 
 /// `INSERT` new records into the database based on the given write arguments,
@@ -76,7 +143,10 @@ pub fn create_records_nonempty(
                     None if !field.is_required() && field.default_value().is_none() => {
                         row.push(Value::null_int32().raw().into())
                     }
-                    None => row.push(default_value()),
+                    None => match sequence_nextval(model, field, ctx).or_else(|| server_generated_uuid_default(field, ctx)) {
+                        Some(generated) => row.push(generated),
+                        None => row.push(default_value()),
+                    },
                 }
             }
 
@@ -88,7 +158,7 @@ pub fn create_records_nonempty(
     let insert = Insert::multi_into(model.as_table(ctx), columns);
     let insert = values.into_iter().fold(insert, |stmt, values| stmt.values(values));
     let insert: Insert = insert.into();
-    let mut insert = insert.add_traceparent(ctx.traceparent);
+    let mut insert = insert.add_traceparent(ctx.traceparent, ctx.trace_comment_mode());
 
     if let Some(selected_fields) = selected_fields {
         insert = insert.returning(projection_into_columns(selected_fields, ctx));
@@ -127,7 +197,7 @@ pub fn create_records_empty(
     ctx: &Context<'_>,
 ) -> Insert<'static> {
     let insert: Insert<'static> = Insert::single_into(model.as_table(ctx)).into();
-    let mut insert = insert.add_traceparent(ctx.traceparent);
+    let mut insert = insert.add_traceparent(ctx.traceparent, ctx.trace_comment_mode());
 
     if let Some(selected_fields) = selected_fields {
         insert = insert.returning(projection_into_columns(selected_fields, ctx));
@@ -192,12 +262,22 @@ pub fn build_update_and_set_query(
                 }
 
                 ScalarWriteOperation::Unset(_) => unreachable!("Unset is not supported on SQL connectors"),
+
+                ScalarWriteOperation::JsonSet(path, rhs) => {
+                    let column: Expression = Column::from((table.clone(), name.clone())).into();
+                    json_update_expression(column, &path, Some(field.value(rhs, ctx).into()), ctx)
+                }
+
+                ScalarWriteOperation::JsonRemove(path) => {
+                    let column: Expression = Column::from((table.clone(), name.clone())).into();
+                    json_update_expression(column, &path, None, ctx)
+                }
             };
 
             acc.set(name, value)
         });
 
-    let query = query.add_traceparent(ctx.traceparent);
+    let query = query.add_traceparent(ctx.traceparent, ctx.trace_comment_mode());
 
     let query = if let Some(selected_fields) = selected_fields {
         query.returning(selected_fields.as_columns(ctx).map(|c| c.set_is_selected(true)))
@@ -224,6 +304,50 @@ pub fn chunk_update_with_ids(
     })
 }
 
+/// Renders a `JsonSet`/`JsonRemove` update as a `json_set`/`json_remove` expression (see
+/// [`quaint::ast::json_set`]), picking the path notation each connector's visitor expects:
+/// array paths for Postgres, escaped dot-notation strings for everyone else.
+///
+/// `value` is `Some` for `JsonSet` and `None` for `JsonRemove`.
+fn json_update_expression<'a>(
+    column: Expression<'a>,
+    path: &[String],
+    value: Option<Expression<'a>>,
+    ctx: &Context<'_>,
+) -> Expression<'a> {
+    let path = if ctx.sql_family().is_postgres() {
+        JsonPath::array(path.to_vec())
+    } else {
+        JsonPath::string(json_dot_path(path))
+    };
+
+    match value {
+        Some(value) => json_set(column, path, value).into(),
+        None => json_remove(column, path).into(),
+    }
+}
+
+/// Renders `path` as a MySQL/SQLite/MSSQL-style dot-notation JSON path (`$.a.b`), quoting any
+/// segment that isn't a plain identifier (e.g. contains a dot or a quote) so it can't be
+/// misread as a path separator: `$."a.b"`.
+fn json_dot_path(path: &[String]) -> String {
+    let mut rendered = String::from("$");
+
+    for segment in path {
+        rendered.push('.');
+
+        if !segment.is_empty() && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            rendered.push_str(segment);
+        } else {
+            rendered.push('"');
+            rendered.push_str(&segment.replace('"', "\\\""));
+            rendered.push('"');
+        }
+    }
+
+    rendered
+}
+
 /// Converts a list of selected fields into an iterator of table columns.
 fn projection_into_columns(
     selected_fields: &ModelProjection,
@@ -237,17 +361,19 @@ pub fn generate_update_statements(
     record_filter: RecordFilter,
     args: WriteArgs,
     selected_fields: Option<&ModelProjection>,
+    order_by: &[OrderBy],
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> Vec<Query<'static>> {
     let RecordFilter { filter, selectors } = record_filter;
     match selectors {
         Some(ids) => {
+            let ids = dedupe_selectors(&ids);
             let slice = &ids[..limit.unwrap_or(ids.len()).min(ids.len())];
             update::update_many_from_ids_and_filter(model, filter, slice, args, selected_fields, ctx)
         }
         None => {
-            let query = update::update_many_from_filter(model, filter, args, selected_fields, limit, ctx);
+            let query = update::update_many_from_filter(model, filter, args, selected_fields, order_by, limit, ctx);
             vec![query]
         }
     }
@@ -257,6 +383,7 @@ pub fn generate_update_statements(
 pub fn generate_delete_statements(
     model: &Model,
     record_filter: RecordFilter,
+    order_by: &[OrderBy],
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> Vec<Query<'static>> {
@@ -264,13 +391,21 @@ pub fn generate_delete_statements(
 
     // If we have selectors, then we must chunk the mutation into multiple if necessary and add the ids to the filter.
     if let Some(selectors) = record_filter.selectors.as_deref() {
+        let selectors = dedupe_selectors(selectors);
         let slice = &selectors[..limit.unwrap_or(selectors.len()).min(selectors.len())];
-        delete_many_from_ids_and_filter(model, slice, filter_condition, limit, ctx)
+        delete_many_from_ids_and_filter(model, slice, filter_condition, order_by, limit, ctx)
     } else {
-        vec![delete_many_from_filter(model, filter_condition, limit, ctx)]
+        vec![delete_many_from_filter(model, filter_condition, order_by, limit, ctx)]
     }
 }
 
+/// Deduplicates `selectors` by their value pairs, preserving first-occurrence order. Upstream
+/// `QueryGraph` execution can select the same row via two different paths, and chunking duplicate
+/// selectors across separate statements would otherwise double-count affected rows.
+fn dedupe_selectors(selectors: &[SelectionResult]) -> Vec<SelectionResult> {
+    selectors.iter().cloned().unique().collect()
+}
+
 pub fn delete_returning(
     model: &Model,
     filter: Filter,
@@ -282,21 +417,39 @@ pub fn delete_returning(
     Delete::from_table(model.as_table(ctx))
         .so_that(filter)
         .returning(projection_into_columns(selected_fields, ctx))
-        .add_traceparent(ctx.traceparent)
+        .add_traceparent(ctx.traceparent, ctx.trace_comment_mode())
         .into()
 }
 
+// MySQL and SQLite support `ORDER BY`/`LIMIT` directly on `DELETE`, so an ordered, row-limited
+// delete uses that instead of the `WHERE id IN (SELECT id ... ORDER BY ... LIMIT n)` subquery
+// Postgres and MSSQL need (see the comment on `update::update_many_from_filter`).
 pub fn delete_many_from_filter(
     model: &Model,
     filter_condition: ConditionTree<'static>,
+    order_by: &[OrderBy],
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> Query<'static> {
-    let filter_condition = wrap_with_limit_subquery_if_needed(model, filter_condition, limit, ctx);
+    if ctx.sql_family().is_mysql() || ctx.sql_family().is_sqlite() {
+        let mut delete = Delete::from_table(model.as_table(ctx)).so_that(filter_condition);
+
+        for order_definition in build_take_ordering(order_by, ctx) {
+            delete = delete.order_by(order_definition);
+        }
+
+        if let Some(limit) = limit {
+            delete = delete.limit(limit);
+        }
+
+        return delete.add_traceparent(ctx.traceparent, ctx.trace_comment_mode()).into();
+    }
+
+    let filter_condition = wrap_with_limit_subquery_if_needed(model, filter_condition, order_by, limit, ctx);
 
     Delete::from_table(model.as_table(ctx))
         .so_that(filter_condition)
-        .add_traceparent(ctx.traceparent)
+        .add_traceparent(ctx.traceparent, ctx.trace_comment_mode())
         .into()
 }
 
@@ -304,6 +457,7 @@ pub fn delete_many_from_ids_and_filter(
     model: &Model,
     ids: &[SelectionResult],
     filter_condition: ConditionTree<'static>,
+    order_by: &[OrderBy],
     limit: Option<usize>,
     ctx: &Context<'_>,
 ) -> Vec<Query<'static>> {
@@ -312,7 +466,7 @@ pub fn delete_many_from_ids_and_filter(
         .collect();
 
     super::chunked_conditions(&columns, ids, ctx, |conditions| {
-        delete_many_from_filter(model, conditions.and(filter_condition.clone()), limit, ctx)
+        delete_many_from_filter(model, conditions.and(filter_condition.clone()), order_by, limit, ctx)
     })
 }
 
@@ -354,6 +508,7 @@ pub fn delete_relation_table_records(
     let parent_id_values = parent_id.db_values(ctx);
     let parent_id_criteria = parent_column.equals(parent_id_values);
 
+    let child_ids = dedupe_selectors(child_ids);
     let child_ids_row = child_ids.iter().flat_map(|id| id.db_values(ctx)).collect::<Row>();
 
     let child_id_criteria = if !child_ids.is_empty()
@@ -369,30 +524,36 @@ pub fn delete_relation_table_records(
 
     Delete::from_table(relation.as_table(ctx))
         .so_that(parent_id_criteria.and(child_id_criteria))
-        .add_traceparent(ctx.traceparent)
+        .add_traceparent(ctx.traceparent, ctx.trace_comment_mode())
 }
 
-/// Generates a list of insert statements to execute. If `selected_fields` is set, insert statements
-/// will return the specified columns of inserted rows.
+/// Generates a list of insert statements to execute, paired with how many rows each one inserts.
+/// If `selected_fields` is set, insert statements will return the specified columns of inserted
+/// rows.
 pub fn generate_insert_statements(
     model: &Model,
     args: Vec<WriteArgs>,
     skip_duplicates: bool,
     selected_fields: Option<&ModelProjection>,
     ctx: &Context<'_>,
-) -> Vec<Insert<'static>> {
+) -> Vec<(usize, Insert<'static>)> {
     let affected_fields = collect_affected_fields(&args, model);
 
     if affected_fields.is_empty() {
         args.into_iter()
-            .map(|_| create_records_empty(model, skip_duplicates, selected_fields, ctx))
+            .map(|_| (1, create_records_empty(model, skip_duplicates, selected_fields, ctx)))
             .collect()
     } else {
         let partitioned_batches = partition_into_batches(args, ctx);
 
         partitioned_batches
             .into_iter()
-            .map(|batch| create_records_nonempty(model, batch, skip_duplicates, &affected_fields, selected_fields, ctx))
+            .map(|batch| {
+                let row_count = batch.len();
+                let insert = create_records_nonempty(model, batch, skip_duplicates, &affected_fields, selected_fields, ctx);
+
+                (row_count, insert)
+            })
             .collect()
     }
 }
@@ -542,3 +703,179 @@ pub fn defaults_for_mysql_write_args<'a>(
         }
     })
 }
+
+#[cfg(test)]
+mod json_update_tests {
+    use super::*;
+    use quaint::{
+        prelude::{ConnectionInfo, ExternalConnectionInfo, SqlFamily},
+        visitor::{Mssql, Mysql, Postgres, Sqlite, Visitor},
+    };
+    use std::sync::Arc;
+
+    fn test_model(provider: &str) -> Model {
+        let schema_str = format!(
+            r#"
+            datasource db {{
+                provider = "{provider}"
+                url      = "{provider}://stub"
+            }}
+
+            model TestModel {{
+                id   Int  @id
+                data Json
+            }}
+        "#
+        );
+
+        let psl_schema = psl::validate(schema_str.into());
+        assert!(!psl_schema.diagnostics.has_errors(), "{:?}", psl_schema.diagnostics);
+
+        let internal_data_model = InternalDataModel {
+            schema: Arc::new(psl_schema),
+        };
+
+        internal_data_model.find_model("TestModel").unwrap()
+    }
+
+    fn json_set_update(model: &Model, ctx: &Context<'_>) -> Update<'static> {
+        let mut args = WriteArgs::new_empty(PrismaValue::Null);
+        // The quote in `settings"theme` must survive as-is in the bound path parameter - it's
+        // inside a bind value, not SQL text, so it needs no escaping to stay safe.
+        args.insert(
+            DatasourceFieldName("data".to_owned()),
+            WriteOperation::scalar_json_set(
+                vec!["settings\"theme".to_owned(), "dark.mode".to_owned()],
+                PrismaValue::String("on".to_owned()),
+            ),
+        );
+
+        build_update_and_set_query(model, args, None, ctx)
+    }
+
+    fn json_remove_update(model: &Model, ctx: &Context<'_>) -> Update<'static> {
+        let mut args = WriteArgs::new_empty(PrismaValue::Null);
+        args.insert(
+            DatasourceFieldName("data".to_owned()),
+            WriteOperation::scalar_json_remove(vec!["settings\"theme".to_owned(), "dark.mode".to_owned()]),
+        );
+
+        build_update_and_set_query(model, args, None, ctx)
+    }
+
+    #[test]
+    fn json_set_renders_jsonb_set_with_an_array_path_on_postgres() {
+        let model = test_model("postgresql");
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(
+            SqlFamily::Postgres,
+            Some("public".to_owned()),
+            None,
+            true,
+        ));
+        let ctx = Context::new(&connection_info, None);
+
+        let (sql, params) = Postgres::build(json_set_update(&model, &ctx)).unwrap();
+
+        assert!(sql.contains("JSONB_SET"), "expected JSONB_SET in: {sql}");
+        assert!(
+            params.iter().any(|p| p.to_string().contains("settings\"theme")),
+            "expected the quoted path segment to survive as a bound parameter, got: {params:?}"
+        );
+    }
+
+    #[test]
+    fn json_remove_renders_the_hash_minus_operator_with_an_array_path_on_postgres() {
+        let model = test_model("postgresql");
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(
+            SqlFamily::Postgres,
+            Some("public".to_owned()),
+            None,
+            true,
+        ));
+        let ctx = Context::new(&connection_info, None);
+
+        let (sql, _) = Postgres::build(json_remove_update(&model, &ctx)).unwrap();
+
+        assert!(sql.contains("#-"), "expected the #- operator in: {sql}");
+    }
+
+    #[test]
+    fn json_set_renders_json_set_with_an_escaped_dot_path_on_mysql() {
+        let model = test_model("mysql");
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mysql, None, None, true));
+        let ctx = Context::new(&connection_info, None);
+
+        let (sql, params) = Mysql::build(json_set_update(&model, &ctx)).unwrap();
+
+        assert!(sql.contains("JSON_SET"), "expected JSON_SET in: {sql}");
+        assert!(
+            params
+                .iter()
+                .any(|p| p.to_string().contains(r#"$."settings\"theme"."dark.mode""#)),
+            "expected an escaped dot-notation path, got: {params:?}"
+        );
+    }
+
+    #[test]
+    fn json_remove_renders_json_remove_on_mysql() {
+        let model = test_model("mysql");
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mysql, None, None, true));
+        let ctx = Context::new(&connection_info, None);
+
+        let (sql, _) = Mysql::build(json_remove_update(&model, &ctx)).unwrap();
+
+        assert!(sql.contains("JSON_REMOVE"), "expected JSON_REMOVE in: {sql}");
+    }
+
+    #[test]
+    fn json_set_renders_json_set_with_an_escaped_dot_path_on_sqlite() {
+        let model = test_model("sqlite");
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Sqlite, None, None, true));
+        let ctx = Context::new(&connection_info, None);
+
+        let (sql, params) = Sqlite::build(json_set_update(&model, &ctx)).unwrap();
+
+        assert!(sql.contains("JSON_SET"), "expected JSON_SET in: {sql}");
+        assert!(
+            params
+                .iter()
+                .any(|p| p.to_string().contains(r#"$."settings\"theme"."dark.mode""#)),
+            "expected an escaped dot-notation path, got: {params:?}"
+        );
+    }
+
+    #[test]
+    fn json_set_renders_json_modify_with_an_escaped_dot_path_on_mssql() {
+        let model = test_model("sqlserver");
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mssql, None, None, true));
+        let ctx = Context::new(&connection_info, None);
+
+        let (sql, params) = Mssql::build(json_set_update(&model, &ctx)).unwrap();
+
+        assert!(sql.contains("JSON_MODIFY"), "expected JSON_MODIFY in: {sql}");
+        assert!(
+            params
+                .iter()
+                .any(|p| p.to_string().contains(r#"$."settings\"theme"."dark.mode""#)),
+            "expected an escaped dot-notation path, got: {params:?}"
+        );
+    }
+
+    #[test]
+    fn json_remove_renders_json_modify_with_null_on_mssql() {
+        let model = test_model("sqlserver");
+        let connection_info = ConnectionInfo::External(ExternalConnectionInfo::new(SqlFamily::Mssql, None, None, true));
+        let ctx = Context::new(&connection_info, None);
+
+        let (sql, _) = Mssql::build(json_remove_update(&model, &ctx)).unwrap();
+
+        assert!(sql.contains("JSON_MODIFY") && sql.contains("NULL"), "expected JSON_MODIFY(..., NULL) in: {sql}");
+    }
+
+    #[test]
+    fn json_dot_path_quotes_segments_with_dots_or_quotes() {
+        assert_eq!(json_dot_path(&["a".to_owned(), "b".to_owned()]), "$.a.b");
+        assert_eq!(json_dot_path(&["a.b".to_owned()]), r#"$."a.b""#);
+        assert_eq!(json_dot_path(&[r#"it's "quoted""#.to_owned()]), r#"$."it's \"quoted\"""#);
+    }
+}
@@ -16,7 +16,7 @@ use query_engine_common::{
 use request_handlers::{load_executor, render_graphql_schema, ConnectorKind, RequestBody, RequestHandler};
 use serde::Deserialize;
 use serde_json::json;
-use std::{collections::HashMap, future::Future, marker::PhantomData, panic::AssertUnwindSafe, sync::Arc};
+use std::{collections::HashMap, future::Future, panic::AssertUnwindSafe, sync::Arc};
 use tokio::sync::RwLock;
 use tracing_futures::{Instrument, WithSubscriber};
 use tracing_subscriber::filter::LevelFilter;
@@ -110,7 +110,7 @@ impl QueryEngine {
         } else {
             #[cfg(feature = "driver-adapters")]
             if let Some(adapter) = maybe_adapter {
-                let js_queryable = driver_adapters::queryable_from_js(adapter);
+                let js_queryable = driver_adapters::queryable_from_js(adapter)?;
 
                 connector_mode = ConnectorMode::Js {
                     adapter: Arc::new(js_queryable),
@@ -210,7 +210,7 @@ impl QueryEngine {
                         }
                         ConnectorMode::Js { ref adapter } => ConnectorKind::Js {
                             adapter: Arc::clone(adapter),
-                            _phantom: PhantomData,
+                            datasource: data_source,
                         },
                     };
                     let executor = load_executor(connector_kind, preview_features, builder.enable_tracing).await?;
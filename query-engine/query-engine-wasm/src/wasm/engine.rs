@@ -22,7 +22,7 @@ use query_engine_common::{
 use request_handlers::ConnectorKind;
 use request_handlers::{load_executor, RequestBody, RequestHandler};
 use serde_json::json;
-use std::{marker::PhantomData, sync::Arc};
+use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{instrument::WithSubscriber, Instrument, Level};
 use tracing_subscriber::filter::LevelFilter;
@@ -70,7 +70,7 @@ impl QueryEngine {
         // Note: if we used `psl::validate`, we'd add ~1MB to the Wasm artifact (before gzip).
         let schema = psl::parse_without_validation(datamodel.into(), CONNECTOR_REGISTRY);
 
-        let js_queryable = Arc::new(driver_adapters::queryable_from_js(adapter));
+        let js_queryable = Arc::new(driver_adapters::queryable_from_js(adapter)?);
 
         let engine_protocol = EngineProtocol::Json;
 
@@ -109,12 +109,20 @@ impl QueryEngine {
 
             let preview_features = builder.schema.configuration.preview_features();
             let arced_schema = Arc::clone(&builder.schema);
+            let arced_schema_2 = Arc::clone(&builder.schema);
 
             let engine = async move {
+                // We only support one data source & generator at the moment, so take the first one (default not exposed yet).
+                let data_source = arced_schema
+                    .configuration
+                    .datasources
+                    .first()
+                    .ok_or_else(|| ApiError::configuration("No valid data source found"))?;
+
                 let executor = load_executor(
                     ConnectorKind::Js {
                         adapter: Arc::clone(&self.adapter),
-                        _phantom: PhantomData,
+                        datasource: data_source,
                     },
                     preview_features,
                     builder.enable_tracing,
@@ -134,7 +142,7 @@ impl QueryEngine {
                 let query_schema_span = tracing::info_span!("prisma:engine:schema");
 
                 let query_schema = query_schema_span
-                    .in_scope(|| schema::build(arced_schema, true))
+                    .in_scope(|| schema::build(arced_schema_2, true))
                     .with_db_version_supports_join_strategy(
                         relation_load_strategy::db_version_supports_joins_strategy(db_version)?,
                     );
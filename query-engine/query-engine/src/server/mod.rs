@@ -434,11 +434,20 @@ fn err_to_http_resp(err: query_core::CoreError, captured_telemetry: Option<Trace
         _ => StatusCode::INTERNAL_SERVER_ERROR,
     };
 
+    let compensation_log = err.compensation_log().cloned();
+    let chunked_write_progress = err.chunked_write_progress();
+
     let mut err: ExtendedUserFacingError = err.into();
     if let Some(telemetry) = captured_telemetry {
         err.set_extension("traces".to_owned(), json!(telemetry.spans));
         err.set_extension("logs".to_owned(), json!(telemetry.events));
     }
+    if let Some(log) = compensation_log {
+        err.set_extension("compensation".to_owned(), json!(log));
+    }
+    if let Some(affected) = chunked_write_progress {
+        err.set_extension("chunkedWriteProgress".to_owned(), json!({ "affected": affected }));
+    }
 
     build_json_response(status, &err)
 }
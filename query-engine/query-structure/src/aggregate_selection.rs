@@ -40,9 +40,18 @@ impl AggregationSelection {
             AggregationSelection::Field(field) => {
                 Either::Left(Self::map_field_types(slice::from_ref(field), |t| t, |a| a))
             }
-            AggregationSelection::Sum(fields) => {
-                Either::Left(Self::map_field_types(fields, |t| t, |_| FieldArity::Required))
-            }
+            AggregationSelection::Sum(fields) => Either::Left(Self::map_field_types(
+                fields,
+                |t| match t {
+                    // A sum can overflow the summed field's own range, so it's returned one size
+                    // up: `Int` (32-bit) promotes to `BigInt` (64-bit), and `BigInt` promotes to
+                    // `Decimal` (arbitrary precision) since there's no wider fixed-size integer.
+                    TypeIdentifier::Int => TypeIdentifier::BigInt,
+                    TypeIdentifier::BigInt => TypeIdentifier::Decimal,
+                    t => t,
+                },
+                |_| FieldArity::Required,
+            )),
             AggregationSelection::Min(fields) => {
                 Either::Left(Self::map_field_types(fields, |t| t, |_| FieldArity::Required))
             }
@@ -50,14 +59,12 @@ impl AggregationSelection {
                 Either::Left(Self::map_field_types(fields, |t| t, |_| FieldArity::Required))
             }
 
-            AggregationSelection::Average(fields) => Either::Left(Self::map_field_types(
-                fields,
-                |t| match t {
-                    TypeIdentifier::Decimal => TypeIdentifier::Decimal,
-                    _ => TypeIdentifier::Float,
-                },
-                |_| FieldArity::Required,
-            )),
+            // An average is always returned as a `Decimal`, regardless of the averaged field's own
+            // type, since averaging necessarily introduces fractional precision that `Float`
+            // can lose for large values and that an integral field type can't represent at all.
+            AggregationSelection::Average(fields) => {
+                Either::Left(Self::map_field_types(fields, |_| TypeIdentifier::Decimal, |_| FieldArity::Required))
+            }
 
             AggregationSelection::Count { all, fields } => Either::Right(
                 Self::map_field_types(fields, |_| TypeIdentifier::Int, |_| FieldArity::Required).chain(all.iter().map(
@@ -133,3 +140,92 @@ pub struct SelectionIdentifier<'a> {
     pub typ: Type,
     pub arity: FieldArity,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    struct TestFields {
+        int_field: ScalarFieldRef,
+        big_int_field: ScalarFieldRef,
+        decimal_field: ScalarFieldRef,
+        float_field: ScalarFieldRef,
+    }
+
+    impl TestFields {
+        fn new() -> Self {
+            let schema_str = r#"
+                datasource db {
+                    provider = "postgresql"
+                    url = "postgres://stub"
+                }
+
+                model Test {
+                    id           Int     @id
+                    int_field    Int
+                    big_int_field BigInt
+                    decimal_field Decimal
+                    float_field   Float
+                }
+            "#;
+
+            let psl_schema = psl::validate(schema_str.into());
+            let internal_datamodel = crate::InternalDataModel {
+                schema: Arc::new(psl_schema),
+            };
+
+            let model = internal_datamodel.find_model("Test").unwrap();
+            let fields = model.fields();
+
+            TestFields {
+                int_field: fields.find_from_scalar("int_field").unwrap(),
+                big_int_field: fields.find_from_scalar("big_int_field").unwrap(),
+                decimal_field: fields.find_from_scalar("decimal_field").unwrap(),
+                float_field: fields.find_from_scalar("float_field").unwrap(),
+            }
+        }
+    }
+
+    fn identifier_types(selection: &AggregationSelection) -> Vec<TypeIdentifier> {
+        selection.identifiers().map(|ident| ident.typ.id).collect()
+    }
+
+    #[test]
+    fn sum_promotes_int_to_big_int_and_big_int_to_decimal() {
+        let fields = TestFields::new();
+
+        let selection = AggregationSelection::Sum(vec![fields.int_field, fields.big_int_field]);
+        assert_eq!(identifier_types(&selection), vec![TypeIdentifier::BigInt, TypeIdentifier::Decimal]);
+    }
+
+    #[test]
+    fn sum_keeps_decimal_and_float_as_is() {
+        let fields = TestFields::new();
+
+        let selection = AggregationSelection::Sum(vec![fields.decimal_field, fields.float_field]);
+        assert_eq!(identifier_types(&selection), vec![TypeIdentifier::Decimal, TypeIdentifier::Float]);
+    }
+
+    #[test]
+    fn average_always_maps_to_decimal() {
+        let fields = TestFields::new();
+
+        let selection = AggregationSelection::Average(vec![
+            fields.int_field,
+            fields.big_int_field,
+            fields.decimal_field,
+            fields.float_field,
+        ]);
+        assert_eq!(
+            identifier_types(&selection),
+            vec![
+                TypeIdentifier::Decimal,
+                TypeIdentifier::Decimal,
+                TypeIdentifier::Decimal,
+                TypeIdentifier::Decimal
+            ]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+/// How a logical `updateMany`/`deleteMany` that has to be split into more than one SQL statement
+/// (e.g. because the connector has a bind-parameter limit) should behave when one of those
+/// statements fails partway through the batch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum ChunkExecutionPolicy {
+    /// Refuse to run a write that would need to be split into more than one statement, since a
+    /// statement that already committed can't be rolled back once a later one fails. Writes that
+    /// only ever need a single statement are unaffected, since those are already atomic.
+    Atomic,
+
+    /// Run every statement regardless of earlier failures, and report which ones succeeded and
+    /// which failed instead of stopping at the first error.
+    BestEffort,
+
+    /// Stop at the first failing statement, same as always, but additionally report how many rows
+    /// the earlier, already-committed statements affected.
+    #[default]
+    FailFast,
+}
+
+impl ChunkExecutionPolicy {
+    pub fn is_atomic(&self) -> bool {
+        matches!(self, Self::Atomic)
+    }
+
+    pub fn is_best_effort(&self) -> bool {
+        matches!(self, Self::BestEffort)
+    }
+
+    pub fn is_fail_fast(&self) -> bool {
+        matches!(self, Self::FailFast)
+    }
+}
+
+impl TryFrom<&str> for ChunkExecutionPolicy {
+    type Error = crate::error::DomainError;
+
+    fn try_from(value: &str) -> crate::Result<Self> {
+        match value {
+            "atomic" => Ok(Self::Atomic),
+            "best_effort" => Ok(Self::BestEffort),
+            "fail_fast" => Ok(Self::FailFast),
+            _ => Err(crate::error::DomainError::ConversionFailure(
+                value.to_owned(),
+                "ChunkExecutionPolicy".to_owned(),
+            )),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use prisma_value::{PrismaValue, PrismaValueType};
 use std::fmt;
 
+#[cfg(feature = "default_generators")]
+use std::cell::RefCell;
+
 /// Represents a default specified on a field.
 #[derive(Clone, PartialEq, Debug)]
 pub struct DefaultValue {
@@ -15,6 +18,9 @@ pub enum DefaultKind {
     Single(PrismaValue),
     /// a dynamic value, e.g. `@default(uuid())`
     Expression(ValueGenerator),
+    /// a composite type default, e.g. `@default({ street: "a", number: autoincrement() })` on an
+    /// embedded type field, with each field's own default kept unevaluated until `get`/`get_evaluated`.
+    Composite(Vec<(String, DefaultValue)>),
 }
 
 impl DefaultKind {
@@ -79,6 +85,12 @@ impl DefaultKind {
                 args: g.args.clone(),
                 return_type: g.return_type().unwrap_or(PrismaValueType::Any),
             }),
+            DefaultKind::Composite(fields) => Some(PrismaValue::Object(
+                fields
+                    .iter()
+                    .filter_map(|(name, default)| default.kind.get().map(|value| (name.clone(), value)))
+                    .collect(),
+            )),
         }
     }
 
@@ -89,6 +101,12 @@ impl DefaultKind {
         match self {
             DefaultKind::Single(ref v) => Some(v.clone()),
             DefaultKind::Expression(g) => g.generate(),
+            DefaultKind::Composite(fields) => Some(PrismaValue::Object(
+                fields
+                    .iter()
+                    .filter_map(|(name, default)| default.kind.get_evaluated().map(|value| (name.clone(), value)))
+                    .collect(),
+            )),
         }
     }
 }
@@ -155,6 +173,12 @@ impl DefaultValue {
         Self { kind, db_name: None }
     }
 
+    pub fn new_composite(fields: Vec<(String, DefaultValue)>) -> Self {
+        let kind = DefaultKind::Composite(fields);
+
+        Self { kind, db_name: None }
+    }
+
     pub fn set_db_name(&mut self, name: impl ToString) {
         self.db_name = Some(name.to_string());
     }
@@ -183,6 +207,17 @@ impl ValueGenerator {
         ValueGenerator::new("autoincrement".to_owned(), vec![]).unwrap()
     }
 
+    /// `@default(autoincrement(start: .., increment: ..))`. Either bound may be absent, in which
+    /// case the connector's own default (usually `1`/`1`) applies.
+    pub fn new_autoincrement_with_sequence_options(start: Option<i64>, increment: Option<i64>) -> Self {
+        let args = vec![
+            start.map(PrismaValue::Int).unwrap_or(PrismaValue::Null),
+            increment.map(PrismaValue::Int).unwrap_or(PrismaValue::Null),
+        ];
+
+        ValueGenerator::new("autoincrement".to_owned(), args).unwrap()
+    }
+
     pub fn new_sequence(args: Vec<PrismaValue>) -> Self {
         ValueGenerator::new("sequence".to_owned(), args).unwrap()
     }
@@ -209,6 +244,12 @@ impl ValueGenerator {
         ValueGenerator::new("ulid".to_owned(), vec![]).unwrap()
     }
 
+    /// `@default(ulid(monotonic: true))`. Values generated within the same process are guaranteed
+    /// to sort strictly after the previous one, even when created within the same millisecond.
+    pub fn new_ulid_monotonic() -> Self {
+        ValueGenerator::new("ulid".to_owned(), vec![PrismaValue::Boolean(true)]).unwrap()
+    }
+
     pub fn new_cuid(version: u8) -> Self {
         ValueGenerator::new("cuid".to_owned(), vec![PrismaValue::Int(version as i64)]).unwrap()
     }
@@ -217,14 +258,16 @@ impl ValueGenerator {
         ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(version as i64)]).unwrap()
     }
 
-    pub fn new_nanoid(length: Option<u8>) -> Self {
+    pub fn new_nanoid(length: Option<u8>, alphabet: Option<String>) -> Self {
         let name = "nanoid".to_owned();
 
-        if let Some(length) = length {
-            ValueGenerator::new(name, vec![PrismaValue::Int(length.into())]).unwrap()
-        } else {
-            ValueGenerator::new(name, vec![]).unwrap()
-        }
+        let args = match (length, alphabet) {
+            (Some(length), Some(alphabet)) => vec![PrismaValue::Int(length.into()), PrismaValue::String(alphabet)],
+            (Some(length), None) => vec![PrismaValue::Int(length.into())],
+            (None, _) => vec![],
+        };
+
+        ValueGenerator::new(name, args).unwrap()
     }
 
     pub fn name(&self) -> &str {
@@ -236,7 +279,7 @@ impl ValueGenerator {
     }
 
     pub fn generator(&self) -> ValueGeneratorFn {
-        self.generator
+        self.generator.clone()
     }
 
     pub fn as_dbgenerated(&self) -> Option<&str> {
@@ -260,27 +303,87 @@ impl ValueGenerator {
         self.name == "autoincrement" || self.name == "sequence"
     }
 
+    /// Is this `@default(uuid(7))`?
+    pub fn is_uuid_v7(&self) -> bool {
+        self.name == "uuid" && matches!(self.generator, ValueGeneratorFn::Uuid(7))
+    }
+
+    /// Is this `@default(ulid(monotonic: true))`?
+    pub fn is_ulid_monotonic(&self) -> bool {
+        self.name == "ulid" && matches!(self.args.first(), Some(PrismaValue::Boolean(true)))
+    }
+
+    /// The `start` value of `@default(autoincrement(start: .., increment: ..))`, if one was given.
+    pub fn autoincrement_start(&self) -> Option<i64> {
+        match self.args.first() {
+            Some(PrismaValue::Int(start)) => Some(*start),
+            _ => None,
+        }
+    }
+
+    /// The `increment` value of `@default(autoincrement(start: .., increment: ..))`, if one was given.
+    pub fn autoincrement_increment(&self) -> Option<i64> {
+        match self.args.get(1) {
+            Some(PrismaValue::Int(increment)) => Some(*increment),
+            _ => None,
+        }
+    }
+
     pub fn return_type(&self) -> Option<PrismaValueType> {
         self.generator.return_type()
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub enum ValueGeneratorFn {
     Uuid(u8),
     Cuid(u8),
-    Ulid,
-    Nanoid(Option<u8>),
+    Ulid(bool),
+    Nanoid(Option<u8>, Option<String>),
     Now,
     Autoincrement,
     DbGenerated,
     Auto,
 }
 
+/// Provides the current time to `@default(now())`. Exists so that tests can inject a fixed instant
+/// instead of depending on the system clock, which would otherwise make their expectations
+/// nondeterministic.
+#[cfg(feature = "default_generators")]
+pub trait Clock: Send + Sync {
+    fn now(&self) -> chrono::DateTime<chrono::FixedOffset>;
+}
+
+#[cfg(feature = "default_generators")]
+struct SystemClock;
+
+#[cfg(feature = "default_generators")]
+impl Clock for SystemClock {
+    fn now(&self) -> chrono::DateTime<chrono::FixedOffset> {
+        chrono::Utc::now().into()
+    }
+}
+
+#[cfg(feature = "default_generators")]
+thread_local! {
+    static CLOCK: RefCell<Box<dyn Clock>> = RefCell::new(Box::new(SystemClock));
+}
+
+/// Overrides the clock consulted by `@default(now())` on the current thread. Intended for tests
+/// that need `generate_now`'s output to be deterministic.
+#[cfg(feature = "default_generators")]
+pub fn set_clock(clock: Box<dyn Clock>) {
+    CLOCK.with(|cell| *cell.borrow_mut() = clock);
+}
+
 impl ValueGeneratorFn {
     fn new(name: &str, args: &[PrismaValue]) -> std::result::Result<Self, String> {
         match name {
-            "ulid" => Ok(Self::Ulid),
+            "ulid" => match args[..] {
+                [] => Ok(Self::Ulid(false)),
+                [PrismaValue::Boolean(monotonic)] => Ok(Self::Ulid(monotonic)),
+                _ => unreachable!(),
+            },
             "cuid" => match args[..] {
                 [PrismaValue::Int(version)] => Ok(Self::Cuid(version as u8)),
                 _ => unreachable!(),
@@ -290,8 +393,15 @@ impl ValueGeneratorFn {
                 _ => unreachable!(),
             },
             "nanoid" => match args[..] {
-                [PrismaValue::Int(length)] => Ok(Self::Nanoid(Some(length as u8))),
-                _ => Ok(Self::Nanoid(None)),
+                [PrismaValue::Int(length), PrismaValue::String(ref alphabet)] => {
+                    if alphabet.is_empty() {
+                        return Err("`nanoid()`'s alphabet argument must not be an empty string.".to_owned());
+                    }
+
+                    Ok(Self::Nanoid(Some(length as u8), Some(alphabet.clone())))
+                }
+                [PrismaValue::Int(length)] => Ok(Self::Nanoid(Some(length as u8), None)),
+                _ => Ok(Self::Nanoid(None, None)),
             },
             "now" => Ok(Self::Now),
             "autoincrement" => Ok(Self::Autoincrement),
@@ -307,16 +417,29 @@ impl ValueGeneratorFn {
         match self {
             Self::Uuid(version) => Some(Self::generate_uuid(*version)),
             Self::Cuid(version) => Some(Self::generate_cuid(*version)),
-            Self::Ulid => Some(Self::generate_ulid()),
-            Self::Nanoid(length) => Some(Self::generate_nanoid(length)),
+            Self::Ulid(monotonic) => Some(Self::generate_ulid(*monotonic)),
+            Self::Nanoid(length, alphabet) => Some(Self::generate_nanoid(length, alphabet)),
             Self::Now => Some(Self::generate_now()),
             Self::Autoincrement | Self::DbGenerated | Self::Auto => None,
         }
     }
 
     #[cfg(feature = "default_generators")]
-    fn generate_ulid() -> PrismaValue {
-        PrismaValue::String(ulid::Ulid::new().to_string())
+    fn generate_ulid(monotonic: bool) -> PrismaValue {
+        if monotonic {
+            static GENERATOR: std::sync::LazyLock<std::sync::Mutex<ulid::Generator>> =
+                std::sync::LazyLock::new(|| std::sync::Mutex::new(ulid::Generator::new()));
+
+            let ulid = GENERATOR
+                .lock()
+                .unwrap()
+                .generate()
+                .expect("system clock went backwards far enough to exhaust the monotonic ULID generator");
+
+            PrismaValue::String(ulid.to_string())
+        } else {
+            PrismaValue::String(ulid::Ulid::new().to_string())
+        }
     }
 
     #[cfg(feature = "default_generators")]
@@ -338,26 +461,30 @@ impl ValueGeneratorFn {
     }
 
     #[cfg(feature = "default_generators")]
-    fn generate_nanoid(length: &Option<u8>) -> PrismaValue {
-        if length.is_some() {
-            let value: usize = usize::from(length.unwrap());
-            PrismaValue::String(nanoid::nanoid!(value))
-        } else {
-            PrismaValue::String(nanoid::nanoid!())
+    fn generate_nanoid(length: &Option<u8>, alphabet: &Option<String>) -> PrismaValue {
+        match (length, alphabet) {
+            (length, Some(alphabet)) => {
+                let size = length.map(usize::from).unwrap_or(21);
+                let alphabet: Vec<char> = alphabet.chars().collect();
+
+                PrismaValue::String(nanoid::format(nanoid::rngs::default, &alphabet, size))
+            }
+            (Some(length), None) => PrismaValue::String(nanoid::nanoid!(usize::from(*length))),
+            (None, None) => PrismaValue::String(nanoid::nanoid!()),
         }
     }
 
     #[cfg(feature = "default_generators")]
     fn generate_now() -> PrismaValue {
-        PrismaValue::DateTime(chrono::Utc::now().into())
+        PrismaValue::DateTime(CLOCK.with(|clock| clock.borrow().now()))
     }
 
     pub fn return_type(&self) -> Option<PrismaValueType> {
         match self {
             ValueGeneratorFn::Uuid(_)
             | ValueGeneratorFn::Cuid(_)
-            | ValueGeneratorFn::Ulid
-            | ValueGeneratorFn::Nanoid(_) => Some(PrismaValueType::String),
+            | ValueGeneratorFn::Ulid(_)
+            | ValueGeneratorFn::Nanoid(_, _) => Some(PrismaValueType::String),
             ValueGeneratorFn::Now => Some(PrismaValueType::Date),
             _ => None,
         }
@@ -375,6 +502,7 @@ impl fmt::Debug for DefaultKind {
         match &self {
             DefaultKind::Single(ref v) => write!(f, "DefaultValue::Single({v:?})"),
             DefaultKind::Expression(g) => write!(f, "DefaultValue::Expression({}(){:?})", g.name(), g.args),
+            DefaultKind::Composite(fields) => write!(f, "DefaultValue::Composite({fields:?})"),
         }
     }
 }
@@ -404,12 +532,15 @@ mod tests {
 
         assert!(uuid_default.is_uuid());
         assert!(!uuid_default.is_autoincrement());
+        assert!(!uuid_default.as_expression().unwrap().is_uuid_v7());
     }
 
     #[test]
     fn default_value_is_uuidv7() {
         let uuid_default = DefaultValue::new_expression(ValueGenerator::new_uuid(7));
 
+        assert!(uuid_default.as_expression().unwrap().is_uuid_v7());
+
         assert!(uuid_default.is_uuid());
         assert!(!uuid_default.is_autoincrement());
     }
@@ -438,14 +569,53 @@ mod tests {
         assert!(!ulid_default.is_now());
     }
 
+    #[test]
+    fn default_value_is_ulid_monotonic() {
+        let ulid_default = DefaultValue::new_expression(ValueGenerator::new_ulid_monotonic());
+
+        assert!(ulid_default.is_ulid());
+        assert!(ulid_default.as_expression().unwrap().is_ulid_monotonic());
+    }
+
+    #[cfg(feature = "default_generators")]
+    #[test]
+    fn monotonic_ulid_strictly_increases() {
+        use prisma_value::PrismaValue;
+
+        let ulid_default = DefaultValue::new_expression(ValueGenerator::new_ulid_monotonic());
+
+        let values: Vec<String> = (0..1000)
+            .map(|_| match ulid_default.kind.get_evaluated().unwrap() {
+                PrismaValue::String(s) => s,
+                other => panic!("expected a String, got {other:?}"),
+            })
+            .collect();
+
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
     #[test]
     fn default_value_is_nanoid() {
-        let nanoid_default = DefaultValue::new_expression(ValueGenerator::new_nanoid(None));
+        let nanoid_default = DefaultValue::new_expression(ValueGenerator::new_nanoid(None, None));
 
         assert!(nanoid_default.is_nanoid());
         assert!(!nanoid_default.is_cuid());
     }
 
+    #[test]
+    fn default_value_is_nanoid_with_custom_alphabet() {
+        use prisma_value::PrismaValue;
+
+        let nanoid_default =
+            DefaultValue::new_expression(ValueGenerator::new_nanoid(Some(21), Some("0123456789abcdef".to_owned())));
+
+        assert!(nanoid_default.is_nanoid());
+        assert_eq!(
+            nanoid_default.as_expression().unwrap().args(),
+            &[PrismaValue::Int(21), PrismaValue::String("0123456789abcdef".to_owned())]
+        );
+    }
+
     #[test]
     fn default_value_is_dbgenerated() {
         let db_generated_default = DefaultValue::new_expression(ValueGenerator::new_dbgenerated("test".to_string()));
@@ -454,4 +624,73 @@ mod tests {
         assert!(!db_generated_default.is_now());
         assert!(!db_generated_default.is_autoincrement());
     }
+
+    #[test]
+    fn composite_default_evaluates_to_a_structured_object() {
+        use bigdecimal::BigDecimal;
+        use prisma_value::PrismaValue;
+
+        let address_default = DefaultValue::new_composite(vec![
+            ("street".to_owned(), DefaultValue::new_single(PrismaValue::String("Evergreen Terrace".to_owned()))),
+            (
+                "geo".to_owned(),
+                DefaultValue::new_composite(vec![(
+                    "lat".to_owned(),
+                    DefaultValue::new_single(PrismaValue::Float(BigDecimal::from(0))),
+                )]),
+            ),
+        ]);
+
+        let expected = PrismaValue::Object(vec![
+            ("street".to_owned(), PrismaValue::String("Evergreen Terrace".to_owned())),
+            (
+                "geo".to_owned(),
+                PrismaValue::Object(vec![("lat".to_owned(), PrismaValue::Float(BigDecimal::from(0)))]),
+            ),
+        ]);
+
+        assert_eq!(address_default.kind.get(), Some(expected));
+    }
+
+    #[cfg(feature = "default_generators")]
+    #[test]
+    fn composite_default_get_evaluated_recurses_into_nested_fields() {
+        use prisma_value::PrismaValue;
+
+        let default = DefaultValue::new_composite(vec![
+            ("id".to_owned(), DefaultValue::new_expression(ValueGenerator::new_uuid(4))),
+            ("name".to_owned(), DefaultValue::new_single(PrismaValue::String("a".to_owned()))),
+        ]);
+
+        let PrismaValue::Object(fields) = default.kind.get_evaluated().unwrap() else {
+            panic!("expected a PrismaValue::Object");
+        };
+
+        assert_eq!(fields.len(), 2);
+        assert!(matches!(fields[0], (ref name, PrismaValue::Uuid(_)) if name == "id"));
+        assert_eq!(fields[1], ("name".to_owned(), PrismaValue::String("a".to_owned())));
+    }
+
+    #[cfg(feature = "default_generators")]
+    #[test]
+    fn generate_now_honors_the_injected_clock() {
+        use super::{set_clock, Clock};
+        use chrono::DateTime;
+        use prisma_value::PrismaValue;
+
+        struct FixedClock(DateTime<chrono::FixedOffset>);
+
+        impl Clock for FixedClock {
+            fn now(&self) -> DateTime<chrono::FixedOffset> {
+                self.0
+            }
+        }
+
+        let fixed_instant = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z").unwrap();
+        set_clock(Box::new(FixedClock(fixed_instant)));
+
+        let now_default = DefaultValue::new_expression(ValueGenerator::new_now());
+
+        assert_eq!(now_default.kind.get_evaluated(), Some(PrismaValue::DateTime(fixed_instant)));
+    }
 }
@@ -316,6 +316,8 @@ impl FieldTypeInformation {
         };
         if self.arity.is_list() {
             PrismaValueType::Array(Box::new(type_))
+        } else if self.arity.is_optional() {
+            PrismaValueType::Nullable(Box::new(type_))
         } else {
             type_
         }
@@ -105,6 +105,39 @@ impl RelationField {
             .collect()
     }
 
+    /// Whether `references` points to a unique criterion (the primary key or an `@unique`) on the
+    /// related model.
+    ///
+    /// This is normally always true: `references` is required to target a unique criterion
+    /// unless `relationMode = "prisma"` and the `relationsToNonUniqueColumns` preview feature
+    /// allow it to point at an arbitrary scalar field instead, in which case the relation is
+    /// read-only (no connect/disconnect) and behaves as a to-many on both sides.
+    pub fn references_unique_fields(&self) -> bool {
+        let mut referenced: Vec<_> = self.referenced_fields().iter().map(|f| f.name().to_owned()).collect();
+
+        if referenced.is_empty() {
+            return true;
+        }
+
+        referenced.sort_unstable();
+
+        let related_model = self.related_model();
+
+        let mut primary_key: Vec<_> = related_model.primary_identifier().prisma_names().collect();
+        primary_key.sort_unstable();
+
+        if primary_key == referenced {
+            return true;
+        }
+
+        related_model.unique_indexes().any(|idx| {
+            let mut index_fields: Vec<_> = idx.fields().map(|f| f.name().to_owned()).collect();
+            index_fields.sort_unstable();
+
+            index_fields == referenced
+        })
+    }
+
     // Scalar fields on the left (source) side of the relation if starting traversal from `self`.
     // Todo This is provisionary.
     pub fn left_scalars(&self) -> Vec<ScalarFieldRef> {
@@ -56,10 +56,32 @@ impl ScalarField {
             ScalarFieldId::InCompositeType(_) => return false,
         };
         let sf = self.dm.walk(sfid);
+
+        if sf.is_computed() {
+            return true;
+        }
+
         let mut relation_fields = sf.model().relation_fields();
         relation_fields.any(|rf| rf.fields().into_iter().flatten().any(|sf2| sf.id == sf2.id))
     }
 
+    /// The raw `@computedSql("...")` expression declared on this field, if any, with `{{self}}`
+    /// left unsubstituted.
+    pub fn computed_sql(&self) -> Option<&str> {
+        match self.id {
+            ScalarFieldId::InModel(id) => self.dm.walk(id).computed_sql(),
+            ScalarFieldId::InCompositeType(_) => None,
+        }
+    }
+
+    /// Whether this is a database-computed, read-only `@computedSql` field.
+    pub fn is_computed(&self) -> bool {
+        match self.id {
+            ScalarFieldId::InModel(id) => self.dm.walk(id).is_computed(),
+            ScalarFieldId::InCompositeType(_) => false,
+        }
+    }
+
     pub fn is_numeric(&self) -> bool {
         self.type_identifier().is_numeric()
     }
@@ -219,6 +241,64 @@ impl ScalarField {
             ScalarFieldId::InCompositeType(_) => false,
         }
     }
+
+    /// The operator class of a single-column `@@index` covering this field, if one is declared
+    /// with a `Gin`, `Gist` or `SpGist` algorithm (the Postgres index kinds that support operator
+    /// classes). Query builders can use this to pick SQL that matches the indexed expression
+    /// exactly instead of one the planner can't use the index for, e.g. a GIN index declared with
+    /// `ops: raw("tsvector_ops")` only helps a query that also goes through `to_tsvector`.
+    pub fn index_operator_class(&self) -> Option<IndexOperatorClass> {
+        let id = match self.id {
+            ScalarFieldId::InModel(id) => id,
+            ScalarFieldId::InCompositeType(_) => return None,
+        };
+        let field = self.dm.walk(id);
+
+        field.model().indexes().find_map(|idx| {
+            use psl::parser_database::IndexAlgorithm;
+
+            if !matches!(idx.algorithm(), Some(IndexAlgorithm::Gin | IndexAlgorithm::Gist | IndexAlgorithm::SpGist)) {
+                return None;
+            }
+
+            let mut attrs = idx.scalar_field_attributes();
+            let attr = attrs.next()?;
+            if attrs.next().is_some() {
+                return None; // Only single-column indexes carry an unambiguous operator class for this field.
+            }
+            if attr.as_index_field().as_scalar_field()?.field_id() != field.field_id() {
+                return None;
+            }
+
+            attr.operator_class().map(|class| match class.get() {
+                either::Either::Left(class) => IndexOperatorClass::Known(class),
+                either::Either::Right(raw) => IndexOperatorClass::Raw(raw.to_owned()),
+            })
+        })
+    }
+}
+
+/// The operator class declared on an indexed field, see [`ScalarField::index_operator_class`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexOperatorClass {
+    /// One of the operator classes Prisma knows about, e.g. `InetOps`.
+    Known(psl::parser_database::OperatorClass),
+    /// An operator class Prisma doesn't have a constant for, declared via `ops: raw("...")`.
+    /// `tsvector_ops`/`tsvector_ops(Config="...")` fall in this bucket, since `tsvector` isn't a
+    /// built-in Prisma scalar type.
+    Raw(String),
+}
+
+impl IndexOperatorClass {
+    /// Whether this operator class supports full-text search (`@@` against a `tsvector`).
+    pub fn is_full_text_search(&self) -> bool {
+        matches!(self, Self::Raw(raw) if raw.starts_with("tsvector_ops"))
+    }
+
+    /// Whether this operator class supports trigram similarity search (`%`/`<->` against `text`).
+    pub fn is_trigram(&self) -> bool {
+        matches!(self, Self::Raw(raw) if raw == "gin_trgm_ops" || raw == "gist_trgm_ops")
+    }
 }
 
 impl Display for ScalarField {
@@ -251,8 +331,23 @@ pub fn dml_default_kind(default_value: &ast::Expression, scalar_type: Option<Sca
         ast::Expression::Function(funcname, _, _) if funcname == "auto" => {
             DefaultKind::Expression(ValueGenerator::new_auto())
         }
-        ast::Expression::Function(funcname, _args, _) if funcname == "autoincrement" => {
-            DefaultKind::Expression(ValueGenerator::new_autoincrement())
+        ast::Expression::Function(funcname, args, _) if funcname == "autoincrement" => {
+            let named_int_arg = |name: &str| {
+                args.arguments
+                    .iter()
+                    .find(|arg| arg.name.as_ref().is_some_and(|arg_name| arg_name.name == name))
+                    .and_then(|arg| arg.value.as_numeric_value())
+                    .and_then(|(val, _)| val.parse::<i64>().ok())
+            };
+
+            let start = named_int_arg("start");
+            let increment = named_int_arg("increment");
+
+            if start.is_some() || increment.is_some() {
+                DefaultKind::Expression(ValueGenerator::new_autoincrement_with_sequence_options(start, increment))
+            } else {
+                DefaultKind::Expression(ValueGenerator::new_autoincrement())
+            }
         }
         ast::Expression::Function(funcname, _args, _) if funcname == "sequence" => {
             DefaultKind::Expression(ValueGenerator::new_sequence(Vec::new()))
@@ -276,16 +371,34 @@ pub fn dml_default_kind(default_value: &ast::Expression, scalar_type: Option<Sca
                 .unwrap_or(DEFAULT_CUID_VERSION);
             DefaultKind::Expression(ValueGenerator::new_cuid(version))
         }
-        ast::Expression::Function(funcname, _, _) if funcname == "ulid" => {
-            DefaultKind::Expression(ValueGenerator::new_ulid())
+        ast::Expression::Function(funcname, args, _) if funcname == "ulid" => {
+            let monotonic = args
+                .arguments
+                .iter()
+                .find(|arg| arg.name.as_ref().is_some_and(|arg_name| arg_name.name == "monotonic"))
+                .and_then(|arg| arg.value.as_constant_value())
+                .is_some_and(|(val, _)| val == "true");
+
+            if monotonic {
+                DefaultKind::Expression(ValueGenerator::new_ulid_monotonic())
+            } else {
+                DefaultKind::Expression(ValueGenerator::new_ulid())
+            }
         }
         ast::Expression::Function(funcname, args, _) if funcname == "nanoid" => {
-            DefaultKind::Expression(ValueGenerator::new_nanoid(
-                args.arguments
-                    .first()
-                    .and_then(|arg| arg.value.as_numeric_value())
-                    .map(|(val, _)| val.parse::<u8>().unwrap()),
-            ))
+            let length = args
+                .arguments
+                .first()
+                .and_then(|arg| arg.value.as_numeric_value())
+                .map(|(val, _)| val.parse::<u8>().unwrap());
+
+            let alphabet = args
+                .arguments
+                .get(1)
+                .and_then(|arg| arg.value.as_string_value())
+                .map(|(val, _)| val.to_owned());
+
+            DefaultKind::Expression(ValueGenerator::new_nanoid(length, alphabet))
         }
         ast::Expression::Function(funcname, _args, _) if funcname == "now" => {
             DefaultKind::Expression(ValueGenerator::new_now())
@@ -259,6 +259,12 @@ pub enum SelectedField {
     Virtual(VirtualSelection),
 }
 
+/// A relation selected as part of a query, including its own nested selections.
+///
+/// `selections` can itself contain further `SelectedField::Relation`s and
+/// `SelectedField::Virtual`s, so a `_count` (or any other virtual) is not limited to the first
+/// level of nesting: it's resolved independently at whatever depth it was selected, the same way
+/// scalar and relation selections are.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RelationSelection {
     pub field: RelationField,
@@ -73,6 +73,53 @@ pub trait ScalarCompare {
     where
         T: Into<ConditionValue>;
 
+    /// Field (an `ltree`) is an ancestor of, or equal to, the given path.
+    fn ancestor_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>;
+
+    fn not_ancestor_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>;
+
+    /// Field (an `ltree`) is a descendant of, or equal to, the given path.
+    fn descendant_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>;
+
+    fn not_descendant_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>;
+
+    /// Field (an `ltree`) matches the given `lquery` pattern.
+    fn matches_lquery<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>;
+
+    fn not_matches_lquery<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>;
+
+    /// Field (a spatial type) contains the given GeoJSON geometry.
+    fn geo_contains<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>;
+
+    fn not_geo_contains<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>;
+
+    /// Field (a spatial type) is within `distance_meters` metres of the given GeoJSON point.
+    fn within_distance<T, D>(&self, point: T, distance_meters: D) -> Filter
+    where
+        T: Into<ConditionValue>,
+        D: Into<ConditionValue>;
+
+    fn not_within_distance<T, D>(&self, point: T, distance_meters: D) -> Filter
+    where
+        T: Into<ConditionValue>,
+        D: Into<ConditionValue>;
+
     fn is_set(&self, val: bool) -> Filter;
 }
 
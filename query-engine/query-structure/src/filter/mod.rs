@@ -40,6 +40,19 @@ pub enum Filter {
     Empty,
 }
 
+/// The result of [`Filter::static_shape`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaticFilterShape {
+    /// The filter is known to exclude at least one row, regardless of what any placeholder inside
+    /// it resolves to.
+    Restricted,
+    /// The filter unconditionally matches every row, e.g. `{}`, or an `OR` containing `{}`.
+    Unconditional,
+    /// Whether the filter matches every row depends on a placeholder that hasn't been resolved
+    /// yet, e.g. `NOT IN ($list)` is unconditional only if `$list` resolves to an empty list.
+    DependsOnPlaceholder,
+}
+
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum AggregationFilter {
     Count(Box<Filter>),
@@ -78,6 +91,19 @@ impl Filter {
         Filter::Empty
     }
 
+    /// Composes `filters` into a single normalized `AND`, for callers building `Filter` trees
+    /// programmatically (e.g. embedders) that want [`Filter::normalize`]'s guarantees - flattened
+    /// nesting, no redundant empty/single-element wrapping - without remembering to call it
+    /// themselves.
+    pub fn and_all(filters: impl IntoIterator<Item = Filter>) -> Self {
+        Self::and(filters.into_iter().collect()).normalize()
+    }
+
+    /// Composes `filters` into a single normalized `OR`. See [`Filter::and_all`].
+    pub fn or_all(filters: impl IntoIterator<Item = Filter>) -> Self {
+        Self::or(filters.into_iter().collect()).normalize()
+    }
+
     /// Returns the size of the topmost filter elements (does not recursively compute the size).
     pub fn size(&self) -> usize {
         match self {
@@ -107,6 +133,18 @@ impl Filter {
         }
     }
 
+    /// True when every scalar filter that would otherwise require batching is a single-column
+    /// `IN`/`NOT IN` list. Connectors that can bind such a list as a single array parameter can
+    /// use this to skip chunking altogether, regardless of the list length.
+    pub fn is_single_column_list_only(&self) -> bool {
+        match self {
+            Self::Scalar(sf) => sf.is_single_column_list(),
+            Self::And(filters) => filters.iter().all(|f| f.is_single_column_list_only()),
+            Self::Or(filters) => filters.iter().all(|f| f.is_single_column_list_only()),
+            _ => true,
+        }
+    }
+
     pub fn batched(self, chunk_size: usize) -> Vec<Filter> {
         fn split_longest(mut filters: Vec<Filter>, chunk_size: usize) -> (Option<ScalarFilter>, Vec<Filter>) {
             let mut longest: Option<ScalarFilter> = None;
@@ -162,6 +200,105 @@ impl Filter {
         }
     }
 
+    /// Puts the filter into a canonical form so that semantically equivalent filters (redundant
+    /// single-element `AND`/`OR`/`NOT` wrappers, differently-ordered commutative conditions,
+    /// double negation) compile to identical SQL, which improves prepared statement / plan cache
+    /// reuse. This must never change which rows a filter matches.
+    ///
+    /// Relation, composite, aggregation and one-relation-is-null filters keep their original
+    /// relative order: SQL builders assign join aliases by filter position, so reordering them
+    /// could change alias assignment even though the matched rows stay the same.
+    pub fn normalize(self) -> Filter {
+        match self {
+            Self::And(filters) => Self::normalize_and_or(filters, true),
+            Self::Or(filters) => Self::normalize_and_or(filters, false),
+            Self::Not(filters) => Self::normalize_not(filters),
+            Self::Relation(mut rf) => {
+                rf.nested_filter = Box::new((*rf.nested_filter).normalize());
+                Self::Relation(rf)
+            }
+            Self::Aggregation(af) => Self::Aggregation(match af {
+                AggregationFilter::Count(f) => AggregationFilter::Count(Box::new((*f).normalize())),
+                AggregationFilter::Average(f) => AggregationFilter::Average(Box::new((*f).normalize())),
+                AggregationFilter::Sum(f) => AggregationFilter::Sum(Box::new((*f).normalize())),
+                AggregationFilter::Min(f) => AggregationFilter::Min(Box::new((*f).normalize())),
+                AggregationFilter::Max(f) => AggregationFilter::Max(Box::new((*f).normalize())),
+            }),
+            other => other,
+        }
+    }
+
+    fn normalize_and_or(filters: Vec<Filter>, is_and: bool) -> Filter {
+        let mut flattened = Vec::with_capacity(filters.len());
+
+        for filter in filters {
+            match filter.normalize() {
+                Self::And(inner) if is_and => flattened.extend(inner),
+                Self::Or(inner) if !is_and => flattened.extend(inner),
+                // A nested node that's unconditionally true/false for a *different* reason than
+                // the same-kind flattening above - an empty `AND`/`{}` nested in an `OR`, or an
+                // empty `OR` nested in an `AND` - doesn't just drop out as an identity element,
+                // it collapses the whole enclosing node, the same way `WHERE 1=1 OR ...` and
+                // `WHERE 1=0 AND ...` would if left to render as SQL.
+                other if !is_and && Self::is_vacuously_true(&other) => return Self::Empty,
+                other if is_and && Self::is_vacuously_false(&other) => return Self::Or(Vec::new()),
+                other => flattened.push(other),
+            }
+        }
+
+        Self::sort_and_dedup(&mut flattened);
+
+        match flattened.len() {
+            0 if is_and => Self::Empty,
+            1 => flattened.pop().unwrap(),
+            _ if is_and => Self::And(flattened),
+            _ => Self::Or(flattened),
+        }
+    }
+
+    /// True for the canonical forms of an unconditionally-true filter that aren't already caught
+    /// by [`Self::normalize_and_or`]'s same-kind flattening: `{}` and an empty `AND`.
+    fn is_vacuously_true(filter: &Filter) -> bool {
+        matches!(filter, Self::Empty) || matches!(filter, Self::And(inner) if inner.is_empty())
+    }
+
+    /// True for the canonical form of an unconditionally-false filter that isn't already caught
+    /// by [`Self::normalize_and_or`]'s same-kind flattening: an empty `OR`.
+    fn is_vacuously_false(filter: &Filter) -> bool {
+        matches!(filter, Self::Or(inner) if inner.is_empty())
+    }
+
+    fn normalize_not(filters: Vec<Filter>) -> Filter {
+        let mut normalized: Vec<Filter> = filters.into_iter().map(Filter::normalize).collect();
+
+        // `NOT: [NOT: [f]]` is a double negation of a single condition and can be dropped: three-
+        // valued SQL logic still satisfies `NOT NOT x == x` (NULL stays NULL either way).
+        if let [Self::Not(inner)] = normalized.as_mut_slice() {
+            if inner.len() == 1 {
+                return inner.pop().unwrap();
+            }
+        }
+
+        Self::sort_and_dedup(&mut normalized);
+        Self::Not(normalized)
+    }
+
+    /// Sorts and deduplicates a commutative filter list by a stable key, but only when every
+    /// element is a plain scalar/bool/empty condition - see [`Self::normalize`] for why relation
+    /// and other joined filters are excluded.
+    fn sort_and_dedup(filters: &mut Vec<Filter>) {
+        let reorderable = filters
+            .iter()
+            .all(|f| matches!(f, Self::Scalar(_) | Self::BoolFilter(_) | Self::Empty));
+
+        if !reorderable {
+            return;
+        }
+
+        filters.sort_by_cached_key(|f| format!("{f:?}"));
+        filters.dedup();
+    }
+
     pub fn set_mode(&mut self, mode: QueryMode) {
         match self {
             Filter::And(inner) => inner.iter_mut().for_each(|f| f.set_mode(mode.clone())),
@@ -212,6 +349,43 @@ impl Filter {
         self == &Filter::Empty
     }
 
+    /// Statically classifies whether this filter restricts the rows it matches, as far as that
+    /// can be decided without knowing what any placeholder inside it resolves to. Used to detect
+    /// `deleteMany`/`updateMany` calls that would silently turn into full-table writes.
+    pub fn static_shape(&self) -> StaticFilterShape {
+        use StaticFilterShape::*;
+
+        match self {
+            Filter::Empty => Unconditional,
+            Filter::BoolFilter(true) => Unconditional,
+            Filter::BoolFilter(false) => Restricted,
+            Filter::And(filters) => {
+                if filters.iter().any(|f| f.static_shape() == Restricted) {
+                    Restricted
+                } else if filters.iter().any(|f| f.static_shape() == DependsOnPlaceholder) {
+                    DependsOnPlaceholder
+                } else {
+                    Unconditional
+                }
+            }
+            // An empty NOT, like an empty AND, is vacuously true.
+            Filter::Not(filters) if filters.is_empty() => Unconditional,
+            // An empty OR matches nothing, same as `NOR ()`.
+            Filter::Or(filters) if filters.is_empty() => Restricted,
+            Filter::Or(filters) => {
+                if filters.iter().any(|f| f.static_shape() == Unconditional) {
+                    Unconditional
+                } else if filters.iter().any(|f| f.static_shape() == DependsOnPlaceholder) {
+                    DependsOnPlaceholder
+                } else {
+                    Restricted
+                }
+            }
+            Filter::Scalar(sf) if sf.condition.may_match_every_row_once_resolved() => DependsOnPlaceholder,
+            _ => Restricted,
+        }
+    }
+
     pub fn scalars(&self) -> Vec<ScalarFieldRef> {
         let mut scalars: Vec<ScalarFieldRef> = Vec::new();
 
@@ -298,3 +472,204 @@ impl From<CompositeFilter> for Filter {
         Filter::Composite(cf)
     }
 }
+
+#[cfg(test)]
+mod static_shape_tests {
+    use super::*;
+
+    #[test]
+    fn empty_filter_is_unconditional() {
+        assert_eq!(Filter::Empty.static_shape(), StaticFilterShape::Unconditional);
+    }
+
+    #[test]
+    fn empty_and_is_unconditional() {
+        // `{}` compiles to an `AND` over zero conditions, which is vacuously true.
+        assert_eq!(Filter::and(vec![]).static_shape(), StaticFilterShape::Unconditional);
+    }
+
+    #[test]
+    fn or_with_always_true_branch_is_unconditional() {
+        let filter = Filter::or(vec![Filter::BoolFilter(false), Filter::Empty]);
+
+        assert_eq!(filter.static_shape(), StaticFilterShape::Unconditional);
+    }
+
+    #[test]
+    fn restrictive_filter_is_restricted() {
+        let filter = Filter::and(vec![Filter::BoolFilter(false)]);
+
+        assert_eq!(filter.static_shape(), StaticFilterShape::Restricted);
+    }
+}
+
+#[cfg(test)]
+mod normalize_tests {
+    use super::*;
+
+    #[test]
+    fn collapses_single_element_and() {
+        assert_eq!(Filter::and(vec![Filter::Empty]).normalize(), Filter::Empty);
+    }
+
+    #[test]
+    fn collapses_single_element_or() {
+        assert_eq!(Filter::or(vec![Filter::BoolFilter(true)]).normalize(), Filter::BoolFilter(true));
+    }
+
+    #[test]
+    fn flattens_nested_and() {
+        let nested = Filter::and(vec![Filter::and(vec![Filter::BoolFilter(true)]), Filter::BoolFilter(false)]);
+
+        assert_eq!(
+            nested.normalize(),
+            Filter::And(vec![Filter::BoolFilter(false), Filter::BoolFilter(true)])
+        );
+    }
+
+    #[test]
+    fn collapses_double_negation() {
+        let filter = Filter::not(vec![Filter::not(vec![Filter::Empty])]);
+
+        assert_eq!(filter.normalize(), Filter::Empty);
+    }
+
+    #[test]
+    fn dedupes_identical_conditions() {
+        let filter = Filter::and(vec![Filter::BoolFilter(true), Filter::BoolFilter(true)]);
+
+        assert_eq!(filter.normalize(), Filter::BoolFilter(true));
+    }
+
+    #[test]
+    fn sorts_commutative_conditions_into_the_same_order() {
+        let a = Filter::and(vec![Filter::BoolFilter(true), Filter::Empty]);
+        let b = Filter::and(vec![Filter::Empty, Filter::BoolFilter(true)]);
+
+        assert_eq!(a.normalize(), b.normalize());
+    }
+
+    #[test]
+    fn an_empty_and_nested_in_an_or_collapses_the_whole_or_to_true() {
+        let filter = Filter::or(vec![Filter::and(vec![]), Filter::BoolFilter(false)]);
+
+        assert_eq!(filter.normalize(), Filter::Empty);
+    }
+
+    #[test]
+    fn an_empty_braces_filter_nested_in_an_or_collapses_the_whole_or_to_true() {
+        let filter = Filter::or(vec![Filter::Empty, Filter::BoolFilter(false)]);
+
+        assert_eq!(filter.normalize(), Filter::Empty);
+    }
+
+    #[test]
+    fn an_empty_or_nested_in_an_and_collapses_the_whole_and_to_false() {
+        let filter = Filter::and(vec![Filter::or(vec![]), Filter::BoolFilter(true)]);
+
+        assert_eq!(filter.normalize(), Filter::Or(vec![]));
+    }
+
+    #[test]
+    fn and_all_normalizes_its_input() {
+        assert_eq!(Filter::and_all(vec![Filter::Empty, Filter::BoolFilter(true)]), Filter::BoolFilter(true));
+    }
+
+    #[test]
+    fn or_all_normalizes_its_input() {
+        assert_eq!(
+            Filter::or_all(vec![Filter::and(vec![]), Filter::BoolFilter(false)]),
+            Filter::Empty
+        );
+    }
+
+    #[test]
+    fn keeps_aggregation_filters_in_their_original_order() {
+        // Unlike scalar/bool conditions, aggregation (and relation) filters are not reordered:
+        // SQL builders assign join aliases by position, so reordering could change which alias
+        // ends up attached to which filter even though the matched rows are unaffected.
+        let filter = Filter::and(vec![
+            Filter::Aggregation(AggregationFilter::Sum(Box::new(Filter::Empty))),
+            Filter::Aggregation(AggregationFilter::Count(Box::new(Filter::Empty))),
+        ]);
+
+        assert_eq!(filter.clone().normalize(), filter);
+    }
+}
+
+/// Checks [`Filter::normalize`] against randomly generated scalar-only filter trees (`Empty`,
+/// `BoolFilter`, `And`, `Or`, `Not`), comparing each tree's evaluation before and after
+/// normalizing it. There's no property-testing crate in this workspace, so this rolls its own
+/// tiny deterministic generator rather than pull one in for a single test.
+#[cfg(test)]
+mod normalize_property_tests {
+    use super::*;
+
+    /// Evaluates a filter built only from the boolean-combinator variants, mirroring the
+    /// semantics the SQL visitor implements for exactly this subset (see `filter/visitor.rs`'s
+    /// `Filter::Not` arm: `NOT: [a, b]` is `AND(NOT a, NOT b)`, so `NOT: []` is vacuously true
+    /// just like `AND: []`).
+    fn eval(filter: &Filter) -> bool {
+        match filter {
+            Filter::Empty => true,
+            Filter::BoolFilter(b) => *b,
+            Filter::And(filters) => filters.iter().all(eval),
+            Filter::Or(filters) => filters.iter().any(eval),
+            Filter::Not(filters) => filters.iter().all(|f| !eval(f)),
+            other => unreachable!("the generator below never produces a {other:?} node"),
+        }
+    }
+
+    /// A tiny xorshift64 PRNG, so the trees generated below are reproducible without a
+    /// property-testing dependency.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next_u64(&mut self) -> u64 {
+            let mut x = self.0;
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            self.0 = x;
+            x
+        }
+
+        fn next_below(&mut self, bound: u64) -> u64 {
+            self.next_u64() % bound
+        }
+    }
+
+    fn arbitrary_filter(rng: &mut Rng, depth: u32) -> Filter {
+        if depth == 0 || rng.next_below(4) == 0 {
+            return match rng.next_below(3) {
+                0 => Filter::Empty,
+                1 => Filter::BoolFilter(true),
+                _ => Filter::BoolFilter(false),
+            };
+        }
+
+        let children: Vec<Filter> = (0..=rng.next_below(3)).map(|_| arbitrary_filter(rng, depth - 1)).collect();
+
+        match rng.next_below(3) {
+            0 => Filter::And(children),
+            1 => Filter::Or(children),
+            _ => Filter::Not(children),
+        }
+    }
+
+    #[test]
+    fn normalize_never_changes_what_a_scalar_only_filter_evaluates_to() {
+        let mut rng = Rng(0x1234_5678_9abc_def0);
+
+        for _ in 0..1000 {
+            let filter = arbitrary_filter(&mut rng, 5);
+            let normalized = filter.clone().normalize();
+
+            assert_eq!(
+                eval(&filter),
+                eval(&normalized),
+                "normalize() changed the evaluation result of {filter:?} (normalized to {normalized:?})"
+            );
+        }
+    }
+}
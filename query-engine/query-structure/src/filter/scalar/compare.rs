@@ -204,6 +204,124 @@ impl ScalarCompare for ScalarFieldRef {
         })
     }
 
+    fn ancestor_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::AncestorOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_ancestor_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::NotAncestorOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn descendant_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::DescendantOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_descendant_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::NotDescendantOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn matches_lquery<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::MatchesLquery(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_matches_lquery<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::NotMatchesLquery(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn geo_contains<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::GeoContains(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_geo_contains<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::NotGeoContains(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn within_distance<T, D>(&self, point: T, distance_meters: D) -> Filter
+    where
+        T: Into<ConditionValue>,
+        D: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::WithinDistance(WithinDistanceCondition {
+                point: point.into(),
+                distance_meters: distance_meters.into(),
+            }),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_within_distance<T, D>(&self, point: T, distance_meters: D) -> Filter
+    where
+        T: Into<ConditionValue>,
+        D: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Single(self.clone()),
+            condition: ScalarCondition::NotWithinDistance(WithinDistanceCondition {
+                point: point.into(),
+                distance_meters: distance_meters.into(),
+            }),
+            mode: QueryMode::Default,
+        })
+    }
+
     fn is_set(&self, val: bool) -> Filter {
         Filter::from(ScalarFilter {
             projection: ScalarProjection::Single(self.clone()),
@@ -416,6 +534,124 @@ impl ScalarCompare for ModelProjection {
         })
     }
 
+    fn ancestor_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::AncestorOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_ancestor_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::NotAncestorOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn descendant_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::DescendantOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_descendant_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::NotDescendantOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn matches_lquery<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::MatchesLquery(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_matches_lquery<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::NotMatchesLquery(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn geo_contains<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::GeoContains(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_geo_contains<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::NotGeoContains(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn within_distance<T, D>(&self, point: T, distance_meters: D) -> Filter
+    where
+        T: Into<ConditionValue>,
+        D: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::WithinDistance(WithinDistanceCondition {
+                point: point.into(),
+                distance_meters: distance_meters.into(),
+            }),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_within_distance<T, D>(&self, point: T, distance_meters: D) -> Filter
+    where
+        T: Into<ConditionValue>,
+        D: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.scalar_fields().collect()),
+            condition: ScalarCondition::NotWithinDistance(WithinDistanceCondition {
+                point: point.into(),
+                distance_meters: distance_meters.into(),
+            }),
+            mode: QueryMode::Default,
+        })
+    }
+
     fn is_set(&self, val: bool) -> Filter {
         Filter::from(ScalarFilter {
             projection: ScalarProjection::Compound(self.scalar_fields().collect()),
@@ -628,6 +864,124 @@ impl ScalarCompare for FieldSelection {
         })
     }
 
+    fn ancestor_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::AncestorOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_ancestor_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::NotAncestorOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn descendant_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::DescendantOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_descendant_of<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::NotDescendantOf(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn matches_lquery<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::MatchesLquery(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_matches_lquery<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::NotMatchesLquery(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn geo_contains<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::GeoContains(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_geo_contains<T>(&self, val: T) -> Filter
+    where
+        T: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::NotGeoContains(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn within_distance<T, D>(&self, point: T, distance_meters: D) -> Filter
+    where
+        T: Into<ConditionValue>,
+        D: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::WithinDistance(WithinDistanceCondition {
+                point: point.into(),
+                distance_meters: distance_meters.into(),
+            }),
+            mode: QueryMode::Default,
+        })
+    }
+
+    fn not_within_distance<T, D>(&self, point: T, distance_meters: D) -> Filter
+    where
+        T: Into<ConditionValue>,
+        D: Into<ConditionValue>,
+    {
+        Filter::from(ScalarFilter {
+            projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
+            condition: ScalarCondition::NotWithinDistance(WithinDistanceCondition {
+                point: point.into(),
+                distance_meters: distance_meters.into(),
+            }),
+            mode: QueryMode::Default,
+        })
+    }
+
     fn is_set(&self, val: bool) -> Filter {
         Filter::from(ScalarFilter {
             projection: ScalarProjection::Compound(self.as_scalar_fields().expect("Todo composites in filters.")),
@@ -26,9 +26,32 @@ pub enum ScalarCondition {
     JsonCompare(JsonCondition),
     Search(ConditionValue, Vec<ScalarProjection>),
     NotSearch(ConditionValue, Vec<ScalarProjection>),
+    /// `ltree @> value`: the field is an ancestor of (or equal to) the given path.
+    AncestorOf(ConditionValue),
+    NotAncestorOf(ConditionValue),
+    /// `ltree <@ value`: the field is a descendant of (or equal to) the given path.
+    DescendantOf(ConditionValue),
+    NotDescendantOf(ConditionValue),
+    /// `ltree ~ value`: the field matches the given `lquery` pattern.
+    MatchesLquery(ConditionValue),
+    NotMatchesLquery(ConditionValue),
+    /// `ST_Contains(field, value)`: the field (a spatial type) contains the given GeoJSON geometry.
+    GeoContains(ConditionValue),
+    NotGeoContains(ConditionValue),
+    /// `ST_Distance_Sphere(field, point) <= distance_meters`: the field (a spatial type) is within
+    /// `distance_meters` metres of the given GeoJSON point.
+    WithinDistance(WithinDistanceCondition),
+    NotWithinDistance(WithinDistanceCondition),
     IsSet(bool),
 }
 
+/// Parameters of a [`ScalarCondition::WithinDistance`]/[`ScalarCondition::NotWithinDistance`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WithinDistanceCondition {
+    pub point: ConditionValue,
+    pub distance_meters: ConditionValue,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct JsonCondition {
     pub condition: Box<ScalarCondition>,
@@ -67,6 +90,16 @@ impl ScalarCondition {
                 }
                 Self::Search(v, fields) => Self::NotSearch(v, fields),
                 Self::NotSearch(v, fields) => Self::Search(v, fields),
+                Self::AncestorOf(v) => Self::NotAncestorOf(v),
+                Self::NotAncestorOf(v) => Self::AncestorOf(v),
+                Self::DescendantOf(v) => Self::NotDescendantOf(v),
+                Self::NotDescendantOf(v) => Self::DescendantOf(v),
+                Self::MatchesLquery(v) => Self::NotMatchesLquery(v),
+                Self::NotMatchesLquery(v) => Self::MatchesLquery(v),
+                Self::GeoContains(v) => Self::NotGeoContains(v),
+                Self::NotGeoContains(v) => Self::GeoContains(v),
+                Self::WithinDistance(v) => Self::NotWithinDistance(v),
+                Self::NotWithinDistance(v) => Self::WithinDistance(v),
                 Self::IsSet(v) => Self::IsSet(!v),
             }
         } else {
@@ -95,7 +128,50 @@ impl ScalarCondition {
             ScalarCondition::JsonCompare(json_cond) => json_cond.condition.as_field_ref(),
             ScalarCondition::Search(v, _) => v.as_field_ref(),
             ScalarCondition::NotSearch(v, _) => v.as_field_ref(),
+            ScalarCondition::AncestorOf(v) => v.as_field_ref(),
+            ScalarCondition::NotAncestorOf(v) => v.as_field_ref(),
+            ScalarCondition::DescendantOf(v) => v.as_field_ref(),
+            ScalarCondition::NotDescendantOf(v) => v.as_field_ref(),
+            ScalarCondition::MatchesLquery(v) => v.as_field_ref(),
+            ScalarCondition::NotMatchesLquery(v) => v.as_field_ref(),
+            ScalarCondition::GeoContains(v) => v.as_field_ref(),
+            ScalarCondition::NotGeoContains(v) => v.as_field_ref(),
+            // The point is what can carry a field reference; the distance is always a literal.
+            ScalarCondition::WithinDistance(v) => v.point.as_field_ref(),
+            ScalarCondition::NotWithinDistance(v) => v.point.as_field_ref(),
             ScalarCondition::IsSet(_) => None,
         }
     }
+
+    /// Whether this condition could end up matching every row once a placeholder it depends on is
+    /// resolved, e.g. `NOT IN ($list)` matches every row if `$list` resolves to an empty list.
+    ///
+    /// Used to tell an unconditionally-empty filter (caught statically) apart from one that can
+    /// only be proven safe once its placeholders are bound, so callers can warn instead of
+    /// erroring when they can't decide yet. See [`Filter::static_shape`].
+    pub fn may_match_every_row_once_resolved(&self) -> bool {
+        matches!(self, ScalarCondition::NotIn(ConditionListValue::Value(PrismaValue::Placeholder(_))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_in_placeholder_list_may_match_every_row() {
+        let condition = ScalarCondition::NotIn(ConditionListValue::Value(PrismaValue::Placeholder(Placeholder::new(
+            "list".to_owned(),
+            PrismaValueType::Any,
+        ))));
+
+        assert!(condition.may_match_every_row_once_resolved());
+    }
+
+    #[test]
+    fn not_in_concrete_list_does_not_match_every_row() {
+        let condition = ScalarCondition::NotIn(ConditionListValue::list(vec![PrismaValue::Int(1)]));
+
+        assert!(!condition.may_match_every_row_once_resolved());
+    }
 }
@@ -70,6 +70,12 @@ impl From<&ScalarFieldRef> for ConditionValue {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ConditionListValue {
     List(PrismaListValue),
+
+    /// The entire list given as a single value, e.g. a `PrismaValue::Placeholder` typed as an
+    /// array when the whole argument is parameterized as one bind value instead of one
+    /// placeholder per element.
+    Value(PrismaValue),
+
     FieldRef(ScalarFieldRef),
 }
 
@@ -81,6 +87,10 @@ impl ConditionListValue {
         Self::List(vals.into_iter().map(Into::into).collect())
     }
 
+    pub fn value(pv: PrismaValue) -> Self {
+        Self::Value(pv)
+    }
+
     pub fn reference(sf: ScalarFieldRef) -> Self {
         Self::FieldRef(sf)
     }
@@ -88,6 +98,7 @@ impl ConditionListValue {
     pub fn len(&self) -> usize {
         match self {
             ConditionListValue::List(list) => list.len(),
+            ConditionListValue::Value(_) => 1,
             ConditionListValue::FieldRef(_) => 1,
         }
     }
@@ -65,6 +65,17 @@ impl ScalarFilter {
         )
     }
 
+    /// True for a single-column `IN`/`NOT IN` filter backed by an explicit value list - the shape
+    /// a connector with array-bind support can send as one query with a single array parameter
+    /// instead of chunking into multiple queries.
+    pub fn is_single_column_list(&self) -> bool {
+        matches!(self.projection, ScalarProjection::Single(_))
+            && matches!(
+                self.condition,
+                ScalarCondition::In(ConditionListValue::List(_)) | ScalarCondition::NotIn(ConditionListValue::List(_))
+            )
+    }
+
     /// If possible, converts the filter into multiple smaller filters.
     pub fn batched(self, chunk_size: usize) -> Vec<ScalarFilter> {
         fn inner(mut list: PrismaListValue, chunk_size: usize) -> Vec<PrismaListValue> {
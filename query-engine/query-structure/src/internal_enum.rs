@@ -16,6 +16,18 @@ impl InternalEnum {
     pub fn schema_name(&self) -> Option<&str> {
         self.dm.walk(self.id).schema().map(|tuple| tuple.0)
     }
+
+    /// The names of the values declared on this enum.
+    pub fn values(&self) -> impl Iterator<Item = &str> {
+        self.dm.walk(self.id).values().map(|v| v.name())
+    }
+
+    /// The `@alias`-declared aliases on this enum's values, as `(alias, canonical value name)`
+    /// pairs. Aliases are accepted as additional input names for writes and filters, but are
+    /// never produced as output.
+    pub fn aliases(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.dm.walk(self.id).values().filter_map(|v| v.alias().map(|alias| (alias, v.name())))
+    }
 }
 
 impl std::fmt::Debug for InternalEnum {
@@ -1,4 +1,5 @@
 mod aggregate_selection;
+mod chunk_execution;
 mod composite_type;
 mod convert;
 mod default_value;
@@ -16,6 +17,7 @@ mod parent_container;
 mod prisma_value_ext;
 mod projections;
 mod query_arguments;
+mod query_timeout;
 mod record;
 mod relation;
 mod selection_result;
@@ -27,6 +29,7 @@ pub mod prelude;
 
 pub use self::{default_value::*, native_type_instance::*, zipper::*};
 pub use aggregate_selection::*;
+pub use chunk_execution::*;
 pub use composite_type::*;
 pub use convert::convert;
 pub use distinct::*;
@@ -41,6 +44,7 @@ pub use model::*;
 pub use order_by::*;
 pub use projections::*;
 pub use query_arguments::*;
+pub use query_timeout::*;
 pub use record::*;
 pub use relation::*;
 pub use selection_result::*;
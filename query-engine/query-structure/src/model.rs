@@ -83,6 +83,26 @@ impl Model {
             .filter(|idx| idx.is_unique())
             .filter(|index| !index.fields().any(|f| f.is_unsupported()))
     }
+
+    /// The `@@queryTimeout` declared on this model for the given statement kind, in milliseconds,
+    /// if any.
+    pub fn query_timeout_ms(&self, kind: StatementKind) -> Option<u32> {
+        let qt = self.walker().query_timeout()?;
+
+        Some(match kind {
+            StatementKind::Read => qt.read_ms(),
+            StatementKind::Write => qt.write_ms(),
+        })
+    }
+}
+
+/// Distinguishes the two timeout buckets a `@@queryTimeout` attribute can configure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatementKind {
+    /// `SELECT` statements.
+    Read,
+    /// `INSERT`/`UPDATE`/`DELETE` statements.
+    Write,
 }
 
 impl std::fmt::Debug for Model {
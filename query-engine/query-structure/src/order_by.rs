@@ -1,4 +1,5 @@
 use crate::{CompositeFieldRef, RelationFieldRef, ScalarFieldRef};
+use prisma_value::PrismaValue;
 use std::fmt::Display;
 
 #[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
@@ -28,6 +29,7 @@ pub enum OrderBy {
     ScalarAggregation(OrderByScalarAggregation),
     ToManyAggregation(OrderByToManyAggregation),
     Relevance(OrderByRelevance),
+    InputOrder(OrderByInputOrder),
 }
 
 impl OrderBy {
@@ -37,6 +39,7 @@ impl OrderBy {
             OrderBy::ToManyAggregation(o) => Some(&o.path),
             OrderBy::ScalarAggregation(_) => None,
             OrderBy::Relevance(_) => None,
+            OrderBy::InputOrder(_) => None,
         }
     }
 
@@ -46,6 +49,9 @@ impl OrderBy {
             OrderBy::ScalarAggregation(o) => o.sort_order,
             OrderBy::ToManyAggregation(o) => o.sort_order,
             OrderBy::Relevance(o) => o.sort_order,
+            // `_inputOrder` has no asc/desc semantics of its own: it always preserves the order of
+            // the `in` list it was derived from.
+            OrderBy::InputOrder(_) => SortOrder::Ascending,
         }
     }
 
@@ -55,6 +61,7 @@ impl OrderBy {
             OrderBy::ScalarAggregation(o) => Some(o.field.clone()),
             OrderBy::ToManyAggregation(_) => None,
             OrderBy::Relevance(_) => None,
+            OrderBy::InputOrder(o) => Some(o.field.clone()),
         }
     }
 
@@ -112,6 +119,13 @@ impl OrderBy {
             path,
         })
     }
+
+    /// Orders by the position of `field`'s value within `values`, preserving the order of an
+    /// explicit list of values (e.g. a `where: { id: { in: [...] } }` filter) rather than any
+    /// intrinsic ordering of the field itself.
+    pub fn input_order(field: ScalarFieldRef, values: Vec<PrismaValue>) -> Self {
+        Self::InputOrder(OrderByInputOrder { field, values })
+    }
 }
 
 /// Describes a hop over to a relation or composite for an orderBy statement.
@@ -171,6 +185,31 @@ pub struct OrderByScalar {
     pub nulls_order: Option<NullsOrder>,
 }
 
+impl OrderByScalar {
+    /// Returns `true` if the order-by path crosses at least one to-one relation that isn't
+    /// required, i.e. a `LEFT JOIN` that can itself produce a NULL row (as opposed to a NULL
+    /// value on an otherwise-present row).
+    fn crosses_optional_hop(&self) -> bool {
+        self.path
+            .iter()
+            .any(|hop| hop.as_relation_hop().is_some_and(|rf| rf.arity().is_optional()))
+    }
+
+    /// The `nulls_order` to actually sort by, after applying the connectors-agnostic default.
+    ///
+    /// A join through an optional to-one relation can produce a NULL for reasons a same-table
+    /// NULL never has (no related row at all, not just a null column), and every connector
+    /// defaults NULL placement differently (Postgres/SQLite sort NULLs last on `ASC`, MySQL/MSSQL
+    /// sort them first). Left alone, the exact same query would come back in a different row order
+    /// depending on the database. So when the client hasn't picked a `nulls` option and the path
+    /// crosses an optional hop, we pin it to `Last` ourselves, for every connector family.
+    pub fn effective_nulls_order(&self) -> Option<NullsOrder> {
+        self.nulls_order
+            .clone()
+            .or_else(|| self.crosses_optional_hop().then_some(NullsOrder::Last))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct OrderByScalarAggregation {
     pub field: ScalarFieldRef,
@@ -210,6 +249,14 @@ pub struct OrderByRelevance {
     pub path: Vec<OrderByHop>,
 }
 
+/// Orders by the position of `field`'s value within the explicit, ordered `values` list. Has no
+/// relation path: it's only valid directly on the queried model's own field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OrderByInputOrder {
+    pub field: ScalarFieldRef,
+    pub values: Vec<PrismaValue>,
+}
+
 impl Display for SortOrder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
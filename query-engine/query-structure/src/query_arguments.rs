@@ -286,7 +286,12 @@ impl QueryArguments {
     }
 
     pub fn has_unbatchable_ordering(&self) -> bool {
-        self.order_by.iter().any(|o| !matches!(o, OrderBy::Scalar(_)))
+        // `InputOrder` is resolvable after chunked batching: unlike aggregations/relevance, it
+        // doesn't depend on the other rows in the (unchunked) result set, so each chunk can be
+        // ordered independently and the chunks can then be merged and resorted in memory.
+        self.order_by
+            .iter()
+            .any(|o| !matches!(o, OrderBy::Scalar(_) | OrderBy::InputOrder(_)))
     }
 
     pub fn has_unbatchable_filters(&self) -> bool {
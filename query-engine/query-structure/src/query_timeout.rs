@@ -0,0 +1,63 @@
+use crate::{Model, StatementKind};
+
+/// Resolves the effective statement timeout, in milliseconds, from the request-level deadline and
+/// the `@@queryTimeout` declared on every model a statement touches. Statements that join multiple
+/// models (and so carry more than one candidate) take the minimum, since the whole statement can
+/// only run as long as its strictest model allows. Returns `None` when none of the candidates
+/// specify a timeout.
+///
+/// This only resolves which timeout should apply; rendering it into a connector-specific
+/// statement (e.g. Postgres' `SET statement_timeout`) and cancelling the query once it elapses is
+/// execution-layer work left to the connectors.
+pub fn resolve_statement_timeout_ms(
+    request_deadline_ms: Option<u32>,
+    model_timeouts_ms: impl IntoIterator<Item = Option<u32>>,
+) -> Option<u32> {
+    request_deadline_ms
+        .into_iter()
+        .chain(model_timeouts_ms.into_iter().flatten())
+        .min()
+}
+
+/// Collects the `@@queryTimeout` values declared on the given models for a statement of the given
+/// kind, in the shape [`resolve_statement_timeout_ms`] expects.
+pub fn model_query_timeouts_ms<'a>(
+    models: impl IntoIterator<Item = &'a Model> + 'a,
+    kind: StatementKind,
+) -> impl Iterator<Item = Option<u32>> + 'a {
+    models.into_iter().map(move |model| model.query_timeout_ms(kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_timeouts_resolves_to_none() {
+        assert_eq!(resolve_statement_timeout_ms(None, [None, None]), None);
+    }
+
+    #[test]
+    fn request_deadline_only() {
+        assert_eq!(resolve_statement_timeout_ms(Some(5_000), [None]), Some(5_000));
+    }
+
+    #[test]
+    fn model_timeout_only() {
+        assert_eq!(resolve_statement_timeout_ms(None, [Some(2_000)]), Some(2_000));
+    }
+
+    #[test]
+    fn takes_the_tighter_of_request_and_model_timeout() {
+        assert_eq!(resolve_statement_timeout_ms(Some(5_000), [Some(2_000)]), Some(2_000));
+        assert_eq!(resolve_statement_timeout_ms(Some(1_000), [Some(2_000)]), Some(1_000));
+    }
+
+    #[test]
+    fn joined_statement_takes_the_minimum_across_models() {
+        assert_eq!(
+            resolve_statement_timeout_ms(None, [Some(30_000), Some(2_000), None]),
+            Some(2_000)
+        );
+    }
+}
@@ -63,8 +63,20 @@ impl ManyRecords {
             .map(|(i, name)| (name.as_str(), i))
             .collect();
 
+        // Precomputed once so each comparison is a cheap lookup instead of rebuilding the map
+        // for every pair of records.
+        let input_order_positions: Vec<Option<HashMap<&PrismaValue, usize>>> = order_bys
+            .iter()
+            .map(|o| match o {
+                OrderBy::InputOrder(by_input_order) => {
+                    Some(by_input_order.values.iter().enumerate().map(|(i, v)| (v, i)).collect())
+                }
+                _ => None,
+            })
+            .collect();
+
         self.records.sort_by(|a, b| {
-            let mut orderings = order_bys.iter().map(|o| match o {
+            let mut orderings = order_bys.iter().zip(&input_order_positions).map(|(o, positions)| match o {
                 OrderBy::Scalar(by_scalar) => {
                     let index = field_indices[by_scalar.field.db_name()];
 
@@ -77,6 +89,22 @@ impl ManyRecords {
                         }
                     }
                 }
+                OrderBy::InputOrder(by_input_order) => {
+                    let positions = positions.as_ref().unwrap();
+                    let index = field_indices[by_input_order.field.db_name()];
+
+                    // `_inputOrder` has no asc/desc semantics, so `reversed` (used for
+                    // cursor-based backward pagination) is intentionally not honored here.
+                    // Values absent from the input list sort last.
+                    let position_of = |record: &Record| {
+                        positions
+                            .get(&record.values[index])
+                            .copied()
+                            .unwrap_or(by_input_order.values.len())
+                    };
+
+                    position_of(a).cmp(&position_of(b))
+                }
                 OrderBy::ScalarAggregation(_) => unimplemented!(),
                 OrderBy::ToManyAggregation(_) => unimplemented!(),
                 OrderBy::Relevance(_) => unimplemented!(),
@@ -68,6 +68,13 @@ impl SelectionResult {
         self.pairs.iter().map(|(field, _)| field.db_name())
     }
 
+    /// Projects this selection down to exactly the fields in `field_selection`, discarding any
+    /// other pairs it carries (e.g. ones inherited from a broader parent selection). Assumes
+    /// `field_selection` is covered by `self`.
+    pub fn project(self, field_selection: &FieldSelection) -> SelectionResult {
+        self.split_into(std::slice::from_ref(field_selection)).remove(0)
+    }
+
     /// Consumes this `SelectionResult` and splits it into a set of `SelectionResult`s based on the passed
     /// `FieldSelection`s. Assumes that the transformation can be done.
     pub fn split_into(self, field_selections: &[FieldSelection]) -> Vec<SelectionResult> {
@@ -199,3 +206,49 @@ impl From<&FieldSelection> for SelectionResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Model;
+    use std::sync::Arc;
+
+    fn test_model() -> Model {
+        let schema_str = r#"
+            datasource db {
+                provider = "postgresql"
+                url      = "postgres://stub"
+            }
+
+            model TestModel {
+                id    Int    @id
+                email String
+            }
+        "#;
+
+        let psl_schema = psl::validate(schema_str.into());
+        assert!(!psl_schema.diagnostics.has_errors(), "{:?}", psl_schema.diagnostics);
+
+        let internal_data_model = crate::InternalDataModel {
+            schema: Arc::new(psl_schema),
+        };
+
+        internal_data_model.find_model("TestModel").unwrap()
+    }
+
+    #[test]
+    fn project_drops_pairs_outside_the_field_selection() {
+        let model = test_model();
+        let id_field = model.fields().scalar().find(|f| f.name() == "id").unwrap();
+        let email_field = model.fields().scalar().find(|f| f.name() == "email").unwrap();
+
+        let selector = SelectionResult::new(vec![
+            (id_field.clone(), PrismaValue::Int(1)),
+            (email_field, PrismaValue::String("extra@example.com".into())),
+        ]);
+
+        let projected = selector.project(&model.primary_identifier());
+
+        assert_eq!(projected, SelectionResult::new(vec![(id_field, PrismaValue::Int(1))]));
+    }
+}
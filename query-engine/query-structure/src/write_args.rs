@@ -1,9 +1,10 @@
 use crate::{
     CompositeFieldRef, Field, Filter, Model, ModelProjection, PrismaValue, ScalarFieldRef, SelectedField,
-    SelectionResult,
+    SelectionResult, TypeIdentifier,
 };
 use indexmap::{map::Keys, IndexMap};
 use std::{borrow::Borrow, convert::TryInto, ops::Deref};
+use thiserror::Error;
 
 /// WriteArgs represent data to be written to an underlying data source.
 #[derive(Debug, PartialEq, Clone)]
@@ -77,6 +78,17 @@ impl WriteOperation {
         Self::Scalar(ScalarWriteOperation::Divide(pv))
     }
 
+    /// Sets a value at `path` inside a `Json` field, creating the path's missing objects along
+    /// the way, without reading and rewriting the whole document.
+    pub fn scalar_json_set(path: Vec<String>, pv: PrismaValue) -> Self {
+        Self::Scalar(ScalarWriteOperation::JsonSet(path, pv))
+    }
+
+    /// Removes the value at `path` inside a `Json` field.
+    pub fn scalar_json_remove(path: Vec<String>) -> Self {
+        Self::Scalar(ScalarWriteOperation::JsonRemove(path))
+    }
+
     pub fn composite_set(pv: PrismaValue) -> Self {
         Self::Composite(CompositeWriteOperation::Set(pv))
     }
@@ -166,6 +178,14 @@ pub enum ScalarWriteOperation {
 
     /// Divide field by value.
     Divide(PrismaValue),
+
+    /// Sets the value at a dot-notation path inside a `Json` field. Only supported on SQL
+    /// connectors, gated behind the `jsonUpdateOperations` preview feature.
+    JsonSet(Vec<String>, PrismaValue),
+
+    /// Removes the value at a dot-notation path inside a `Json` field. Only supported on SQL
+    /// connectors, gated behind the `jsonUpdateOperations` preview feature.
+    JsonRemove(Vec<String>),
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -449,6 +469,164 @@ impl WriteArgs {
             )
         }
     }
+
+    /// Checks every scalar `Set` value in these args against the `TypeIdentifier` and, where
+    /// known, the native-type constraints (varchar length, integer range, ...) of the
+    /// corresponding field in `projection`, so that obvious mismatches surface as a descriptive
+    /// error here instead of a cryptic one from the database once the statement is sent.
+    ///
+    /// Placeholder values are skipped, since their real value isn't known until execution, as are
+    /// list-arity fields, whose element-wise validation isn't covered by this pass.
+    pub fn validate_against(&self, projection: &ModelProjection) -> Result<(), FieldConversionError> {
+        for field in projection.scalar_fields() {
+            if field.arity().is_list() {
+                continue;
+            }
+
+            let Some(WriteOperation::Scalar(ScalarWriteOperation::Set(value))) = self.get_field_value(field.db_name())
+            else {
+                continue;
+            };
+
+            if matches!(value, PrismaValue::Placeholder(_) | PrismaValue::Null) {
+                continue;
+            }
+
+            validate_scalar_value(&field, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returned by [`WriteArgs::validate_against`] when an input value can't be converted to its
+/// target column without either truncation or a type mismatch.
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("The value `{value}` provided for field `{field}` on model `{model}` is not a valid {expected}")]
+pub struct FieldConversionError {
+    pub model: String,
+    pub field: String,
+    pub value: PrismaValue,
+    pub expected: String,
+}
+
+impl FieldConversionError {
+    fn new(field: &ScalarFieldRef, value: &PrismaValue, expected: impl Into<String>) -> Self {
+        Self {
+            model: field.container().name(),
+            field: field.name().to_owned(),
+            value: value.clone(),
+            expected: expected.into(),
+        }
+    }
+}
+
+fn validate_scalar_value(field: &ScalarFieldRef, value: &PrismaValue) -> Result<(), FieldConversionError> {
+    match field.type_identifier() {
+        TypeIdentifier::Int => match value {
+            PrismaValue::Int(n) => {
+                let (min, max) = match field.native_type().as_ref().map(|nt| nt.name()) {
+                    Some("SmallInt") => (i16::MIN as i64, i16::MAX as i64),
+                    _ => (i32::MIN as i64, i32::MAX as i64),
+                };
+
+                if *n < min || *n > max {
+                    return Err(FieldConversionError::new(field, value, format!("Int ({min}..={max})")));
+                }
+            }
+            _ => return Err(FieldConversionError::new(field, value, "Int")),
+        },
+
+        TypeIdentifier::BigInt => {
+            if !matches!(value, PrismaValue::BigInt(_) | PrismaValue::Int(_)) {
+                return Err(FieldConversionError::new(field, value, "BigInt"));
+            }
+        }
+
+        TypeIdentifier::Float | TypeIdentifier::Decimal => {
+            if !matches!(value, PrismaValue::Float(_) | PrismaValue::Int(_)) {
+                return Err(FieldConversionError::new(field, value, "numeric value"));
+            }
+        }
+
+        TypeIdentifier::Boolean => {
+            if !matches!(value, PrismaValue::Boolean(_)) {
+                return Err(FieldConversionError::new(field, value, "Boolean"));
+            }
+        }
+
+        TypeIdentifier::String => match value {
+            PrismaValue::String(s) => {
+                if let Some(max_len) = field.native_type().as_ref().and_then(varchar_length) {
+                    if s.chars().count() > max_len {
+                        return Err(FieldConversionError::new(
+                            field,
+                            value,
+                            format!("String of at most {max_len} characters"),
+                        ));
+                    }
+                }
+            }
+            _ => return Err(FieldConversionError::new(field, value, "String")),
+        },
+
+        TypeIdentifier::Enum(_) => match value {
+            PrismaValue::Enum(variant) => {
+                let internal_enum = field
+                    .internal_enum()
+                    .expect("a field with TypeIdentifier::Enum must resolve to an internal enum");
+
+                if !internal_enum.values().any(|v| v == variant.as_str()) {
+                    return Err(FieldConversionError::new(
+                        field,
+                        value,
+                        format!("variant of enum `{}`", internal_enum.name()),
+                    ));
+                }
+            }
+            _ => return Err(FieldConversionError::new(field, value, "enum value")),
+        },
+
+        TypeIdentifier::UUID => {
+            if !matches!(value, PrismaValue::Uuid(_) | PrismaValue::String(_)) {
+                return Err(FieldConversionError::new(field, value, "UUID"));
+            }
+        }
+
+        TypeIdentifier::Json => {
+            if !matches!(value, PrismaValue::Json(_) | PrismaValue::String(_)) {
+                return Err(FieldConversionError::new(field, value, "Json"));
+            }
+        }
+
+        TypeIdentifier::DateTime => {
+            if !matches!(value, PrismaValue::DateTime(_)) {
+                return Err(FieldConversionError::new(field, value, "DateTime"));
+            }
+        }
+
+        TypeIdentifier::Bytes => {
+            if !matches!(value, PrismaValue::Bytes(_)) {
+                return Err(FieldConversionError::new(field, value, "Bytes value"));
+            }
+        }
+
+        // Fields we can't meaningfully type-check here: `Unsupported` has no known Rust-side
+        // representation to check against.
+        TypeIdentifier::Unsupported => (),
+    }
+
+    Ok(())
+}
+
+/// Returns the declared length of a `VarChar`/`Char`-like native type, if any. Connectors name
+/// these consistently even though the exact set of bounded string native types differs between
+/// them (e.g. `NVarChar` on MSSQL).
+fn varchar_length(native_type: &crate::NativeTypeInstance) -> Option<usize> {
+    match native_type.name() {
+        "VarChar" | "Char" | "NVarChar" | "NChar" => native_type.args().first()?.parse().ok(),
+        _ => None,
+    }
 }
 
 /// Picks all arguments out of `args` that are updating a value for a field
@@ -505,3 +683,105 @@ pub fn apply_expression(val: PrismaValue, scalar_write: ScalarWriteOperation) ->
         ScalarWriteOperation::Unset(_) => unimplemented!(),
     }
 }
+
+#[cfg(test)]
+mod validate_against_tests {
+    use super::*;
+    use crate::Model;
+    use std::sync::Arc;
+
+    fn test_model() -> Model {
+        let schema_str = r#"
+            datasource db {
+                provider = "postgresql"
+                url      = "postgres://stub"
+            }
+
+            enum Role {
+                ADMIN
+                USER
+            }
+
+            model TestModel {
+                id   Int    @id
+                age  Int
+                code String @db.VarChar(5)
+                role Role
+            }
+        "#;
+
+        let psl_schema = psl::validate(schema_str.into());
+        assert!(!psl_schema.diagnostics.has_errors(), "{:?}", psl_schema.diagnostics);
+
+        let internal_data_model = crate::InternalDataModel {
+            schema: Arc::new(psl_schema),
+        };
+
+        internal_data_model.find_model("TestModel").unwrap()
+    }
+
+    fn projection(model: &Model) -> ModelProjection {
+        ModelProjection::new(model.fields().scalar().map(Field::from).collect())
+    }
+
+    fn args_with(field: &str, value: WriteOperation) -> WriteArgs {
+        let mut args = WriteArgs::new_empty(PrismaValue::Null);
+        args.insert(DatasourceFieldName(field.to_owned()), value);
+        args
+    }
+
+    #[test]
+    fn int_overflow_is_rejected() {
+        let model = test_model();
+        let args = args_with("age", WriteOperation::scalar_set(PrismaValue::Int(i64::from(i32::MAX) + 1)));
+
+        let err = args.validate_against(&projection(&model)).unwrap_err();
+        assert_eq!(err.field, "age");
+    }
+
+    #[test]
+    fn unknown_enum_variant_is_rejected() {
+        let model = test_model();
+        let args = args_with(
+            "role",
+            WriteOperation::scalar_set(PrismaValue::Enum("SUPERADMIN".to_owned())),
+        );
+
+        let err = args.validate_against(&projection(&model)).unwrap_err();
+        assert_eq!(err.field, "role");
+    }
+
+    #[test]
+    fn bytes_into_a_string_column_is_rejected() {
+        let model = test_model();
+        let args = args_with("code", WriteOperation::scalar_set(PrismaValue::Bytes(vec![1, 2, 3])));
+
+        let err = args.validate_against(&projection(&model)).unwrap_err();
+        assert_eq!(err.field, "code");
+    }
+
+    #[test]
+    fn string_exceeding_varchar_length_is_rejected() {
+        let model = test_model();
+        let args = args_with("code", WriteOperation::scalar_set(PrismaValue::String("too-long".to_owned())));
+
+        let err = args.validate_against(&projection(&model)).unwrap_err();
+        assert_eq!(err.field, "code");
+    }
+
+    #[test]
+    fn valid_args_pass() {
+        let model = test_model();
+        let mut args = args_with("age", WriteOperation::scalar_set(PrismaValue::Int(30)));
+        args.insert(
+            DatasourceFieldName("code".to_owned()),
+            WriteOperation::scalar_set(PrismaValue::String("ab".to_owned())),
+        );
+        args.insert(
+            DatasourceFieldName("role".to_owned()),
+            WriteOperation::scalar_set(PrismaValue::Enum("ADMIN".to_owned())),
+        );
+
+        assert!(args.validate_against(&projection(&model)).is_ok());
+    }
+}
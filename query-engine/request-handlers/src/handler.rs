@@ -115,20 +115,74 @@ impl<'a> RequestHandler<'a> {
         }
     }
 
+    /// Executes one [`CompactedDocument`] per distinct model/shape found in the original batch.
+    ///
+    /// Inside an interactive transaction (`tx_id` is `Some`), the documents share that transaction's
+    /// single connection and must run one after another. Outside of one, each document's synthesized
+    /// `findMany` is independent, so they run concurrently on separate pooled connections, the same way
+    /// `QueryExecutor::execute_all` fans out a non-transactional batch.
+    ///
+    /// Each document's [`CompactedDocument::original_indices`] records where its per-operation results
+    /// belong in the overall, pre-grouping batch response, so the final `PrismaResponse::Multi` is
+    /// ordered exactly as if compaction had never grouped anything by model.
     async fn handle_compacted(
         &self,
-        document: CompactedDocument,
+        documents: Vec<CompactedDocument>,
         tx_id: Option<TxId>,
         traceparent: Option<TraceParent>,
     ) -> PrismaResponse {
+        let total = documents.iter().map(|document| document.original_indices.len()).sum();
+
+        let group_results = if tx_id.is_some() {
+            let mut group_results = Vec::with_capacity(documents.len());
+            for document in documents {
+                group_results.push(self.handle_compacted_one(document, tx_id.clone(), traceparent).await);
+            }
+            group_results
+        } else {
+            futures::future::join_all(
+                documents
+                    .into_iter()
+                    .map(|document| self.handle_compacted_one(document, tx_id.clone(), traceparent)),
+            )
+            .await
+        };
+
+        let mut responses: Vec<Option<GQLResponse>> = std::iter::repeat_with(|| None).take(total).collect();
+
+        for (original_indices, group) in group_results {
+            for (original_index, response) in original_indices.into_iter().zip(group) {
+                responses[original_index] = Some(response);
+            }
+        }
+
+        let responses: Vec<GQLResponse> = responses
+            .into_iter()
+            .map(|response| response.expect("every compacted result should map back to an original batch index"))
+            .collect();
+
+        PrismaResponse::Multi(responses.into())
+    }
+
+    /// Executes a single [`CompactedDocument`]'s synthesized `findMany` and maps its results back to
+    /// the original `findUnique` operations it was compacted from. Returns the document's
+    /// [`CompactedDocument::original_indices`] alongside the per-operation responses (in the same
+    /// order), so the caller can place them at the right position in the overall batch response.
+    async fn handle_compacted_one(
+        &self,
+        document: CompactedDocument,
+        tx_id: Option<TxId>,
+        traceparent: Option<TraceParent>,
+    ) -> (Vec<usize>, Vec<GQLResponse>) {
         let plural_name = document.plural_name();
         let singular_name = document.single_name();
         let throw_on_empty = document.throw_on_empty();
         let keys: Vec<String> = document.keys;
         let arguments = document.arguments;
         let nested_selection = document.nested_selection;
+        let original_indices = document.original_indices;
 
-        match AssertUnwindSafe(self.handle_request(document.operation, tx_id, traceparent))
+        let results = match AssertUnwindSafe(self.handle_request(document.operation, tx_id, traceparent))
             .catch_unwind()
             .await
         {
@@ -197,14 +251,22 @@ impl<'a> RequestHandler<'a> {
                     })
                     .collect();
 
-                PrismaResponse::Multi(results.into())
+                results
             }
 
-            Ok(Err(err)) => PrismaResponse::Multi(GQLError::from_core_error(err).into()),
+            Ok(Err(err)) => {
+                let gql_error: GQLResponse = GQLError::from_core_error(err).into();
+                original_indices.iter().map(|_| gql_error.clone()).collect()
+            }
 
             // panicked
-            Err(err) => PrismaResponse::Multi(GQLError::from_panic_payload(err).into()),
-        }
+            Err(err) => {
+                let gql_error: GQLResponse = GQLError::from_panic_payload(err).into();
+                original_indices.iter().map(|_| gql_error.clone()).collect()
+            }
+        };
+
+        (original_indices, results)
     }
 
     async fn handle_request(
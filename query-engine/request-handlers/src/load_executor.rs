@@ -7,7 +7,6 @@ use query_core::{executor::InterpretingExecutor, Connector, QueryExecutor};
 use sql_query_connector::*;
 use std::collections::HashMap;
 use std::env;
-use std::marker::PhantomData;
 use std::sync::Arc;
 use url::Url;
 
@@ -16,7 +15,7 @@ pub enum ConnectorKind<'a> {
     Rust { url: String, datasource: &'a Datasource },
     Js {
         adapter: Arc<dyn ExternalConnector>,
-        _phantom: PhantomData<&'a ()>, // required for WASM target, where JS is the only variant and lifetime gets unused
+        datasource: &'a Datasource,
     },
 }
 
@@ -33,7 +32,7 @@ pub async fn load(
         }
 
         #[cfg(feature = "driver-adapters")]
-        ConnectorKind::Js { adapter, _phantom } => driver_adapter(adapter, features).await,
+        ConnectorKind::Js { adapter, datasource } => driver_adapter(adapter, datasource, features).await,
 
         #[cfg(native)]
         ConnectorKind::Rust { url, datasource } => {
@@ -69,11 +68,13 @@ pub async fn load(
 #[cfg(feature = "driver-adapters")]
 async fn driver_adapter(
     driver_adapter: Arc<dyn ExternalConnector>,
+    datasource: &Datasource,
     features: PreviewFeatures,
 ) -> Result<Box<dyn QueryExecutor + Send + Sync>, query_core::CoreError> {
     use quaint::connector::ExternalConnector;
 
-    let js = Js::new(driver_adapter, features).await?;
+    let required_schemas: Vec<String> = datasource.namespaces.iter().map(|(name, _)| name.clone()).collect();
+    let js = Js::new(driver_adapter, features, &required_schemas).await?;
     Ok(executor_for(js, false))
 }
 
@@ -6,7 +6,7 @@ use query_core::{
 
 use crate::HandlerError;
 
-#[derive(Debug, serde::Serialize, Default, PartialEq)]
+#[derive(Debug, Clone, serde::Serialize, Default, PartialEq)]
 pub struct GQLResponse {
     #[serde(skip_serializing_if = "IndexMap::is_empty")]
     pub data: Map,
@@ -31,7 +31,7 @@ pub struct GQLBatchResponse {
     pub extensions: Map,
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
 pub struct GQLError {
     error: String,
     user_facing_error: user_facing_errors::Error,
@@ -130,6 +130,15 @@ impl From<ResponseData> for GQLResponse {
     fn from(response: ResponseData) -> Self {
         let mut gql_response = GQLResponse::with_capacity(1);
 
+        if let Some(chunks) = response.chunks {
+            gql_response.set_extension("chunks".to_owned(), serde_json::json!(chunks));
+        }
+        if let Some(conflicts) = response.conflicts {
+            gql_response.set_extension("conflicts".to_owned(), serde_json::json!(conflicts));
+        }
+        if let Some(skipped) = response.skipped {
+            gql_response.set_extension("skipped".to_owned(), serde_json::json!(skipped));
+        }
         gql_response.insert_data(response.key, response.data);
         gql_response
     }
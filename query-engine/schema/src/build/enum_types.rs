@@ -1,6 +1,6 @@
 use super::*;
 use crate::EnumType;
-use constants::{filters, itx, json_null, load_strategy, ordering};
+use constants::{chunk_execution_policy, filters, itx, json_null, load_strategy, ordering};
 use psl::parser_database as db;
 use query_structure::prelude::ParentContainer;
 
@@ -111,6 +111,21 @@ pub fn itx_isolation_levels(ctx: &'_ QuerySchema) -> Option<EnumType> {
     Some(EnumType::string(ident, values))
 }
 
+/// Lets `updateMany`/`deleteMany` callers opt into [`query_structure::ChunkExecutionPolicy`]'s
+/// non-default behaviors when a write has to be split into more than one statement.
+pub(crate) fn chunk_execution_policy_enum() -> EnumType {
+    let ident = Identifier::new_prisma(IdentifierType::ChunkExecutionPolicy);
+
+    EnumType::string(
+        ident,
+        vec![
+            chunk_execution_policy::ATOMIC.to_owned(),
+            chunk_execution_policy::BEST_EFFORT.to_owned(),
+            chunk_execution_policy::FAIL_FAST.to_owned(),
+        ],
+    )
+}
+
 pub(crate) fn relation_load_strategy(ctx: &QuerySchema) -> Option<EnumType> {
     if !ctx.can_resolve_relation_with_joins() {
         return None;
@@ -30,6 +30,14 @@ pub(crate) fn relation_load_strategy_argument(ctx: &QuerySchema) -> Option<Input
     })
 }
 
+/// Builds "returnMinimal" argument for create/update fields. When set, the write only returns the
+/// primary identifier from the database, and any other client-requested field is fetched with a
+/// normal follow-up read instead of being selected back from the write itself. Useful to avoid
+/// round-tripping large columns (e.g. `bytea`/`text` blobs) that were just written.
+pub(crate) fn return_minimal_argument() -> InputField<'static> {
+    input_field(args::RETURN_MINIMAL, vec![InputType::boolean()], None).optional()
+}
+
 /// Builds "where" (unique) and "relationLoadStrategy" arguments for the findUnique field.
 pub(crate) fn find_unique_arguments(ctx: &QuerySchema, model: Model) -> Vec<InputField<'_>> {
     std::iter::once(where_unique_argument(ctx, model))
@@ -44,7 +52,8 @@ pub(crate) fn delete_one_arguments(ctx: &QuerySchema, model: Model) -> Vec<Input
         .collect()
 }
 
-/// Builds "where" (unique), "data" and "relationLoadStrategy" arguments intended for the update field.
+/// Builds "where" (unique), "data", "relationLoadStrategy" and "returnMinimal" arguments intended
+/// for the update field.
 pub(crate) fn update_one_arguments(ctx: &QuerySchema, model: Model) -> Vec<InputField<'_>> {
     let unique_arg = where_unique_argument(ctx, model.clone());
     let update_types = update_one_objects::update_one_input_types(ctx, model, None);
@@ -52,6 +61,7 @@ pub(crate) fn update_one_arguments(ctx: &QuerySchema, model: Model) -> Vec<Input
     let mut args = vec![input_field(args::DATA.to_owned(), update_types, None), unique_arg];
 
     args.extend(relation_load_strategy_argument(ctx));
+    args.push(return_minimal_argument());
 
     args
 }
@@ -73,26 +83,44 @@ pub(crate) fn upsert_arguments(ctx: &QuerySchema, model: Model) -> Vec<InputFiel
     args
 }
 
-/// Builds "where", "data" and "limit" arguments intended for the update many field.
+/// Builds "chunkExecutionPolicy" argument for the update/delete many fields.
+pub(crate) fn chunk_execution_policy_argument() -> InputField<'static> {
+    input_field(
+        args::CHUNK_EXECUTION_POLICY,
+        vec![InputType::Enum(enum_types::chunk_execution_policy_enum())],
+        None,
+    )
+    .optional()
+}
+
+/// Builds "where", "data", "orderBy", "limit" and "chunkExecutionPolicy" arguments intended for
+/// the update many field.
 pub(crate) fn update_many_arguments(ctx: &QuerySchema, model: Model) -> Vec<InputField<'_>> {
     let update_many_types = update_many_objects::update_many_input_types(ctx, model.clone(), None);
     let where_arg = where_argument(ctx, &model);
+    let order_by_arg = order_by_argument(ctx, model.clone().into(), OrderByOptions::new());
     let limit_arg = input_field(args::LIMIT, vec![InputType::int()], None).optional();
 
     vec![
         input_field(args::DATA.to_owned(), update_many_types, None),
         where_arg,
+        order_by_arg,
         limit_arg,
+        chunk_execution_policy_argument(),
     ]
 }
 
-/// Builds "where" and "limit" argument intended for the delete many field.
+/// Builds "where", "orderBy", "limit" and "chunkExecutionPolicy" argument intended for the delete
+/// many field.
 pub(crate) fn delete_many_arguments(ctx: &QuerySchema, model: Model) -> Vec<InputField<'_>> {
     let where_arg = where_argument(ctx, &model);
+    let order_by_arg = order_by_argument(ctx, model.clone().into(), OrderByOptions::new());
 
     vec![
         where_arg,
+        order_by_arg,
         input_field(args::LIMIT, vec![InputType::int()], None).optional(),
+        chunk_execution_policy_argument(),
     ]
 }
 
@@ -204,6 +232,7 @@ impl<'a> ManyRecordsSelectionArgumentsBuilder<'a> {
             include_relations: true,
             include_scalar_aggregations: false,
             include_full_text_search: self.ctx.can_full_text_search(),
+            include_input_order: true,
         };
 
         let mut args = vec![
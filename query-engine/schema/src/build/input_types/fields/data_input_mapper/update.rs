@@ -130,6 +130,7 @@ impl DataInputFieldMapper for UpdateDataInputFieldMapper {
 
             append_opt(&mut fields, input_fields::nested_update_many_field(ctx, rf.clone()));
             append_opt(&mut fields, input_fields::nested_delete_many_field(ctx, &rf));
+            append_opt(&mut fields, input_fields::nested_disconnect_many_field(ctx, &rf));
             fields
         });
 
@@ -233,6 +233,7 @@ fn full_scalar_filter_type(
                 .chain(inclusion_filters(ctx, mapped_scalar_type.clone(), nullable))
                 .chain(alphanumeric_filters(ctx, mapped_scalar_type.clone()))
                 .chain(string_filters(ctx, type_name.as_ref(), mapped_scalar_type.clone()))
+                .chain(ltree_filters(ctx, native_type_name, mapped_scalar_type.clone()))
                 .chain(query_mode_field(ctx, nested))
                 .collect(),
 
@@ -260,6 +261,8 @@ fn full_scalar_filter_type(
                     }
                 }
 
+                filters.extend(spatial_filters(ctx, native_type_name, mapped_scalar_type.clone()));
+
                 filters
             }
 
@@ -436,6 +439,60 @@ fn string_filters<'a>(
     string_filters.into_iter()
 }
 
+fn ltree_filters<'a>(
+    ctx: &'a QuerySchema,
+    native_type_name: Option<&str>,
+    mapped_type: InputType<'a>,
+) -> impl Iterator<Item = InputField<'a>> {
+    let fields = if ctx.can_filter_ltree() && native_type_name == Some("Ltree") {
+        vec![
+            simple_input_field(filters::ANCESTOR_OF, mapped_type.clone(), None).optional(),
+            simple_input_field(filters::DESCENDANT_OF, mapped_type.clone(), None).optional(),
+            simple_input_field(filters::MATCHES_LQUERY, mapped_type, None).optional(),
+        ]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
+/// Builds the `geoContains` and `within` filters for `Point`/`Geometry`-typed MySQL columns, which
+/// are mapped to the `Json` scalar type and carry their value as GeoJSON.
+fn spatial_filters<'a>(
+    ctx: &'a QuerySchema,
+    native_type_name: Option<&str>,
+    mapped_type: InputType<'a>,
+) -> impl Iterator<Item = InputField<'a>> {
+    let fields = if ctx.can_filter_spatial() && matches!(native_type_name, Some("Point") | Some("Geometry")) {
+        vec![
+            simple_input_field(filters::GEO_CONTAINS, mapped_type, None).optional(),
+            simple_input_field(filters::WITHIN, InputType::object(within_filter_object(ctx)), None).optional(),
+        ]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
+/// Builds the `within` filter's argument object: a GeoJSON reference `point` and a `distanceMeters`
+/// radius. Ordering by distance from the reference point is not supported yet and is tracked as a
+/// separate follow-up.
+fn within_filter_object(ctx: &'_ QuerySchema) -> InputObjectType<'_> {
+    let ident = Identifier::new_prisma(IdentifierType::WithinFilterInput);
+    let mut object = init_input_object_type(ident);
+
+    object.set_fields(move || {
+        vec![
+            simple_input_field(filters::POINT, InputType::json(), None),
+            simple_input_field(filters::DISTANCE_METERS, InputType::float(), None),
+        ]
+    });
+
+    object
+}
+
 fn json_filters(ctx: &'_ QuerySchema) -> impl Iterator<Item = InputField<'_>> {
     // TODO: also add json-specific "keys" filters
     // TODO: add json_type filter
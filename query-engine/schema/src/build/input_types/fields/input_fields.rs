@@ -124,6 +124,32 @@ pub(crate) fn nested_delete_many_field<'a>(
     }
 }
 
+/// Builds "disconnectMany" field for nested updates (on relation fields).
+///
+/// Unlike "disconnect", which requires the unique identifiers of the records to disconnect,
+/// "disconnectMany" accepts an arbitrary filter, disconnecting every record it matches among
+/// the ones currently related to the parent.
+pub(crate) fn nested_disconnect_many_field<'a>(
+    ctx: &'a QuerySchema,
+    parent_field: &RelationFieldRef,
+) -> Option<InputField<'a>> {
+    if parent_field.is_list() {
+        let input_object = filter_objects::scalar_filter_object_type(ctx, parent_field.related_model(), false);
+        let input_type = InputType::object(input_object);
+
+        Some(
+            input_field(
+                operations::DISCONNECT_MANY,
+                vec![input_type.clone(), InputType::list(input_type)],
+                None,
+            )
+            .optional(),
+        )
+    } else {
+        None
+    }
+}
+
 /// Builds "updateMany" field for nested updates (on relation fields).
 pub(crate) fn nested_update_many_field(ctx: &'_ QuerySchema, parent_field: RelationFieldRef) -> Option<InputField<'_>> {
     if parent_field.is_list() {
@@ -10,6 +10,7 @@ pub(crate) struct OrderByOptions {
     pub(crate) include_relations: bool,
     pub(crate) include_scalar_aggregations: bool,
     pub(crate) include_full_text_search: bool,
+    pub(crate) include_input_order: bool,
 }
 
 impl OrderByOptions {
@@ -71,6 +72,10 @@ pub(crate) fn order_by_object_type(
             append_opt(&mut fields, order_by_field_text_search(container.clone()))
         }
 
+        if options.include_input_order {
+            fields.push(order_by_field_input_order());
+        }
+
         fields
     });
     input_object
@@ -123,7 +128,14 @@ fn orderby_field_mapper<'a>(
         // To-one relation field.
         ModelField::Relation(rf) if options.include_relations => {
             let related_model = rf.related_model();
-            let related_object_type = order_by_object_type(ctx, related_model.into(), options);
+
+            // `_inputOrder` only makes sense against the root query's own `where`, so don't let it
+            // leak into a nested relation's order-by object.
+            let nested_options = OrderByOptions {
+                include_input_order: false,
+                ..options
+            };
+            let related_object_type = order_by_object_type(ctx, related_model.into(), nested_options);
 
             Some(simple_input_field(rf.name().to_owned(), InputType::object(related_object_type), None).optional())
         }
@@ -228,6 +240,14 @@ fn order_by_to_many_aggregate_object_type<'a>(container: &ParentContainer) -> In
     input_object
 }
 
+/// Builds the `_inputOrder` orderBy field: an opt-in boolean that, combined with a single `in`
+/// filter over one unique field, preserves the order of that filter's list in the result instead
+/// of ordering by the column's own value. Validated against the query's `where` at extraction
+/// time, since the order-by object itself has no visibility into the filter.
+fn order_by_field_input_order<'a>() -> InputField<'a> {
+    simple_input_field(ordering::UNDERSCORE_INPUT_ORDER, InputType::boolean(), None).optional()
+}
+
 fn order_by_field_text_search<'a>(container: ParentContainer) -> Option<InputField<'a>> {
     let scalar_fields: Vec<_> = container
         .fields()
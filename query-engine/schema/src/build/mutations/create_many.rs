@@ -13,7 +13,7 @@ pub(crate) fn create_many(ctx: &'_ QuerySchema, model: Model) -> OutputField<'_>
 
     field(
         field_name,
-        move || create_many_arguments(ctx, model),
+        move || create_many_arguments(ctx, model, true),
         OutputType::object(objects::affected_records_object_type()),
         Some(QueryInfo {
             model: Some(model_id),
@@ -30,7 +30,7 @@ pub(crate) fn create_many_and_return(ctx: &'_ QuerySchema, model: Model) -> Outp
 
     field(
         field_name,
-        move || create_many_arguments(ctx, model),
+        move || create_many_arguments(ctx, model, false),
         OutputType::list(InnerOutputType::Object(object_type)),
         Some(QueryInfo {
             model: Some(model_id),
@@ -66,18 +66,37 @@ pub(crate) fn create_many_and_return_output_type(ctx: &'_ QuerySchema, model: Mo
     obj
 }
 
-/// Builds "skip_duplicates" and "data" arguments intended for the create many field.
-pub(crate) fn create_many_arguments(ctx: &'_ QuerySchema, model: Model) -> Vec<InputField<'_>> {
+/// Builds "data", "skipDuplicates", "returnSkipped" and (for plain `createMany`, not
+/// `createManyAndReturn`) "collectErrors" arguments intended for the create many field.
+pub(crate) fn create_many_arguments(
+    ctx: &'_ QuerySchema,
+    model: Model,
+    include_collect_errors: bool,
+) -> Vec<InputField<'_>> {
     let create_many_type = InputType::object(create_many_object_type(ctx, model, None));
     let data_arg = input_field(args::DATA, list_union_type(create_many_type, true), None);
 
+    let mut args = vec![data_arg];
+
     if ctx.has_capability(ConnectorCapability::CreateSkipDuplicates) {
-        let skip_arg = input_field(args::SKIP_DUPLICATES, vec![InputType::boolean()], None).optional();
+        args.push(input_field(args::SKIP_DUPLICATES, vec![InputType::boolean()], None).optional());
 
-        vec![data_arg, skip_arg]
-    } else {
-        vec![data_arg]
+        // Reports which rows `skipDuplicates` skipped instead of only reporting how many were
+        // inserted. Like "collectErrors", `createManyAndReturn` has no representation for "this
+        // row was skipped" alongside the rows it successfully returns, so it's withheld there too.
+        if include_collect_errors {
+            args.push(input_field(args::RETURN_SKIPPED, vec![InputType::boolean()], None).optional());
+        }
+    }
+
+    // Reports per-row unique/null constraint conflicts instead of aborting the whole batch on the
+    // first one. `createManyAndReturn` has no representation for "this row failed" alongside the
+    // rows it successfully returns, so the argument is withheld there.
+    if include_collect_errors {
+        args.push(input_field(args::COLLECT_ERRORS, vec![InputType::boolean()], None).optional());
     }
+
+    args
 }
 
 // Create many data input type.
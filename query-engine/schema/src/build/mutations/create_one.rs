@@ -37,6 +37,7 @@ pub(crate) fn create_one_arguments(ctx: &QuerySchema, model: Model) -> Vec<Input
 
     std::iter::once(data_field)
         .chain(arguments::relation_load_strategy_argument(ctx))
+        .chain(std::iter::once(arguments::return_minimal_argument()))
         .collect()
 }
 
@@ -59,7 +59,7 @@ pub(crate) fn group_by_output_object_type(ctx: &'_ QuerySchema, model: Model) ->
                 UNDERSCORE_SUM,
                 &model,
                 numeric_fields,
-                field::map_scalar_output_type_for_field,
+                field_sum_output_type,
                 identity,
                 false,
             ),
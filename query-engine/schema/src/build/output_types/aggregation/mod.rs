@@ -6,10 +6,22 @@ pub(crate) mod plain;
 
 fn field_avg_output_type(ctx: &'_ QuerySchema, field: ScalarField) -> OutputType<'_> {
     match field.type_identifier() {
-        TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float => {
-            OutputType::non_list(OutputType::float())
+        TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Decimal => {
+            OutputType::non_list(OutputType::decimal())
         }
-        TypeIdentifier::Decimal => OutputType::non_list(OutputType::decimal()),
+        _ => field::map_scalar_output_type_for_field(ctx, field),
+    }
+}
+
+/// `_sum` promotes its result type relative to the summed field, since the sum of many values can
+/// exceed the range the field itself is declared to hold: `Int` (32-bit) promotes to `BigInt`
+/// (64-bit), and `BigInt` promotes to `Decimal` (arbitrary precision), matching the widening the
+/// database itself performs (e.g. Postgres' `sum(int4)` returns `bigint`, `sum(int8)` returns
+/// `numeric`).
+fn field_sum_output_type(ctx: &'_ QuerySchema, field: ScalarField) -> OutputType<'_> {
+    match field.type_identifier() {
+        TypeIdentifier::Int => OutputType::non_list(OutputType::bigint()),
+        TypeIdentifier::BigInt => OutputType::non_list(OutputType::decimal()),
         _ => field::map_scalar_output_type_for_field(ctx, field),
     }
 }
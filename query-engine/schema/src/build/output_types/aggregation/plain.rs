@@ -57,7 +57,7 @@ pub(crate) fn aggregation_object_type(ctx: &'_ QuerySchema, model: Model) -> Obj
                 UNDERSCORE_SUM,
                 &model,
                 numeric_fields,
-                field::map_scalar_output_type_for_field,
+                field_sum_output_type,
                 identity,
                 false,
             ),
@@ -2,6 +2,7 @@ pub mod args {
     pub const WHERE: &str = "where";
     pub const DATA: &str = "data";
     pub const RELATION_LOAD_STRATEGY: &str = "relationLoadStrategy";
+    pub const CHUNK_EXECUTION_POLICY: &str = "chunkExecutionPolicy";
 
     // upsert args
     pub const CREATE: &str = "create";
@@ -23,9 +24,14 @@ pub mod args {
 
     // createMany-specific args
     pub const SKIP_DUPLICATES: &str = "skipDuplicates";
+    pub const COLLECT_ERRORS: &str = "collectErrors";
+    pub const RETURN_SKIPPED: &str = "returnSkipped";
 
     // deleteMany-specific args
     pub const LIMIT: &str = "limit";
+
+    // create/update-specific args
+    pub const RETURN_MINIMAL: &str = "returnMinimal";
 }
 
 pub mod operations {
@@ -35,6 +41,7 @@ pub mod operations {
     pub const CREATE_MANY: &str = "createMany";
     pub const CONNECT_OR_CREATE: &str = "connectOrCreate";
     pub const DISCONNECT: &str = "disconnect";
+    pub const DISCONNECT_MANY: &str = "disconnectMany";
     pub const UPDATE: &str = "update";
     pub const UPDATE_MANY: &str = "updateMany";
     pub const DELETE: &str = "delete";
@@ -69,6 +76,11 @@ pub mod filters {
     pub const UNDERSCORE_REF: &str = "_ref";
     pub const UNDERSCORE_CONTAINER: &str = "_container";
 
+    // ltree filters (Postgres only)
+    pub const ANCESTOR_OF: &str = "ancestorOf";
+    pub const DESCENDANT_OF: &str = "descendantOf";
+    pub const MATCHES_LQUERY: &str = "matchesLquery";
+
     // legacy filter
     pub const NOT_IN: &str = "notIn";
 
@@ -110,6 +122,12 @@ pub mod filters {
     pub const STRING_STARTS_WITH: &str = "string_starts_with";
     pub const STRING_ENDS_WITH: &str = "string_ends_with";
     pub const JSON_TYPE: &str = "json_type";
+
+    // spatial filters (MySQL only)
+    pub const GEO_CONTAINS: &str = "geoContains";
+    pub const WITHIN: &str = "within";
+    pub const POINT: &str = "point";
+    pub const DISTANCE_METERS: &str = "distanceMeters";
 }
 
 pub mod aggregations {
@@ -140,6 +158,10 @@ pub mod ordering {
     pub const SORT: &str = "sort";
     pub const NULLS: &str = "nulls";
     pub const FIELDS: &str = "fields";
+
+    /// Orders results by their position in the list of a single `in` filter over one unique
+    /// field, e.g. preserving `where: { id: { in: [3, 1, 2] } }`'s order instead of the column's.
+    pub const UNDERSCORE_INPUT_ORDER: &str = "_inputOrder";
 }
 
 pub mod json_null {
@@ -175,3 +197,9 @@ pub mod load_strategy {
     pub const JOIN: &str = "join";
     pub const QUERY: &str = "query";
 }
+
+pub mod chunk_execution_policy {
+    pub const ATOMIC: &str = "atomic";
+    pub const BEST_EFFORT: &str = "best_effort";
+    pub const FAIL_FAST: &str = "fail_fast";
+}
@@ -75,12 +75,15 @@ impl DatabaseEnumType {
         &self.identifier
     }
 
+    /// Maps an input value to its canonical database value, accepting both the value's declared
+    /// name and any `@alias` it was given (e.g. for zero-downtime renames: old clients keep
+    /// sending the old name as an alias of the renamed value until they are upgraded).
     pub fn map_input_value(&self, val: &str) -> Option<PrismaValue> {
         Some(PrismaValue::Enum(
             self.internal_enum
                 .walker()
                 .values()
-                .find(|ev| ev.name() == val)?
+                .find(|ev| ev.name() == val || ev.alias() == Some(val))?
                 .database_name()
                 .to_owned(),
         ))
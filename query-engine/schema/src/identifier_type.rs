@@ -35,6 +35,7 @@ pub enum IdentifierType {
     OrderByRelevanceInput(ParentContainer),
     OrderByToManyAggregateInput(ParentContainer),
     RelationCreateInput(RelationField, RelationField, bool),
+    ChunkExecutionPolicy,
     RelationLoadStrategy,
     RelationUpdateInput(RelationField, RelationField, bool),
     ScalarFieldEnum(Model),
@@ -53,6 +54,7 @@ pub enum IdentifierType {
     UpdateToOneRelWhereCombinationInput(RelationField),
     UpdateManyAndReturnOutput(Model),
     WhereInput(ParentContainer),
+    WithinFilterInput,
     WhereUniqueInput(Model),
     Raw(String),
 }
@@ -213,6 +215,7 @@ impl std::fmt::Display for IdentifierType {
             IdentifierType::WhereInput(container) => {
                 write!(f, "{}WhereInput", container.name())
             }
+            IdentifierType::WithinFilterInput => f.write_str("WithinFilter"),
             IdentifierType::WhereUniqueInput(model) => {
                 write!(f, "{}WhereUniqueInput", model.name())
             }
@@ -311,6 +314,7 @@ impl std::fmt::Display for IdentifierType {
                 ),
                 _ => write!(f, "{}UncheckedUpdateManyInput", model.name()),
             },
+            IdentifierType::ChunkExecutionPolicy => write!(f, "ChunkExecutionPolicy"),
             IdentifierType::RelationLoadStrategy => write!(f, "RelationLoadStrategy"),
             IdentifierType::UpdateManyAndReturnOutput(model) => {
                 write!(f, "UpdateMany{}AndReturnOutputType", model.name())
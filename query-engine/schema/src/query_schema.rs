@@ -15,6 +15,18 @@ enum Operation {
 
 type LazyField = Box<dyn for<'a> Fn(&'a QuerySchema) -> OutputField<'a> + Send + Sync>;
 
+// Note on caching `InputObjectType`/`ObjectType` trees across requests: every reference to, say,
+// a model's `WhereInput` (e.g. `filter_objects::where_object_type`) builds a brand new
+// `InputObjectType<'a>` whose lazily-evaluated field list is only memoized for the lifetime of
+// that one value (see `Arc<LazyLock<..>>` on `InputObjectType`/`ObjectType`), not shared across
+// the many call sites that construct "the same" type by name. That duplicate work is real, but
+// sharing it would mean storing `InputObjectType<'a>`/`ObjectType<'a>` - which borrow `&'a
+// QuerySchema` - inside `QuerySchema` itself, a self-referential struct that safe Rust can't
+// express without restructuring this type around `Arc<Self>` throughout the builder. `query_fields`
+// and `mutation_fields` above sidestep the problem by being computed once in `new()`, before any
+// `&'a QuerySchema` borrow exists; a general by-identifier cache for nested input/output objects
+// doesn't have that luxury and needs a bigger lifetime redesign than a single change here.
+
 /// The query schema defines which operations (query/mutations) are possible on a database, based
 /// on a Prisma schema.
 ///
@@ -112,6 +124,33 @@ impl QuerySchema {
             && self.has_capability(ConnectorCapability::NativeFullTextSearch)
     }
 
+    pub(crate) fn can_filter_ltree(&self) -> bool {
+        self.has_capability(ConnectorCapability::LtreeFilters)
+    }
+
+    pub(crate) fn can_filter_spatial(&self) -> bool {
+        self.has_capability(ConnectorCapability::SpatialFiltering)
+    }
+
+    /// Whether the active connector can generate `@default(uuid(7))` values itself, so the engine
+    /// should leave the column out of the `INSERT` instead of generating a value.
+    ///
+    /// Unlike most capabilities, this can't be declared statically on the connector: Postgres only
+    /// gains `uuid_generate_v7()` once the third-party `pg_uuidv7` extension is installed, and
+    /// introspection doesn't probe `pg_proc`/`pg_extension` today to detect that. Until that
+    /// detection exists, a user declaring the extension in the datasource's `extensions = [...]`
+    /// list is treated as their opt-in.
+    pub fn can_generate_uuid_v7_server_side(&self) -> bool {
+        self.internal_data_model
+            .schema
+            .configuration
+            .datasources
+            .first()
+            .and_then(|ds| ds.downcast_connector_data::<psl::builtin_connectors::PostgresDatasourceProperties>())
+            .and_then(|props| props.extensions())
+            .is_some_and(|extensions| extensions.find_by_name("pg_uuidv7").is_some())
+    }
+
     /// Returns whether the loaded connector supports the join strategy.
     pub fn can_resolve_relation_with_joins(&self) -> bool {
         !matches!(self.join_strategy_support(), JoinStrategySupport::No)